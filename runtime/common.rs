@@ -0,0 +1,321 @@
+// The part of the runtime that runtime/stub.rs and the jit of src/jit.rs
+// share: the representation of snake values, printing them, parsing the
+// input, the messages of the errors and the garbage collector. Both
+// include! this file, next to the codes of runtime/error_codes.rs.
+
+#[repr(C)]
+#[derive(PartialEq, Eq, Copy, Clone)]
+struct SnakeVal(u64);
+
+static INT_TAG_MASK: u64 = 0x00_00_00_00_00_00_00_03;
+static FLOAT_TAG: u64 = 0x00_00_00_00_00_00_00_02;
+static PTR_TAG_MASK: u64 = 0x00_00_00_00_00_00_00_07;
+static TUPLE_TAG: u64 = 0x00_00_00_00_00_00_00_01;
+static CLOSURE_TAG: u64 = 0x00_00_00_00_00_00_00_05;
+static STRING_TAG: u64 = 0x00_00_00_00_00_00_00_03;
+static HEAP_SIZE: usize = 1 << 20; // in words, for each of the two semispaces
+static FORWARDED_BIT: u64 = 0x80_00_00_00_00_00_00_00;
+static SNAKE_TRU: SnakeVal = SnakeVal(0xFF_FF_FF_FF_FF_FF_FF_FF);
+static SNAKE_FLS: SnakeVal = SnakeVal(0x7F_FF_FF_FF_FF_FF_FF_FF);
+
+// What readnum() and readbool() return on a line that isn't a value of
+// their type, the compiled code then fails with INPUT_ERROR
+static READ_FAILED: SnakeVal = SnakeVal(0x00_00_00_00_00_00_00_07);
+
+// reinterprets the bytes of an unsigned number to a signed number
+fn unsigned_to_signed(x: u64) -> i64 {
+    i64::from_le_bytes(x.to_le_bytes())
+}
+
+// Floats are printed with 15 significant digits, so that the bits lost to
+// the tag do not show up in the output
+fn sprint_float(x: f64) -> String {
+    let rounded: f64 = format!("{:.14e}", x).parse().unwrap();
+    format!("{:?}", rounded)
+}
+
+fn is_float(x: SnakeVal) -> bool {
+    x.0 & INT_TAG_MASK == FLOAT_TAG
+}
+
+fn is_tuple(x: SnakeVal) -> bool {
+    x.0 & PTR_TAG_MASK == TUPLE_TAG
+}
+
+fn is_closure(x: SnakeVal) -> bool {
+    x.0 & PTR_TAG_MASK == CLOSURE_TAG
+}
+
+fn is_string(x: SnakeVal) -> bool {
+    x.0 & PTR_TAG_MASK == STRING_TAG
+}
+
+// The bytes of the string x, which is [length in bytes, bytes...]
+unsafe fn str_bytes<'a>(x: SnakeVal) -> &'a [u8] {
+    let addr = (x.0 - STRING_TAG) as *const u64;
+    std::slice::from_raw_parts(addr.add(1) as *const u8, *addr as usize)
+}
+
+// Writes the string of (bytes) at dest, padding its last word with zeros,
+// and returns it. The compiled code made room for it before the call.
+unsafe fn write_str(dest: *mut u64, bytes: &[u8]) -> SnakeVal {
+    let words = bytes.len().div_ceil(8);
+    *dest = bytes.len() as u64;
+    std::ptr::write_bytes(dest.add(1), 0, words);
+    std::ptr::copy_nonoverlapping(bytes.as_ptr(), dest.add(1) as *mut u8, bytes.len());
+    SnakeVal(dest as u64 | STRING_TAG)
+}
+
+// A string inside of a tuple is printed as its literal
+fn quote(bytes: &[u8]) -> String {
+    let mut out = String::from("\"");
+    for c in String::from_utf8_lossy(bytes).chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+// describes the kind of value for error messages
+fn kind_of(x: SnakeVal) -> &'static str {
+    if x.0 & INT_TAG_MASK == 0 {
+        "an integer"
+    } else if is_float(x) {
+        "a floating point"
+    } else if is_tuple(x) {
+        "a tuple"
+    } else if is_closure(x) {
+        "a function"
+    } else if is_string(x) {
+        "a string"
+    } else {
+        "a boolean"
+    }
+}
+
+fn sprint_snake_val(x: SnakeVal) -> String {
+    sprint_snake_val_loop(x, &mut Vec::new())
+}
+
+// parents holds the tuples we are currently inside of, so that cyclic
+// tuples are printed as <loop> instead of forever
+fn sprint_snake_val_loop(x: SnakeVal, parents: &mut Vec<u64>) -> String {
+    if is_tuple(x) {
+        if parents.contains(&x.0) {
+            return String::from("<loop>");
+        }
+        parents.push(x.0);
+        let addr = (x.0 - TUPLE_TAG) as *const u64;
+        let len = unsafe { *addr } as usize;
+        let elems: Vec<String> = (0..len)
+            .map(|i| sprint_snake_val_loop(SnakeVal(unsafe { *addr.add(i + 1) }), parents))
+            .collect();
+        parents.pop();
+        if len == 1 {
+            format!("({},)", elems[0])
+        } else {
+            format!("({})", elems.join(", "))
+        }
+    } else if is_closure(x) {
+        String::from("<closure>")
+    } else if is_string(x) {
+        quote(unsafe { str_bytes(x) })
+    } else if x.0 & INT_TAG_MASK == 0 {
+        // it's an integer
+        format!("{}", unsigned_to_signed(x.0) >> 2)
+    } else if is_float(x) {
+        sprint_float(f64::from_bits(x.0 & !INT_TAG_MASK))
+    } else if x == SNAKE_TRU {
+        String::from("true")
+    } else if x == SNAKE_FLS {
+        String::from("false")
+    } else {
+        format!("Invalid snake value 0x{:x}", x.0)
+    }
+}
+
+// x as print writes it, without the newline: a string on its own as its
+// bytes, even if they aren't valid UTF-8
+fn printed_bytes(x: SnakeVal) -> Vec<u8> {
+    if is_string(x) {
+        unsafe { str_bytes(x) }.to_vec()
+    } else {
+        sprint_snake_val(x).into_bytes()
+    }
+}
+
+fn parse_num(s: &str) -> Option<SnakeVal> {
+    match s.parse::<i64>() {
+        Ok(n) if (-(1 << 61)..(1 << 61)).contains(&n) => Some(SnakeVal((n << 2) as u64)),
+        _ => None,
+    }
+}
+
+fn parse_bool(s: &str) -> Option<SnakeVal> {
+    match s {
+        "true" => Some(SNAKE_TRU),
+        "false" => Some(SNAKE_FLS),
+        _ => None,
+    }
+}
+
+// The input of the program from its command-line argument, false without
+// one
+fn parse_input(arg: Option<&str>) -> Result<SnakeVal, String> {
+    match arg.map(str::trim) {
+        None => Ok(SNAKE_FLS),
+        Some(arg) => match parse_num(arg).or_else(|| parse_bool(arg)) {
+            Some(v) => Ok(v),
+            None => Err(format!("input expected an integer or a boolean but got {}", quote(arg.as_bytes()))),
+        },
+    }
+}
+
+// Parses the next line of (input), without the surrounding whitespace, or
+// returns the message of the INPUT_ERROR
+fn read_value(input: &mut dyn std::io::BufRead, who: &str, expected: &str, parse: fn(&str) -> Option<SnakeVal>) -> Result<SnakeVal, String> {
+    let mut line = Vec::new();
+    // a stdin that fails has nothing more to read
+    let got = match input.read_until(b'\n', &mut line) {
+        Ok(0) | Err(_) => String::from("the end of the input"),
+        Ok(_) => {
+            let line = String::from_utf8_lossy(&line);
+            match parse(line.trim()) {
+                Some(v) => return Ok(v),
+                None => quote(line.trim().as_bytes()),
+            }
+        }
+    };
+    Err(format!("{} expected {} but got {}", who, expected, got))
+}
+
+// The message of the error (err_code) about (v). An INPUT_ERROR about
+// READ_FAILED has the message read_value returned instead.
+fn error_message(err_code: ErrorCode, v: SnakeVal) -> String {
+    if err_code == ARITH_ERROR {
+        format!("arithmetic expected a number but got {} {}", kind_of(v), sprint_snake_val(v))
+    } else if err_code == CMP_ERROR {
+        format!("comparison expected an integer but got {} {}", kind_of(v), sprint_snake_val(v))
+    } else if err_code == IF_ERROR && is_tuple(v) {
+        format!("if expected a boolean but got a tuple {}", sprint_snake_val(v))
+    } else if err_code == IF_ERROR && is_string(v) {
+        format!("if expected a boolean but got a string {}", sprint_snake_val(v))
+    } else if err_code == IF_ERROR {
+        format!("if expected a boolean but got a number {}", sprint_snake_val(v))
+    } else if err_code == LOGIC_ERROR && is_tuple(v) {
+        format!("logic expected a boolean but got a tuple {}", sprint_snake_val(v))
+    } else if err_code == LOGIC_ERROR && is_string(v) {
+        format!("logic expected a boolean but got a string {}", sprint_snake_val(v))
+    } else if err_code == LOGIC_ERROR {
+        format!("logic expected a boolean but got a number {}", sprint_snake_val(v))
+    } else if err_code == OVFL_ERROR {
+        String::from("overflow")
+    } else if err_code == DIV_ZERO_ERROR {
+        String::from("divided by zero")
+    } else if err_code == INDEX_NOT_TUPLE_ERROR {
+        format!("indexing expected a tuple but got {} {}", kind_of(v), sprint_snake_val(v))
+    } else if err_code == INDEX_NOT_INT_ERROR {
+        format!("index expected an integer but got {} {}", kind_of(v), sprint_snake_val(v))
+    } else if err_code == INDEX_BOUNDS_ERROR {
+        format!("index out of bounds {}", sprint_snake_val(v))
+    } else if err_code == CALL_NOT_CLOSURE_ERROR {
+        format!("call expected a function but got {} {}", kind_of(v), sprint_snake_val(v))
+    } else if err_code == ARITY_ERROR {
+        let arity = unsafe { *((v.0 - CLOSURE_TAG) as *const u64) };
+        format!("function of arity {} called with wrong number of arguments", arity)
+    } else if err_code == OOM_ERROR {
+        String::from("out of memory")
+    } else if err_code == STRING_ERROR {
+        format!("string operation expected a string but got {} {}", kind_of(v), sprint_snake_val(v))
+    } else if err_code == TONUM_ERROR {
+        format!("tonum expected the digits of an integer but got {}", sprint_snake_val(v))
+    } else if err_code == INPUT_ERROR {
+        // typed code checks that the input has its inferred type, an int or a bool
+        let expected = if kind_of(v) == "a boolean" { "an integer" } else { "a boolean" };
+        format!("input expected {} but got {} {}", expected, kind_of(v), sprint_snake_val(v))
+    } else if err_code == INT_ARITH_ERROR {
+        format!("integer arithmetic expected an integer but got {} {}", kind_of(v), sprint_snake_val(v))
+    } else {
+        format!("I apologize to you, dear user. I made a bug. Here's a snake value: {}", sprint_snake_val(v))
+    }
+}
+
+fn is_heap_object(x: SnakeVal) -> bool {
+    is_tuple(x) || is_closure(x) || is_string(x)
+}
+
+// Copies the object x points to into to-space at free, unless that already
+// happened, and returns the value pointing to the copy. The header of a
+// copied object is overwritten with the new value and FORWARDED_BIT, which
+// no tuple length, closure arity or string length has set.
+unsafe fn forward(x: SnakeVal, free: &mut *mut u64, copied: &mut Vec<SnakeVal>) -> SnakeVal {
+    if !is_heap_object(x) {
+        return x;
+    }
+    let tag = x.0 & PTR_TAG_MASK;
+    let addr = (x.0 - tag) as *mut u64;
+    let header = *addr;
+    if header & FORWARDED_BIT != 0 {
+        return SnakeVal(header & !FORWARDED_BIT);
+    }
+    // tuples are [len, elems...], closures are [arity, code address, env],
+    // strings are [length in bytes, bytes...]
+    let size = if tag == TUPLE_TAG {
+        header as usize + 1
+    } else if tag == STRING_TAG {
+        (header as usize).div_ceil(8) + 1
+    } else {
+        3
+    };
+    std::ptr::copy_nonoverlapping(addr, *free, size);
+    let new_x = SnakeVal(*free as u64 | tag);
+    *free = free.add(size);
+    *addr = new_x.0 | FORWARDED_BIT;
+    copied.push(new_x);
+    new_x
+}
+
+// Forwards the snake values stored in the words [start, end)
+unsafe fn forward_words(start: *mut u64, end: *mut u64, free: &mut *mut u64, copied: &mut Vec<SnakeVal>) {
+    let mut slot = start;
+    while slot < end {
+        *slot = forward(SnakeVal(*slot), free, copied).0;
+        slot = slot.add(1);
+    }
+}
+
+// A copying collector, which copies the live objects into (to_space) and
+// returns the new heap pointer. The roots are the stack slots of every
+// snake frame: the innermost frame spans [stack_pointer, base_pointer),
+// each caller's frame starts above the saved base pointer and the return
+// address, and start_here marks the bottom of the stack with a null base
+// pointer. The caller then swaps the semispaces.
+unsafe fn copy_live(base_pointer: *mut u64, stack_pointer: *mut u64, to_space: *mut u64) -> *mut u64 {
+    let mut free = to_space;
+    let mut copied: Vec<SnakeVal> = Vec::new();
+
+    let mut top = stack_pointer;
+    let mut base = base_pointer;
+    while !base.is_null() {
+        forward_words(top, base, &mut free, &mut copied);
+        top = base.add(2);
+        base = *base as *mut u64;
+    }
+
+    // copy whatever the copied objects point to, strings point to nothing
+    while let Some(x) = copied.pop() {
+        let addr = (x.0 & !PTR_TAG_MASK) as *mut u64;
+        if is_tuple(x) {
+            forward_words(addr.add(1), addr.add(*addr as usize + 1), &mut free, &mut copied);
+        } else if is_closure(x) {
+            forward_words(addr.add(2), addr.add(3), &mut free, &mut copied);
+        }
+    }
+    free
+}
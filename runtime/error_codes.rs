@@ -0,0 +1,19 @@
+// The codes snake_error is called with. The compiler, runtime/stub.rs and
+// the jit all include! this file.
+pub type ErrorCode = u64;
+pub static ARITH_ERROR: ErrorCode = 0;
+pub static CMP_ERROR:   ErrorCode = 1;
+pub static IF_ERROR:    ErrorCode = 2;
+pub static LOGIC_ERROR: ErrorCode = 3;
+pub static OVFL_ERROR:  ErrorCode = 4;
+pub static DIV_ZERO_ERROR: ErrorCode = 5;
+pub static INDEX_NOT_TUPLE_ERROR: ErrorCode = 6;
+pub static INDEX_NOT_INT_ERROR: ErrorCode = 7;
+pub static INDEX_BOUNDS_ERROR: ErrorCode = 8;
+pub static CALL_NOT_CLOSURE_ERROR: ErrorCode = 9;
+pub static ARITY_ERROR: ErrorCode = 10;
+pub static OOM_ERROR: ErrorCode = 11;
+pub static STRING_ERROR: ErrorCode = 12;
+pub static TONUM_ERROR: ErrorCode = 13;
+pub static INPUT_ERROR: ErrorCode = 14;
+pub static INT_ARITH_ERROR: ErrorCode = 15;
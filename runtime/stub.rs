@@ -1,70 +1,137 @@
-#[repr(C)]
-#[derive(PartialEq, Eq, Copy, Clone)]
-struct SnakeVal(u64);
+// The code shared with the jit of src/jit.rs
+include!("error_codes.rs");
+include!("common.rs");
 
-static BOOL_TAG: u64 = 0x00_00_00_00_00_00_00_01;
-static SNAKE_TRU: SnakeVal = SnakeVal(0xFF_FF_FF_FF_FF_FF_FF_FF);
-static SNAKE_FLS: SnakeVal = SnakeVal(0x7F_FF_FF_FF_FF_FF_FF_FF);
-
-#[link(name = "compiled_code", kind = "static")]
+// The runtime is built once as a static library and linked with the
+// object of each program, which defines start_here
 extern "sysv64" {
 
     // The \x01 here is an undocumented feature of LLVM that ensures
     // it does not add an underscore in front of the name.
     #[link_name = "\x01start_here"]
-    fn start_here() -> SnakeVal;
+    fn start_here(heap: *mut u64, input: SnakeVal) -> SnakeVal;
 }
 
-// reinterprets the bytes of an unsigned number to a signed number
-fn unsigned_to_signed(x: u64) -> i64 {
-    i64::from_le_bytes(x.to_le_bytes())
-}
+// The heap is split into two semispaces. The compiled code allocates in
+// FROM_SPACE and calls snake_try_gc once the heap pointer would cross
+// HEAP_END, which copies the live objects into TO_SPACE and swaps the two.
+#[export_name = "\x01HEAP_END"]
+static mut HEAP_END: *const u64 = 0 as *const u64;
+static mut FROM_SPACE: *mut u64 = 0 as *mut u64;
+static mut TO_SPACE: *mut u64 = 0 as *mut u64;
 
-fn sprint_snake_val(x: SnakeVal) -> String {
-    if x.0 & BOOL_TAG == 0 {
-        // it's a number
-        format!("{}", unsigned_to_signed(x.0) >> 1)
-    } else if x == SNAKE_TRU {
-        String::from("true")
-    } else if x == SNAKE_FLS {
-        String::from("false")
-    } else {
-        format!("Invalid snake value 0x{:x}", x.0)
-    }
+// Prints x followed by a newline
+fn write_snake_val(x: SnakeVal) {
+    use std::io::Write;
+    let mut out = std::io::stdout();
+    out.write_all(&printed_bytes(x)).unwrap();
+    out.write_all(b"\n").unwrap();
 }
 
 #[export_name = "\x01print_snake_val"]
 extern "sysv64" fn print_snake_val(v: SnakeVal) -> SnakeVal {
-    println!("{}", sprint_snake_val(v.clone()));
+    write_snake_val(v);
     return v
 }
 
-type ErrorCode = u64;
-static ARITH_ERROR: ErrorCode = 0;
-static CMP_ERROR:   ErrorCode = 1;
-static IF_ERROR:    ErrorCode = 2;
-static LOGIC_ERROR: ErrorCode = 3;
-static OVFL_ERROR:  ErrorCode = 4;
+#[export_name = "\x01snake_str_concat"]
+extern "sysv64" fn snake_str_concat(dest: *mut u64, a: SnakeVal, b: SnakeVal) -> SnakeVal {
+    unsafe { write_str(dest, &[str_bytes(a), str_bytes(b)].concat()) }
+}
+
+// start and end are integers the compiled code checked the bounds of
+#[export_name = "\x01snake_str_slice"]
+extern "sysv64" fn snake_str_slice(dest: *mut u64, s: SnakeVal, start: SnakeVal, end: SnakeVal) -> SnakeVal {
+    let start = (start.0 >> 2) as usize;
+    let end = (end.0 >> 2) as usize;
+    unsafe { write_str(dest, &str_bytes(s)[start..end]) }
+}
+
+// The number n as it is printed, in at most 32 bytes
+#[export_name = "\x01snake_num_to_str"]
+extern "sysv64" fn snake_num_to_str(dest: *mut u64, n: SnakeVal) -> SnakeVal {
+    unsafe { write_str(dest, sprint_snake_val(n).as_bytes()) }
+}
+
+// The integer written in s, or s itself if it is not one
+#[export_name = "\x01snake_str_to_num"]
+extern "sysv64" fn snake_str_to_num(s: SnakeVal) -> SnakeVal {
+    let n = std::str::from_utf8(unsafe { str_bytes(s) }).ok().and_then(|s| s.parse::<i64>().ok());
+    match n {
+        Some(n) if (-(1 << 61)..(1 << 61)).contains(&n) => SnakeVal((n << 2) as u64),
+        _ => s,
+    }
+}
+
+#[export_name = "\x01snake_str_equal"]
+extern "sysv64" fn snake_str_equal(a: SnakeVal, b: SnakeVal) -> SnakeVal {
+    if unsafe { str_bytes(a) == str_bytes(b) } { SNAKE_TRU } else { SNAKE_FLS }
+}
+
+// the message of the INPUT_ERROR the last read failed with
+static READ_ERROR: std::sync::Mutex<String> = std::sync::Mutex::new(String::new());
+
+fn read_input(who: &str, expected: &str, parse: fn(&str) -> Option<SnakeVal>) -> SnakeVal {
+    match read_value(&mut std::io::stdin().lock(), who, expected, parse) {
+        Ok(v) => v,
+        Err(msg) => {
+            *READ_ERROR.lock().unwrap() = msg;
+            READ_FAILED
+        }
+    }
+}
+
+#[export_name = "\x01snake_read_num"]
+extern "sysv64" fn snake_read_num() -> SnakeVal {
+    read_input("readnum", "an integer", parse_num)
+}
+
+#[export_name = "\x01snake_read_bool"]
+extern "sysv64" fn snake_read_bool() -> SnakeVal {
+    read_input("readbool", "a boolean", parse_bool)
+}
+
+// See copy_live, the compiled code checks whether enough space was freed
+#[export_name = "\x01snake_try_gc"]
+extern "sysv64" fn snake_try_gc(base_pointer: *mut u64, stack_pointer: *mut u64) -> *mut u64 {
+    unsafe {
+        let free = copy_live(base_pointer, stack_pointer, TO_SPACE);
+        let old_space = FROM_SPACE;
+        FROM_SPACE = TO_SPACE;
+        TO_SPACE = old_space;
+        HEAP_END = FROM_SPACE.add(HEAP_SIZE);
+        free
+    }
+}
 
 #[export_name = "\x01snake_error"]
 extern "sysv64" fn snake_error(err_code: ErrorCode, v: SnakeVal) {
-    if err_code == ARITH_ERROR {
-        eprintln!("arithmetic expected a number but got a boolean {}", sprint_snake_val(v));
-    } else if err_code == CMP_ERROR {
-        eprintln!("comparison expected a number but got a boolean {}", sprint_snake_val(v));
-    } else if err_code == IF_ERROR {
-        eprintln!("if expected a boolean but got a number {}", sprint_snake_val(v));
-    } else if err_code == LOGIC_ERROR {
-        eprintln!("logic expected a boolean but got a number {}", sprint_snake_val(v));
-    } else if err_code == OVFL_ERROR {
-        eprintln!("overflow");    
+    if err_code == INPUT_ERROR && v == READ_FAILED {
+        eprintln!("{}", READ_ERROR.lock().unwrap());
     } else {
-        eprintln!("I apologize to you, dear user. I made a bug. Here's a snake value: {}", sprint_snake_val(v));
+        eprintln!("{}", error_message(err_code, v));
     }
     std::process::exit(1);
 }
 
-fn main() {
-    let output = unsafe { start_here() };
-    println!("{}", sprint_snake_val(output));
+#[export_name = "main"]
+extern "C" fn main() -> i32 {
+    let input = match parse_input(std::env::args().nth(1).as_deref()) {
+        Ok(v) => v,
+        Err(msg) => {
+            eprintln!("{}", msg);
+            std::process::exit(1);
+        }
+    };
+    // the semispaces live as long as the program, start_here never frees them
+    let mut from_space: Vec<u64> = vec![0; HEAP_SIZE];
+    let mut to_space: Vec<u64> = vec![0; HEAP_SIZE];
+    let output = unsafe {
+        FROM_SPACE = from_space.as_mut_ptr();
+        TO_SPACE = to_space.as_mut_ptr();
+        HEAP_END = FROM_SPACE.add(HEAP_SIZE);
+        start_here(FROM_SPACE, input)
+    };
+    write_snake_val(output);
+    0
 }
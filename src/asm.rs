@@ -16,6 +16,10 @@ pub enum Reg {
     R13,
     R14,
     R15,
+
+    // x87 floating point stack registers
+    St0,
+    St1,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -71,6 +75,8 @@ pub enum Instr {
     Add(BinArgs),
     Sub(BinArgs),
     IMul(BinArgs),
+    IDiv(Reg),   // signed divide Rdx:Rax, quotient in Rax and remainder in Rdx
+    Cqo,         // sign extend Rax into Rdx:Rax
     And(BinArgs),
     Or(BinArgs),
     Xor(BinArgs),
@@ -87,9 +93,13 @@ pub enum Instr {
     Comment(String),
 
     Call(String),
+    CallIndirect(Reg),   // call the address stored in the register
     Ret,
 
+    Lea(Reg, String),    // load the address of a label
+
     Jmp(String),
+    JmpIndirect(Reg),
     Je(String),
     Jne(String),
     Jl(String),
@@ -103,6 +113,15 @@ pub enum Instr {
 
     Jo(String),  // jump if last arith operation overflowed
     Jno(String), // jump if last arith operation didn't overflow
+
+    // x87 floating point operations
+    Fld(MemRef),     // push a 64-bit float from memory onto the fp stack
+    Fild(MemRef),    // push a 64-bit integer from memory onto the fp stack, converted to float
+    Fstp(Loc),       // pop st0 into memory or into another fp register
+    Fadd(Reg, Reg),  // st_i := st_i + st_j
+    Fsub(Reg, Reg),  // st_i := st_i - st_j
+    Fmul(Reg, Reg),  // st_i := st_i * st_j
+    Fdiv(Reg, Reg),  // st_i := st_i / st_j
 }
 
 pub fn reg_to_string(r: Reg) -> String {
@@ -123,6 +142,8 @@ pub fn reg_to_string(r: Reg) -> String {
         Reg::R13 => String::from("r13"),
         Reg::R14 => String::from("r14"),
         Reg::R15 => String::from("r15"),
+        Reg::St0 => String::from("st0"),
+        Reg::St1 => String::from("st1"),
     }
 }
 
@@ -181,6 +202,15 @@ pub fn bin_args_to_string(args: BinArgs) -> String {
     }
 }
 
+// shifts by a register take the amount in cl
+pub fn shift_args_to_string(args: BinArgs) -> String {
+    match args {
+        BinArgs::ToReg(r, Arg32::Reg(Reg::Rcx)) => format!("{}, cl", reg_to_string(r)),
+        BinArgs::ToMem(mem, Reg32::Reg(Reg::Rcx)) => format!("qword {}, cl", mem_ref_to_string(mem)),
+        _ => bin_args_to_string(args),
+    }
+}
+
 pub fn loc_to_string(loc: Loc) -> String {
     match loc {
         Loc::Reg(r) => reg_to_string(r),
@@ -188,6 +218,13 @@ pub fn loc_to_string(loc: Loc) -> String {
     }
 }
 
+pub fn fp_loc_to_string(loc: Loc) -> String {
+    match loc {
+        Loc::Reg(r) => reg_to_string(r),
+        Loc::Mem(m) => format!("qword {}", mem_ref_to_string(m)),
+    }
+}
+
 pub fn instr_to_string(i: &Instr) -> String {
     match i {
         Instr::Mov(args) => {
@@ -202,6 +239,12 @@ pub fn instr_to_string(i: &Instr) -> String {
         Instr::IMul(args) => {
             format!("        imul {}", bin_args_to_string(*args))
         }
+        Instr::IDiv(r) => {
+            format!("        idiv {}", reg_to_string(*r))
+        }
+        Instr::Cqo => {
+            "        cqo".to_string()
+        }
         Instr::And(args) => {
             format!("        and {}", bin_args_to_string(*args))
         }
@@ -212,13 +255,13 @@ pub fn instr_to_string(i: &Instr) -> String {
             format!("        xor {}", bin_args_to_string(*args))
         }
         Instr::Shr(args) => {
-            format!("        shr {}", bin_args_to_string(*args))
+            format!("        shr {}", shift_args_to_string(*args))
         }
         Instr::Sar(args) => {
-            format!("        sar {}", bin_args_to_string(*args))
+            format!("        sar {}", shift_args_to_string(*args))
         }
         Instr::Shl(args) => {
-            format!("        shl {}", bin_args_to_string(*args))
+            format!("        shl {}", shift_args_to_string(*args))
         }
         Instr::Cmp(args) => {
             format!("        cmp {}", bin_args_to_string(*args))
@@ -243,13 +286,22 @@ pub fn instr_to_string(i: &Instr) -> String {
         Instr::Call(s) => {
             format!("        call {}", s)
         }
+        Instr::CallIndirect(r) => {
+            format!("        call {}", reg_to_string(*r))
+        }
         Instr::Ret => {
-            format!("        ret")
+            "        ret".to_string()
+        }
+        Instr::Lea(r, s) => {
+            format!("        lea {}, [rel {}]", reg_to_string(*r), s)
         }
 
         Instr::Jmp(s) => {
             format!("        jmp {}", s)
         }
+        Instr::JmpIndirect(r) => {
+            format!("        jmp {}", reg_to_string(*r))
+        }
         Instr::Je(s) => {
             format!("        je {}", s)
         }
@@ -283,14 +335,35 @@ pub fn instr_to_string(i: &Instr) -> String {
         Instr::Jno(s) => {
             format!("        jno {}", s)
         }
+        Instr::Fld(m) => {
+            format!("        fld qword {}", mem_ref_to_string(*m))
+        }
+        Instr::Fild(m) => {
+            format!("        fild qword {}", mem_ref_to_string(*m))
+        }
+        Instr::Fstp(loc) => {
+            format!("        fstp {}", fp_loc_to_string(*loc))
+        }
+        Instr::Fadd(r1, r2) => {
+            format!("        fadd {}, {}", reg_to_string(*r1), reg_to_string(*r2))
+        }
+        Instr::Fsub(r1, r2) => {
+            format!("        fsub {}, {}", reg_to_string(*r1), reg_to_string(*r2))
+        }
+        Instr::Fmul(r1, r2) => {
+            format!("        fmul {}, {}", reg_to_string(*r1), reg_to_string(*r2))
+        }
+        Instr::Fdiv(r1, r2) => {
+            format!("        fdiv {}, {}", reg_to_string(*r1), reg_to_string(*r2))
+        }
     }
 }
 
 pub fn instrs_to_string(is: &[Instr]) -> String {
     let mut buf = String::new();
     for i in is {
-        buf.push_str(&instr_to_string(&i));
-        buf.push_str("\n");
+        buf.push_str(&instr_to_string(i));
+        buf.push('\n');
     }
     buf
 }
@@ -1,30 +1,58 @@
 use crate::asm::instrs_to_string;
-use crate::asm::{Arg32, Arg64, BinArgs, Instr, MemRef, MovArgs, Reg, Reg32};
-use crate::syntax::{Exp, FunDecl, ImmExp, Prim, SeqExp, SeqProg, SurfProg};
+use crate::asm::{Arg32, Arg64, BinArgs, Instr, Loc, MemRef, MovArgs, Reg, Reg32};
+use crate::syntax::{exp_ann, Exp, FunDecl, ImmExp, Prim, SeqExp, SeqProg, SurfProg};
+use crate::elf::object_file;
+use crate::encode::encode;
+use crate::optimize::optimize_prog;
+use crate::regalloc::allocate_registers;
+use crate::typecheck::{type_check, Type};
 
 use std::collections::{HashMap, HashSet};
 // use std::fmt::format;
 
 
 
-type ErrorCode = u64;
-static ARITH_ERROR: ErrorCode = 0;
-static CMP_ERROR:   ErrorCode = 1;
-static IF_ERROR:    ErrorCode = 2;
-static LOGIC_ERROR: ErrorCode = 3;
-static OVFL_ERROR:  ErrorCode = 4;
+include!("../runtime/error_codes.rs");
 
+// The reserved variable bound to the program's input, the value parsed
+// from the command-line argument of the executable
+pub static INPUT: &str = "input";
+
+// Tagging scheme (lowest bits of a snake value):
+//   integer   ...00   (62-bit two's complement number shifted left by 2)
+//   float     ...10   (IEEE-754 double with the 2 lowest mantissa bits dropped)
+//   boolean   ..111   (SNAKE_TRU / SNAKE_FLS)
+//   tuple     ..001   (8-byte aligned address of [length, elem_0, ..., elem_n-1])
+//   closure   ..101   (8-byte aligned address of [arity, code address, env tuple])
+//   string    ..011   (8-byte aligned address of [length, bytes...], the bytes
+//                      packed 8 to a word and the last word padded with zeros)
 struct SnakeVal(u64);
-static TAG_MASK: u64 = 0x00_00_00_00_00_00_00_01;
+static TAG_MASK: u64 = 0x00_00_00_00_00_00_00_01;       // 0 for numbers (int or float)
+static INT_TAG_MASK: u64 = 0x00_00_00_00_00_00_00_03;   // 00 for integers
+static FLOAT_TAG: u64 = 0x00_00_00_00_00_00_00_02;
+static PTR_TAG_MASK: u64 = 0x00_00_00_00_00_00_00_07;   // 3 bits for booleans and heap values
+static BOOL_TAG: u64 = 0x00_00_00_00_00_00_00_07;
+static TUPLE_TAG: u64 = 0x00_00_00_00_00_00_00_01;
+static CLOSURE_TAG: u64 = 0x00_00_00_00_00_00_00_05;
+static STRING_TAG: u64 = 0x00_00_00_00_00_00_00_03;
+
+// the next free word of the heap, reserved for the whole program
+static HEAP_PTR: Reg = Reg::R15;
+// callee-saved registers for let-bound variables
+static VAR_REGS: [Reg; 4] = [Reg::Rbx, Reg::R12, Reg::R13, Reg::R14];
 static NOT_MUSK: u64 = 0x80_00_00_00_00_00_00_00;
 static SNAKE_TRU: SnakeVal = SnakeVal(0xFF_FF_FF_FF_FF_FF_FF_FF);
 static SNAKE_FLS: SnakeVal = SnakeVal(0x7F_FF_FF_FF_FF_FF_FF_FF);
 
-static MAX_SNAKE_INT: i64 = i64::MAX >> 1;
-static MIN_SNAKE_INT: i64 = i64::MIN >> 1;
+static MAX_SNAKE_INT: i64 = i64::MAX >> 2;
+static MIN_SNAKE_INT: i64 = i64::MIN >> 2;
+
+fn float_to_snake_val(f: f64) -> u64 {
+    (f.to_bits() & !INT_TAG_MASK) | FLOAT_TAG
+}
 
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum CompileErr<Span> {
     UnboundVariable {
         unbound: String,
@@ -34,10 +62,11 @@ pub enum CompileErr<Span> {
         undefined: String,
         location: Span,
     },
-    // The Span here is the Span of the let-expression that has the two duplicated bindings
+    // The Spans here are the Spans of the expressions bound by the two duplicated bindings
     DuplicateBinding {
         duplicated_name: String,
         location: Span,
+        previous: Span,
     },
 
     Overflow {
@@ -48,6 +77,7 @@ pub enum CompileErr<Span> {
     DuplicateFunName {
         duplicated_name: String,
         location: Span, // the location of the 2nd function
+        previous: Span, // the location of the 1st function
     },
 
     DuplicateArgName {
@@ -55,21 +85,35 @@ pub enum CompileErr<Span> {
         location: Span,
     },
 
-    FunctionUsedAsValue {
+    FunctionCalledWrongArity {
         function_name: String,
+        correct_arity: usize,
+        arity_used: usize,
+        location: Span, // location of the function *call*
+    },
+
+    // a let, parameter or function binds the reserved input variable
+    ReservedVariable {
+        name: String,
         location: Span,
     },
 
-    ValueUsedAsFunction {
-        variable_name: String,
+    // reported by the type checker
+    TypeMismatch {
+        expected: String,
+        found: String,
         location: Span,
     },
 
-    FunctionCalledWrongArity {
-        function_name: String,
-        correct_arity: usize,
-        arity_used: usize,
-        location: Span, // location of the function *call*
+    InfiniteType {
+        var: String,
+        ty: String,
+        location: Span,
+    },
+
+    // the size of an indexed tuple could not be inferred
+    UnknownTupleSize {
+        location: Span,
     },
 }
 
@@ -83,108 +127,154 @@ pub fn check_prog<Span>(p: &SurfProg<Span>) -> Result<(), CompileErr<Span>>
 where
     Span: Clone,
 {
-    fn check_prog_helper<Span>(e: &SurfProg<Span>, mut var_env: HashSet<String>, mut fun_env: HashMap<String, Vec<String>>) -> Result<(), CompileErr<Span>> 
+    match check_prog_all(p).into_iter().next() {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+// Like check_prog, but reports every error in the program. After an error
+// the checker recovers as if the program were fixed: an unbound variable
+// or undefined function is treated as defined from then on, and duplicate
+// bindings, arguments and functions shadow the earlier ones.
+pub fn check_prog_all<Span>(p: &SurfProg<Span>) -> Vec<CompileErr<Span>>
+where
+    Span: Clone,
+{
+    // (reported) holds the unbound names already reported
+    struct Checker<Span> {
+        errs: Vec<CompileErr<Span>>,
+        reported: HashSet<String>,
+    }
+
+    fn check_prog_helper<Span>(e: &SurfProg<Span>, mut var_env: HashSet<String>, mut fun_env: HashMap<String, Vec<String>>, ck: &mut Checker<Span>)
     where Span: Clone{
         match e {
             Exp::Num(n, span) => {
                 if *n > MAX_SNAKE_INT || *n < MIN_SNAKE_INT {
-                    return Err(CompileErr::Overflow { num: *n, location: span.clone()}) 
-                } else {
-                    return Ok(())
+                    ck.errs.push(CompileErr::Overflow { num: *n, location: span.clone()});
                 }
             },
 
-            Exp::Bool(_, _) => return Ok(()),
+            Exp::Float(_, _) | Exp::Bool(_, _) | Exp::Str(_, _) => {},
 
             Exp::Var(var_name, span) => {
-                if var_env.contains(var_name) {
-                    return Ok(())
-                } else {
-                    if fun_env.contains_key(var_name){
-                        return Err(CompileErr::FunctionUsedAsValue { function_name: var_name.clone(), location: span.clone() });
-                    } else {
-                        return Err(CompileErr::UnboundVariable{unbound: var_name.clone(), location: span.clone()});
-                    }
+                // functions can be used as values too
+                if !var_env.contains(var_name) && !fun_env.contains_key(var_name) && ck.reported.insert(var_name.clone()) {
+                    ck.errs.push(CompileErr::UnboundVariable{unbound: var_name.clone(), location: span.clone()});
                 }
             },
 
             Exp::Prim(_, exp_vec, _) => {
                 for exp in exp_vec.iter() {
-                    check_prog_helper(exp, var_env.clone(), fun_env.clone())?;
+                    check_prog_helper(exp, var_env.clone(), fun_env.clone(), ck);
                 }
-                return Ok(())
             },
 
-            Exp::Let{bindings, body, ann} => {
-                let mut this_env = HashSet::new();
+            Exp::Let{bindings, body, ann: _} => {
+                let mut this_env: HashMap<String, &Span> = HashMap::new();
                 for (var_name, exp) in bindings.iter() {
-                    let insert_flag: bool = this_env.insert(var_name.clone());
-                    if insert_flag == false {
-                        return Err(CompileErr::DuplicateBinding{duplicated_name: var_name.clone(), location: ann.clone()});
+                    if let Some(previous) = this_env.insert(var_name.clone(), exp_ann(exp)) {
+                        ck.errs.push(CompileErr::DuplicateBinding{duplicated_name: var_name.clone(), location: exp_ann(exp).clone(), previous: previous.clone()});
+                    }
+                    if var_name == INPUT {
+                        ck.errs.push(CompileErr::ReservedVariable{name: var_name.clone(), location: exp_ann(exp).clone()});
                     }
-                    check_prog_helper(exp, var_env.clone(), fun_env.clone())?;
+                    check_prog_helper(exp, var_env.clone(), fun_env.clone(), ck);
                     var_env.insert(var_name.clone());
+                    fun_env.remove(var_name); // the variable shadows a function of the same name
                 }
-                check_prog_helper(body, var_env.clone(), fun_env.clone())
+                check_prog_helper(body, var_env, fun_env, ck)
             },
 
             Exp::If{cond, thn, els, ann: _} => {
-                check_prog_helper(cond, var_env.clone(), fun_env.clone())?;
-                check_prog_helper(thn, var_env.clone(), fun_env.clone())?;
-                check_prog_helper(els, var_env.clone(), fun_env.clone())?;
-                return Ok(())
+                check_prog_helper(cond, var_env.clone(), fun_env.clone(), ck);
+                check_prog_helper(thn, var_env.clone(), fun_env.clone(), ck);
+                check_prog_helper(els, var_env, fun_env, ck);
             },
 
             Exp::FunDefs { decls, body, ann: _ } => {
-                let mut seen_fun_names: HashSet<String> = HashSet::new();
+                let mut seen_fun_names: HashMap<String, &Span> = HashMap::new();
                 for fun_decl in decls.iter() {
                     // 1. check DuplicateFunName
-                    let funname_insert_flag = seen_fun_names.insert(fun_decl.name.clone());
-                    if funname_insert_flag == false {
-                        return Err(CompileErr::DuplicateFunName { duplicated_name: fun_decl.name.clone(), location: fun_decl.ann.clone() })
+                    if let Some(previous) = seen_fun_names.insert(fun_decl.name.clone(), &fun_decl.ann) {
+                        ck.errs.push(CompileErr::DuplicateFunName { duplicated_name: fun_decl.name.clone(), location: fun_decl.ann.clone(), previous: previous.clone() });
+                    }
+                    if fun_decl.name == INPUT {
+                        ck.errs.push(CompileErr::ReservedVariable { name: fun_decl.name.clone(), location: fun_decl.ann.clone() });
                     }
                     // 2. check dupicate arg names 
                     let mut seen_arg_names: HashSet<String> = HashSet::new();
                     for arg_name in fun_decl.parameters.iter() {
-                        let argname_insert_flag = seen_arg_names.insert(arg_name.clone());
-                        if argname_insert_flag == false {
-                            return Err(CompileErr::DuplicateArgName { duplicated_name: arg_name.clone(), location: fun_decl.ann.clone() })
+                        if !seen_arg_names.insert(arg_name.clone()) {
+                            ck.errs.push(CompileErr::DuplicateArgName { duplicated_name: arg_name.clone(), location: fun_decl.ann.clone() });
+                        }
+                        if arg_name == INPUT {
+                            ck.errs.push(CompileErr::ReservedVariable { name: arg_name.clone(), location: fun_decl.ann.clone() });
                         }
                     }
-                    // declaration is well-formed, add to global env
+                    // add to global env, the last of duplicated functions wins
                     fun_env.insert(fun_decl.name.clone(), fun_decl.parameters.clone());
+                    var_env.remove(&fun_decl.name); // the function shadows a variable of the same name
                 }
 
                 // the function bodies and body_expr should be checked AFTER all fun decl is registered in global fun_env
                 for fun_decl in decls {
                     let mut env_for_this_body = var_env.clone();
+                    let mut fun_env_for_this_body = fun_env.clone();
                     for arg_name in fun_decl.parameters.iter() {
                         env_for_this_body.insert(arg_name.clone());
+                        fun_env_for_this_body.remove(arg_name);
+                    }
+                    check_prog_helper(&fun_decl.body, env_for_this_body, fun_env_for_this_body, ck); // check function body
+                }
+                check_prog_helper(body, var_env, fun_env, ck)
+            },
+
+            Exp::Lambda { parameters, body, ann } => {
+                let mut seen_arg_names: HashSet<String> = HashSet::new();
+                for arg_name in parameters.iter() {
+                    if !seen_arg_names.insert(arg_name.clone()) {
+                        ck.errs.push(CompileErr::DuplicateArgName { duplicated_name: arg_name.clone(), location: ann.clone() });
+                    }
+                    if arg_name == INPUT {
+                        ck.errs.push(CompileErr::ReservedVariable { name: arg_name.clone(), location: ann.clone() });
                     }
-                    check_prog_helper(&fun_decl.body, env_for_this_body.clone(), fun_env.clone())?; // check function body
+                    var_env.insert(arg_name.clone());
+                    fun_env.remove(arg_name);
+                }
+                check_prog_helper(body, var_env, fun_env, ck)
+            },
+
+            Exp::ClosureCall { fun, args, is_tail: _, ann: _ } => {
+                check_prog_helper(fun, var_env.clone(), fun_env.clone(), ck);
+                for arg in args.iter() {
+                    check_prog_helper(arg, var_env.clone(), fun_env.clone(), ck);
                 }
-                check_prog_helper(body, var_env.clone(), fun_env.clone())?;
-                Ok({})
             },
 
             Exp::Call(func_name, args, ann) => {
-                // check whether there is a local variable with same name
-                if var_env.contains(func_name) == true {
-                    return Err(CompileErr::ValueUsedAsFunction { variable_name: func_name.clone(), location: ann.clone() });
+                for arg in args.iter() {
+                    check_prog_helper(arg, var_env.clone(), fun_env.clone(), ck);
+                }
+                // a local variable holds a closure, its arity is only known at runtime
+                if var_env.contains(func_name) {
+                    return;
                 }
                 // check whether the name is defined in fun_env
                 // if defined, check the args arity
                 match fun_env.get(func_name) {
                     None => {
-                        return Err(CompileErr::UndefinedFunction { undefined: func_name.clone(), location: ann.clone() });
+                        if ck.reported.insert(func_name.clone()) {
+                            ck.errs.push(CompileErr::UndefinedFunction { undefined: func_name.clone(), location: ann.clone() });
+                        }
                     },
                     Some(defined_args) => {
                         if args.len() != defined_args.len() {
-                            return Err(CompileErr::FunctionCalledWrongArity { function_name: func_name.clone(), correct_arity: defined_args.len(), arity_used: args.len(), location: ann.clone() });
+                            ck.errs.push(CompileErr::FunctionCalledWrongArity { function_name: func_name.clone(), correct_arity: defined_args.len(), arity_used: args.len(), location: ann.clone() });
                         }
                     },
                 }
-                Ok({})
             },
 
             Exp::InternalTailCall(_func_name, _args, _ann) => {
@@ -193,13 +283,21 @@ where
 
             Exp::ExternalCall { fun_name: _, args: _, is_tail: _, ann: _ } => {
                 panic!("ExternalCall shouldn't occur at check_prog stage")
+            },
+
+            Exp::MakeClosure { .. } => {
+                panic!("MakeClosure shouldn't occur at check_prog stage")
+            }
+
+            Exp::Seq { .. } => {
+                panic!("Seq shouldn't occur at check_prog stage")
             }
         }
     }
 
-    let var_env: HashSet<String> = HashSet::new();
-    let fun_env: HashMap<String, Vec<String>> = HashMap::new();
-    check_prog_helper(p, var_env, fun_env)
+    let mut ck = Checker { errs: Vec::new(), reported: HashSet::new() };
+    check_prog_helper(p, HashSet::from([INPUT.to_string()]), HashMap::new(), &mut ck);
+    ck.errs
 }
 
 
@@ -212,18 +310,26 @@ where
 fn uniquify(e: &Exp<u32>) -> Exp<()> {
     fn uniquify_helper(e: &Exp<u32>, mut var_name_mapping: HashMap<String, String>, mut fun_name_mapping: HashMap<String, String>) -> Exp<()> {
         match e {
-            Exp::Num(val, _ann) => Exp::Num(val.clone(), ()),
+            Exp::Num(val, _ann) => Exp::Num(*val, ()),
+
+            Exp::Float(val, _ann) => Exp::Float(*val, ()),
+
+            Exp::Bool(bool, _ann) => Exp::Bool(*bool, ()),
 
-            Exp::Bool(bool, _ann) => Exp::Bool(bool.clone(), ()),
+            Exp::Str(s, _ann) => Exp::Str(s.clone(), ()),
 
-            Exp::Var(var_name, _ann) => Exp::Var(var_name_mapping.get(var_name).unwrap().clone(), ()),
+            Exp::Var(var_name, _ann) => {
+                // a function used as a value
+                let new_name = var_name_mapping.get(var_name).or_else(|| fun_name_mapping.get(var_name)).unwrap();
+                Exp::Var(new_name.clone(), ())
+            },
 
             Exp::Prim(op, exprs, _ann) => {
                 let mut uniquified_exprs: Vec<Box<Exp<()>>> = vec![];
                 for expr in exprs.iter() {
                     uniquified_exprs.push(Box::new(uniquify_helper(expr, var_name_mapping.clone(), fun_name_mapping.clone())));
                 }
-                Exp::Prim(op.clone(), uniquified_exprs, ())
+                Exp::Prim(*op, uniquified_exprs, ())
             },
 
             Exp::Let { bindings, body, ann } => {
@@ -233,6 +339,7 @@ fn uniquify(e: &Exp<u32>) -> Exp<()> {
                     let new_expr = uniquify_helper(expr, var_name_mapping.clone(), fun_name_mapping.clone()); //// before renaming, process its expr
                     let new_var_name = format!("{}#{}", var_name.clone(), ann.clone()); //// for each name, rename it with tag
                     var_name_mapping.insert(var_name.clone(), new_var_name.clone()); //// update name mapping
+                    fun_name_mapping.remove(var_name);
                     new_bindings.push((new_var_name, new_expr));
                 }
                 // process the body
@@ -263,6 +370,7 @@ fn uniquify(e: &Exp<u32>) -> Exp<()> {
                     // get the new function name
                     let new_fun_name = format!("{}#{}", decl.name.clone(), ann.clone()); // new function name
                     fun_name_mapping.insert(decl.name.clone(), new_fun_name.clone()); // need to do before uniquifying the body, since a local function may refer to it
+                    var_name_mapping.remove(&decl.name);
                 }
 
                 // second pass: update all the decl bodies
@@ -272,14 +380,16 @@ fn uniquify(e: &Exp<u32>) -> Exp<()> {
 
                     // new var_name_mapping for function body
                     let mut this_decl_var_name_mapping = var_name_mapping.clone();
+                    let mut this_decl_fun_name_mapping = fun_name_mapping.clone();
                     for param_name in decl.parameters.iter() {
                         let new_param_name = format!("{}#{}", param_name.clone(), ann.clone());
                         this_decl_var_name_mapping.insert(param_name.clone(), new_param_name.clone());
+                        this_decl_fun_name_mapping.remove(param_name);
                         new_parameters.push(new_param_name.clone());
                     }
                     
                     // new function body
-                    let new_body = uniquify_helper(&decl.body, this_decl_var_name_mapping.clone(), fun_name_mapping.clone());
+                    let new_body = uniquify_helper(&decl.body, this_decl_var_name_mapping.clone(), this_decl_fun_name_mapping);
                     // new declaration
                     let new_decl: FunDecl<Exp<()>, ()> = FunDecl{
                         name: fun_name_mapping.get(&decl.name).unwrap().clone(),
@@ -299,6 +409,44 @@ fn uniquify(e: &Exp<u32>) -> Exp<()> {
                 Exp::FunDefs { decls: new_decls, body: Box::new(new_def_body), ann: () }
             },
 
+            Exp::Lambda { parameters, body, ann } => {
+                // a lambda becomes a local function that is immediately used as a value
+                let name = format!("lambda#{}", ann);
+                let mut new_parameters: Vec<String> = vec![];
+                for param_name in parameters.iter() {
+                    let new_param_name = format!("{}#{}", param_name.clone(), ann.clone());
+                    var_name_mapping.insert(param_name.clone(), new_param_name.clone());
+                    fun_name_mapping.remove(param_name);
+                    new_parameters.push(new_param_name);
+                }
+                let new_body = uniquify_helper(body, var_name_mapping, fun_name_mapping);
+
+                Exp::FunDefs {
+                    decls: vec![FunDecl { name: name.clone(), parameters: new_parameters, body: new_body, ann: () }],
+                    body: Box::new(Exp::Var(name, ())),
+                    ann: (),
+                }
+            },
+
+            Exp::ClosureCall { fun, args, is_tail, ann: _ } => {
+                let new_fun = uniquify_helper(fun, var_name_mapping.clone(), fun_name_mapping.clone());
+                let new_args = args.iter().map(|arg| uniquify_helper(arg, var_name_mapping.clone(), fun_name_mapping.clone())).collect();
+
+                Exp::ClosureCall { fun: Box::new(new_fun), args: new_args, is_tail: *is_tail, ann: () }
+            },
+
+            Exp::Call(fun_name, args, _ann) if var_name_mapping.contains_key(fun_name) => {
+                // calling a variable that holds a closure
+                let new_args = args.iter().map(|arg| uniquify_helper(arg, var_name_mapping.clone(), fun_name_mapping.clone())).collect();
+
+                Exp::ClosureCall {
+                    fun: Box::new(Exp::Var(var_name_mapping.get(fun_name).unwrap().clone(), ())),
+                    args: new_args,
+                    is_tail: false,
+                    ann: (),
+                }
+            },
+
             Exp::Call(fun_name, args, _ann) => {
                 // replace function name
                 let new_fun_name = match fun_name_mapping.get(fun_name) {
@@ -320,11 +468,19 @@ fn uniquify(e: &Exp<u32>) -> Exp<()> {
 
             Exp::ExternalCall { fun_name: _, args: _, is_tail: _, ann: _ } => {
                 panic!("ExternalCall shouldn't occur at uniquifying stage")
+            },
+
+            Exp::MakeClosure { .. } => {
+                panic!("MakeClosure shouldn't occur at uniquifying stage")
+            }
+
+            Exp::Seq { .. } => {
+                panic!("Seq shouldn't occur at uniquifying stage")
             }
         }
     }
 
-    let var_name_mapping: HashMap<String, String> = HashMap::new();
+    let var_name_mapping: HashMap<String, String> = HashMap::from([(INPUT.to_string(), INPUT.to_string())]);
     let fun_name_mapping: HashMap<String, String> = HashMap::new();
     uniquify_helper(e, var_name_mapping, fun_name_mapping)
 }
@@ -342,9 +498,14 @@ fn should_lift<Ann>(p: &Exp<Ann>) -> HashSet<String> {
         match p {
             Exp::Num(_, _) => HashSet::new(),
 
+            Exp::Float(_, _) => HashSet::new(),
+
             Exp::Bool(_, _) => HashSet::new(),
 
-            Exp::Var(_, _) => HashSet::new(),
+            Exp::Str(_, _) => HashSet::new(),
+
+            // a function used as a value is lifted to get a closure entry point
+            Exp::Var(var_name, _) => HashSet::from([var_name.clone()]),
 
             Exp::Prim(_, exprs, _) => {
                 let mut calls_to_lift = HashSet::new();
@@ -409,20 +570,40 @@ fn should_lift<Ann>(p: &Exp<Ann>) -> HashSet<String> {
                 calls_to_lift
             }, 
 
+            Exp::ClosureCall { fun, args, is_tail: _, ann: _ } => {
+                let mut calls_to_lift = should_lift_helper(fun, false);
+                for expr in args {
+                    calls_to_lift.extend(should_lift_helper(expr, false));
+                }
+
+                calls_to_lift
+            },
+
             Exp::InternalTailCall(_, _, _) => {
                 panic!("InternalTailCall shouldn't occur at should_lift stage")
             },
 
             Exp::ExternalCall { fun_name: _, args: _, is_tail: _, ann: _ } => {
                 panic!("ExternalCall shouldn't occur at should_lift stage")
+            },
+
+            Exp::Lambda { .. } | Exp::MakeClosure { .. } => {
+                panic!("Lambda and MakeClosure shouldn't occur at should_lift stage")
+            }
+
+            Exp::Seq { .. } => {
+                panic!("Seq shouldn't occur at should_lift stage")
             }
         }
     }
 
     fn lift_other_fun_in_scope<Ann>(p: &Exp<Ann>, fun_in_scope: HashSet<String>, lifted_fun: &HashSet<String>) -> HashSet<String> {
         match p {
-            Exp::Num(_, _) | Exp::Bool(_, _) | Exp::Var(_, _) |
-            Exp::InternalTailCall(_, _, _) | Exp::ExternalCall { fun_name: _, args: _, is_tail: _, ann: _ } => lifted_fun.clone(),
+            Exp::Num(_, _) | Exp::Float(_, _) | Exp::Bool(_, _) | Exp::Str(_, _) | Exp::Var(_, _) |
+            Exp::InternalTailCall(_, _, _) | Exp::ExternalCall { fun_name: _, args: _, is_tail: _, ann: _ } |
+            Exp::Lambda { .. } | Exp::MakeClosure { .. } => lifted_fun.clone(),
+
+            Exp::Seq { .. } => panic!("Seq shouldn't occur at should_lift stage"),
             
             Exp::Prim(_, exprs, _) => {
                 let mut calls_to_lift = lifted_fun.clone();
@@ -489,16 +670,43 @@ fn should_lift<Ann>(p: &Exp<Ann>) -> HashSet<String> {
                     calls_to_lift.extend(lift_other_fun_in_scope(expr, fun_in_scope.clone(), lifted_fun));
                 }
 
+                calls_to_lift
+            },
+
+            Exp::ClosureCall { fun, args, is_tail: _, ann: _ } => {
+                let mut calls_to_lift = lift_other_fun_in_scope(fun, fun_in_scope.clone(), lifted_fun);
+                for expr in args {
+                    calls_to_lift.extend(lift_other_fun_in_scope(expr, fun_in_scope.clone(), lifted_fun));
+                }
+
                 calls_to_lift
             }
         }
     }
 
     let fun_non_tail = should_lift_helper(p, true);
-    let fun_to_lift = lift_other_fun_in_scope(p, HashSet::new(), &fun_non_tail);
     
-    let res = fun_to_lift;
-    res
+    
+    
+    lift_other_fun_in_scope(p, HashSet::new(), &fun_non_tail)
+}
+
+// Whether the program reads the input variable (names are unique after
+// uniquify, so only the reserved variable itself is called input)
+fn uses_input<Ann>(p: &Exp<Ann>) -> bool {
+    match p {
+        Exp::Num(_, _) | Exp::Float(_, _) | Exp::Bool(_, _) | Exp::Str(_, _) => false,
+        Exp::Var(var_name, _) => var_name == INPUT,
+        Exp::Prim(_, exprs, _) => exprs.iter().any(|expr| uses_input(expr)),
+        Exp::Let { bindings, body, ann: _ } => bindings.iter().any(|(_, expr)| uses_input(expr)) || uses_input(body),
+        Exp::If { cond, thn, els, ann: _ } => uses_input(cond) || uses_input(thn) || uses_input(els),
+        Exp::FunDefs { decls, body, ann: _ } => decls.iter().any(|decl| uses_input(&decl.body)) || uses_input(body),
+        Exp::Call(_, args, _) | Exp::InternalTailCall(_, args, _) | Exp::ExternalCall { fun_name: _, args, is_tail: _, ann: _ } => args.iter().any(|expr| uses_input(expr)),
+        Exp::ClosureCall { fun, args, is_tail: _, ann: _ } => uses_input(fun) || args.iter().any(|expr| uses_input(expr)),
+        Exp::Lambda { body, .. } => uses_input(body),
+        Exp::MakeClosure { .. } => panic!("MakeClosure shouldn't occur at lambda lifting stage"),
+        Exp::Seq { .. } => panic!("Seq shouldn't occur at lambda lifting stage"),
+    }
 }
 
 // Lift some functions to global definitions
@@ -509,7 +717,7 @@ fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
     // (0) extend the parameter lists of all declarations in original AST
     // (1) seperate all declarations (including InternalTailCalls) and main_body_expr
     // (2) replace Call with InternalTailCalls / ExternalCalls (but leave the parameter list be for this stage)
-    fn extend_declaration_and_lift<Ann>(p: &Exp<Ann>, env: HashSet<String>, fun_to_lift: &HashSet<String>, is_tail: bool) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
+    fn extend_declaration_and_lift<Ann>(p: &Exp<Ann>, env: HashSet<String>, fun_to_lift: &HashSet<String>, closures: &HashMap<String, usize>, is_tail: bool) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
         match p {
             Exp::FunDefs { decls, body, ann: _ } => {
                 //* we still need to return ALL func decls in the expr, 
@@ -518,17 +726,22 @@ fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
 
                 let mut decls_not_lifted = vec![]; // decls not to list (manually add to main_body_expr before return)
 
+                // the closures of the functions used as values are variables of this scope
+                let mut env = env;
+                env.extend(decls.iter().filter(|decl| closures.contains_key(&decl.name)).map(|decl| closure_var_name(&decl.name)));
+                // every function of the scope captures the same variables, in the same order
+                let captured_vars: Vec<String> = env.clone().into_iter().collect();
+
                 // process the declarations
                 for decl in decls {
                     // capture variables in this scope, for lifting the inside bodies
                     let mut new_env = env.clone();
-                    new_env.extend(decl.parameters.clone().into_iter());
+                    new_env.extend(decl.parameters.clone());
 
                     // lift the inside bodies
-                    let (decls_inside, expr_body) = extend_declaration_and_lift(&decl.body, new_env.clone(), fun_to_lift, true); // function body is in tail position
+                    let (decls_inside, expr_body) = extend_declaration_and_lift(&decl.body, new_env.clone(), fun_to_lift, closures, true); // function body is in tail position
 
                     // extend parameter list of this declaration
-                    let captured_vars: Vec<String> = env.clone().into_iter().collect();
                     let new_parameters = [captured_vars.clone(), decl.parameters.clone()].concat();
 
                     // construct the new declaration
@@ -552,14 +765,15 @@ fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
                 // panic!("count of decls_not_lifted: {}");
 
                 // process the body
-                let (decls_in_body, main_body_expr) = extend_declaration_and_lift(body, env.clone(), fun_to_lift, is_tail); // parse the body 
+                let (decls_in_body, main_body_expr) = extend_declaration_and_lift(body, env.clone(), fun_to_lift, closures, is_tail); // parse the body 
                 all_decls.extend(decls_in_body); // update the decls to return
+                let main_body_expr = allocate_closures(decls, &captured_vars, closures, main_body_expr);
 
                 // construct returned expr
                 // (need more discussion)
                 // if there are func not to be lifted in the def sequence, 
                 // the returned main_body_expr' should be a new FunDefs containing (1) not-to-lift funcs (2) original main_body_expr
-                if decls_not_lifted.len() > 0 {
+                if !decls_not_lifted.is_empty() {
 
                     let new_main_body_expr = Exp::FunDefs {
                         decls: decls_not_lifted, 
@@ -581,14 +795,14 @@ fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
 
                 // update processsed decls and cur_env
                 for (var, expr) in bindings {
-                    let (decls_inside, expr_body) = extend_declaration_and_lift(expr, cur_env.clone(), fun_to_lift, false); // bindings are never in tail position
+                    let (decls_inside, expr_body) = extend_declaration_and_lift(expr, cur_env.clone(), fun_to_lift, closures, false); // bindings are never in tail position
                     all_decls.extend(decls_inside); // extend decls
                     cur_env.insert(var.clone()); // extend cur_env
                     new_bindings.push((var.clone(), expr_body.clone()));
                 }
 
                 // process the body of Let and extract decls in the body
-                let (body_decls, body_expr) = extend_declaration_and_lift(body, cur_env.clone(), fun_to_lift, is_tail);
+                let (body_decls, body_expr) = extend_declaration_and_lift(body, cur_env.clone(), fun_to_lift, closures, is_tail);
                 all_decls.extend(body_decls);
 
                 (all_decls, Exp::Let {
@@ -599,7 +813,9 @@ fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
             },
 
             Exp::Num(val, _ann) => (vec![], Exp::Num(*val, ())),
+            Exp::Float(val, _ann) => (vec![], Exp::Float(*val, ())),
             Exp::Bool(val, _ann) => (vec![], Exp::Bool(*val, ())),
+            Exp::Str(s, _ann) => (vec![], Exp::Str(s.clone(), ())),
             Exp::Var(var, _ann) => (vec![], Exp::Var(var.clone(), ())),
 
             Exp::Prim(op, exprs, _ann) => {
@@ -607,23 +823,23 @@ fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
                 let mut new_exprs = vec![];
 
                 for expr in exprs {
-                    let (expr_decls, expr_body) = extend_declaration_and_lift(expr, env.clone(), fun_to_lift, false); // operands are never in tail position
+                    let (expr_decls, expr_body) = extend_declaration_and_lift(expr, env.clone(), fun_to_lift, closures, false); // operands are never in tail position
                     all_decls.extend(expr_decls);
                     new_exprs.push(Box::new(expr_body));
                 }
 
-                (all_decls, Exp::Prim(op.clone(), new_exprs, ()))
+                (all_decls, Exp::Prim(*op, new_exprs, ()))
             }
 
             Exp::If { cond, thn, els, ann: _ } => {
                 let mut all_decls = vec![];
 
                 // process three exprs
-                let (cond_decls, cond_body) = extend_declaration_and_lift(cond, env.clone(), fun_to_lift, false); // condition is never in tail position
+                let (cond_decls, cond_body) = extend_declaration_and_lift(cond, env.clone(), fun_to_lift, closures, false); // condition is never in tail position
                 all_decls.extend(cond_decls);
-                let (thn_decls, thn_body) = extend_declaration_and_lift(thn, env.clone(), fun_to_lift, is_tail);
+                let (thn_decls, thn_body) = extend_declaration_and_lift(thn, env.clone(), fun_to_lift, closures, is_tail);
                 all_decls.extend(thn_decls);
-                let (els_decls, els_body) = extend_declaration_and_lift(els, env.clone(), fun_to_lift, is_tail);
+                let (els_decls, els_body) = extend_declaration_and_lift(els, env.clone(), fun_to_lift, closures, is_tail);
                 all_decls.extend(els_decls);
 
                 (all_decls, Exp::If { 
@@ -639,7 +855,7 @@ fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
                 let mut new_arg_exprs = vec![];
 
                 for expr in arg_exprs {
-                    let (expr_decls, expr_body) = extend_declaration_and_lift(expr, env.clone(), fun_to_lift, false);
+                    let (expr_decls, expr_body) = extend_declaration_and_lift(expr, env.clone(), fun_to_lift, closures, false);
                     all_decls.extend(expr_decls);
                     new_arg_exprs.push(expr_body);
                 }
@@ -650,7 +866,7 @@ fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
                      Exp::ExternalCall { 
                         fun_name: fun_name.clone(), 
                         args: new_arg_exprs, 
-                        is_tail: is_tail, 
+                        is_tail, 
                         ann: () }
                     )
                 } else { // internal tail call
@@ -664,12 +880,38 @@ fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
                 }
             },
 
+            Exp::ClosureCall { fun, args, is_tail: _, ann: _ } => {
+                let (mut all_decls, new_fun) = extend_declaration_and_lift(fun, env.clone(), fun_to_lift, closures, false);
+                let mut new_args = vec![];
+
+                for expr in args {
+                    let (expr_decls, expr_body) = extend_declaration_and_lift(expr, env.clone(), fun_to_lift, closures, false);
+                    all_decls.extend(expr_decls);
+                    new_args.push(expr_body);
+                }
+
+                (all_decls, Exp::ClosureCall {
+                    fun: Box::new(new_fun),
+                    args: new_args,
+                    is_tail,
+                    ann: ()
+                })
+            },
+
             Exp::InternalTailCall(_, _, _) => {
                 panic!("InternalTailCall shouldn't occur at extend_parameters stage")
             },
 
             Exp::ExternalCall { fun_name: _, args: _, is_tail: _, ann: _ } => {
                 panic!("ExternalCall shouldn't occur at extend_parameters stage")
+            },
+
+            Exp::Lambda { .. } | Exp::MakeClosure { .. } => {
+                panic!("Lambda and MakeClosure shouldn't occur at extend_parameters stage")
+            }
+
+            Exp::Seq { .. } => {
+                panic!("Seq shouldn't occur at extend_parameters stage")
             }
         }
     }
@@ -687,7 +929,7 @@ fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
     }
 
     // get the mapping from function name to its parameter (name) list
-    fn fun_decls_to_hashmap(all_decls: &Vec<FunDecl<Exp<()>, ()>>) -> HashMap<String, Vec<Exp<()>>> {
+    fn fun_decls_to_hashmap(all_decls: &[FunDecl<Exp<()>, ()>]) -> HashMap<String, Vec<Exp<()>>> {
         let mut map = HashMap::new();
 
         for decl in all_decls.iter() {
@@ -699,22 +941,26 @@ fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
     }
 
     // extend all Calls' parameter list: [<added free variables>, <original argument expressions>].concat()
-    fn extend_fun_calls(exp: &Exp<()>, name_param_mapping: &HashMap<String, Vec<Exp<()>>>) -> Exp<()> {
+    fn extend_fun_calls(exp: &Exp<()>, name_param_mapping: &HashMap<String, Vec<Exp<()>>>, closure_arities: &HashMap<String, usize>) -> Exp<()> {
         match exp {
-            Exp::Num(_, _) | Exp::Bool(_, _) | Exp::Var(_, _) => exp.clone(),
+            Exp::Num(_, _) | Exp::Float(_, _) | Exp::Bool(_, _) | Exp::Str(_, _) => exp.clone(),
+
+            // a function used as a value: the closure allocated when its scope was entered
+            Exp::Var(var_name, _) if closure_arities.contains_key(var_name) => Exp::Var(closure_var_name(var_name), ()),
+            Exp::Var(_, _) => exp.clone(),
 
-            Exp::Prim(op, exprs, ann) => {
+            Exp::Prim(op, exprs, _) => {
                 let mut new_exprs = vec![];
                 for expr in exprs {
-                    new_exprs.push(Box::new(extend_fun_calls(expr, name_param_mapping)));
+                    new_exprs.push(Box::new(extend_fun_calls(expr, name_param_mapping, closure_arities)));
                 }
 
-                Exp::Prim(op.clone(), new_exprs, ann.clone())
+                Exp::Prim(*op, new_exprs, ())
             },
 
             Exp::Let { bindings, body, ann: _ } => {
-                let new_bindings = bindings.iter().map(|(var_name, bind_expr)| (var_name.clone(), extend_fun_calls(bind_expr, name_param_mapping))).collect();
-                let new_body = extend_fun_calls(body, name_param_mapping);
+                let new_bindings = bindings.iter().map(|(var_name, bind_expr)| (var_name.clone(), extend_fun_calls(bind_expr, name_param_mapping, closure_arities))).collect();
+                let new_body = extend_fun_calls(body, name_param_mapping, closure_arities);
                 
                 Exp::Let { 
                     bindings: new_bindings, 
@@ -725,10 +971,10 @@ fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
 
             Exp::If { cond, thn, els, ann } => {
                 Exp::If { 
-                    cond: Box::new(extend_fun_calls(cond, name_param_mapping)), 
-                    thn: Box::new(extend_fun_calls(thn, name_param_mapping)), 
-                    els: Box::new(extend_fun_calls(els, name_param_mapping)),
-                    ann: ann.clone() 
+                    cond: Box::new(extend_fun_calls(cond, name_param_mapping, closure_arities)), 
+                    thn: Box::new(extend_fun_calls(thn, name_param_mapping, closure_arities)), 
+                    els: Box::new(extend_fun_calls(els, name_param_mapping, closure_arities)),
+                    ann: *ann 
                 }
             },
 
@@ -739,15 +985,15 @@ fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
                     new_decls.push(FunDecl {
                         name: decl.name.clone(),
                         parameters: decl.parameters.clone(),
-                        body: extend_fun_calls(&decl.body, name_param_mapping),
-                        ann: ann.clone(),
+                        body: extend_fun_calls(&decl.body, name_param_mapping, closure_arities),
+                        ann: *ann,
                     })
                 }
 
                 Exp::FunDefs { 
                     decls: new_decls, 
-                    body: Box::new(extend_fun_calls(body, name_param_mapping)), 
-                    ann: ann.clone(),
+                    body: Box::new(extend_fun_calls(body, name_param_mapping, closure_arities)), 
+                    ann: *ann,
                 }
             },
 
@@ -755,9 +1001,35 @@ fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
                 panic!("Call shouldn't occur at extend_fun_calls stage")
             },
 
-            Exp::InternalTailCall(fun_name, arg_exprs, ann) => {
+            Exp::MakeClosure { fun_name, arity, env, ann: _ } => {
+                Exp::MakeClosure {
+                    fun_name: fun_name.clone(),
+                    arity: *arity,
+                    env: Box::new(extend_fun_calls(env, name_param_mapping, closure_arities)),
+                    ann: ()
+                }
+            },
+
+            Exp::Lambda { .. } => {
+                panic!("Lambda shouldn't occur at extend_fun_calls stage")
+            },
+
+            Exp::Seq { .. } => {
+                panic!("Seq shouldn't occur at extend_fun_calls stage")
+            },
+
+            Exp::ClosureCall { fun, args, is_tail, ann: _ } => {
+                Exp::ClosureCall {
+                    fun: Box::new(extend_fun_calls(fun, name_param_mapping, closure_arities)),
+                    args: args.iter().map(|expr| extend_fun_calls(expr, name_param_mapping, closure_arities)).collect(),
+                    is_tail: *is_tail,
+                    ann: ()
+                }
+            },
+
+            Exp::InternalTailCall(fun_name, arg_exprs, _) => {
                 let extended_arg_exprs: Vec<Exp<()>> = arg_exprs.iter().map(|expr| {
-                    extend_fun_calls(expr, name_param_mapping)
+                    extend_fun_calls(expr, name_param_mapping, closure_arities)
                 }).collect();
 
                 let var_name_list = match name_param_mapping.get(fun_name) {
@@ -773,13 +1045,13 @@ fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
                 Exp::InternalTailCall(
                     fun_name.clone(), 
                     extended_call_param_list, 
-                    ann.clone()
+                    ()
                 )
             },
 
             Exp::ExternalCall { fun_name, args: arg_exprs, is_tail, ann: _ } => {
                 let extended_arg_exprs: Vec<Exp<()>> = arg_exprs.iter().map(|expr| {
-                    extend_fun_calls(expr, name_param_mapping)
+                    extend_fun_calls(expr, name_param_mapping, closure_arities)
                 }).collect();
 
                 let var_name_list = match name_param_mapping.get(fun_name) {
@@ -800,7 +1072,7 @@ fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
         }
     }
 
-    fn extend_fun_decls(decls: &Vec<FunDecl<Exp<()>, ()>>, name_param_mapping: &HashMap<String, Vec<Exp<()>>>) -> Vec<FunDecl<Exp<()>, ()>> {
+    fn extend_fun_decls(decls: &[FunDecl<Exp<()>, ()>], name_param_mapping: &HashMap<String, Vec<Exp<()>>>, closure_arities: &HashMap<String, usize>) -> Vec<FunDecl<Exp<()>, ()>> {
         decls.iter().map(|decl| {
             // extended param list
             let var_name_list = match name_param_mapping.get(&decl.name) {
@@ -818,7 +1090,7 @@ fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
             assert_eq!(var_name_list.len(), extended_call_param_list.len());
 
             // extended body
-            let extended_body = extend_fun_calls(&decl.body, name_param_mapping);
+            let extended_body = extend_fun_calls(&decl.body, name_param_mapping, closure_arities);
 
 
             FunDecl {
@@ -832,21 +1104,189 @@ fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
 
     // seperate decls and main_body
     let fun_to_lift = should_lift(p); // extract all func_name to lift
-    let (unextended_all_decls, unextended_main_body) = extend_declaration_and_lift(p, HashSet::new(), &fun_to_lift, true); 
+    // the input is a variable of main, captured like any other when it is used
+    let main_env = if uses_input(p) { HashSet::from([INPUT.to_string()]) } else { HashSet::new() };
+    let closure_arities = closure_arities(p);
+    let (unextended_all_decls, unextended_main_body) = extend_declaration_and_lift(p, main_env, &fun_to_lift, &closure_arities, true);
 
     // extend the call parameters 
     let name_param_mapping = fun_decls_to_hashmap(&unextended_all_decls);
     // panic!("length of mapping: {}, count of all_decls: {}, count of fun_to_lift: {}",
     //     name_param_mapping.len(), unextended_all_decls.len(), fun_to_lift.len());
-    let extended_main_body = extend_fun_calls(&unextended_main_body, &name_param_mapping);
-    let extended_all_decls = extend_fun_decls(&unextended_all_decls, &name_param_mapping);
-    let extended_global_decls = generate_global_decls(extended_all_decls.clone(), &fun_to_lift);
+    let extended_main_body = extend_fun_calls(&unextended_main_body, &name_param_mapping, &closure_arities);
+    let extended_all_decls = extend_fun_decls(&unextended_all_decls, &name_param_mapping, &closure_arities);
+    let mut extended_global_decls = generate_global_decls(extended_all_decls.clone(), &fun_to_lift);
+    extended_global_decls.extend(generate_closure_entries(&closure_arities, &name_param_mapping));
 
     (extended_global_decls, extended_main_body)
 
 }
 
 
+// ********************************************************************
+//
+//                         closure conversion
+//
+// ********************************************************************
+
+// A function used as a value becomes a closure: a heap object holding its
+// arity, the address of its closure entry point and a tuple of the
+// variables the lifted function captured as extra parameters.
+// The entry point takes that tuple followed by the arguments, and
+// tail calls the lifted function with the unpacked captured variables.
+
+fn closure_entry_name(fun_name: &str) -> String {
+    format!("{}#closure", fun_name)
+}
+
+// the variable holding the closure of a function, so that every use of
+// the function as a value is the same closure
+fn closure_var_name(fun_name: &str) -> String {
+    format!("{}#value", fun_name)
+}
+
+// Allocate the closures of the functions of a scope used as values before
+// its body runs. The functions of a scope capture the same variables, so
+// their closures share one environment tuple, and since the closures are
+// themselves captured, they are stored in it once they exist:
+// let f#group = (x, 0, 0), f#value = closure(f#closure, 1, f#group), ...,
+//     f#value#set = f#group[1] := f#value, ... in body
+fn allocate_closures<Ann>(decls: &[FunDecl<Exp<Ann>, Ann>], captured_vars: &[String], closure_arities: &HashMap<String, usize>, body: Exp<()>) -> Exp<()> {
+    let fun_names: Vec<&String> = decls.iter().map(|decl| &decl.name).filter(|fun_name| closure_arities.contains_key(*fun_name)).collect();
+    if fun_names.is_empty() {
+        return body;
+    }
+
+    let env_name = format!("{}#group", fun_names[0]);
+    let closure_vars: HashSet<String> = fun_names.iter().map(|fun_name| closure_var_name(fun_name)).collect();
+
+    let env = captured_vars.iter().map(|var| {
+        if closure_vars.contains(var) { Box::new(Exp::Num(0, ())) } else { Box::new(Exp::Var(var.clone(), ())) }
+    }).collect();
+    let mut bindings = vec![(env_name.clone(), Exp::Prim(Prim::MakeTuple, env, ()))];
+
+    for fun_name in fun_names {
+        bindings.push((closure_var_name(fun_name), Exp::MakeClosure {
+            fun_name: closure_entry_name(fun_name),
+            arity: *closure_arities.get(fun_name).unwrap(),
+            env: Box::new(Exp::Var(env_name.clone(), ())),
+            ann: (),
+        }));
+    }
+
+    for (i, var) in captured_vars.iter().enumerate() {
+        if closure_vars.contains(var) {
+            bindings.push((format!("{}#set", var), Exp::Prim(Prim::SetIndex, vec![
+                Box::new(Exp::Var(env_name.clone(), ())),
+                Box::new(Exp::Num(i as i64, ())),
+                Box::new(Exp::Var(var.clone(), ())),
+            ], ())));
+        }
+    }
+
+    Exp::Let { bindings, body: Box::new(body), ann: () }
+}
+
+// Find the functions used as values (names are unique after uniquify)
+// and their arity
+fn closure_arities<Ann>(p: &Exp<Ann>) -> HashMap<String, usize> {
+    fn closure_arities_helper<Ann>(p: &Exp<Ann>, arities: &mut HashMap<String, usize>, vars: &mut HashSet<String>) {
+        match p {
+            Exp::Num(_, _) | Exp::Float(_, _) | Exp::Bool(_, _) | Exp::Str(_, _) => {},
+
+            Exp::Var(var_name, _) => {
+                vars.insert(var_name.clone());
+            },
+
+            Exp::Prim(_, exprs, _) => {
+                for expr in exprs {
+                    closure_arities_helper(expr, arities, vars);
+                }
+            },
+
+            Exp::Let { bindings, body, ann: _ } => {
+                for (_var_name, expr) in bindings {
+                    closure_arities_helper(expr, arities, vars);
+                }
+                closure_arities_helper(body, arities, vars);
+            },
+
+            Exp::If { cond, thn, els, ann: _ } => {
+                closure_arities_helper(cond, arities, vars);
+                closure_arities_helper(thn, arities, vars);
+                closure_arities_helper(els, arities, vars);
+            },
+
+            Exp::FunDefs { decls, body, ann: _ } => {
+                for decl in decls {
+                    arities.insert(decl.name.clone(), decl.parameters.len());
+                    closure_arities_helper(&decl.body, arities, vars);
+                }
+                closure_arities_helper(body, arities, vars);
+            },
+
+            Exp::Call(_, args, _) | Exp::InternalTailCall(_, args, _) | Exp::ExternalCall { fun_name: _, args, is_tail: _, ann: _ } => {
+                for expr in args {
+                    closure_arities_helper(expr, arities, vars);
+                }
+            },
+
+            Exp::ClosureCall { fun, args, is_tail: _, ann: _ } => {
+                closure_arities_helper(fun, arities, vars);
+                for expr in args {
+                    closure_arities_helper(expr, arities, vars);
+                }
+            },
+
+            Exp::Lambda { .. } | Exp::MakeClosure { .. } => {
+                panic!("Lambda and MakeClosure shouldn't occur at closure conversion stage")
+            }
+
+            Exp::Seq { .. } => {
+                panic!("Seq shouldn't occur at closure conversion stage")
+            }
+        }
+    }
+
+    let mut arities = HashMap::new();
+    let mut vars = HashSet::new();
+    closure_arities_helper(p, &mut arities, &mut vars);
+    arities.retain(|fun_name, _| vars.contains(fun_name));
+    arities
+}
+
+// generate the global closure entry points:
+// def f#closure(f#env, x, y): f(f#env[0], ..., f#env[n-1], x, y)
+fn generate_closure_entries(closure_arities: &HashMap<String, usize>, name_param_mapping: &HashMap<String, Vec<Exp<()>>>) -> Vec<FunDecl<Exp<()>, ()>> {
+    let mut fun_names: Vec<&String> = closure_arities.keys().collect();
+    fun_names.sort(); // keep the generated code deterministic
+
+    fun_names.into_iter().map(|fun_name| {
+        let var_name_list = name_param_mapping.get(fun_name).unwrap();
+        let num_captured = var_name_list.len() - closure_arities.get(fun_name).unwrap();
+        let env_name = format!("{}#env", fun_name);
+
+        let mut args: Vec<Exp<()>> = (0..num_captured).map(|i| {
+            Exp::Prim(Prim::GetIndex, vec![Box::new(Exp::Var(env_name.clone(), ())), Box::new(Exp::Num(i as i64, ()))], ())
+        }).collect();
+        args.extend(var_name_list[num_captured..].iter().cloned());
+
+        let mut parameters = vec![env_name.clone()];
+        parameters.extend(var_name_list[num_captured..].iter().map(|expr| match expr {
+            Exp::Var(var, _) => var.clone(),
+            _ => panic!("name_param_mapping should contain only Exp::Var!"),
+        }));
+
+        FunDecl {
+            name: closure_entry_name(fun_name),
+            parameters,
+            body: Exp::ExternalCall { fun_name: fun_name.clone(), args, is_tail: true, ann: () },
+            ann: (),
+        }
+    }).collect()
+}
+
+
 // ********************************************************************
 //
 //                              taggings 
@@ -854,21 +1294,25 @@ fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
 // ********************************************************************
 
 fn tag_exp<Ann>(e: &Exp<Ann>, tag: &mut u32) -> Exp<u32> {
-    *tag = *tag + 1;
-    let cur_tag: u32 = tag.clone();
+    *tag += 1;
+    let cur_tag: u32 = *tag;
     match e {
-        Exp::Num(n, _) => return Exp::Num(*n, cur_tag),
+        Exp::Num(n, _) => Exp::Num(*n, cur_tag),
+
+        Exp::Float(f, _) => Exp::Float(*f, cur_tag),
 
-        Exp::Bool(b, _) => return Exp::Bool(*b, cur_tag),
+        Exp::Bool(b, _) => Exp::Bool(*b, cur_tag),
 
-        Exp::Var(v, _) => return Exp::Var(v.clone(), cur_tag),
+        Exp::Str(s, _) => Exp::Str(s.clone(), cur_tag),
+
+        Exp::Var(v, _) => Exp::Var(v.clone(), cur_tag),
 
         Exp::Prim(prim, exp_vec, _) => {
             let mut tagged_exp_vec: Vec<Box<Exp<u32>>> = Vec::new();
             for exp in exp_vec.iter() {
                 tagged_exp_vec.push(Box::new(tag_exp(exp, tag)));
             }
-            return Exp::Prim(*prim, tagged_exp_vec, cur_tag)
+            Exp::Prim(*prim, tagged_exp_vec, cur_tag)
         },
 
         Exp::Let{bindings, body, ..} => {
@@ -876,7 +1320,7 @@ fn tag_exp<Ann>(e: &Exp<Ann>, tag: &mut u32) -> Exp<u32> {
             for (var_name, exp) in bindings.iter() {
                 tagged_bindings.push((var_name.clone(), tag_exp(exp, tag)));
             }
-            return Exp::Let{
+            Exp::Let{
                 bindings: tagged_bindings, 
                 body: Box::new(tag_exp(body, tag)), 
                 ann: cur_tag
@@ -884,7 +1328,7 @@ fn tag_exp<Ann>(e: &Exp<Ann>, tag: &mut u32) -> Exp<u32> {
         },
 
         Exp::If{cond, thn, els, ..} => {
-            return Exp::If{
+            Exp::If{
                 cond: Box::new(tag_exp(cond, tag)),
                 thn: Box::new(tag_exp(thn, tag)),
                 els: Box::new(tag_exp(els, tag)),
@@ -931,23 +1375,56 @@ fn tag_exp<Ann>(e: &Exp<Ann>, tag: &mut u32) -> Exp<u32> {
                 is_tail: *is_tail, 
                 ann: cur_tag, 
             }
+        },
+
+        Exp::Lambda { parameters, body, ann: _ } => {
+            Exp::Lambda {
+                parameters: parameters.clone(),
+                body: Box::new(tag_exp(body, tag)),
+                ann: cur_tag,
+            }
+        },
+
+        Exp::ClosureCall { fun, args, is_tail, ann: _ } => {
+            let new_fun = tag_exp(fun, tag);
+            let new_args = args.iter().map(|expr| tag_exp(expr, tag)).collect();
+
+            Exp::ClosureCall {
+                fun: Box::new(new_fun),
+                args: new_args,
+                is_tail: *is_tail,
+                ann: cur_tag,
+            }
+        },
+
+        Exp::MakeClosure { fun_name, arity, env, ann: _ } => {
+            Exp::MakeClosure {
+                fun_name: fun_name.clone(),
+                arity: *arity,
+                env: Box::new(tag_exp(env, tag)),
+                ann: cur_tag,
+            }
+        },
+
+        Exp::Seq { .. } => {
+            panic!("Seq shouldn't occur at tagging stage")
         }
     }
 }
 
-fn tag_fundecl(defs: &Vec<FunDecl<Exp<()>, ()>>, tag: &mut u32) -> Vec<FunDecl<Exp<u32>, u32>> {
+fn tag_fundecl(defs: &[FunDecl<Exp<()>, ()>], tag: &mut u32) -> Vec<FunDecl<Exp<u32>, u32>> {
     defs.iter().map(|decl| {
         FunDecl {
             name: decl.name.clone(),
             parameters: decl.parameters.clone(),
             body: tag_exp(&decl.body, tag),
-            ann: tag.clone(),
+            ann: *tag,
         }
     }).collect()
 }
 
 
-fn tag_prog(defs: &Vec<FunDecl<Exp<()>, ()>>, main: Exp<()>) -> (Vec<FunDecl<Exp<u32>, u32>>, Exp<u32>) {
+fn tag_prog(defs: &[FunDecl<Exp<()>, ()>], main: Exp<()>) -> (Vec<FunDecl<Exp<u32>, u32>>, Exp<u32>) {
     let mut tag: u32 = 0;
     let tagged_defs = tag_fundecl(defs, &mut tag);
     let tagged_main = tag_exp(&main, &mut tag);
@@ -958,38 +1435,95 @@ fn tag_prog(defs: &Vec<FunDecl<Exp<()>, ()>>, main: Exp<()>) -> (Vec<FunDecl<Exp
 fn sequentialize(e: &Exp<u32>) -> SeqExp<()> {
     match e {
         Exp::Num(i, _) => SeqExp::Imm(ImmExp::Num(*i), ()),
+        Exp::Float(f, _) => SeqExp::Imm(ImmExp::Float(*f), ()),
         Exp::Bool(b, _) => SeqExp::Imm(ImmExp::Bool(*b), ()),
+        Exp::Str(s, _) => SeqExp::Str(s.clone(), ()),
         Exp::Var(x, _) => SeqExp::Imm(ImmExp::Var(x.clone()), ()),
         Exp::Prim(cur_prim, exp_vec, cur_tag) => {
             match cur_prim {
-                Prim::Add1 | Prim::Sub1 | Prim::Not | Prim::Print | Prim::IsBool | Prim::IsNum => {
+                Prim::Add1 | Prim::Sub1 | Prim::Not | Prim::Print | Prim::IsBool | Prim::IsNum | Prim::IsTuple
+                | Prim::IsStr | Prim::Len | Prim::ToStr | Prim::ToNum | Prim::Abs => {
                     let seq_exp = sequentialize(exp_vec[0].as_ref());
                     let cur_name = format!("#prim1_{}", cur_tag);
-                    return SeqExp::Let{ // let #prim1_tag = seq_exp in Prim1(#prim1_tag)
+                    SeqExp::Let{ // let #prim1_tag = seq_exp in Prim1(#prim1_tag)
                         var: cur_name.clone(), 
                         bound_exp: Box::new(seq_exp),
-                        body: Box::new(SeqExp::Prim(cur_prim.clone(), vec![ImmExp::Var(cur_name)], ())),
+                        body: Box::new(SeqExp::Prim(*cur_prim, vec![ImmExp::Var(cur_name)], ())),
                         ann: ()
                     }
                 } ,   
-                Prim::Add | Prim::Sub | Prim::Mul | Prim::And | Prim::Or | Prim::Lt | Prim::Gt 
-                | Prim::Le | Prim::Ge | Prim::Eq | Prim::Neq => {
+                Prim::And | Prim::Or => {
+                    // && and || short-circuit, so the right operand is only
+                    // evaluated in the branch where it decides the result:
+                    //     let x1 = se1 in let c = x1 && x1 in
+                    //     if c: (let x2 = se2 in c && x2) else: false
+                    // and dually for ||. The prim applications do the runtime
+                    // bool checks, so both operands still fail with a logic
+                    // error rather than an if error.
+                    let seq_exp_1 = sequentialize(exp_vec[0].as_ref());
+                    let seq_exp_2 = sequentialize(exp_vec[1].as_ref());
+                    let name_1 = format!("#prim2_1_{}", cur_tag);
+                    let name_2 = format!("#prim2_2_{}", cur_tag);
+                    let cond_name = format!("#prim2_cond_{}", cur_tag);
+                    let eval_right = SeqExp::Let {
+                        var: name_2.clone(),
+                        bound_exp: Box::new(seq_exp_2),
+                        body: Box::new(SeqExp::Prim(*cur_prim, vec![ImmExp::Var(cond_name.clone()), ImmExp::Var(name_2)], ())),
+                        ann: ()
+                    };
+                    let decided = SeqExp::Imm(ImmExp::Bool(*cur_prim == Prim::Or), ());
+                    let (thn, els) = if *cur_prim == Prim::And { (eval_right, decided) } else { (decided, eval_right) };
+                    SeqExp::Let {
+                        var: name_1.clone(),
+                        bound_exp: Box::new(seq_exp_1),
+                        body: Box::new(SeqExp::Let {
+                            var: cond_name.clone(),
+                            bound_exp: Box::new(SeqExp::Prim(*cur_prim, vec![ImmExp::Var(name_1.clone()), ImmExp::Var(name_1)], ())),
+                            body: Box::new(SeqExp::If {
+                                cond: ImmExp::Var(cond_name),
+                                thn: Box::new(thn),
+                                els: Box::new(els),
+                                ann: ()
+                            }),
+                            ann: ()
+                        }),
+                        ann: ()
+                    }
+                }
+                Prim::Add | Prim::Sub | Prim::Mul | Prim::Div | Prim::Lt | Prim::Gt 
+                | Prim::Le | Prim::Ge | Prim::Eq | Prim::Neq | Prim::GetIndex | Prim::Concat
+                | Prim::IntDiv | Prim::Rem | Prim::BitAnd | Prim::BitOr | Prim::BitXor | Prim::Shl | Prim::Shr
+                | Prim::Min | Prim::Max => {
                     let seq_exp_1 = sequentialize(exp_vec[0].as_ref());
                     let seq_exp_2 = sequentialize(exp_vec[1].as_ref());
                     let name_1 = format!("#prim2_1_{}", cur_tag);
                     let name_2 = format!("#prim2_2_{}", cur_tag);
-                    return SeqExp::Let{
+                    SeqExp::Let{
                         var: name_1.clone(),
                         bound_exp: Box::new(seq_exp_1),
                         body: Box::new(SeqExp::Let{
                             var: name_2.clone(),
                             bound_exp: Box::new(seq_exp_2),
-                            body: Box::new(SeqExp::Prim(cur_prim.clone(), vec![ImmExp::Var(name_1), ImmExp::Var(name_2)], ())),
+                            body: Box::new(SeqExp::Prim(*cur_prim, vec![ImmExp::Var(name_1), ImmExp::Var(name_2)], ())),
                             ann: ()
                         }),
                         ann: ()
                     }
                 }
+                Prim::SetIndex | Prim::Substr | Prim::MakeTuple | Prim::ReadNum | Prim::ReadBool => {
+                    // let #primn_i_tag = seq_exp_i in ... in PrimN(#primn_0_tag, ...)
+                    let names: Vec<String> = (0..exp_vec.len()).map(|i| format!("#primn_{}_{}", i, cur_tag)).collect();
+                    let mut current_body = SeqExp::Prim(*cur_prim, names.iter().map(|name| ImmExp::Var(name.clone())).collect(), ());
+                    for (name, exp) in names.iter().zip(exp_vec.iter()).rev() {
+                        current_body = SeqExp::Let {
+                            var: name.clone(),
+                            bound_exp: Box::new(sequentialize(exp)),
+                            body: Box::new(current_body),
+                            ann: (),
+                        };
+                    }
+                    current_body
+                }
             }
         },
         Exp::Let { bindings, body, ann: _ } => {
@@ -1003,14 +1537,14 @@ fn sequentialize(e: &Exp<u32>) -> SeqExp<()> {
                     ann: (),
                 };
             }
-            return current_body;
+            current_body
         },
         Exp::If { cond, thn, els, ann } => {
             let seq_exp_1 = sequentialize(cond);
             let seq_exp_2 = sequentialize(thn);
             let seq_exp_3 = sequentialize(els);
             let cond_name = format!("#if_cond_{}", ann);
-            return SeqExp::Let{ // let x1 = se1 in if x1: se2 else: se3
+            SeqExp::Let{ // let x1 = se1 in if x1: se2 else: se3
                 var: cond_name.clone(),
                 bound_exp: Box::new(seq_exp_1),
                 body: Box::new(SeqExp::If{
@@ -1032,9 +1566,9 @@ fn sequentialize(e: &Exp<u32>) -> SeqExp<()> {
                     ann: () 
                 })
             }
-            return SeqExp::FunDefs { 
+            SeqExp::FunDefs { 
                 decls: tagged_decl, 
-                body: Box::new(sequentialize(&body)), 
+                body: Box::new(sequentialize(body)), 
                 ann: () 
             }
         },
@@ -1059,7 +1593,7 @@ fn sequentialize(e: &Exp<u32>) -> SeqExp<()> {
                     ann: (),
                 };
             }
-            return current_body
+            current_body
         },
         Exp::ExternalCall { fun_name, args, is_tail, ann } => {
             let mut seq_args: Vec<ImmExp> = Vec::new();
@@ -1072,7 +1606,7 @@ fn sequentialize(e: &Exp<u32>) -> SeqExp<()> {
             let mut current_body = SeqExp::ExternalCall { 
                 fun_name: fun_name.clone(), 
                 args: seq_args.clone(), 
-                is_tail: is_tail.clone(), 
+                is_tail: *is_tail, 
                 ann: () 
             };
             for (var_name, var_exp) in bindings.iter().rev() {
@@ -1084,7 +1618,53 @@ fn sequentialize(e: &Exp<u32>) -> SeqExp<()> {
                     ann: (),
                 };
             }
-            return current_body
+            current_body
+        },
+        Exp::ClosureCall { fun, args, is_tail, ann } => {
+            // the closure is evaluated before the arguments
+            let fun_name = format!("#closure_{}", ann);
+            let mut seq_args: Vec<ImmExp> = Vec::new();
+            let mut bindings: Vec<(String, &Exp<u32>)> = vec![(fun_name.clone(), fun)];
+            for (i, arg) in args.iter().enumerate() {
+                let arg_name = format!("#function_{}_arg_{}", ann, i);
+                seq_args.push(ImmExp::Var(arg_name.clone()));
+                bindings.push((arg_name, arg));
+            }
+            let mut current_body = SeqExp::ClosureCall {
+                fun: ImmExp::Var(fun_name),
+                args: seq_args,
+                is_tail: *is_tail,
+                ann: ()
+            };
+            for (var_name, var_exp) in bindings.iter().rev() {
+                current_body = SeqExp::Let {
+                    var: var_name.clone(),
+                    bound_exp: Box::new(sequentialize(var_exp)),
+                    body: Box::new(current_body),
+                    ann: (),
+                };
+            }
+            current_body
+        },
+        Exp::MakeClosure { fun_name, arity, env, ann } => {
+            let env_name = format!("#closure_env_{}", ann);
+            SeqExp::Let {
+                var: env_name.clone(),
+                bound_exp: Box::new(sequentialize(env)),
+                body: Box::new(SeqExp::MakeClosure {
+                    fun_name: fun_name.clone(),
+                    arity: *arity,
+                    env: ImmExp::Var(env_name),
+                    ann: ()
+                }),
+                ann: ()
+            }
+        },
+        Exp::Lambda { .. } => {
+            panic!("Lambda shouldn't occur at sequentialize stage")
+        },
+        Exp::Seq { .. } => {
+            panic!("Seq shouldn't occur at sequentialize stage")
         }
     }
 }
@@ -1099,21 +1679,21 @@ fn seq_prog(decls: &[FunDecl<Exp<u32>, u32>], p: &Exp<u32>) -> SeqProg<()> {
             ann: ()
         })
     }
-    return SeqProg{
+    SeqProg{
         funs: seq_funs,
-        main: sequentialize(&p),
+        main: sequentialize(p),
         ann: ()
     }
 }
 
 fn tag_seq_exp(seq_e: &SeqExp<()>, tag: &mut u32) -> SeqExp<u32> {
-    *tag = *tag + 1;
-    let cur_tag: u32 = tag.clone();
+    *tag += 1;
+    let cur_tag: u32 = *tag;
     match seq_e {
-        SeqExp::Imm(i, _) => return SeqExp::Imm(i.clone(), cur_tag),
-        SeqExp::Prim(prim, s_e_vec, _) => return SeqExp::Prim(*prim, s_e_vec.clone(), cur_tag),
+        SeqExp::Imm(i, _) => SeqExp::Imm(i.clone(), cur_tag),
+        SeqExp::Prim(prim, s_e_vec, _) => SeqExp::Prim(*prim, s_e_vec.clone(), cur_tag),
         SeqExp::Let{var, bound_exp, body, ..} => {
-            return SeqExp::Let{
+            SeqExp::Let{
                 var: var.clone(),
                 bound_exp: Box::new(tag_seq_exp(bound_exp, tag)),
                 body: Box::new(tag_seq_exp(body, tag)),
@@ -1121,7 +1701,7 @@ fn tag_seq_exp(seq_e: &SeqExp<()>, tag: &mut u32) -> SeqExp<u32> {
             }
         },
         SeqExp::If{cond, thn, els, ..} => {
-            return SeqExp::If{
+            SeqExp::If{
                 cond: cond.clone(),
                 thn: Box::new(tag_seq_exp(thn, tag)),
                 els: Box::new(tag_seq_exp(els, tag)),
@@ -1131,7 +1711,7 @@ fn tag_seq_exp(seq_e: &SeqExp<()>, tag: &mut u32) -> SeqExp<u32> {
         SeqExp::FunDefs { decls, body, ann: _ } => {
             let mut tagged_decls: Vec<FunDecl<SeqExp<u32>, u32>> = Vec::new();
             for decl in decls.iter() {
-                let decl_tag: u32 = tag.clone();
+                let decl_tag: u32 = *tag;
                 tagged_decls.push(FunDecl { 
                     name: decl.name.clone(), 
                     parameters: decl.parameters.clone(), 
@@ -1139,17 +1719,24 @@ fn tag_seq_exp(seq_e: &SeqExp<()>, tag: &mut u32) -> SeqExp<u32> {
                     ann: decl_tag
                 })
             }
-            return SeqExp::FunDefs { 
+            SeqExp::FunDefs { 
                 decls: tagged_decls, 
                 body: Box::new(tag_seq_exp(body, tag)), 
                 ann: cur_tag
-            };
+            }
         },
         SeqExp::InternalTailCall(s, i_exp_vec, _) => {
-            return SeqExp::InternalTailCall(s.clone(), i_exp_vec.clone(), cur_tag);
+            SeqExp::InternalTailCall(s.clone(), i_exp_vec.clone(), cur_tag)
         },
         SeqExp::ExternalCall { fun_name, args, is_tail, ann: _ } => {
-            return SeqExp::ExternalCall { fun_name: fun_name.clone(), args: args.clone(), is_tail: is_tail.clone(), ann: cur_tag };
+            SeqExp::ExternalCall { fun_name: fun_name.clone(), args: args.clone(), is_tail: *is_tail, ann: cur_tag }
+        },
+        SeqExp::ClosureCall { fun, args, is_tail, ann: _ } => {
+            SeqExp::ClosureCall { fun: fun.clone(), args: args.clone(), is_tail: *is_tail, ann: cur_tag }
+        },
+        SeqExp::Str(s, _) => SeqExp::Str(s.clone(), cur_tag),
+        SeqExp::MakeClosure { fun_name, arity, env, ann: _ } => {
+            SeqExp::MakeClosure { fun_name: fun_name.clone(), arity: *arity, env: env.clone(), ann: cur_tag }
         }
     }
 }
@@ -1161,7 +1748,7 @@ fn tag_sprog(p: &SeqProg<()>) -> SeqProg<u32> {
     let mut tag: u32 = 1;
     let mut new_funs: Vec<FunDecl<SeqExp<u32>, u32>> = Vec::new();
     for fun in p.funs.iter() {
-        let cur_tag = tag.clone();
+        let cur_tag = tag;
         new_funs.push(FunDecl { 
             name: fun.name.clone(), 
             parameters: fun.parameters.clone(), 
@@ -1170,96 +1757,681 @@ fn tag_sprog(p: &SeqProg<()>) -> SeqProg<u32> {
         })
     }
 
-    return SeqProg{funs: new_funs, main: tag_seq_exp(&p.main, &mut tag), ann: 0};
+    SeqProg{funs: new_funs, main: tag_seq_exp(&p.main, &mut tag), ann: 0}
 }
 
 
-fn get_offset(var: &String, env: &HashMap<String, i32>) -> i32 {
+fn get_location(var: &String, env: &HashMap<String, Arg64>) -> Arg64 {
     match env.get(var) {
-        Some(offset) => return *offset,
+        Some(location) => *location,
         None => {
             panic!("Variable {} should be in scope, content of current env is {:?}", var.clone(), env)
         }
     }
 }
 
-fn compile_imm_to_arg(i: &ImmExp, env: &HashMap<String, i32>) -> Arg64 {
+fn get_offset(var: &String, env: &HashMap<String, Arg64>) -> i32 {
+    match get_location(var, env) {
+        Arg64::Mem(MemRef{reg: Reg::Rbp, offset}) => offset,
+        location => panic!("Variable {} should be on the stack, but it is in {:?}", var, location)
+    }
+}
+
+// The location of a new variable, either the register it was allocated
+// or the next free stack slot
+fn new_location(var: &String, env: &HashMap<String, Arg64>, frame: &Frame) -> Arg64 {
+    match frame.registers.get(var) {
+        Some(reg) => Arg64::Reg(*reg),
+        None => Arg64::Mem(MemRef{reg: Reg::Rbp, offset: -8 * (env.len() as i32 + 1)})
+    }
+}
+
+fn compile_imm_to_arg(i: &ImmExp, env: &HashMap<String, Arg64>) -> Arg64 {
     match i {
-        ImmExp::Num(n) => return Arg64::Signed(*n << 1),
+        ImmExp::Num(n) => Arg64::Signed(*n << 2),
+        ImmExp::Float(f) => Arg64::Unsigned(float_to_snake_val(*f)),
         ImmExp::Bool(b) => {
-            if *b {return Arg64::Unsigned(SNAKE_TRU.0)} else {return Arg64::Unsigned(SNAKE_FLS.0)}
+            if *b {Arg64::Unsigned(SNAKE_TRU.0)} else {Arg64::Unsigned(SNAKE_FLS.0)}
         },
-        ImmExp::Var(x) => {
-            let addr = get_offset(x, env);
-            return Arg64::Mem(MemRef{reg: Reg::Rsp, offset: addr})
+        ImmExp::Var(x) => get_location(x, env)
+    }
+}
+
+// The layout of the frame of the function being compiled
+struct Frame {
+    // the size of the frame in bytes, a multiple of 16 so that Rsp stays
+    // aligned for calls
+    space: i32,
+    // the let-bound variables that live in registers
+    registers: HashMap<String, Reg>,
+    // the callee-saved registers the function uses and where they are saved
+    saved: Vec<(Reg, MemRef)>,
+}
+
+fn fun_frame(body: &SeqExp<u32>, num_params: i32) -> Frame {
+    let registers = allocate_registers(body, &VAR_REGS);
+    let var_num = space_needed_helper(body) + num_params;
+    let saved: Vec<(Reg, MemRef)> = VAR_REGS.iter()
+        .filter(|reg| registers.values().any(|r| r == *reg))
+        .enumerate()
+        .map(|(i, reg)| (*reg, MemRef{reg: Reg::Rbp, offset: -8 * (var_num + i as i32 + 1)}))
+        .collect();
+    let slots = var_num + saved.len() as i32;
+    Frame { space: 8 * (slots + slots % 2), registers, saved }
+}
+
+// The stack slot of the (i)th argument of a call made from the current
+// frame: the callee's frame starts 16 bytes below Rsp, after the return
+// address and the saved Rbp.
+fn call_arg_slot(i: usize) -> MemRef {
+    MemRef{reg: Reg::Rsp, offset: -24 - 8 * i as i32}
+}
+
+// The (i)th parameter of the current frame
+fn param_slot(i: usize) -> MemRef {
+    MemRef{reg: Reg::Rbp, offset: -8 * (i as i32 + 1)}
+}
+
+// Sets up the frame of a function with (num_params) parameters, zeroes
+// its other slots, so that the garbage collector never finds stale values
+// on the stack, and saves the callee-saved registers it uses. The saved
+// registers hold the caller's variables, the collector updates them too.
+fn compile_fun_prologue(num_params: i32, frame: &Frame) -> Vec<Instr> {
+    let mut is = vec![
+        Instr::Push(Arg32::Reg(Reg::Rbp)),                                               // push Rbp
+        Instr::Mov(MovArgs::ToReg(Reg::Rbp, Arg64::Reg(Reg::Rsp))),                      // mov Rbp, Rsp
+        Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(frame.space))),                // sub Rsp, (space)
+    ];
+    if num_params < frame.space / 8 {
+        is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Signed(0))));
+        for i in num_params..frame.space / 8 {
+            is.push(Instr::Mov(MovArgs::ToMem(param_slot(i as usize), Reg32::Reg(Reg::Rax))));
         }
     }
+    for (reg, slot) in frame.saved.iter() {
+        is.push(Instr::Mov(MovArgs::ToMem(*slot, Reg32::Reg(*reg))));
+    }
+    is
 }
 
-fn runtime_overflow_check() -> Vec<Instr> {
+fn compile_fun_epilogue(frame: &Frame) -> Vec<Instr> {
     let mut is: Vec<Instr> = Vec::new();
-    is.push(Instr::Comment(String::from("Check overflow")));
-    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(OVFL_ERROR))));
-    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))));
-    is.push(Instr::Jo(String::from("snake_err")));
-    return is
+    for (reg, slot) in frame.saved.iter() {
+        is.push(Instr::Mov(MovArgs::ToReg(*reg, Arg64::Mem(*slot))));
+    }
+    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rsp, Arg64::Reg(Reg::Rbp))));                 // mov Rsp, Rbp
+    is.push(Instr::Pop(Loc::Reg(Reg::Rbp)));                                             // pop Rbp
+    is
+}
+
+fn runtime_overflow_check() -> Vec<Instr> {
+    vec![
+        Instr::Comment(String::from("Check overflow")),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(OVFL_ERROR))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))),
+        Instr::Jo(String::from("snake_err")),
+    ]
 }
 
 fn check_type_num(reg: Reg, err_code: ErrorCode) -> Vec<Instr> {
-    let mut is: Vec<Instr> = Vec::new();
-    is.push(Instr::Comment(String::from("Check Whether Num")));
-    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(err_code))));       // first par of snake_err(): err_code
-    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(reg))));                 // second par of snake_err(): snakeval
-    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rbx, Arg64::Unsigned(TAG_MASK))));       // mov Rbx, TAG_MASK
-    is.push(Instr::Test(BinArgs::ToReg(Reg::Rbx, Arg32::Reg(reg))));                // test Rbx, (reg)
-    is.push(Instr::Jnz(String::from("snake_err")));                                 // jnz snake_err
-    return is
+    vec![
+        Instr::Comment(String::from("Check Whether Num")),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(err_code))),       // first par of snake_err(): err_code
+        Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(reg))),                 // second par of snake_err(): snakeval
+        Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Unsigned(TAG_MASK))),       // mov R11, TAG_MASK
+        Instr::Test(BinArgs::ToReg(Reg::R11, Arg32::Reg(reg))),                // test R11, (reg)
+        Instr::Jnz(String::from("snake_err")),                                 // jnz snake_err
+    ]
+}
+
+fn check_type_int(reg: Reg, err_code: ErrorCode) -> Vec<Instr> {
+    vec![
+        Instr::Comment(String::from("Check Whether Int")),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(err_code))),       // first par of snake_err(): err_code
+        Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(reg))),                 // second par of snake_err(): snakeval
+        Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Unsigned(INT_TAG_MASK))),   // mov R11, INT_TAG_MASK
+        Instr::Test(BinArgs::ToReg(Reg::R11, Arg32::Reg(reg))),                // test R11, (reg)
+        Instr::Jnz(String::from("snake_err")),                                 // jnz snake_err
+    ]
+}
+
+// check that the lowest 3 bits of (reg) are (tag)
+fn check_ptr_tag(reg: Reg, tag: u64, err_code: ErrorCode) -> Vec<Instr> {
+    vec![
+        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(err_code))),       // mov Rdi, (err_code)
+        Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(reg))),                 // mov Rsi, (reg)
+        Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Reg(reg))),                 // mov R11, (reg)
+        Instr::And(BinArgs::ToReg(Reg::R11, Arg32::Unsigned(PTR_TAG_MASK as u32))),    // and R11, PTR_TAG_MASK
+        Instr::Cmp(BinArgs::ToReg(Reg::R11, Arg32::Unsigned(tag as u32))),     // cmp R11, (tag)
+        Instr::Jne(String::from("snake_err")),                                 // jne snake_err
+    ]
 }
 
 fn check_type_bool(reg: Reg, err_code: ErrorCode) -> Vec<Instr> {
-    let mut is: Vec<Instr> = Vec::new();
-    is.push(Instr::Comment(String::from("Check Whether Bool")));
-    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(err_code))));       // mov Rdi, LOGIC_ERROR
-    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(reg))));                 // mov Rsi, (reg)
-    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rbx, Arg64::Unsigned(TAG_MASK))));       // mov Rbx, TAG_MASK
-    is.push(Instr::Test(BinArgs::ToReg(Reg::Rbx, Arg32::Reg(reg))));                // test Rbx, (reg)
-    is.push(Instr::Jz(String::from("snake_err")));                                  // jz snake_err
-    return is
+    let mut is = vec![Instr::Comment(String::from("Check Whether Bool"))];
+    is.extend(check_ptr_tag(reg, BOOL_TAG, err_code));
+    is
+}
+
+fn check_type_closure(reg: Reg, err_code: ErrorCode) -> Vec<Instr> {
+    let mut is = vec![Instr::Comment(String::from("Check Whether Closure"))];
+    is.extend(check_ptr_tag(reg, CLOSURE_TAG, err_code));
+    is
+}
+
+fn check_type_tuple(reg: Reg, err_code: ErrorCode) -> Vec<Instr> {
+    let mut is = vec![Instr::Comment(String::from("Check Whether Tuple"))];
+    is.extend(check_ptr_tag(reg, TUPLE_TAG, err_code));
+    is
+}
+
+fn check_type_string(reg: Reg, err_code: ErrorCode) -> Vec<Instr> {
+    let mut is = vec![Instr::Comment(String::from("Check Whether String"))];
+    is.extend(check_ptr_tag(reg, STRING_TAG, err_code));
+    is
 }
 
 fn runtime_prim1_check(reg: Reg, p: &Prim) -> Vec<Instr> {
     match p {
-        Prim::Add1 | Prim::Sub1 => return check_type_num(reg.clone(), ARITH_ERROR),
-        Prim::Not               => return check_type_bool(reg.clone(), LOGIC_ERROR),
-        _ => return Vec::new()
+        Prim::Add1 | Prim::Sub1 => check_type_num(reg, ARITH_ERROR),
+        Prim::Not               => check_type_bool(reg, LOGIC_ERROR),
+        Prim::ToStr             => check_type_num(reg, ARITH_ERROR),
+        Prim::Abs               => check_type_int(reg, INT_ARITH_ERROR),
+        Prim::Len | Prim::ToNum => check_type_string(reg, STRING_ERROR),
+        _ => Vec::new()
     }
 }
 
 fn runtime_prim2_check(reg: Reg, p: &Prim) -> Vec<Instr> {
     match p {
-        Prim::Add | Prim::Sub | Prim::Mul                       => return check_type_num(reg.clone(), ARITH_ERROR),
-        Prim::And | Prim::Or                                    => return check_type_bool(reg.clone(), LOGIC_ERROR),
-        Prim::Lt | Prim::Gt | Prim::Le | Prim::Ge               => return check_type_num(reg.clone(), CMP_ERROR),
-        _ => return Vec::new()
+        Prim::Add | Prim::Sub | Prim::Mul | Prim::Div           => check_type_num(reg, ARITH_ERROR),
+        Prim::And | Prim::Or                                    => check_type_bool(reg, LOGIC_ERROR),
+        Prim::Lt | Prim::Gt | Prim::Le | Prim::Ge               => check_type_int(reg, CMP_ERROR),
+        Prim::Concat                                            => check_type_string(reg, STRING_ERROR),
+        Prim::IntDiv | Prim::Rem | Prim::BitAnd | Prim::BitOr | Prim::BitXor |
+        Prim::Shl | Prim::Shr | Prim::Min | Prim::Max           => check_type_int(reg, INT_ARITH_ERROR),
+        _ => Vec::new()
     }
 }
 
 fn runtime_if_check(reg: Reg) -> Vec<Instr> {
-    return check_type_bool(reg.clone(), IF_ERROR)
+    check_type_bool(reg, IF_ERROR)
+}
+
+// Scratch stack slots below the local variables, used to move values
+// between the general purpose registers and the x87 stack.
+fn float_slots(space: &i32) -> (MemRef, MemRef) {
+    (MemRef{reg: Reg::Rbp, offset: -space - 8}, MemRef{reg: Reg::Rbp, offset: -space - 16})
+}
+
+// Convert the number in (reg) to an untagged 64-bit float stored in (slot)
+fn convert_to_float(reg: Reg, slot: MemRef, label: &str) -> Vec<Instr> {
+    let is_float_lab = format!("{}_is_float", label);
+    let done_lab = format!("{}_converted", label);
+
+    vec![
+        Instr::Test(BinArgs::ToReg(reg, Arg32::Unsigned(FLOAT_TAG as u32))),   // test (reg), FLOAT_TAG
+        Instr::Jnz(is_float_lab.clone()),                                      // jnz (is_float_lab)
+        Instr::Sar(BinArgs::ToReg(reg, Arg32::Unsigned(2))),                   // int: untag
+        Instr::Mov(MovArgs::ToMem(slot, Reg32::Reg(reg))),                     // mov [slot], (reg)
+        Instr::Fild(slot),                                                     // fild qword [slot]
+        Instr::Fstp(Loc::Mem(slot)),                                           // fstp qword [slot]
+        Instr::Jmp(done_lab.clone()),
+        Instr::Label(is_float_lab),
+        Instr::And(BinArgs::ToReg(reg, Arg32::Signed(!(INT_TAG_MASK as i32)))),  // float: untag
+        Instr::Mov(MovArgs::ToMem(slot, Reg32::Reg(reg))),                     // mov [slot], (reg)
+        Instr::Label(done_lab),
+    ]
+}
+
+// Arithmetic on the numbers in Rax and R10, result in Rax.
+// Two integers are computed directly; as soon as one of the operands is a
+// float both are converted and the operation runs on the x87 stack.
+// Division always produces a float.
+fn compile_arith_to_instr(p: &Prim, ann: &u32, space: &i32) -> Vec<Instr> {
+    let float_lab = format!("float_arith#{}", ann);
+    let done_lab = format!("arith_done#{}", ann);
+    let (slot_a, slot_b) = float_slots(space);
+
+    let mut is: Vec<Instr> = Vec::new();
+    if *p != Prim::Div {
+        is.push(Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Reg(Reg::Rax))));                // mov R11, Rax
+        is.push(Instr::Or(BinArgs::ToReg(Reg::R11, Arg32::Reg(Reg::R10))));                 // or R11, R10
+        is.push(Instr::Test(BinArgs::ToReg(Reg::R11, Arg32::Unsigned(FLOAT_TAG as u32))));  // any float operand?
+        is.push(Instr::Jnz(float_lab.clone()));
+        match p {
+            Prim::Add => {
+                is.push(Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))));
+            },
+            Prim::Sub => {
+                is.push(Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))));
+            },
+            Prim::Mul => {
+                is.push(Instr::Sar(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(2))));
+                is.push(Instr::IMul(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))));
+            },
+            _ => panic!("unexpected situation: expect arithmetic")
+        }
+        is.extend(runtime_overflow_check());                                                // overflow check
+        is.push(Instr::Jmp(done_lab.clone()));
+    }
+
+    is.push(Instr::Label(float_lab.clone()));
+    is.extend(convert_to_float(Reg::Rax, slot_a, &format!("{}_lhs", float_lab)));
+    is.extend(convert_to_float(Reg::R10, slot_b, &format!("{}_rhs", float_lab)));
+    if *p == Prim::Div {
+        is.push(Instr::Comment(String::from("Check division by zero")));
+        is.push(Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(DIV_ZERO_ERROR))));
+        is.push(Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::R10))));
+        is.push(Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Mem(slot_b))));
+        is.push(Instr::Shl(BinArgs::ToReg(Reg::R11, Arg32::Unsigned(1))));                  // drop the sign: +0.0 and -0.0
+        is.push(Instr::Jz(String::from("snake_err")));
+    }
+    is.push(Instr::Fld(slot_b));                                                            // st1 = rhs
+    is.push(Instr::Fld(slot_a));                                                            // st0 = lhs
+    is.push(match p {
+        Prim::Add => Instr::Fadd(Reg::St0, Reg::St1),
+        Prim::Sub => Instr::Fsub(Reg::St0, Reg::St1),
+        Prim::Mul => Instr::Fmul(Reg::St0, Reg::St1),
+        Prim::Div => Instr::Fdiv(Reg::St0, Reg::St1),
+        _ => panic!("unexpected situation: expect arithmetic")
+    });
+    is.push(Instr::Fstp(Loc::Mem(slot_a)));                                                 // result
+    is.push(Instr::Fstp(Loc::Reg(Reg::St0)));                                               // pop rhs
+    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Mem(slot_a))));
+
+    // an infinite or NaN result (all exponent bits set) is an overflow
+    is.push(Instr::Comment(String::from("Check float overflow")));
+    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(OVFL_ERROR))));
+    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))));
+    is.push(Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Reg(Reg::Rax))));
+    is.push(Instr::Shr(BinArgs::ToReg(Reg::R11, Arg32::Unsigned(52))));
+    is.push(Instr::And(BinArgs::ToReg(Reg::R11, Arg32::Unsigned(0x7FF))));
+    is.push(Instr::Cmp(BinArgs::ToReg(Reg::R11, Arg32::Unsigned(0x7FF))));
+    is.push(Instr::Je(String::from("snake_err")));
+
+    is.push(Instr::And(BinArgs::ToReg(Reg::Rax, Arg32::Signed(!(INT_TAG_MASK as i32)))));  // tag as float
+    is.push(Instr::Or(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(FLOAT_TAG as u32))));
+    is.push(Instr::Label(done_lab));
+    is
 }
 
-fn compile_prim1_to_instr(p: &Prim, space: &i32) -> Vec<Instr> {
+// Integer-only arithmetic on the integers in Rax and R10, result in Rax.
+// The tagged value of n is 4n, so
+//   - the bitwise operators work on the tagged values directly;
+//   - idiv of 4a by 4b gives the quotient of a and b untagged and the
+//     remainder already tagged. The divisor is never -1, so idiv can't
+//     fault, only tagging the quotient of MIN_SNAKE_INT // -1 overflows;
+//   - shifts take the untagged amount in cl, and a left shift overflows
+//     when shifting back doesn't give the operand.
+// Clobbers Rcx, Rdx and R11.
+fn compile_int_arith_to_instr(p: &Prim, ann: &u32) -> Vec<Instr> {
+    let mut is: Vec<Instr> = Vec::new();
+    match p {
+        Prim::BitAnd => is.push(Instr::And(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10)))),
+        Prim::BitOr => is.push(Instr::Or(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10)))),
+        Prim::BitXor => is.push(Instr::Xor(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10)))),
+        Prim::IntDiv | Prim::Rem => {
+            is.push(Instr::Comment(String::from("Check division by zero")));
+            is.push(Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(DIV_ZERO_ERROR))));
+            is.push(Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::R10))));
+            is.push(Instr::Test(BinArgs::ToReg(Reg::R10, Arg32::Reg(Reg::R10))));
+            is.push(Instr::Jz(String::from("snake_err")));
+            is.push(Instr::Cqo);                                                            // rdx:rax = 4a
+            is.push(Instr::IDiv(Reg::R10));                                                 // rax = a / b, rdx = 4(a % b)
+            if *p == Prim::IntDiv {
+                is.push(Instr::IMul(BinArgs::ToReg(Reg::Rax, Arg32::Signed(1 << 2))));      // tag as int
+                is.extend(runtime_overflow_check());
+            } else {
+                is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(Reg::Rdx))));
+            }
+        },
+        Prim::Shl | Prim::Shr => {
+            is.push(Instr::Comment(String::from("Check the shift amount")));
+            is.push(Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(OVFL_ERROR))));
+            is.push(Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::R10))));
+            is.push(Instr::Mov(MovArgs::ToReg(Reg::Rcx, Arg64::Reg(Reg::R10))));
+            is.push(Instr::Sar(BinArgs::ToReg(Reg::Rcx, Arg32::Unsigned(2))));              // untag the amount
+            is.push(Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Reg(Reg::Rcx))));
+            is.push(Instr::Shr(BinArgs::ToReg(Reg::R11, Arg32::Unsigned(6))));              // negative or over 63?
+            is.push(Instr::Jnz(String::from("snake_err")));
+            if *p == Prim::Shl {
+                is.push(Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Reg(Reg::Rax))));
+                is.push(Instr::Shl(BinArgs::ToReg(Reg::R11, Arg32::Reg(Reg::Rcx))));
+                is.push(Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Reg(Reg::R11))));
+                is.push(Instr::Sar(BinArgs::ToReg(Reg::Rdx, Arg32::Reg(Reg::Rcx))));
+                is.push(Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))));
+                is.push(Instr::Cmp(BinArgs::ToReg(Reg::Rdx, Arg32::Reg(Reg::Rax))));
+                is.push(Instr::Jne(String::from("snake_err")));
+                is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(Reg::R11))));
+            } else {
+                is.push(Instr::Sar(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rcx))));
+                is.push(Instr::And(BinArgs::ToReg(Reg::Rax, Arg32::Signed(!(INT_TAG_MASK as i32)))));  // tag as int
+            }
+        },
+        Prim::Min | Prim::Max => {
+            let done_lab = format!("min_max_done#{}", ann);
+            is.push(Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))));
+            is.push(if *p == Prim::Min { Instr::Jle(done_lab.clone()) } else { Instr::Jge(done_lab.clone()) });
+            is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(Reg::R10))));
+            is.push(Instr::Label(done_lab));
+        },
+        _ => panic!("unexpected situation: expect integer arithmetic")
+    }
+    is
+}
+
+// Rax := whether the lowest 3 bits of Rax are (tag)
+fn compile_ptr_tag_test(tag: u64, label: &str) -> Vec<Instr> {
+    vec![
+        Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Reg(Reg::Rax))),                        // mov R11, Rax
+        Instr::And(BinArgs::ToReg(Reg::R11, Arg32::Unsigned(PTR_TAG_MASK as u32))),       // and R11, PTR_TAG_MASK
+        Instr::Cmp(BinArgs::ToReg(Reg::R11, Arg32::Unsigned(tag as u32))),                // cmp R11, (tag)
+        Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(SNAKE_TRU.0))),                // mov Rax, SNAKE_TRU
+        Instr::Je(label.to_string()),                                                      // je (label)
+        Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(SNAKE_FLS.0))),                // mov Rax, SNAKE_FLS
+        Instr::Label(label.to_string()),
+    ]
+}
+
+// Checks the tuple and index operands and leaves Rax pointing 8 bytes
+// before the selected element, i.e. the element is at [Rax + 8].
+// The bounds are checked even when the program is typed.
+fn compile_tuple_access(tuple: Arg64, index: Arg64, typed: bool) -> Vec<Instr> {
+    let mut is: Vec<Instr> = Vec::new();
+    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, tuple)));
+    if !typed {
+        is.extend(check_type_tuple(Reg::Rax, INDEX_NOT_TUPLE_ERROR));
+    }
+    is.push(Instr::Mov(MovArgs::ToReg(Reg::R10, index)));
+    if !typed {
+        is.extend(check_type_int(Reg::R10, INDEX_NOT_INT_ERROR));
+    }
+
+    is.push(Instr::Comment(String::from("Check index bounds")));
+    is.push(Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(TUPLE_TAG as u32))));         // untag the address
+    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(INDEX_BOUNDS_ERROR))));
+    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::R10))));
+    is.push(Instr::Sar(BinArgs::ToReg(Reg::R10, Arg32::Unsigned(2))));                        // untag the index
+    is.push(Instr::Cmp(BinArgs::ToReg(Reg::R10, Arg32::Signed(0))));
+    is.push(Instr::Jl(String::from("snake_err")));
+    is.push(Instr::Cmp(BinArgs::ToReg(Reg::R10, Arg32::Mem(MemRef{reg: Reg::Rax, offset: 0}))));   // compare with the length
+    is.push(Instr::Jge(String::from("snake_err")));
+
+    is.push(Instr::Shl(BinArgs::ToReg(Reg::R10, Arg32::Unsigned(3))));
+    is.push(Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))));
+    is
+}
+
+// Makes room for (words) words at the heap pointer, running the garbage
+// collector when they would cross HEAP_END
+fn compile_heap_check(words: usize, ann: &u32) -> Vec<Instr> {
+    compile_heap_check_with(&[], Arg32::Signed(8 * words as i32), ann)
+}
+
+// Makes room for (bytes) bytes at the heap pointer. For a size only known
+// at runtime, (size) computes it into R10 from the variables; it runs again
+// after a collection, which may have moved the values it reads.
+fn compile_heap_check_with(size: &[Instr], bytes: Arg32, ann: &u32) -> Vec<Instr> {
+    let ok_lab = format!("alloc_ok#{}", ann);
+    let mut is = vec![Instr::Comment(String::from("Check heap space"))];
+    is.extend(size.iter().cloned());
+    is.extend(vec![
+        Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(HEAP_PTR))),
+        Instr::Add(BinArgs::ToReg(Reg::Rax, bytes)),
+        Instr::Lea(Reg::R11, String::from("HEAP_END")),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Mem(MemRef{reg: Reg::R11, offset: 0}))),
+        Instr::Jle(ok_lab.clone()),
+    ]);
+    // the variables in registers are pushed to be found and updated by
+    // the collector, an even number of them keeps Rsp aligned
+    for reg in VAR_REGS.iter() {
+        is.push(Instr::Push(Arg32::Reg(*reg)));
+    }
+    is.extend(vec![
+        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Reg(Reg::Rbp))),                      // snake_try_gc(Rbp, Rsp)
+        Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rsp))),
+        Instr::Call(String::from("snake_try_gc")),
+    ]);
+    for reg in VAR_REGS.iter().rev() {
+        is.push(Instr::Pop(Loc::Reg(*reg)));
+    }
+    is.push(Instr::Mov(MovArgs::ToReg(HEAP_PTR, Arg64::Reg(Reg::Rax))));                   // the new heap pointer
+    is.extend(size.iter().cloned());
+    is.extend(vec![
+        Instr::Add(BinArgs::ToReg(Reg::Rax, bytes)),
+        Instr::Lea(Reg::R11, String::from("HEAP_END")),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Mem(MemRef{reg: Reg::R11, offset: 0}))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(OOM_ERROR))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))),
+        Instr::Jg(String::from("snake_err")),
+        Instr::Label(ok_lab),
+    ]);
+    is
+}
+
+// Allocates [length, elem_0, ..., elem_n-1] at the heap pointer
+fn compile_make_tuple(elems: &[ImmExp], env: &HashMap<String, Arg64>, ann: &u32) -> Vec<Instr> {
+    let mut is: Vec<Instr> = Vec::new();
+    is.push(Instr::Comment(String::from("MakeTuple")));
+    is.extend(compile_heap_check(elems.len() + 1, ann));
+    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(elems.len() as u64))));
+    is.push(Instr::Mov(MovArgs::ToMem(MemRef{reg: HEAP_PTR, offset: 0}, Reg32::Reg(Reg::Rax))));
+    for (i, elem) in elems.iter().enumerate() {
+        is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, compile_imm_to_arg(elem, env))));
+        is.push(Instr::Mov(MovArgs::ToMem(MemRef{reg: HEAP_PTR, offset: 8 * (i as i32 + 1)}, Reg32::Reg(Reg::Rax))));
+    }
+    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(HEAP_PTR))));
+    is.push(Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(TUPLE_TAG as u32))));         // tag the address
+    is.push(Instr::Add(BinArgs::ToReg(HEAP_PTR, Arg32::Signed(8 * (elems.len() as i32 + 1)))));
+    is
+}
+
+// Allocates [arity, code address, env] at the heap pointer
+fn compile_make_closure(fun_name: &str, arity: usize, env_tuple: Arg64, ann: &u32) -> Vec<Instr> {
+    let mut is = vec![Instr::Comment(String::from("MakeClosure"))];
+    is.extend(compile_heap_check(3, ann));
+    is.extend(vec![
+        Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(arity as u64))),
+        Instr::Mov(MovArgs::ToMem(MemRef{reg: HEAP_PTR, offset: 0}, Reg32::Reg(Reg::Rax))),
+        Instr::Lea(Reg::Rax, fun_name.to_string()),
+        Instr::Mov(MovArgs::ToMem(MemRef{reg: HEAP_PTR, offset: 8}, Reg32::Reg(Reg::Rax))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rax, env_tuple)),
+        Instr::Mov(MovArgs::ToMem(MemRef{reg: HEAP_PTR, offset: 16}, Reg32::Reg(Reg::Rax))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(HEAP_PTR))),
+        Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(CLOSURE_TAG as u32))),        // tag the address
+        Instr::Add(BinArgs::ToReg(HEAP_PTR, Arg32::Signed(24))),
+    ]);
+    is
+}
+
+// R10 := the number of bytes of the string (s)
+fn compile_string_len(s: Arg64) -> Vec<Instr> {
+    vec![
+        Instr::Mov(MovArgs::ToReg(Reg::R10, s)),
+        Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Mem(MemRef{reg: Reg::R10, offset: -(STRING_TAG as i32)}))),
+    ]
+}
+
+// R10 := the size in bytes of a string of R10 bytes, its length word and
+// the bytes rounded up to whole words
+fn compile_string_size() -> Vec<Instr> {
+    vec![
+        Instr::Add(BinArgs::ToReg(Reg::R10, Arg32::Signed(8 + 7))),
+        Instr::And(BinArgs::ToReg(Reg::R10, Arg32::Signed(-8))),
+    ]
+}
+
+// Calls the runtime function (name) with (args), which must not be in the
+// argument registers
+fn compile_runtime_call(name: &str, args: &[Arg64]) -> Vec<Instr> {
+    let regs = [Reg::Rdi, Reg::Rsi, Reg::Rdx, Reg::Rcx];
+    let mut is: Vec<Instr> = args.iter().zip(regs.iter())
+        .map(|(arg, reg)| Instr::Mov(MovArgs::ToReg(*reg, *arg)))
+        .collect();
+    is.push(Instr::Call(name.to_string()));
+    is
+}
+
+// Strings whose bytes are only known at runtime are written at the heap
+// pointer by a function of the runtime, which returns the tagged string.
+// This moves the heap pointer past the string in Rax.
+fn compile_string_written() -> Vec<Instr> {
+    let mut is = compile_string_len(Arg64::Reg(Reg::Rax));
+    is.extend(compile_string_size());
+    is.push(Instr::Add(BinArgs::ToReg(HEAP_PTR, Arg32::Reg(Reg::R10))));
+    is
+}
+
+// Allocates [length, bytes...] for the literal (s) at the heap pointer
+fn compile_make_string(s: &str, ann: &u32) -> Vec<Instr> {
+    let words: Vec<u64> = s.as_bytes().chunks(8).map(|chunk| {
+        let mut word = [0u8; 8];
+        word[..chunk.len()].copy_from_slice(chunk);
+        u64::from_le_bytes(word)
+    }).collect();
+    let mut is = vec![Instr::Comment(String::from("MakeString"))];
+    is.extend(compile_heap_check(words.len() + 1, ann));
+    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(s.len() as u64))));
+    is.push(Instr::Mov(MovArgs::ToMem(MemRef{reg: HEAP_PTR, offset: 0}, Reg32::Reg(Reg::Rax))));
+    for (i, word) in words.iter().enumerate() {
+        is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(*word))));
+        is.push(Instr::Mov(MovArgs::ToMem(MemRef{reg: HEAP_PTR, offset: 8 * (i as i32 + 1)}, Reg32::Reg(Reg::Rax))));
+    }
+    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(HEAP_PTR))));
+    is.push(Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(STRING_TAG as u32))));        // tag the address
+    is.push(Instr::Add(BinArgs::ToReg(HEAP_PTR, Arg32::Signed(8 * (words.len() as i32 + 1)))));
+    is
+}
+
+// (a) ++ (b), whose tags were checked
+fn compile_concat(a: Arg64, b: Arg64, ann: &u32) -> Vec<Instr> {
+    let mut size = compile_string_len(b);
+    size.push(Instr::Mov(MovArgs::ToReg(Reg::R11, a)));
+    size.push(Instr::Add(BinArgs::ToReg(Reg::R10, Arg32::Mem(MemRef{reg: Reg::R11, offset: -(STRING_TAG as i32)}))));
+    size.extend(compile_string_size());
+
+    let mut is = vec![Instr::Comment(String::from("Concat"))];
+    is.extend(compile_heap_check_with(&size, Arg32::Reg(Reg::R10), ann));
+    is.extend(compile_runtime_call("snake_str_concat", &[Arg64::Reg(HEAP_PTR), a, b]));
+    is.extend(compile_string_written());
+    is
+}
+
+// substr(s, start, end), the bytes [start, end) of s. Like the index of a
+// tuple, the bounds are checked even when the program is typed.
+fn compile_substr(s: Arg64, start: Arg64, end: Arg64, ann: &u32, typed: bool) -> Vec<Instr> {
+    let mut is = vec![Instr::Comment(String::from("Substr"))];
+    if !typed {
+        is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, s)));
+        is.extend(check_type_string(Reg::Rax, STRING_ERROR));
+        for index in [start, end].iter() {
+            is.push(Instr::Mov(MovArgs::ToReg(Reg::R10, *index)));
+            is.extend(check_type_int(Reg::R10, INDEX_NOT_INT_ERROR));
+        }
+    }
+
+    // 0 <= start <= end <= len(s)
+    is.push(Instr::Comment(String::from("Check substring bounds")));
+    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(INDEX_BOUNDS_ERROR))));
+    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rsi, start)));
+    is.push(Instr::Mov(MovArgs::ToReg(Reg::R10, start)));
+    is.push(Instr::Cmp(BinArgs::ToReg(Reg::R10, Arg32::Signed(0))));
+    is.push(Instr::Jl(String::from("snake_err")));
+    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rsi, end)));
+    is.push(Instr::Mov(MovArgs::ToReg(Reg::R11, end)));
+    is.push(Instr::Cmp(BinArgs::ToReg(Reg::R11, Arg32::Reg(Reg::R10))));
+    is.push(Instr::Jl(String::from("snake_err")));
+    is.push(Instr::Sar(BinArgs::ToReg(Reg::R11, Arg32::Unsigned(2))));
+    is.extend(compile_string_len(s));
+    is.push(Instr::Cmp(BinArgs::ToReg(Reg::R11, Arg32::Reg(Reg::R10))));
+    is.push(Instr::Jg(String::from("snake_err")));
+
+    let mut size = vec![
+        Instr::Mov(MovArgs::ToReg(Reg::R10, end)),
+        Instr::Mov(MovArgs::ToReg(Reg::R11, start)),
+        Instr::Sub(BinArgs::ToReg(Reg::R10, Arg32::Reg(Reg::R11))),
+        Instr::Sar(BinArgs::ToReg(Reg::R10, Arg32::Unsigned(2))),
+    ];
+    size.extend(compile_string_size());
+    is.extend(compile_heap_check_with(&size, Arg32::Reg(Reg::R10), ann));
+    is.extend(compile_runtime_call("snake_str_slice", &[Arg64::Reg(HEAP_PTR), s, start, end]));
+    is.extend(compile_string_written());
+    is
+}
+
+// tostring(n), whose tag was checked. Numbers are printed in at most 32
+// bytes, which are allocated before the runtime knows how many it needs.
+fn compile_to_str(n: Arg64, ann: &u32) -> Vec<Instr> {
+    let mut is = vec![Instr::Comment(String::from("ToStr"))];
+    is.extend(compile_heap_check(1 + 32 / 8, ann));
+    is.extend(compile_runtime_call("snake_num_to_str", &[Arg64::Reg(HEAP_PTR), n]));
+    is.extend(compile_string_written());
+    is
+}
+
+// readnum() / readbool(): the runtime reads a line of stdin and returns
+// the value it holds, or READ_FAILED when the line isn't one, in which case
+// it keeps the message snake_error prints for INPUT_ERROR.
+static READ_FAILED: u64 = 0x00_00_00_00_00_00_00_07;
+
+fn compile_read(p: &Prim) -> Vec<Instr> {
+    let fun_name = if *p == Prim::ReadNum { "snake_read_num" } else { "snake_read_bool" };
+    let mut is = vec![Instr::Comment(format!("{:?}", p))];
+    is.extend(compile_runtime_call(fun_name, &[]));
+    is.extend(vec![
+        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(INPUT_ERROR))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))),
+        Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Unsigned(READ_FAILED))),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R11))),
+        Instr::Je(String::from("snake_err")),
+    ]);
+    is
+}
+
+// Rax := whether Rax and R10 are equal: the same value, or two strings
+// of the same bytes
+fn compile_equal(ann: &u32) -> Vec<Instr> {
+    let same_lab = format!("equal#{}", ann);
+    let different_lab = format!("not_equal#{}", ann);
+    let done_lab = format!("equal_done#{}", ann);
+
+    let mut is = vec![
+        Instr::Comment(String::from("Equal")),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))),
+        Instr::Je(same_lab.clone()),
+    ];
+    for reg in [Reg::Rax, Reg::R10].iter() {
+        is.push(Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Reg(*reg))));
+        is.push(Instr::And(BinArgs::ToReg(Reg::R11, Arg32::Unsigned(PTR_TAG_MASK as u32))));
+        is.push(Instr::Cmp(BinArgs::ToReg(Reg::R11, Arg32::Unsigned(STRING_TAG as u32))));
+        is.push(Instr::Jne(different_lab.clone()));
+    }
+    is.extend(compile_runtime_call("snake_str_equal", &[Arg64::Reg(Reg::Rax), Arg64::Reg(Reg::R10)]));
+    is.extend(vec![
+        Instr::Jmp(done_lab.clone()),
+        Instr::Label(different_lab),
+        Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(SNAKE_FLS.0))),
+        Instr::Jmp(done_lab.clone()),
+        Instr::Label(same_lab),
+        Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(SNAKE_TRU.0))),
+        Instr::Label(done_lab),
+    ]);
+    is
+}
+
+fn compile_prim1_to_instr(p: &Prim, ann: &u32, space: &i32) -> Vec<Instr> {
     let mut is: Vec<Instr> = Vec::new();
     match p {
         Prim::Add1 => {
             is.push(Instr::Comment(String::from("Add1")));
-            is.push(Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Signed(1 << 1))));
-            is.extend(runtime_overflow_check());                                        // overflow check
+            is.push(Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Signed(1 << 2))));
+            is.extend(compile_arith_to_instr(&Prim::Add, ann, space));
         },
         Prim::Sub1 => {
             is.push(Instr::Comment(String::from("Sub1")));
-            is.push(Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Signed(1 << 1))));
-            is.extend(runtime_overflow_check());                                        // overflow check
+            is.push(Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Signed(1 << 2))));
+            is.extend(compile_arith_to_instr(&Prim::Sub, ann, space));
         },
         Prim::Not => {
             // let NOT_MUSK: u64 = 0x80_00_00_00_00_00_00_00;
@@ -1270,9 +2442,7 @@ fn compile_prim1_to_instr(p: &Prim, space: &i32) -> Vec<Instr> {
         Prim::Print => {
             is.push(Instr::Comment(String::from("Print")));
             is.push(Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Reg(Reg::Rax))));            // mov Rdi, (reg)
-            is.push(Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(*space + 8))));     // sub Rsp, (space + 8)
             is.push(Instr::Call(String::from("print_snake_val")));                          // call print_snake_val
-            is.push(Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(*space + 8))));     // add Rsp, (space + 8)
         },
         Prim::IsNum => {
             is.push(Instr::Comment(String::from("IsNum")));
@@ -1286,37 +2456,57 @@ fn compile_prim1_to_instr(p: &Prim, space: &i32) -> Vec<Instr> {
         },
         Prim::IsBool => {
             is.push(Instr::Comment(String::from("IsBool")));
-            is.push(Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Unsigned(TAG_MASK))));       // mov R10, TAG_MASK
-
-            is.push(Instr::And(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))));            // and Rax, R10
-            is.push(Instr::Shl(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(63))));             // shl Rax, 63
-
-            is.push(Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Unsigned(SNAKE_FLS.0))));      // mov R10, SNAKE_FLS
-            is.push(Instr::Or(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))));             // or Rax, R10
+            is.extend(compile_ptr_tag_test(BOOL_TAG, &format!("is_bool#{}", ann)));
+        },
+        Prim::IsTuple => {
+            is.push(Instr::Comment(String::from("IsTuple")));
+            is.extend(compile_ptr_tag_test(TUPLE_TAG, &format!("is_tuple#{}", ann)));
+        },
+        Prim::IsStr => {
+            is.push(Instr::Comment(String::from("IsStr")));
+            is.extend(compile_ptr_tag_test(STRING_TAG, &format!("is_string#{}", ann)));
+        },
+        Prim::Len => {
+            is.push(Instr::Comment(String::from("Len")));
+            is.extend(compile_string_len(Arg64::Reg(Reg::Rax)));
+            is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(Reg::R10))));
+            is.push(Instr::Shl(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(2))));             // tag as int
+        },
+        Prim::ToNum => {
+            // the runtime gives back the string when it isn't an integer
+            is.push(Instr::Comment(String::from("ToNum")));
+            is.extend(compile_runtime_call("snake_str_to_num", &[Arg64::Reg(Reg::Rax)]));
+            is.push(Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(TONUM_ERROR))));
+            is.push(Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))));
+            is.push(Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Reg(Reg::Rax))));
+            is.push(Instr::And(BinArgs::ToReg(Reg::R11, Arg32::Unsigned(PTR_TAG_MASK as u32))));
+            is.push(Instr::Cmp(BinArgs::ToReg(Reg::R11, Arg32::Unsigned(STRING_TAG as u32))));
+            is.push(Instr::Je(String::from("snake_err")));
+        },
+        Prim::Abs => {
+            // x ^ s - s with s all ones for a negative x, abs(MIN_SNAKE_INT) overflows
+            is.push(Instr::Comment(String::from("Abs")));
+            is.push(Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Reg(Reg::Rax))));
+            is.push(Instr::Sar(BinArgs::ToReg(Reg::R11, Arg32::Unsigned(63))));
+            is.push(Instr::Xor(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R11))));
+            is.push(Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R11))));
+            is.extend(runtime_overflow_check());
         },
         _ => panic!("unexpected situation: expect Prim1")
     }
-    return is
+    is
 }
 
-fn compile_prim2_to_instr(p: &Prim, ann: &u32) -> Vec<Instr> {
+fn compile_prim2_to_instr(p: &Prim, ann: &u32, space: &i32) -> Vec<Instr> {
     let mut is: Vec<Instr> = Vec::new();
     match p {
-        Prim::Add => {
-            is.push(Instr::Comment(String::from("Add")));
-            is.push(Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))));
-            is.extend(runtime_overflow_check());                                        // overflow check
-        },
-        Prim::Sub => {
-            is.push(Instr::Comment(String::from("Sub")));
-            is.push(Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))));
-            is.extend(runtime_overflow_check());                                        // overflow check
+        Prim::Add | Prim::Sub | Prim::Mul | Prim::Div => {
+            is.push(Instr::Comment(format!("{:?}", p)));
+            is.extend(compile_arith_to_instr(p, ann, space));
         },
-        Prim::Mul => {
-            is.push(Instr::Comment(String::from("Mul")));
-            is.push(Instr::Sar(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(1))));             
-            is.push(Instr::IMul(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))));
-            is.extend(runtime_overflow_check());                                        // overflow check 
+        Prim::IntDiv | Prim::Rem | Prim::BitAnd | Prim::BitOr | Prim::BitXor | Prim::Shl | Prim::Shr | Prim::Min | Prim::Max => {
+            is.push(Instr::Comment(format!("{:?}", p)));
+            is.extend(compile_int_arith_to_instr(p, ann));
         },
         Prim::And => {
             is.push(Instr::And(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))));
@@ -1324,14 +2514,18 @@ fn compile_prim2_to_instr(p: &Prim, ann: &u32) -> Vec<Instr> {
         Prim::Or => {
             is.push(Instr::Or(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))));
         },
+        Prim::Eq => is.extend(compile_equal(ann)),
+        Prim::Neq => {
+            is.extend(compile_equal(ann));
+            is.push(Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Unsigned(NOT_MUSK))));
+            is.push(Instr::Xor(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))));
+        },
         _ => {
             let cur_str: String = {match p {
                 Prim::Lt => format!("less_than#{}", ann),
                 Prim::Gt => format!("greater_than#{}", ann),
                 Prim::Le => format!("less_equal#{}", ann),
                 Prim::Ge => format!("greater_equal#{}", ann),
-                Prim::Eq => format!("equal#{}", ann),
-                Prim::Neq => format!("unequal#{}", ann),
                 _ => panic!("unexpected situation: expect comparison")
             }};
             is.push(Instr::Comment(String::from("Compare")));
@@ -1342,21 +2536,20 @@ fn compile_prim2_to_instr(p: &Prim, ann: &u32) -> Vec<Instr> {
                 Prim::Gt => Instr::Jg(cur_str.clone()),
                 Prim::Le => Instr::Jle(cur_str.clone()),
                 Prim::Ge => Instr::Jge(cur_str.clone()),
-                Prim::Eq => Instr::Je(cur_str.clone()),
-                Prim::Neq => Instr::Jne(cur_str.clone()),
                 _ => panic!("unexpected situation: expect comparison")
             });
             is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(SNAKE_FLS.0))));  // mov rax, SNAKE_FLS
             is.push(Instr::Label(cur_str));                                             // (cur_label)
         }
     }
-    return is
+    is
 }
 
 fn space_needed_helper(e: &SeqExp<u32>) -> i32 {
     let mut var_num = 0;
     match e {
-        SeqExp::Imm(..) | SeqExp::Prim(..) | SeqExp::InternalTailCall(..) | SeqExp::ExternalCall{..} => {},
+        SeqExp::Imm(..) | SeqExp::Prim(..) | SeqExp::InternalTailCall(..) | SeqExp::ExternalCall{..}
+        | SeqExp::ClosureCall{..} | SeqExp::MakeClosure{..} | SeqExp::Str(..) => {},
         SeqExp::Let{var: _, bound_exp, body, ann: _} => {
             var_num = std::cmp::max(space_needed_helper(bound_exp), 1 + space_needed_helper(body));
         },
@@ -1364,63 +2557,116 @@ fn space_needed_helper(e: &SeqExp<u32>) -> i32 {
             var_num = std::cmp::max(space_needed_helper(thn), space_needed_helper(els));
         },
         SeqExp::FunDefs{decls, body, ann: _} => {
-            let mut max_space = 0;
+            // the parameters of every local function get their own slots
+            let mut max_space = space_needed_helper(body);
             for decl in decls.iter() {
+                var_num += decl.parameters.len() as i32;
                 max_space = std::cmp::max(max_space, space_needed_helper(&decl.body));
             }
-            var_num = max_space + space_needed_helper(body);
+            var_num += max_space;
         }
     }
-    return var_num
-}
-
-fn space_needed(e: &SeqExp<u32>, arg_num: i32) -> i32 {
-    let var_num = space_needed_helper(e) + arg_num;
-    if var_num % 2 == 0 {
-        return  8 * (var_num + 1)
-    } else {
-        return 8 * var_num
-    }
+    var_num
 }
 
-fn compile_to_instrs_help(e: &SeqExp<u32>, mut env: HashMap<String, i32>, space: &i32, env_size: i32) -> Vec<Instr> {
+// (env) maps variables to their register or stack slot, (internal_funs)
+// maps the local functions in scope to the offsets of their parameters.
+// The runtime tag checks are left out when the program is (typed).
+fn compile_to_instrs_help(e: &SeqExp<u32>, mut env: HashMap<String, Arg64>, internal_funs: &HashMap<String, Vec<i32>>, frame: &Frame, typed: bool) -> Vec<Instr> {
     match e {
         SeqExp::Imm(i_exp, _ann) => {
-            return vec![Instr::Mov(MovArgs::ToReg(Reg::Rax, compile_imm_to_arg(i_exp, &env)))]
+            vec![Instr::Mov(MovArgs::ToReg(Reg::Rax, compile_imm_to_arg(i_exp, &env)))]
         },
         SeqExp::Prim(prim, i_exp_vec, ann) => {
             match prim {
-                Prim::Add1 | Prim::Sub1 | Prim::Not | Prim::Print | Prim::IsNum | Prim::IsBool => {
+                Prim::Add1 | Prim::Sub1 | Prim::Not | Prim::Print | Prim::IsNum | Prim::IsBool | Prim::IsTuple
+                | Prim::IsStr | Prim::Len | Prim::ToNum | Prim::Abs => {
                     let mut is: Vec<Instr> = Vec::new();
                     is.push(Instr::Comment(String::from("Prim1")));
                     is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, compile_imm_to_arg(&i_exp_vec[0], &env))));
-                    is.extend(runtime_prim1_check(Reg::Rax, prim));
-                    is.extend(compile_prim1_to_instr(prim, space));
-                    return is
+                    if !typed {
+                        is.extend(runtime_prim1_check(Reg::Rax, prim));
+                    }
+                    is.extend(compile_prim1_to_instr(prim, ann, &frame.space));
+                    is
                 },
-                Prim::Add | Prim::Sub | Prim::Mul | Prim::And | Prim::Or | Prim::Lt | Prim::Gt | Prim::Le | Prim::Ge | Prim::Eq | Prim::Neq => {
+                Prim::Add | Prim::Sub | Prim::Mul | Prim::Div | Prim::And | Prim::Or | Prim::Lt | Prim::Gt | Prim::Le | Prim::Ge | Prim::Eq | Prim::Neq
+                | Prim::IntDiv | Prim::Rem | Prim::BitAnd | Prim::BitOr | Prim::BitXor | Prim::Shl | Prim::Shr | Prim::Min | Prim::Max => {
                     let mut is: Vec<Instr> = Vec::new();
                     is.push(Instr::Comment(String::from("Prim2")));
                     is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, compile_imm_to_arg(&i_exp_vec[0], &env))));
-                    is.extend(runtime_prim2_check(Reg::Rax, prim));
+                    if !typed {
+                        is.extend(runtime_prim2_check(Reg::Rax, prim));
+                    }
                     is.push(Instr::Mov(MovArgs::ToReg(Reg::R10, compile_imm_to_arg(&i_exp_vec[1], &env))));
-                    is.extend(runtime_prim2_check(Reg::R10, prim));
-                    is.extend(compile_prim2_to_instr(prim, ann));
-                    return is
+                    if !typed {
+                        is.extend(runtime_prim2_check(Reg::R10, prim));
+                    }
+                    is.extend(compile_prim2_to_instr(prim, ann, &frame.space));
+                    is
+                }
+                Prim::GetIndex => {
+                    let mut is: Vec<Instr> = Vec::new();
+                    is.push(Instr::Comment(String::from("GetIndex")));
+                    is.extend(compile_tuple_access(compile_imm_to_arg(&i_exp_vec[0], &env), compile_imm_to_arg(&i_exp_vec[1], &env), typed));
+                    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Mem(MemRef{reg: Reg::Rax, offset: 8}))));
+                    is
+                }
+                Prim::SetIndex => {
+                    // evaluates to the updated tuple
+                    let mut is: Vec<Instr> = Vec::new();
+                    is.push(Instr::Comment(String::from("SetIndex")));
+                    is.extend(compile_tuple_access(compile_imm_to_arg(&i_exp_vec[0], &env), compile_imm_to_arg(&i_exp_vec[1], &env), typed));
+                    is.push(Instr::Mov(MovArgs::ToReg(Reg::R10, compile_imm_to_arg(&i_exp_vec[2], &env))));
+                    is.push(Instr::Mov(MovArgs::ToMem(MemRef{reg: Reg::Rax, offset: 8}, Reg32::Reg(Reg::R10))));
+                    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, compile_imm_to_arg(&i_exp_vec[0], &env))));
+                    is
+                }
+                Prim::MakeTuple => compile_make_tuple(i_exp_vec, &env, ann),
+                Prim::ToStr => {
+                    let n = compile_imm_to_arg(&i_exp_vec[0], &env);
+                    let mut is: Vec<Instr> = Vec::new();
+                    if !typed {
+                        is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, n)));
+                        is.extend(runtime_prim1_check(Reg::Rax, prim));
+                    }
+                    is.extend(compile_to_str(n, ann));
+                    is
+                }
+                Prim::Concat => {
+                    let a = compile_imm_to_arg(&i_exp_vec[0], &env);
+                    let b = compile_imm_to_arg(&i_exp_vec[1], &env);
+                    let mut is: Vec<Instr> = Vec::new();
+                    if !typed {
+                        is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, a)));
+                        is.extend(runtime_prim2_check(Reg::Rax, prim));
+                        is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, b)));
+                        is.extend(runtime_prim2_check(Reg::Rax, prim));
+                    }
+                    is.extend(compile_concat(a, b, ann));
+                    is
+                }
+                Prim::Substr => {
+                    let args: Vec<Arg64> = i_exp_vec.iter().map(|arg| compile_imm_to_arg(arg, &env)).collect();
+                    compile_substr(args[0], args[1], args[2], ann, typed)
                 }
+                Prim::ReadNum | Prim::ReadBool => compile_read(prim),
             }
         },
         SeqExp::Let{var, bound_exp, body, ann: _} => {
-            let mut is = compile_to_instrs_help(&bound_exp, env.clone(), space, env_size);
+            let mut is = compile_to_instrs_help(bound_exp, env.clone(), internal_funs, frame, typed);
             is.push(Instr::Comment(format!("Let var: {}", var)));
-            env.insert(var.clone(), -8 * (env.len() as i32 + 1));
-
-            let new_offset = get_offset(var, &env);
-            is.push(Instr::Mov(MovArgs::ToMem(MemRef{reg: Reg::Rsp, offset: new_offset}, Reg32::Reg(Reg::Rax))));
+            let location = new_location(var, &env, frame);
+            env.insert(var.clone(), location);
+            is.push(match location {
+                Arg64::Reg(reg) => Instr::Mov(MovArgs::ToReg(reg, Arg64::Reg(Reg::Rax))),
+                Arg64::Mem(slot) => Instr::Mov(MovArgs::ToMem(slot, Reg32::Reg(Reg::Rax))),
+                _ => unreachable!()
+            });
 
-            is.extend(compile_to_instrs_help(&body, env.clone(), space, env_size+1));
+            is.extend(compile_to_instrs_help(body, env.clone(), internal_funs, frame, typed));
 
-            return is
+            is
         },
 
         SeqExp::If{cond, thn, els, ann} => {
@@ -1430,117 +2676,198 @@ fn compile_to_instrs_help(e: &SeqExp<u32>, mut env: HashMap<String, i32>, space:
             let mut is: Vec<Instr> = Vec::new();
             is.push(Instr::Comment(String::from("If")));
             is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, compile_imm_to_arg(cond, &env))));    // mov Rax, eval(cond)
-            is.extend(runtime_if_check(Reg::Rax));
+            if !typed {
+                is.extend(runtime_if_check(Reg::Rax));
+            }
             is.push(Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Unsigned(SNAKE_FLS.0))));        // mov R10, SNAKE_FLS
             is.push(Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::R10))));                // cmp rax, r10
 
             is.push(Instr::Je(else_lab.clone()));                                               // Je if_false#{ann}
-            is.extend(compile_to_instrs_help(&thn, env.clone(), space, env_size));            // eval(thn)
+            is.extend(compile_to_instrs_help(thn, env.clone(), internal_funs, frame, typed));            // eval(thn)
             is.push(Instr::Jmp(done_lab.clone()));                                              // Jmp done#{ann}
             is.push(Instr::Label(else_lab.clone()));   // if_false#{ann}       
-            is.extend(compile_to_instrs_help(&els, env.clone(), space, env_size));            // eval(els)
+            is.extend(compile_to_instrs_help(els, env.clone(), internal_funs, frame, typed));            // eval(els)
             is.push(Instr::Label(done_lab.clone()));                                            // done#{ann}
 
-            return is
+            is
         },
 
         SeqExp::FunDefs {decls, body, ann} => {
-            // panic!("env_len:{}, env_size:{}", env.len(), env_size);
+            let done_lab = format!("fundefs_done#{}", ann);
+
+            // the local functions share the frame: their parameters, apart
+            // from the captured variables already in scope, get new slots
+            let mut internal_funs = internal_funs.clone();
+            for decl in decls.iter() {
+                for var_name in decl.parameters.iter() {
+                    if !env.contains_key(var_name) {
+                        let slot = MemRef{reg: Reg::Rbp, offset: -8 * (env.len() as i32 + 1)};
+                        env.insert(var_name.clone(), Arg64::Mem(slot));
+                    }
+                }
+            }
+            for decl in decls.iter() {
+                let offsets = decl.parameters.iter().map(|var_name| get_offset(var_name, &env)).collect();
+                internal_funs.insert(decl.name.clone(), offsets);
+            }
+
             let mut is: Vec<Instr> = Vec::new();
             is.push(Instr::Comment(format!("FunDefs{}_body", ann)));
-            is.extend(compile_to_instrs_help(&body, env.clone(), space, env_size));
-            is.push(Instr::Ret);
+            is.extend(compile_to_instrs_help(body, env.clone(), &internal_funs, frame, typed));
+            is.push(Instr::Jmp(done_lab.clone()));
 
+            // local functions are only called in tail position,
+            // so they return from the enclosing function
             is.push(Instr::Comment(format!("FunDefs{}_decls", ann)));
-
             for decl in decls {
                 is.push(Instr::Label(decl.name.clone()));
-
-                // get the env of this scope: (free variables from outside + func params inside)
-                let mut this_env = env.clone();
-                for var_name in decl.parameters.iter() {
-                    if !this_env.contains_key(var_name) { // only update variables not in old env keys
-                        this_env.insert(var_name.clone(), -8*(this_env.len() as i32 + 1));
-                    }
-                }
-
-                is.extend(compile_to_instrs_help(&decl.body, this_env.clone(), space, this_env.len() as i32));
+                is.extend(compile_to_instrs_help(&decl.body, env.clone(), &internal_funs, frame, typed));
+                is.extend(compile_fun_epilogue(frame));
                 is.push(Instr::Ret);
             }
+            is.push(Instr::Label(done_lab));
 
-        
-            return is
+            is
         },
 
         SeqExp::InternalTailCall(s, i_exp_vec, _ann) => {
             let mut is: Vec<Instr> = Vec::new();
             is.push(Instr::Comment(String::from("InCall")));
 
-            let mut count: i32 = 16;
-            for arg in i_exp_vec.iter() {
-                is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, compile_imm_to_arg(&arg, &env))));
-                is.push(Instr::Mov(MovArgs::ToMem(MemRef{reg: Reg::Rsp, offset: -space - count}, Reg32::Reg(Reg::Rax))));
-                count += 8;
+            // stage the arguments first, they may be read from the parameter slots
+            for (i, arg) in i_exp_vec.iter().enumerate() {
+                is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, compile_imm_to_arg(arg, &env))));
+                is.push(Instr::Mov(MovArgs::ToMem(call_arg_slot(i), Reg32::Reg(Reg::Rax))));
             }
-            let mut arg_idx: i32 = 8;
-            for _ in i_exp_vec.iter() {
-                is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Mem(MemRef{reg: Reg::Rsp, offset: -space - arg_idx - 8}))));
-                is.push(Instr::Mov(MovArgs::ToMem(MemRef{reg: Reg::Rsp, offset: -arg_idx}, Reg32::Reg(Reg::Rax))));
-                arg_idx += 8;
+            let offsets = match internal_funs.get(s) {
+                Some(offsets) => offsets,
+                None => panic!("Local function {} should be in scope", s)
+            };
+            for (i, offset) in offsets.iter().enumerate() {
+                is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Mem(call_arg_slot(i)))));
+                is.push(Instr::Mov(MovArgs::ToMem(MemRef{reg: Reg::Rbp, offset: *offset}, Reg32::Reg(Reg::Rax))));
             }
             is.push(Instr::Jmp(s.clone()));
 
-            return is
+            is
         },
         SeqExp::ExternalCall{fun_name, args, is_tail, ann: _} => {
             let mut is: Vec<Instr> = Vec::new();
             is.push(Instr::Comment(String::from("ExCall")));
 
-            // if fun_name.clone() == String::from("div#21") && *is_tail {
-            //     panic!("{:#?}\n{:#?}", args, env);
-            // }
-
-            let mut count: i32 = 16;
-            for arg in args.iter() {
-                is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, compile_imm_to_arg(&arg, &env))));
-                is.push(Instr::Mov(MovArgs::ToMem(MemRef{reg: Reg::Rsp, offset: -space - count}, Reg32::Reg(Reg::Rax))));
-                count += 8;
+            for (i, arg) in args.iter().enumerate() {
+                is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, compile_imm_to_arg(arg, &env))));
+                is.push(Instr::Mov(MovArgs::ToMem(call_arg_slot(i), Reg32::Reg(Reg::Rax))));
             }
 
             if *is_tail {
-                let mut arg_idx: i32 = 8;
-                for _ in args.iter() {
-                    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Mem(MemRef{reg: Reg::Rsp, offset: -space - arg_idx - 8}))));
-                    is.push(Instr::Mov(MovArgs::ToMem(MemRef{reg: Reg::Rsp, offset: -arg_idx}, Reg32::Reg(Reg::Rax))));
-                    arg_idx += 8;
+                // reuse the frame: the arguments become our parameters
+                for i in 0..args.len() {
+                    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Mem(call_arg_slot(i)))));
+                    is.push(Instr::Mov(MovArgs::ToMem(param_slot(i), Reg32::Reg(Reg::Rax))));
                 }
+                is.extend(compile_fun_epilogue(frame));
                 is.push(Instr::Jmp(fun_name.clone()));
             } else {
-                is.push(Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(*space))));
                 is.push(Instr::Call(fun_name.clone()));
-                is.push(Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(*space))));
             }
 
-            return is
+            is
+        },
+        SeqExp::ClosureCall{fun, args, is_tail, ann: _} => {
+            let mut is: Vec<Instr> = Vec::new();
+            is.push(Instr::Comment(String::from("ClosureCall")));
+
+            is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, compile_imm_to_arg(fun, &env))));
+            if !typed {
+                is.extend(check_type_closure(Reg::Rax, CALL_NOT_CLOSURE_ERROR));
+            }
+            is.push(Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(CLOSURE_TAG as u32))));      // untag the address
+
+            if !typed {
+                is.push(Instr::Comment(String::from("Check arity")));
+                is.push(Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Unsigned(ARITY_ERROR))));
+                is.push(Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Mem(MemRef{reg: Reg::Rax, offset: 0}))));
+                is.push(Instr::Cmp(BinArgs::ToReg(Reg::R10, Arg32::Signed(args.len() as i32))));
+                is.push(Instr::Jne(String::from("snake_err")));
+            }
+
+            // the env tuple is passed as the first argument
+            let env_tuple = Arg64::Mem(MemRef{reg: Reg::Rax, offset: 16});
+            for (i, arg) in std::iter::once(env_tuple).chain(args.iter().map(|arg| compile_imm_to_arg(arg, &env))).enumerate() {
+                is.push(Instr::Mov(MovArgs::ToReg(Reg::R10, arg)));
+                is.push(Instr::Mov(MovArgs::ToMem(call_arg_slot(i), Reg32::Reg(Reg::R10))));
+            }
+            is.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Mem(MemRef{reg: Reg::Rax, offset: 8}))));
+
+            if *is_tail {
+                for i in 0..=args.len() {
+                    is.push(Instr::Mov(MovArgs::ToReg(Reg::R10, Arg64::Mem(call_arg_slot(i)))));
+                    is.push(Instr::Mov(MovArgs::ToMem(param_slot(i), Reg32::Reg(Reg::R10))));
+                }
+                is.extend(compile_fun_epilogue(frame));
+                is.push(Instr::JmpIndirect(Reg::Rax));
+            } else {
+                is.push(Instr::CallIndirect(Reg::Rax));
+            }
+
+            is
+        },
+        SeqExp::MakeClosure{fun_name, arity, env: env_tuple, ann} => {
+            compile_make_closure(fun_name, *arity, compile_imm_to_arg(env_tuple, &env), ann)
         }
+        SeqExp::Str(s, ann) => compile_make_string(s, ann),
     }
 }
 
-fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
+// (input_t) is the type the type checker inferred for the input of a typed
+// program, None for untyped code
+fn compile_to_instrs(p: &SeqProg<u32>, input_t: Option<&Type>) -> Vec<Instr> {
+    let typed = input_t.is_some();
+    // start_here(heap, input) saves the registers we use, sets up the heap
+    // pointer and calls main with the input. The registers for variables
+    // start out as 0 and the null Rbp marks the bottom of the stack, so that
+    // the garbage collector only ever sees snake values.
     let mut instrs: Vec<Instr> = Vec::new();
-
-    // start by calling main
+    for reg in VAR_REGS.iter().chain([HEAP_PTR, Reg::Rbp].iter()) {
+        instrs.push(Instr::Push(Arg32::Reg(*reg)));
+    }
+    instrs.push(Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(8))));          // align Rsp for the call
+    instrs.push(Instr::Mov(MovArgs::ToReg(HEAP_PTR, Arg64::Reg(Reg::Rdi))));
+    instrs.push(Instr::Mov(MovArgs::ToMem(call_arg_slot(0), Reg32::Reg(Reg::Rsi))));
+    for reg in VAR_REGS.iter().chain([Reg::Rbp].iter()) {
+        instrs.push(Instr::Mov(MovArgs::ToReg(*reg, Arg64::Signed(0))));
+    }
     instrs.push(Instr::Call(String::from("main")));
+    instrs.push(Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(8))));
+    for reg in VAR_REGS.iter().chain([HEAP_PTR, Reg::Rbp].iter()).rev() {
+        instrs.push(Instr::Pop(Loc::Reg(*reg)));
+    }
     instrs.push(Instr::Ret);
 
     // asm for the main body (entry point)
     instrs.push(Instr::Label(String::from("main")));
+    let frame = fun_frame(&p.main, 1);
+    instrs.extend(compile_fun_prologue(1, &frame));
+    // typed code trusts the input to have its inferred type, which the
+    // command line does not promise, so it is checked once here
+    let check_input = match input_t {
+        Some(Type::Int) => check_type_int(Reg::Rax, INPUT_ERROR),
+        Some(Type::Bool) => check_type_bool(Reg::Rax, INPUT_ERROR),
+        _ => Vec::new(),
+    };
+    if !check_input.is_empty() {
+        instrs.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Mem(param_slot(0)))));
+        instrs.extend(check_input);
+    }
     instrs.extend(compile_to_instrs_help(
         &p.main,
-        HashMap::new(),
-        &space_needed(&p.main, 0),
-        0
+        HashMap::from([(INPUT.to_string(), Arg64::Mem(param_slot(0)))]),
+        &HashMap::new(),
+        &frame,
+        typed,
     ));
+    instrs.extend(compile_fun_epilogue(&frame));
     instrs.push(Instr::Ret);
 
 
@@ -1548,23 +2875,27 @@ fn compile_to_instrs(p: &SeqProg<u32>) -> Vec<Instr> {
     // asm for the global function definition
     for fun in p.funs.iter() {
         instrs.push(Instr::Label(fun.name.clone()));
-        let mut env: HashMap<String, i32> = HashMap::new();
+        let mut env: HashMap<String, Arg64> = HashMap::new();
         for (i, arg) in fun.parameters.iter().enumerate() {
-            env.insert(arg.clone(), -8 * (i as i32 + 1));
+            env.insert(arg.clone(), Arg64::Mem(param_slot(i)));
         }
         let num_pars = fun.parameters.len() as i32;
+        let frame = fun_frame(&fun.body, num_pars);
+        instrs.extend(compile_fun_prologue(num_pars, &frame));
         instrs.extend(compile_to_instrs_help(
             &fun.body,
             env,
-            &space_needed(&fun.body, num_pars),
-            num_pars
+            &HashMap::new(),
+            &frame,
+            typed,
         ));
+        instrs.extend(compile_fun_epilogue(&frame));
         instrs.push(Instr::Ret);
     }
     instrs.push(Instr::Label(String::from("snake_err")));
     instrs.push(Instr::Call(String::from("snake_error")));
 
-    return instrs
+    instrs
 }
 
 
@@ -1577,27 +2908,131 @@ pub fn compile_to_string<Span1>(p: &Exp<Span1>) -> Result<String, CompileErr<Spa
 where Span1: Clone,
 {
     check_prog(p)?;
+    Ok(compile_checked_prog(p, None))
+}
 
-    let uniq_p = uniquify(&tag_exp(p, &mut 0));
-
-    let (defs, main) = lambda_lift(&uniq_p);
-
-    let (t_defs, t_main) = tag_prog(&defs, main.clone());
-
-    let seq_p = tag_sprog(&seq_prog(&t_defs, &t_main));
-
-    // panic!("{:#?}", seq_p);
-
+// Like compile_to_string, but also runs the type checker and leaves out
+// the runtime tag checks it makes redundant
+pub fn compile_to_string_typed<Span1>(p: &Exp<Span1>) -> Result<String, CompileErr<Span1>>
+where Span1: Clone,
+{
+    check_prog(p)?;
+    let (_, input_t) = type_check(p)?;
+    Ok(compile_checked_prog(p, Some(&input_t)))
+}
 
-    Ok(format!(
+fn compile_checked_prog<Span1>(p: &Exp<Span1>, input_t: Option<&Type>) -> String {
+    format!(
         "\
 section .text
         global start_here
         extern snake_error
         extern print_snake_val
+        extern snake_try_gc
+        extern snake_str_concat
+        extern snake_str_slice
+        extern snake_str_equal
+        extern snake_num_to_str
+        extern snake_str_to_num
+        extern snake_read_num
+        extern snake_read_bool
+        extern HEAP_END
 start_here:
 {}
 ",
-        instrs_to_string(&compile_to_instrs(&seq_p))
-    ))
-}
\ No newline at end of file
+        instrs_to_string(&compile_checked_instrs(p, input_t))
+    )
+}
+
+fn compile_checked_instrs<Span1>(p: &Exp<Span1>, input_t: Option<&Type>) -> Vec<Instr> {
+    let uniq_p = uniquify(&tag_exp(p, &mut 0));
+
+    let (defs, main) = lambda_lift(&uniq_p);
+
+    compile_to_instrs(&seq_lifted_prog(&defs, main), input_t)
+}
+
+fn seq_lifted_prog(defs: &[FunDecl<Exp<()>, ()>], main: Exp<()>) -> SeqProg<u32> {
+    let (t_defs, t_main) = tag_prog(defs, main);
+
+    tag_sprog(&optimize_prog(&seq_prog(&t_defs, &t_main)))
+}
+
+// ********************************************************************
+//
+//                    intermediate representations
+//
+// ********************************************************************
+
+// The passes of the compiler whose output can be printed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Ast,    // the program that passed check_prog
+    Uniq,   // every variable renamed apart by uniquify
+    Lifted, // after lambda_lift, the lifted functions around main
+    Seq,    // after seq_prog and optimize_prog
+    Asm,    // the assembly of compile_to_instrs
+}
+
+impl Stage {
+    pub const ALL: [Stage; 5] = [Stage::Ast, Stage::Uniq, Stage::Lifted, Stage::Seq, Stage::Asm];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Stage::Ast => "ast",
+            Stage::Uniq => "uniq",
+            Stage::Lifted => "lifted",
+            Stage::Seq => "seq",
+            Stage::Asm => "asm",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Stage> {
+        Stage::ALL.iter().copied().find(|stage| stage.name() == name)
+    }
+}
+
+// The program after (stage), in Snake syntax up to the forms the compiler
+// introduces, see pretty.rs
+pub fn emit_stage<Span1>(p: &Exp<Span1>, stage: Stage, typed: bool) -> Result<String, CompileErr<Span1>>
+where Span1: Clone,
+{
+    check_prog(p)?;
+    let input_t = if typed { Some(type_check(p)?.1) } else { None };
+    let uniq_p = uniquify(&tag_exp(p, &mut 0));
+    let (defs, main) = lambda_lift(&uniq_p);
+    Ok(match stage {
+        Stage::Ast => p.to_string(),
+        Stage::Uniq => uniq_p.to_string(),
+        Stage::Lifted if defs.is_empty() => main.to_string(),
+        Stage::Lifted => Exp::FunDefs { decls: defs, body: Box::new(main), ann: () }.to_string(),
+        Stage::Seq => seq_lifted_prog(&defs, main).to_string(),
+        Stage::Asm => compile_checked_prog(p, input_t.as_ref()),
+    })
+}
+
+// ********************************************************************
+//
+//                        compile to object
+//
+// ********************************************************************
+
+// Like compile_to_string, but encodes the program directly into an ELF64
+// object defining start_here, so that no assembler is needed
+pub fn compile_to_object<Span1>(p: &Exp<Span1>, typed: bool) -> Result<Vec<u8>, CompileErr<Span1>>
+where Span1: Clone,
+{
+    let mut instrs = vec![Instr::Label(String::from("start_here"))];
+    instrs.extend(compile_prog_to_instrs(p, typed)?);
+    Ok(object_file(&encode(&instrs), &["start_here"]))
+}
+
+// The instructions of start_here, without its label, for the backends
+// that do not go through assembly text
+pub fn compile_prog_to_instrs<Span1>(p: &Exp<Span1>, typed: bool) -> Result<Vec<Instr>, CompileErr<Span1>>
+where Span1: Clone,
+{
+    check_prog(p)?;
+    let input_t = if typed { Some(type_check(p)?.1) } else { None };
+    Ok(compile_checked_instrs(p, input_t.as_ref()))
+}
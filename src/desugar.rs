@@ -0,0 +1,70 @@
+use crate::syntax::{Exp, FunDecl};
+
+// ********************************************************************
+//
+//                             desugar
+//
+// ********************************************************************
+
+// The name desugar binds the discarded value of the first expression of a
+// sequence to. It can't be lexed, so it never clashes with a user variable,
+// and the warnings check runs before desugaring, so it is never reported as
+// unused.
+pub static SEQ_VAR: &str = "#seq";
+
+// Rewrites the surface-only forms into the core language before check_prog,
+// so that none of the later passes, nor the interpreter, have to know about
+// them:
+//     e1; e2    ~>    let #seq = e1 in e2
+// e2 ends up as the body of a let, so it stays in tail position.
+pub fn desugar<Ann: Clone>(e: &Exp<Ann>) -> Exp<Ann> {
+    let boxed = |e: &Exp<Ann>| Box::new(desugar(e));
+    match e {
+        Exp::Num(..) | Exp::Float(..) | Exp::Bool(..) | Exp::Str(..) | Exp::Var(..) => e.clone(),
+        Exp::Prim(p, es, ann) => Exp::Prim(*p, es.iter().map(|e| boxed(e)).collect(), ann.clone()),
+        Exp::Let { bindings, body, ann } => Exp::Let {
+            bindings: bindings.iter().map(|(x, e)| (x.clone(), desugar(e))).collect(),
+            body: boxed(body),
+            ann: ann.clone(),
+        },
+        Exp::If { cond, thn, els, ann } => Exp::If {
+            cond: boxed(cond),
+            thn: boxed(thn),
+            els: boxed(els),
+            ann: ann.clone(),
+        },
+        Exp::FunDefs { decls, body, ann } => Exp::FunDefs {
+            decls: decls
+                .iter()
+                .map(|decl| FunDecl {
+                    name: decl.name.clone(),
+                    parameters: decl.parameters.clone(),
+                    body: desugar(&decl.body),
+                    ann: decl.ann.clone(),
+                })
+                .collect(),
+            body: boxed(body),
+            ann: ann.clone(),
+        },
+        Exp::Lambda { parameters, body, ann } => Exp::Lambda {
+            parameters: parameters.clone(),
+            body: boxed(body),
+            ann: ann.clone(),
+        },
+        Exp::Seq { first, second, ann } => Exp::Let {
+            bindings: vec![(SEQ_VAR.to_string(), desugar(first))],
+            body: boxed(second),
+            ann: ann.clone(),
+        },
+        Exp::Call(fun, es, ann) => Exp::Call(fun.clone(), es.iter().map(desugar).collect(), ann.clone()),
+        Exp::ClosureCall { fun, args, is_tail, ann } => Exp::ClosureCall {
+            fun: boxed(fun),
+            args: args.iter().map(desugar).collect(),
+            is_tail: *is_tail,
+            ann: ann.clone(),
+        },
+        Exp::InternalTailCall(..) | Exp::ExternalCall { .. } | Exp::MakeClosure { .. } => {
+            panic!("internal compiler forms shouldn't occur at desugaring stage")
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::compile::{
+    ErrorCode, ARITH_ERROR, ARITY_ERROR, CALL_NOT_CLOSURE_ERROR, CMP_ERROR, DIV_ZERO_ERROR, IF_ERROR,
+    INDEX_BOUNDS_ERROR, INDEX_NOT_INT_ERROR, INDEX_NOT_TUPLE_ERROR, INPUT_ERROR, INT_ARITH_ERROR, LOGIC_ERROR, OOM_ERROR,
+    OVFL_ERROR, STRING_ERROR, TONUM_ERROR,
+};
+use crate::interp::InterpErr;
+
+// ********************************************************************
+//
+//             differential testing: interpreter vs compiler
+//
+// ********************************************************************
+
+// How a run of a program ended
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Ending {
+    Finished,
+    // with a runtime error, identified by its snake_error code
+    Failed(ErrorCode),
+    // in any other way, e.g. with an I/O error or a signal
+    Crashed(String),
+}
+
+// What a run of a program did
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Outcome {
+    pub stdout: String,
+    pub ending: Ending,
+}
+
+// The start of the messages of runtime/common.rs for each snake_error code
+static ERROR_MESSAGES: [(&str, ErrorCode); 18] = [
+    ("arithmetic expected", ARITH_ERROR),
+    ("comparison expected", CMP_ERROR),
+    ("if expected", IF_ERROR),
+    ("logic expected", LOGIC_ERROR),
+    ("overflow", OVFL_ERROR),
+    ("divided by zero", DIV_ZERO_ERROR),
+    ("indexing expected", INDEX_NOT_TUPLE_ERROR),
+    ("index expected", INDEX_NOT_INT_ERROR),
+    ("index out of bounds", INDEX_BOUNDS_ERROR),
+    ("call expected", CALL_NOT_CLOSURE_ERROR),
+    ("function of arity", ARITY_ERROR),
+    ("out of memory", OOM_ERROR),
+    ("string operation expected", STRING_ERROR),
+    ("tonum expected", TONUM_ERROR),
+    ("input expected", INPUT_ERROR),
+    ("readnum expected", INPUT_ERROR),
+    ("readbool expected", INPUT_ERROR),
+    ("integer arithmetic expected", INT_ARITH_ERROR),
+];
+
+fn error_name(code: ErrorCode) -> &'static str {
+    ERROR_MESSAGES
+        .iter()
+        .find(|(_, c)| *c == code)
+        .map(|(msg, _)| *msg)
+        .unwrap_or("unknown error")
+}
+
+// Both sides print the same lines, only the final newline may differ
+fn normalize(stdout: &[u8]) -> String {
+    String::from_utf8_lossy(stdout).trim_end().to_string()
+}
+
+impl Outcome {
+    pub fn of_interp(stdout: &[u8], res: Result<(), InterpErr>) -> Outcome {
+        let ending = match res {
+            Ok(()) => Ending::Finished,
+            Err(e) => match e.error_code() {
+                Some(code) => Ending::Failed(code),
+                None => Ending::Crashed(e.to_string()),
+            },
+        };
+        Outcome {
+            stdout: normalize(stdout),
+            ending,
+        }
+    }
+
+    // (success) is whether the executable exited with status 0, its error
+    // message is the first line of (stderr)
+    pub fn of_compiled(stdout: &[u8], stderr: &[u8], success: bool) -> Outcome {
+        let stderr = String::from_utf8_lossy(stderr);
+        let msg = stderr.lines().next().unwrap_or("").trim();
+        let ending = if success {
+            Ending::Finished
+        } else {
+            match ERROR_MESSAGES.iter().find(|(prefix, _)| msg.starts_with(prefix)) {
+                Some((_, code)) => Ending::Failed(*code),
+                None if msg.is_empty() => Ending::Crashed(String::from("no error message")),
+                None => Ending::Crashed(msg.to_string()),
+            }
+        };
+        Outcome {
+            stdout: normalize(stdout),
+            ending,
+        }
+    }
+}
+
+impl Display for Ending {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Ending::Finished => write!(f, "finished"),
+            Ending::Failed(code) => write!(f, "failed with error {} ({}...)", code, error_name(*code)),
+            Ending::Crashed(msg) => write!(f, "crashed: {}", msg),
+        }
+    }
+}
+
+// The outcomes of running a program compiled and with the reference
+// interpreter. The interpreter isn't run when the compiled code runs out
+// of the heap, whose size the interpreter doesn't limit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Comparison {
+    pub compiled: Outcome,
+    pub interp: Option<Outcome>,
+}
+
+impl Comparison {
+    pub fn is_inconclusive(&self) -> bool {
+        self.interp.is_none()
+    }
+
+    pub fn agrees(&self) -> bool {
+        match &self.interp {
+            None => true,
+            Some(interp) => *interp == self.compiled,
+        }
+    }
+}
+
+// Whether the compiled code ending with (ending) leaves nothing to
+// compare the interpreter with
+pub fn is_inconclusive(ending: &Ending) -> bool {
+    *ending == Ending::Failed(OOM_ERROR)
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let interp = match &self.interp {
+            None => return write!(f, "inconclusive: the compiled code ran out of memory"),
+            Some(interp) => interp,
+        };
+        if self.agrees() {
+            let lines = interp.stdout.lines().count();
+            return write!(
+                f,
+                "the interpreter and the compiled code agree: {} line{} of output, {}",
+                lines,
+                if lines == 1 { "" } else { "s" },
+                interp.ending
+            );
+        }
+        writeln!(f, "the interpreter and the compiled code diverge")?;
+        let interp_lines: Vec<&str> = interp.stdout.lines().collect();
+        let compiled_lines: Vec<&str> = self.compiled.stdout.lines().collect();
+        let first_diff = (0..interp_lines.len().max(compiled_lines.len()))
+            .find(|i| interp_lines.get(*i) != compiled_lines.get(*i));
+        if let Some(i) = first_diff {
+            writeln!(f, "  output differs at line {}:", i + 1)?;
+            writeln!(f, "    interpreter: {}", interp_lines.get(i).unwrap_or(&"<end of output>"))?;
+            writeln!(f, "    compiled:    {}", compiled_lines.get(i).unwrap_or(&"<end of output>"))?;
+        }
+        writeln!(f, "  interpreter {}", interp.ending)?;
+        write!(f, "  compiled code {}", self.compiled.ending)
+    }
+}
@@ -0,0 +1,203 @@
+use crate::encode::{Object, RelocKind};
+
+// ********************************************************************
+//
+//                     ELF64 relocatable objects
+//
+// ********************************************************************
+
+// Section header types and flags
+static SHT_PROGBITS: u32 = 1;
+static SHT_SYMTAB: u32 = 2;
+static SHT_STRTAB: u32 = 3;
+static SHT_RELA: u32 = 4;
+static SHF_ALLOC: u64 = 0x2;
+static SHF_EXECINSTR: u64 = 0x4;
+static SHF_INFO_LINK: u64 = 0x40;
+
+// Symbol bindings and types
+static STB_LOCAL: u8 = 0;
+static STB_GLOBAL: u8 = 1;
+static STT_NOTYPE: u8 = 0;
+static STT_FUNC: u8 = 2;
+static STT_SECTION: u8 = 3;
+
+static R_X86_64_PC32: u32 = 2;
+static R_X86_64_PLT32: u32 = 4;
+
+// The indices of the sections of the objects we write
+static TEXT: u16 = 1;
+static SYMTAB: u32 = 3;
+static STRTAB: u32 = 4;
+static SHSTRTAB: u16 = 5;
+static NUM_SECTIONS: u16 = 7;
+
+// A string table, starting with the empty string as ELF requires
+struct StrTab {
+    bytes: Vec<u8>,
+}
+
+impl StrTab {
+    fn new() -> StrTab {
+        StrTab { bytes: vec![0] }
+    }
+
+    fn add(&mut self, s: &str) -> u32 {
+        let ix = self.bytes.len() as u32;
+        self.bytes.extend_from_slice(s.as_bytes());
+        self.bytes.push(0);
+        ix
+    }
+}
+
+struct SectionHeader {
+    name: u32,
+    typ: u32,
+    flags: u64,
+    offset: u64,
+    size: u64,
+    link: u32,
+    info: u32,
+    align: u64,
+    entsize: u64,
+}
+
+impl SectionHeader {
+    fn write(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.name.to_le_bytes());
+        out.extend_from_slice(&self.typ.to_le_bytes());
+        out.extend_from_slice(&self.flags.to_le_bytes());
+        out.extend_from_slice(&0u64.to_le_bytes()); // address
+        out.extend_from_slice(&self.offset.to_le_bytes());
+        out.extend_from_slice(&self.size.to_le_bytes());
+        out.extend_from_slice(&self.link.to_le_bytes());
+        out.extend_from_slice(&self.info.to_le_bytes());
+        out.extend_from_slice(&self.align.to_le_bytes());
+        out.extend_from_slice(&self.entsize.to_le_bytes());
+    }
+}
+
+fn symbol(out: &mut Vec<u8>, name: u32, bind: u8, typ: u8, section: u16, value: u64) {
+    out.extend_from_slice(&name.to_le_bytes());
+    out.push(bind << 4 | typ);
+    out.push(0); // default visibility
+    out.extend_from_slice(&section.to_le_bytes());
+    out.extend_from_slice(&value.to_le_bytes());
+    out.extend_from_slice(&0u64.to_le_bytes()); // size
+}
+
+fn align(out: &mut Vec<u8>, to: usize) {
+    while !out.len().is_multiple_of(to) {
+        out.push(0);
+    }
+}
+
+// Lays out (obj) as the .text section of an x86-64 relocatable object
+// that exports the labels of (globals). The symbols obj refers to but does
+// not define are left undefined for the linker.
+pub fn object_file(obj: &Object, globals: &[&str]) -> Vec<u8> {
+    let mut strtab = StrTab::new();
+    let mut symtab = Vec::new();
+    symbol(&mut symtab, 0, STB_LOCAL, STT_NOTYPE, 0, 0);
+    symbol(&mut symtab, 0, STB_LOCAL, STT_SECTION, TEXT, 0);
+    let first_global = 2;
+    for global in globals.iter() {
+        let offset = *obj
+            .labels
+            .get(*global)
+            .unwrap_or_else(|| panic!("exported label {} is not defined", global));
+        symbol(&mut symtab, strtab.add(global), STB_GLOBAL, STT_FUNC, TEXT, offset as u64);
+    }
+    let mut externs: Vec<&str> = Vec::new();
+    for reloc in obj.relocations.iter() {
+        if !externs.contains(&reloc.symbol.as_str()) {
+            externs.push(&reloc.symbol);
+        }
+    }
+    for name in externs.iter() {
+        symbol(&mut symtab, strtab.add(name), STB_GLOBAL, STT_NOTYPE, 0, 0);
+    }
+
+    let mut rela = Vec::new();
+    for reloc in obj.relocations.iter() {
+        let sym = first_global + globals.len() + externs.iter().position(|x| *x == reloc.symbol).unwrap();
+        let typ = match reloc.kind {
+            RelocKind::Pc32 => R_X86_64_PC32,
+            RelocKind::Plt32 => R_X86_64_PLT32,
+        };
+        rela.extend_from_slice(&(reloc.offset as u64).to_le_bytes());
+        rela.extend_from_slice(&((sym as u64) << 32 | typ as u64).to_le_bytes());
+        rela.extend_from_slice(&reloc.addend.to_le_bytes());
+    }
+
+    let mut shstrtab = StrTab::new();
+    let text_name = shstrtab.add(".text");
+    let rela_name = shstrtab.add(".rela.text");
+    let symtab_name = shstrtab.add(".symtab");
+    let strtab_name = shstrtab.add(".strtab");
+    let shstrtab_name = shstrtab.add(".shstrtab");
+    // marks the stack as non-executable
+    let note_name = shstrtab.add(".note.GNU-stack");
+
+    // the contents of the sections follow the 64 byte header
+    let mut out = vec![0; 64];
+    let mut headers = vec![SectionHeader {
+        name: 0,
+        typ: 0,
+        flags: 0,
+        offset: 0,
+        size: 0,
+        link: 0,
+        info: 0,
+        align: 0,
+        entsize: 0,
+    }];
+    let mut section = |out: &mut Vec<u8>, name, typ, flags, contents: &[u8], link, info, align_to, entsize| {
+        align(out, align_to as usize);
+        headers.push(SectionHeader {
+            name,
+            typ,
+            flags,
+            offset: out.len() as u64,
+            size: contents.len() as u64,
+            link,
+            info,
+            align: align_to,
+            entsize,
+        });
+        out.extend_from_slice(contents);
+    };
+    section(&mut out, text_name, SHT_PROGBITS, SHF_ALLOC | SHF_EXECINSTR, &obj.code, 0, 0, 16, 0);
+    section(&mut out, rela_name, SHT_RELA, SHF_INFO_LINK, &rela, SYMTAB, TEXT as u32, 8, 24);
+    section(&mut out, symtab_name, SHT_SYMTAB, 0, &symtab, STRTAB, first_global as u32, 8, 24);
+    section(&mut out, strtab_name, SHT_STRTAB, 0, &strtab.bytes, 0, 0, 1, 0);
+    section(&mut out, shstrtab_name, SHT_STRTAB, 0, &shstrtab.bytes, 0, 0, 1, 0);
+    section(&mut out, note_name, SHT_PROGBITS, 0, &[], 0, 0, 1, 0);
+    debug_assert_eq!(headers.len(), NUM_SECTIONS as usize);
+
+    align(&mut out, 8);
+    let shoff = out.len() as u64;
+    for header in headers.iter() {
+        header.write(&mut out);
+    }
+
+    let mut ehdr = Vec::with_capacity(64);
+    ehdr.extend_from_slice(b"\x7fELF");
+    ehdr.extend_from_slice(&[2, 1, 1, 0]); // 64-bit, little endian, version 1, System V
+    ehdr.extend_from_slice(&[0; 8]);
+    ehdr.extend_from_slice(&1u16.to_le_bytes()); // relocatable
+    ehdr.extend_from_slice(&62u16.to_le_bytes()); // x86-64
+    ehdr.extend_from_slice(&1u32.to_le_bytes());
+    ehdr.extend_from_slice(&0u64.to_le_bytes()); // entry point
+    ehdr.extend_from_slice(&0u64.to_le_bytes()); // program headers
+    ehdr.extend_from_slice(&shoff.to_le_bytes());
+    ehdr.extend_from_slice(&0u32.to_le_bytes()); // flags
+    ehdr.extend_from_slice(&64u16.to_le_bytes());
+    ehdr.extend_from_slice(&0u16.to_le_bytes());
+    ehdr.extend_from_slice(&0u16.to_le_bytes());
+    ehdr.extend_from_slice(&64u16.to_le_bytes());
+    ehdr.extend_from_slice(&NUM_SECTIONS.to_le_bytes());
+    ehdr.extend_from_slice(&SHSTRTAB.to_le_bytes());
+    out[..64].copy_from_slice(&ehdr);
+    out
+}
@@ -0,0 +1,407 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use crate::asm::{Arg32, Arg64, BinArgs, Instr, Loc, MemRef, MovArgs, Reg, Reg32};
+
+// ********************************************************************
+//
+//                     x86-64 machine code encoding
+//
+// ********************************************************************
+
+// How the linker patches a 32-bit field that refers to a symbol outside
+// of the code
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RelocKind {
+    Pc32,   // the address of data, relative to the next instruction
+    Plt32,  // a call or jump to a function
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Relocation {
+    pub offset: usize, // of the 32-bit field in the code
+    pub symbol: String,
+    pub kind: RelocKind,
+    pub addend: i64,
+}
+
+// The encoded instructions, with the offsets of their labels and the
+// references to symbols they do not define
+#[derive(Clone, Debug, Default)]
+pub struct Object {
+    pub code: Vec<u8>,
+    pub labels: HashMap<String, usize>,
+    pub relocations: Vec<Relocation>,
+}
+
+// a rel32 field at (offset) pointing to (label), resolved after all the
+// labels are known
+struct Fixup {
+    offset: usize,
+    label: String,
+    kind: RelocKind,
+}
+
+fn reg_num(r: Reg) -> u8 {
+    match r {
+        Reg::Rax => 0,
+        Reg::Rcx => 1,
+        Reg::Rdx => 2,
+        Reg::Rbx => 3,
+        Reg::Rsp => 4,
+        Reg::Rbp => 5,
+        Reg::Rsi => 6,
+        Reg::Rdi => 7,
+        Reg::R8 => 8,
+        Reg::R9 => 9,
+        Reg::R10 => 10,
+        Reg::R11 => 11,
+        Reg::R12 => 12,
+        Reg::R13 => 13,
+        Reg::R14 => 14,
+        Reg::R15 => 15,
+        Reg::St0 | Reg::St1 => panic!("{:?} is not a general purpose register", r),
+    }
+}
+
+fn st_num(r: Reg) -> u8 {
+    match r {
+        Reg::St0 => 0,
+        Reg::St1 => 1,
+        _ => panic!("{:?} is not an x87 register", r),
+    }
+}
+
+// The ModRM operand: a register or memory at [reg + offset]
+#[derive(Clone, Copy)]
+enum Rm {
+    Reg(Reg),
+    Mem(MemRef),
+}
+
+impl Rm {
+    fn base(&self) -> u8 {
+        match self {
+            Rm::Reg(r) => reg_num(*r),
+            Rm::Mem(m) => reg_num(m.reg),
+        }
+    }
+}
+
+struct Encoder {
+    obj: Object,
+    fixups: Vec<Fixup>,
+}
+
+impl Encoder {
+    fn byte(&mut self, b: u8) {
+        self.obj.code.push(b);
+    }
+
+    fn bytes(&mut self, bs: &[u8]) {
+        self.obj.code.extend_from_slice(bs);
+    }
+
+    fn imm32(&mut self, i: i32) {
+        self.bytes(&i.to_le_bytes());
+    }
+
+    // REX prefix, left out when it would be empty
+    fn rex(&mut self, w: bool, reg: u8, rm: &Rm, force: bool) {
+        let rex = 0x40 | (w as u8) << 3 | (reg >> 3) << 2 | rm.base() >> 3;
+        if rex != 0x40 || force {
+            self.byte(rex);
+        }
+    }
+
+    // ModRM byte with (reg) as the register or opcode extension, followed
+    // by the SIB byte and displacement of a memory operand
+    fn modrm(&mut self, reg: u8, rm: &Rm) {
+        let reg = (reg & 7) << 3;
+        match rm {
+            Rm::Reg(r) => self.byte(0xC0 | reg | (reg_num(*r) & 7)),
+            Rm::Mem(m) => {
+                let base = reg_num(m.reg) & 7;
+                // rbp and r13 always need a displacement
+                let mode: u8 = if m.offset == 0 && base != 5 {
+                    0x00
+                } else if i8::try_from(m.offset).is_ok() {
+                    0x40
+                } else {
+                    0x80
+                };
+                self.byte(mode | reg | base);
+                // rsp and r12 need a SIB byte
+                if base == 4 {
+                    self.byte(0x24);
+                }
+                match mode {
+                    0x40 => self.byte(m.offset as i8 as u8),
+                    0x80 => self.imm32(m.offset),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    // a 64-bit instruction with a ModRM operand
+    fn op64(&mut self, opcode: &[u8], reg: u8, rm: Rm) {
+        self.rex(true, reg, &rm, false);
+        self.bytes(opcode);
+        self.modrm(reg, &rm);
+    }
+
+    fn rel32(&mut self, label: &str, kind: RelocKind) {
+        self.fixups.push(Fixup {
+            offset: self.obj.code.len(),
+            label: label.to_string(),
+            kind,
+        });
+        self.imm32(0);
+    }
+
+    // add, or, and, sub, xor and cmp share their encodings, (ext) is the
+    // opcode extension of the immediate form
+    fn arith(&mut self, ext: u8, args: &BinArgs) {
+        let op_rm_r = ext << 3 | 0x01;
+        let op_r_rm = ext << 3 | 0x03;
+        match *args {
+            BinArgs::ToReg(dst, Arg32::Reg(src)) => self.op64(&[op_rm_r], reg_num(src), Rm::Reg(dst)),
+            BinArgs::ToReg(dst, Arg32::Mem(m)) => self.op64(&[op_r_rm], reg_num(dst), Rm::Mem(m)),
+            BinArgs::ToReg(dst, Arg32::Signed(i)) => self.arith_imm(ext, Rm::Reg(dst), i),
+            BinArgs::ToReg(dst, Arg32::Unsigned(u)) => self.arith_imm(ext, Rm::Reg(dst), u as i32),
+            BinArgs::ToMem(m, Reg32::Reg(src)) => self.op64(&[op_rm_r], reg_num(src), Rm::Mem(m)),
+            BinArgs::ToMem(m, Reg32::Imm(i)) => self.arith_imm(ext, Rm::Mem(m), i),
+        }
+    }
+
+    // the immediate is sign extended to 64 bits
+    fn arith_imm(&mut self, ext: u8, rm: Rm, i: i32) {
+        match i8::try_from(i) {
+            Ok(b) => {
+                self.op64(&[0x83], ext, rm);
+                self.byte(b as u8);
+            }
+            Err(_) => {
+                self.op64(&[0x81], ext, rm);
+                self.imm32(i);
+            }
+        }
+    }
+
+    fn shift(&mut self, ext: u8, args: &BinArgs) {
+        let (rm, amount) = match *args {
+            BinArgs::ToReg(dst, Arg32::Signed(i)) => (Rm::Reg(dst), i as u8),
+            BinArgs::ToReg(dst, Arg32::Unsigned(u)) => (Rm::Reg(dst), u as u8),
+            BinArgs::ToMem(m, Reg32::Imm(i)) => (Rm::Mem(m), i as u8),
+            BinArgs::ToReg(dst, Arg32::Reg(Reg::Rcx)) => return self.op64(&[0xD3], ext, Rm::Reg(dst)),
+            BinArgs::ToMem(m, Reg32::Reg(Reg::Rcx)) => return self.op64(&[0xD3], ext, Rm::Mem(m)),
+            _ => panic!("shifts take an immediate or cl as the amount: {:?}", args),
+        };
+        self.op64(&[0xC1], ext, rm);
+        self.byte(amount);
+    }
+
+    fn jcc(&mut self, cc: u8, label: &str) {
+        self.bytes(&[0x0F, 0x80 | cc]);
+        self.rel32(label, RelocKind::Plt32);
+    }
+
+    // an x87 instruction on a 64-bit memory operand
+    fn fpu_mem(&mut self, opcode: u8, ext: u8, m: MemRef) {
+        self.rex(false, 0, &Rm::Mem(m), false);
+        self.byte(opcode);
+        self.modrm(ext, &Rm::Mem(m));
+    }
+
+    // fadd, fsub, fmul and fdiv between st0 and st(i), (st0_ext) is the
+    // encoding of st0 := st0 op st(i) and (sti_ext) of st(i) := st(i) op st0
+    fn fpu_arith(&mut self, st0_ext: u8, sti_ext: u8, dst: Reg, src: Reg) {
+        match (st_num(dst), st_num(src)) {
+            (0, i) => self.bytes(&[0xD8, st0_ext + i]),
+            (i, 0) => self.bytes(&[0xDC, sti_ext + i]),
+            _ => panic!("x87 arithmetic needs st0 as an operand"),
+        }
+    }
+
+    fn instr(&mut self, i: &Instr) {
+        match i {
+            Instr::Mov(MovArgs::ToReg(dst, arg)) => match *arg {
+                Arg64::Reg(src) => self.op64(&[0x89], reg_num(src), Rm::Reg(*dst)),
+                Arg64::Mem(m) => self.op64(&[0x8B], reg_num(*dst), Rm::Mem(m)),
+                Arg64::Signed(n) => self.mov_imm(*dst, n),
+                Arg64::Unsigned(u) => self.mov_imm(*dst, u as i64),
+            },
+            Instr::Mov(MovArgs::ToMem(m, Reg32::Reg(src))) => self.op64(&[0x89], reg_num(*src), Rm::Mem(*m)),
+            Instr::Mov(MovArgs::ToMem(m, Reg32::Imm(n))) => {
+                self.op64(&[0xC7], 0, Rm::Mem(*m));
+                self.imm32(*n);
+            }
+
+            Instr::Add(args) => self.arith(0, args),
+            Instr::Or(args) => self.arith(1, args),
+            Instr::And(args) => self.arith(4, args),
+            Instr::Sub(args) => self.arith(5, args),
+            Instr::Xor(args) => self.arith(6, args),
+            Instr::Cmp(args) => self.arith(7, args),
+            Instr::Test(args) => match *args {
+                BinArgs::ToReg(dst, Arg32::Reg(src)) => self.op64(&[0x85], reg_num(src), Rm::Reg(dst)),
+                BinArgs::ToReg(dst, Arg32::Mem(m)) => self.op64(&[0x85], reg_num(dst), Rm::Mem(m)),
+                BinArgs::ToMem(m, Reg32::Reg(src)) => self.op64(&[0x85], reg_num(src), Rm::Mem(m)),
+                BinArgs::ToReg(dst, Arg32::Signed(n)) => self.test_imm(Rm::Reg(dst), n),
+                BinArgs::ToReg(dst, Arg32::Unsigned(u)) => self.test_imm(Rm::Reg(dst), u as i32),
+                BinArgs::ToMem(m, Reg32::Imm(n)) => self.test_imm(Rm::Mem(m), n),
+            },
+            Instr::IMul(args) => match *args {
+                BinArgs::ToReg(dst, Arg32::Reg(src)) => self.op64(&[0x0F, 0xAF], reg_num(dst), Rm::Reg(src)),
+                BinArgs::ToReg(dst, Arg32::Mem(m)) => self.op64(&[0x0F, 0xAF], reg_num(dst), Rm::Mem(m)),
+                BinArgs::ToReg(dst, Arg32::Signed(n)) => {
+                    self.op64(&[0x69], reg_num(dst), Rm::Reg(dst));
+                    self.imm32(n);
+                }
+                BinArgs::ToReg(dst, Arg32::Unsigned(u)) => {
+                    self.op64(&[0x69], reg_num(dst), Rm::Reg(dst));
+                    self.imm32(u as i32);
+                }
+                BinArgs::ToMem(..) => panic!("imul cannot write to memory: {:?}", args),
+            },
+            Instr::IDiv(r) => self.op64(&[0xF7], 7, Rm::Reg(*r)),
+            Instr::Cqo => self.bytes(&[0x48, 0x99]),
+            Instr::Shl(args) => self.shift(4, args),
+            Instr::Shr(args) => self.shift(5, args),
+            Instr::Sar(args) => self.shift(7, args),
+
+            Instr::Push(arg) => match *arg {
+                Arg32::Reg(r) => {
+                    self.rex(false, 0, &Rm::Reg(r), false);
+                    self.byte(0x50 | (reg_num(r) & 7));
+                }
+                Arg32::Mem(m) => {
+                    self.rex(false, 0, &Rm::Mem(m), false);
+                    self.byte(0xFF);
+                    self.modrm(6, &Rm::Mem(m));
+                }
+                Arg32::Signed(n) => {
+                    self.byte(0x68);
+                    self.imm32(n);
+                }
+                Arg32::Unsigned(u) => {
+                    self.byte(0x68);
+                    self.imm32(u as i32);
+                }
+            },
+            Instr::Pop(loc) => match *loc {
+                Loc::Reg(r) => {
+                    self.rex(false, 0, &Rm::Reg(r), false);
+                    self.byte(0x58 | (reg_num(r) & 7));
+                }
+                Loc::Mem(m) => {
+                    self.rex(false, 0, &Rm::Mem(m), false);
+                    self.byte(0x8F);
+                    self.modrm(0, &Rm::Mem(m));
+                }
+            },
+
+            Instr::Label(l) => {
+                if self.obj.labels.insert(l.clone(), self.obj.code.len()).is_some() {
+                    panic!("label {} is defined twice", l)
+                }
+            }
+            Instr::Comment(_) => {}
+
+            Instr::Call(l) => {
+                self.byte(0xE8);
+                self.rel32(l, RelocKind::Plt32);
+            }
+            Instr::CallIndirect(r) => {
+                self.rex(false, 0, &Rm::Reg(*r), false);
+                self.byte(0xFF);
+                self.modrm(2, &Rm::Reg(*r));
+            }
+            Instr::Ret => self.byte(0xC3),
+            Instr::Lea(r, l) => {
+                // lea r, [rel l]
+                self.rex(true, reg_num(*r), &Rm::Reg(Reg::Rax), false);
+                self.byte(0x8D);
+                self.byte(0x05 | (reg_num(*r) & 7) << 3);
+                self.rel32(l, RelocKind::Pc32);
+            }
+
+            Instr::Jmp(l) => {
+                self.byte(0xE9);
+                self.rel32(l, RelocKind::Plt32);
+            }
+            Instr::JmpIndirect(r) => {
+                self.rex(false, 0, &Rm::Reg(*r), false);
+                self.byte(0xFF);
+                self.modrm(4, &Rm::Reg(*r));
+            }
+            Instr::Jo(l) => self.jcc(0x0, l),
+            Instr::Jno(l) => self.jcc(0x1, l),
+            Instr::Je(l) | Instr::Jz(l) => self.jcc(0x4, l),
+            Instr::Jne(l) | Instr::Jnz(l) => self.jcc(0x5, l),
+            Instr::Js(l) => self.jcc(0x8, l),
+            Instr::Jl(l) => self.jcc(0xC, l),
+            Instr::Jge(l) => self.jcc(0xD, l),
+            Instr::Jle(l) => self.jcc(0xE, l),
+            Instr::Jg(l) => self.jcc(0xF, l),
+
+            Instr::Fld(m) => self.fpu_mem(0xDD, 0, *m),
+            Instr::Fild(m) => self.fpu_mem(0xDF, 5, *m),
+            Instr::Fstp(Loc::Mem(m)) => self.fpu_mem(0xDD, 3, *m),
+            Instr::Fstp(Loc::Reg(r)) => self.bytes(&[0xDD, 0xD8 + st_num(*r)]),
+            Instr::Fadd(dst, src) => self.fpu_arith(0xC0, 0xC0, *dst, *src),
+            Instr::Fmul(dst, src) => self.fpu_arith(0xC8, 0xC8, *dst, *src),
+            Instr::Fsub(dst, src) => self.fpu_arith(0xE0, 0xE8, *dst, *src),
+            Instr::Fdiv(dst, src) => self.fpu_arith(0xF0, 0xF8, *dst, *src),
+        }
+    }
+
+    // the shortest of the sign-extended and the full 64-bit immediate
+    fn mov_imm(&mut self, dst: Reg, n: i64) {
+        match i32::try_from(n) {
+            Ok(n) => {
+                self.op64(&[0xC7], 0, Rm::Reg(dst));
+                self.imm32(n);
+            }
+            Err(_) => {
+                self.rex(true, 0, &Rm::Reg(dst), false);
+                self.byte(0xB8 | (reg_num(dst) & 7));
+                self.bytes(&n.to_le_bytes());
+            }
+        }
+    }
+
+    fn test_imm(&mut self, rm: Rm, n: i32) {
+        self.op64(&[0xF7], 0, rm);
+        self.imm32(n);
+    }
+}
+
+// Encodes (instrs) into machine code. Jumps and calls to labels of
+// (instrs) are resolved, the other ones become relocations.
+pub fn encode(instrs: &[Instr]) -> Object {
+    let mut enc = Encoder {
+        obj: Object::default(),
+        fixups: Vec::new(),
+    };
+    for i in instrs.iter() {
+        enc.instr(i);
+    }
+    let mut obj = enc.obj;
+    for fixup in enc.fixups {
+        match obj.labels.get(&fixup.label) {
+            Some(&target) => {
+                let rel = target as i64 - (fixup.offset as i64 + 4);
+                obj.code[fixup.offset..fixup.offset + 4].copy_from_slice(&(rel as i32).to_le_bytes());
+            }
+            None => obj.relocations.push(Relocation {
+                offset: fixup.offset,
+                symbol: fixup.label,
+                kind: fixup.kind,
+                addend: -4,
+            }),
+        }
+    }
+    obj
+}
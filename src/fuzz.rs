@@ -0,0 +1,633 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::diff::Comparison;
+use crate::syntax::{Exp, FunDecl, Prim, SurfProg};
+
+// ********************************************************************
+//
+//                 random programs for fuzzing the compiler
+//
+// ********************************************************************
+
+// A splitmix64 generator, so that a seed always gives the same program
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // uniform in 0..n
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    // true with probability 1 / n
+    pub fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+
+    fn choose<'a, T>(&mut self, xs: &'a [T]) -> &'a T {
+        &xs[self.below(xs.len())]
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Ty {
+    Int,
+    Bool,
+}
+
+// What a call may pass as the fuel of the function it calls. Every
+// generated function takes its fuel as its first parameter, stops
+// recursing once it reaches 0 and passes one less to the functions it
+// calls, so that generated programs always terminate.
+#[derive(Clone, Debug)]
+enum Fuel {
+    // no calls allowed, e.g. in the base case of a function
+    Empty,
+    // the fuel of the enclosing function
+    Var(String),
+    // a small constant, outside of any function
+    Top,
+}
+
+// The names in scope where an expression is generated. Lambdas are only
+// ever bound by let and called by name, and don't call functions, so
+// they can't make a program loop either.
+#[derive(Clone, Debug)]
+struct Scope {
+    vars: Vec<(String, Ty)>,
+    funs: Vec<(String, usize)>,
+    lambdas: Vec<(String, usize)>,
+    fuel: Fuel,
+}
+
+impl Scope {
+    fn bind(&mut self, x: &str, ty: Ty) {
+        self.vars.retain(|(y, _)| y != x);
+        self.vars.push((x.to_string(), ty));
+    }
+}
+
+struct Gen {
+    rng: Rng,
+    // the number of nodes left to generate before only leaves are
+    budget: usize,
+    next_name: usize,
+}
+
+static MAX_DEPTH: usize = 12;
+static MAX_FUEL: usize = 4;
+
+impl Gen {
+    fn fresh(&mut self, prefix: &str) -> String {
+        self.next_name += 1;
+        format!("{}{}", prefix, self.next_name)
+    }
+
+    fn num(&mut self) -> Exp<()> {
+        // now and then close to the bounds of 63 bit integers
+        if self.rng.one_in(40) {
+            let big = 2i64.pow(61) - 1 - self.rng.below(3) as i64;
+            return Exp::Num(if self.rng.one_in(2) { big } else { -big - 1 }, ());
+        }
+        Exp::Num(self.rng.below(21) as i64 - 10, ())
+    }
+
+    fn leaf(&mut self, ty: Ty, scope: &Scope) -> Exp<()> {
+        // a value of the wrong type now and then, for the runtime errors
+        let ty = match (ty, self.rng.one_in(60)) {
+            (Ty::Int, true) => Ty::Bool,
+            (Ty::Bool, true) => Ty::Int,
+            (ty, false) => ty,
+        };
+        let vars: Vec<&String> = scope.vars.iter().filter(|(_, t)| *t == ty).map(|(x, _)| x).collect();
+        if !vars.is_empty() && self.rng.below(3) > 0 {
+            return Exp::Var(self.rng.choose(&vars).to_string(), ());
+        }
+        match ty {
+            Ty::Int => self.num(),
+            Ty::Bool => Exp::Bool(self.rng.one_in(2), ()),
+        }
+    }
+
+    fn exp(&mut self, ty: Ty, scope: &Scope, depth: usize) -> Exp<()> {
+        if self.budget == 0 || depth >= MAX_DEPTH {
+            return self.leaf(ty, scope);
+        }
+        self.budget -= 1;
+        let depth = depth + 1;
+        match self.rng.below(10) {
+            0 | 1 => self.leaf(ty, scope),
+            2 => self.let_exp(ty, scope, depth),
+            3 => Exp::If {
+                cond: Box::new(self.exp(Ty::Bool, scope, depth)),
+                thn: Box::new(self.exp(ty, scope, depth)),
+                els: Box::new(self.exp(ty, scope, depth)),
+                ann: (),
+            },
+            4 => self.fun_defs(ty, scope, depth),
+            5 => self.call(ty, scope, depth),
+            6 => self.lambda(ty, scope, depth),
+            7 if self.rng.one_in(2) => Exp::Prim(Prim::Print, vec![Box::new(self.exp(ty, scope, depth))], ()),
+            8 if self.rng.one_in(3) => {
+                let first_ty = if self.rng.one_in(3) { Ty::Bool } else { Ty::Int };
+                Exp::Seq {
+                    first: Box::new(Exp::Prim(Prim::Print, vec![Box::new(self.exp(first_ty, scope, depth))], ())),
+                    second: Box::new(self.exp(ty, scope, depth)),
+                    ann: (),
+                }
+            }
+            _ => self.prim(ty, scope, depth),
+        }
+    }
+
+    fn prim(&mut self, ty: Ty, scope: &Scope, depth: usize) -> Exp<()> {
+        let (p, arg_tys) = match ty {
+            Ty::Int => *self.rng.choose(&[
+                (Prim::Add1, &[Ty::Int][..]),
+                (Prim::Sub1, &[Ty::Int]),
+                (Prim::Add, &[Ty::Int, Ty::Int]),
+                (Prim::Sub, &[Ty::Int, Ty::Int]),
+                (Prim::Add, &[Ty::Int, Ty::Int]),
+                (Prim::Mul, &[Ty::Int, Ty::Int]),
+                (Prim::IntDiv, &[Ty::Int, Ty::Int]),
+                (Prim::Rem, &[Ty::Int, Ty::Int]),
+                (Prim::BitAnd, &[Ty::Int, Ty::Int]),
+                (Prim::BitOr, &[Ty::Int, Ty::Int]),
+                (Prim::BitXor, &[Ty::Int, Ty::Int]),
+                (Prim::Min, &[Ty::Int, Ty::Int]),
+                (Prim::Max, &[Ty::Int, Ty::Int]),
+                (Prim::Abs, &[Ty::Int]),
+            ]),
+            Ty::Bool => *self.rng.choose(&[
+                (Prim::Not, &[Ty::Bool][..]),
+                (Prim::And, &[Ty::Bool, Ty::Bool]),
+                (Prim::Or, &[Ty::Bool, Ty::Bool]),
+                (Prim::Lt, &[Ty::Int, Ty::Int]),
+                (Prim::Le, &[Ty::Int, Ty::Int]),
+                (Prim::Gt, &[Ty::Int, Ty::Int]),
+                (Prim::Ge, &[Ty::Int, Ty::Int]),
+                (Prim::Eq, &[Ty::Int, Ty::Int]),
+                (Prim::Neq, &[Ty::Bool, Ty::Bool]),
+                (Prim::IsNum, &[Ty::Int]),
+                (Prim::IsBool, &[Ty::Int]),
+            ]),
+        };
+        let args = arg_tys.iter().map(|t| Box::new(self.exp(*t, scope, depth))).collect();
+        Exp::Prim(p, args, ())
+    }
+
+    fn let_exp(&mut self, ty: Ty, scope: &Scope, depth: usize) -> Exp<()> {
+        let mut inner = scope.clone();
+        let mut bindings: Vec<(String, Exp<()>)> = Vec::new();
+        for _ in 0..1 + self.rng.below(3) {
+            let bound_ty = if self.rng.one_in(3) { Ty::Bool } else { Ty::Int };
+            let bound = self.exp(bound_ty, &inner, depth);
+            // sometimes shadow a variable bound further out, never a fuel
+            let shadowable = |x: &String| x.starts_with('x') && bindings.iter().all(|(y, _)| x != y);
+            let x = match inner.vars.iter().find(|(x, _)| shadowable(x)) {
+                Some((x, _)) if self.rng.one_in(4) => x.clone(),
+                _ => self.fresh("x"),
+            };
+            inner.bind(&x, bound_ty);
+            bindings.push((x, bound));
+        }
+        Exp::Let {
+            bindings,
+            body: Box::new(self.exp(ty, &inner, depth)),
+            ann: (),
+        }
+    }
+
+    // 1 to 3 mutually recursive functions from ints to ints, each guarded
+    // by its fuel:
+    //     def f(n, x, ...): if n < 1: <no calls> else: <calls with sub1(n)>
+    fn fun_defs(&mut self, ty: Ty, scope: &Scope, depth: usize) -> Exp<()> {
+        let mut inner = scope.clone();
+        let group: Vec<(String, usize)> = (0..1 + self.rng.below(3))
+            .map(|_| (self.fresh("f"), 1 + self.rng.below(3)))
+            .collect();
+        inner.funs.extend(group.iter().cloned());
+        let decls = group
+            .iter()
+            .map(|(name, arity)| {
+                let fuel = self.fresh("n");
+                let mut parameters = vec![fuel.clone()];
+                parameters.extend((1..*arity).map(|_| self.fresh("x")));
+                let mut body_scope = inner.clone();
+                for x in parameters.iter() {
+                    body_scope.bind(x, Ty::Int);
+                }
+                body_scope.fuel = Fuel::Empty;
+                let base = self.exp(Ty::Int, &body_scope, depth);
+                body_scope.fuel = Fuel::Var(fuel.clone());
+                let recur = self.exp(Ty::Int, &body_scope, depth);
+                FunDecl {
+                    name: name.clone(),
+                    parameters,
+                    body: Exp::If {
+                        cond: Box::new(Exp::Prim(
+                            Prim::Lt,
+                            vec![Box::new(Exp::Var(fuel, ())), Box::new(Exp::Num(1, ()))],
+                            (),
+                        )),
+                        thn: Box::new(base),
+                        els: Box::new(recur),
+                        ann: (),
+                    },
+                    ann: (),
+                }
+            })
+            .collect();
+        Exp::FunDefs {
+            decls,
+            body: Box::new(self.exp(ty, &inner, depth)),
+            ann: (),
+        }
+    }
+
+    fn call(&mut self, ty: Ty, scope: &Scope, depth: usize) -> Exp<()> {
+        let use_lambda = !scope.lambdas.is_empty() && (self.rng.one_in(2) || matches!(scope.fuel, Fuel::Empty));
+        let (f, arity, fuel) = if use_lambda {
+            let (f, arity) = self.rng.choose(&scope.lambdas).clone();
+            (f, arity, None)
+        } else {
+            let fuel = match &scope.fuel {
+                _ if scope.funs.is_empty() => return self.prim(ty, scope, depth),
+                Fuel::Empty => return self.prim(ty, scope, depth),
+                Fuel::Var(n) => Exp::Prim(Prim::Sub1, vec![Box::new(Exp::Var(n.clone(), ()))], ()),
+                Fuel::Top => Exp::Num(self.rng.below(MAX_FUEL + 1) as i64, ()),
+            };
+            let (f, arity) = self.rng.choose(&scope.funs).clone();
+            (f, arity, Some(fuel))
+        };
+        let mut args: Vec<Exp<()>> = fuel.into_iter().collect();
+        while args.len() < arity {
+            args.push(self.exp(Ty::Int, scope, depth));
+        }
+        let call = Exp::Call(f, args, ());
+        match ty {
+            Ty::Int => call,
+            Ty::Bool => {
+                let other = self.exp(Ty::Int, scope, depth);
+                Exp::Prim(Prim::Lt, vec![Box::new(call), Box::new(other)], ())
+            }
+        }
+    }
+
+    // let g = lambda (x, ...): <no calls to functions> end in <calls g>
+    fn lambda(&mut self, ty: Ty, scope: &Scope, depth: usize) -> Exp<()> {
+        let g = self.fresh("g");
+        let parameters: Vec<String> = (0..self.rng.below(3)).map(|_| self.fresh("x")).collect();
+        let mut body_scope = scope.clone();
+        for x in parameters.iter() {
+            body_scope.bind(x, Ty::Int);
+        }
+        body_scope.fuel = Fuel::Empty;
+        let lambda_body = self.exp(Ty::Int, &body_scope, depth);
+        let mut inner = scope.clone();
+        inner.lambdas.push((g.clone(), parameters.len()));
+        let body = self.exp(ty, &inner, depth);
+        Exp::Let {
+            bindings: vec![(
+                g,
+                Exp::Lambda {
+                    parameters,
+                    body: Box::new(lambda_body),
+                    ann: (),
+                },
+            )],
+            body: Box::new(body),
+            ann: (),
+        }
+    }
+}
+
+// A random well-scoped program that terminates, from (seed). (size) bounds
+// its number of compound expressions.
+pub fn gen_prog(seed: u64, size: usize) -> SurfProg<()> {
+    let mut gen = Gen {
+        rng: Rng::new(seed),
+        budget: size,
+        next_name: 0,
+    };
+    let scope = Scope {
+        vars: Vec::new(),
+        funs: Vec::new(),
+        lambdas: Vec::new(),
+        fuel: Fuel::Top,
+    };
+    let ty = if gen.rng.one_in(4) { Ty::Bool } else { Ty::Int };
+    gen.exp(ty, &scope, 0)
+}
+
+// ********************************************************************
+//
+//                       shrinking failing programs
+//
+// ********************************************************************
+
+// Why a generated program was reported
+#[derive(Debug)]
+pub enum Failure {
+    // the compiled code and the interpreter disagree
+    Diverged(Comparison),
+    // the program doesn't parse or isn't well-formed, which is a bug of
+    // the generator or of the printer, not of the compiler
+    Rejected(String),
+    // the compiler failed or panicked on it
+    Crashed(String),
+}
+
+impl Failure {
+    // Whether both failures are the same kind of bug
+    pub fn same_kind(&self, other: &Failure) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Failure::Diverged(comparison) => write!(f, "{}", comparison),
+            Failure::Rejected(msg) => write!(f, "the generated program was rejected:\n{}", msg),
+            Failure::Crashed(msg) => write!(f, "the compiler crashed: {}", msg),
+        }
+    }
+}
+
+// Compares programs by their number of nodes, then by the size of their
+// constants and their number of variables, so that shrinking always
+// terminates. 0 is the smallest constant, booleans shrink to it.
+fn measure(e: &Exp<()>) -> (usize, u64) {
+    match e {
+        Exp::Num(n, _) => (1, n.unsigned_abs()),
+        Exp::Bool(b, _) => (1, 1 + *b as u64),
+        Exp::Float(..) => (1, 0),
+        Exp::Str(s, _) => (1, s.len() as u64),
+        Exp::Var(..) => (1, 1),
+        _ => {
+            let mut total: (usize, u64) = (1, 0);
+            for child in children(e) {
+                let (nodes, weight) = measure(child);
+                total = (total.0 + nodes, total.1.saturating_add(weight));
+            }
+            total
+        }
+    }
+}
+
+fn children(e: &Exp<()>) -> Vec<&Exp<()>> {
+    match e {
+        Exp::Num(..) | Exp::Float(..) | Exp::Bool(..) | Exp::Str(..) | Exp::Var(..) => vec![],
+        Exp::Prim(_, args, _) => args.iter().map(|arg| &**arg).collect(),
+        Exp::Let { bindings, body, .. } => bindings.iter().map(|(_, e)| e).chain(Some(&**body)).collect(),
+        Exp::If { cond, thn, els, .. } => vec![cond, thn, els],
+        Exp::FunDefs { decls, body, .. } => decls.iter().map(|d| &d.body).chain(Some(&**body)).collect(),
+        Exp::Lambda { body, .. } => vec![body],
+        Exp::Seq { first, second, .. } => vec![first, second],
+        Exp::Call(_, args, _) => args.iter().collect(),
+        Exp::ClosureCall { fun, args, .. } => Some(&**fun).into_iter().chain(args.iter()).collect(),
+        Exp::InternalTailCall(..) | Exp::ExternalCall { .. } | Exp::MakeClosure { .. } => {
+            panic!("internal compiler forms shouldn't occur before lambda lifting")
+        }
+    }
+}
+
+// Whether (body) is the fuel guard the generator puts around the body of
+// a function with (parameters)
+fn is_fuel_guard(body: &Exp<()>, parameters: &[String]) -> bool {
+    match body {
+        Exp::If { cond, .. } => match cond.as_ref() {
+            Exp::Prim(Prim::Lt, args, _) => matches!(args[0].as_ref(), Exp::Var(n, _) if Some(n) == parameters.first()),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+// The expressions one step smaller than (e), the biggest steps first.
+// Candidates may be ill-scoped, the caller checks them. The fuel guards
+// of functions and the fuel passed to them are kept, so that candidates
+// still terminate. (funs) are the functions in scope.
+fn candidates(e: &Exp<()>, funs: &[String]) -> Vec<Exp<()>> {
+    let mut out: Vec<Exp<()>> = Vec::new();
+    // replace e by one of its subexpressions
+    match e {
+        Exp::Call(f, args, _) if funs.contains(f) => out.extend(args.iter().skip(1).cloned()),
+        Exp::FunDefs { body, .. } => out.push((**body).clone()),
+        _ => out.extend(children(e).into_iter().cloned()),
+    }
+    match e {
+        Exp::Num(n, _) => {
+            out.push(Exp::Num(0, ()));
+            out.push(Exp::Num(n / 2, ()));
+        }
+        Exp::Str(s, _) => {
+            out.push(Exp::Str(String::new(), ()));
+            out.push(Exp::Str(s[..s.len() / 2].to_string(), ()));
+            out.push(Exp::Num(0, ()));
+        }
+        _ => {
+            out.push(Exp::Num(0, ()));
+            out.push(Exp::Bool(false, ()));
+        }
+    }
+
+    // shrink a single subexpression
+    match e {
+        Exp::Num(..) | Exp::Float(..) | Exp::Bool(..) | Exp::Str(..) | Exp::Var(..) => {}
+        Exp::Prim(p, args, _) => {
+            for (i, arg) in args.iter().enumerate() {
+                for c in candidates(arg, funs) {
+                    let mut args = args.clone();
+                    *args[i] = c;
+                    out.push(Exp::Prim(*p, args, ()));
+                }
+            }
+        }
+        Exp::Let { bindings, body, .. } => {
+            if bindings.len() > 1 {
+                for i in 0..bindings.len() {
+                    let mut bindings = bindings.clone();
+                    bindings.remove(i);
+                    out.push(Exp::Let {
+                        bindings,
+                        body: body.clone(),
+                        ann: (),
+                    });
+                }
+            }
+            for (i, (_, bound)) in bindings.iter().enumerate() {
+                for c in candidates(bound, funs) {
+                    let mut bindings = bindings.clone();
+                    bindings[i].1 = c;
+                    out.push(Exp::Let {
+                        bindings,
+                        body: body.clone(),
+                        ann: (),
+                    });
+                }
+            }
+            for c in candidates(body, funs) {
+                out.push(Exp::Let {
+                    bindings: bindings.clone(),
+                    body: Box::new(c),
+                    ann: (),
+                });
+            }
+        }
+        Exp::If { cond, thn, els, .. } => {
+            let rebuild = |cond: &Exp<()>, thn: &Exp<()>, els: &Exp<()>| Exp::If {
+                cond: Box::new(cond.clone()),
+                thn: Box::new(thn.clone()),
+                els: Box::new(els.clone()),
+                ann: (),
+            };
+            out.extend(candidates(cond, funs).iter().map(|c| rebuild(c, thn, els)));
+            out.extend(candidates(thn, funs).iter().map(|c| rebuild(cond, c, els)));
+            out.extend(candidates(els, funs).iter().map(|c| rebuild(cond, thn, c)));
+        }
+        Exp::FunDefs { decls, body, .. } => {
+            let mut inner: Vec<String> = funs.to_vec();
+            inner.extend(decls.iter().map(|d| d.name.clone()));
+            if decls.len() > 1 {
+                for i in 0..decls.len() {
+                    let mut decls = decls.clone();
+                    decls.remove(i);
+                    out.push(Exp::FunDefs {
+                        decls,
+                        body: body.clone(),
+                        ann: (),
+                    });
+                }
+            }
+            for (i, decl) in decls.iter().enumerate() {
+                let bodies = match &decl.body {
+                    Exp::If { cond, thn, els, .. } if is_fuel_guard(&decl.body, &decl.parameters) => {
+                        let rebuild = |thn: &Exp<()>, els: &Exp<()>| Exp::If {
+                            cond: cond.clone(),
+                            thn: Box::new(thn.clone()),
+                            els: Box::new(els.clone()),
+                            ann: (),
+                        };
+                        let mut bodies: Vec<Exp<()>> =
+                            candidates(thn, &inner).iter().map(|c| rebuild(c, els)).collect();
+                        bodies.extend(candidates(els, &inner).iter().map(|c| rebuild(thn, c)));
+                        bodies
+                    }
+                    body => candidates(body, &inner),
+                };
+                for c in bodies {
+                    let mut decls = decls.clone();
+                    decls[i].body = c;
+                    out.push(Exp::FunDefs {
+                        decls,
+                        body: body.clone(),
+                        ann: (),
+                    });
+                }
+            }
+            for c in candidates(body, &inner) {
+                out.push(Exp::FunDefs {
+                    decls: decls.clone(),
+                    body: Box::new(c),
+                    ann: (),
+                });
+            }
+        }
+        Exp::Lambda { parameters, body, .. } => {
+            for c in candidates(body, funs) {
+                out.push(Exp::Lambda {
+                    parameters: parameters.clone(),
+                    body: Box::new(c),
+                    ann: (),
+                });
+            }
+        }
+        Exp::Seq { first, second, .. } => {
+            for c in candidates(first, funs) {
+                out.push(Exp::Seq {
+                    first: Box::new(c),
+                    second: second.clone(),
+                    ann: (),
+                });
+            }
+            for c in candidates(second, funs) {
+                out.push(Exp::Seq {
+                    first: first.clone(),
+                    second: Box::new(c),
+                    ann: (),
+                });
+            }
+        }
+        Exp::Call(f, args, _) => {
+            let first = if funs.contains(f) { 1 } else { 0 };
+            for i in first..args.len() {
+                for c in candidates(&args[i], funs) {
+                    let mut args = args.clone();
+                    args[i] = c;
+                    out.push(Exp::Call(f.clone(), args, ()));
+                }
+            }
+            // the fuel passed from the top can still get smaller
+            if let Some(Exp::Num(n, _)) = args.first().filter(|_| first == 1) {
+                for smaller in [0, n / 2].iter().filter(|m| *m != n) {
+                    let mut args = args.clone();
+                    args[0] = Exp::Num(*smaller, ());
+                    out.push(Exp::Call(f.clone(), args, ()));
+                }
+            }
+        }
+        Exp::ClosureCall { fun, args, is_tail, .. } => {
+            for (i, arg) in args.iter().enumerate() {
+                for c in candidates(arg, funs) {
+                    let mut args = args.clone();
+                    args[i] = c;
+                    out.push(Exp::ClosureCall {
+                        fun: fun.clone(),
+                        args,
+                        is_tail: *is_tail,
+                        ann: (),
+                    });
+                }
+            }
+        }
+        Exp::InternalTailCall(..) | Exp::ExternalCall { .. } | Exp::MakeClosure { .. } => {
+            panic!("internal compiler forms shouldn't occur before lambda lifting")
+        }
+    }
+    out
+}
+
+// Repeatedly replaces (p) by a smaller program for which (fails) still
+// holds, until no candidate does
+pub fn shrink<F>(p: SurfProg<()>, mut fails: F) -> SurfProg<()>
+where
+    F: FnMut(&SurfProg<()>) -> bool,
+{
+    let mut p = p;
+    loop {
+        let size = measure(&p);
+        let smaller = candidates(&p, &[])
+            .into_iter()
+            .filter(|c| measure(c) < size)
+            .find(|c| fails(c));
+        match smaller {
+            Some(c) => p = c,
+            None => return p,
+        }
+    }
+}
@@ -1,12 +1,22 @@
+use crate::compile::{
+    ErrorCode, ARITH_ERROR, ARITY_ERROR, CALL_NOT_CLOSURE_ERROR, CMP_ERROR, DIV_ZERO_ERROR, IF_ERROR,
+    INDEX_BOUNDS_ERROR, INDEX_NOT_INT_ERROR, INDEX_NOT_TUPLE_ERROR, INPUT, INPUT_ERROR, INT_ARITH_ERROR, LOGIC_ERROR, OVFL_ERROR,
+    STRING_ERROR, TONUM_ERROR,
+};
+use crate::lexer;
 use crate::syntax::{Exp, Prim, SurfProg, SurfFunDecl};
 
+use std::io::BufRead;
 use std::rc::Rc;
 
 #[derive(Debug, Clone)]
 enum SnakeVal {
-    Num(i64), // should fit into 63 bits though
+    Num(i64), // should fit into 62 bits though
+    Float(f64), // the 2 lowest mantissa bits are always 0, as in the compiled representation
     Bool(bool),
+    Tuple(usize), // index into the heap
     Closure(usize), // index into the closure arena
+    Str(Rc<Vec<u8>>), // immutable, so sharing the bytes is as good as copying them
 }
 
 // A reference-counted linked list/the functional programmer's List
@@ -45,9 +55,34 @@ pub enum InterpErr {
         got: String,
         msg: String,
     },
+    ExpectedTuple {
+        who: String,
+        got: String,
+        msg: String,
+    },
+    ExpectedStr {
+        who: String,
+        got: String,
+        msg: String,
+    },
+    NotANumber {
+        got: String,
+    },
+    // the command-line argument or a line of stdin isn't what (who) reads
+    BadInput {
+        who: String,
+        got: String,
+    },
+    IndexOutOfBounds {
+        index: i64,
+        len: usize,
+    },
     Overflow {
         msg: String,
     },
+    DivByZero {
+        msg: String,
+    },
     Write {
         msg: String,
     },
@@ -62,12 +97,31 @@ type Interp<T> = Result<T, InterpErr>;
 use std::fmt;
 use std::fmt::Display;
 
+// Floats are printed with 15 significant digits, so that the bits lost to
+// the tag do not show up in the output
+fn sprint_float(x: f64) -> String {
+    let rounded: f64 = format!("{:.14e}", x).parse().unwrap();
+    format!("{:?}", rounded)
+}
+
+// A string inside of a tuple is printed as its literal
+fn sprint_str(s: &[u8]) -> String {
+    lexer::quote(&String::from_utf8_lossy(s))
+}
+
+fn snake_float(x: f64) -> SnakeVal {
+    SnakeVal::Float(f64::from_bits(x.to_bits() & !0b11))
+}
+
 impl Display for SnakeVal {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             SnakeVal::Num(n) => write!(f, "{}", n),
+            SnakeVal::Float(x) => write!(f, "{}", sprint_float(*x)),
             SnakeVal::Bool(b) => write!(f, "{}", b),
+            SnakeVal::Tuple(_) => write!(f, "tuple"),
             SnakeVal::Closure { .. } => write!(f, "closure"),
+            SnakeVal::Str(s) => write!(f, "{}", sprint_str(s)),
         }
     }
 }
@@ -84,7 +138,26 @@ impl Display for InterpErr {
             InterpErr::ExpectedFun { who, got: v, msg } => {
                 write!(f, "{} expected a function, but got {} in {}", who, v, msg)
             }
+            InterpErr::ExpectedTuple { who, got: v, msg } => {
+                write!(f, "{} expected a tuple, but got {} in {}", who, v, msg)
+            }
+            InterpErr::ExpectedStr { who, got: v, msg } => {
+                write!(f, "{} expected a string, but got {} in {}", who, v, msg)
+            }
+            InterpErr::NotANumber { got } => write!(f, "tonum expected the digits of an integer, but got {}", got),
+            InterpErr::BadInput { who, got } => {
+                let expected = match who.as_str() {
+                    "readnum" => "an integer",
+                    "readbool" => "a boolean",
+                    _ => "an integer or a boolean",
+                };
+                write!(f, "{} expected {} but got {}", who, expected, got)
+            }
+            InterpErr::IndexOutOfBounds { index, len } => {
+                write!(f, "index {} out of bounds for a value of length {}", index, len)
+            }
             InterpErr::Overflow { msg } => write!(f, "Operation {} overflowed", msg),
+            InterpErr::DivByZero { msg } => write!(f, "Operation {} divided by zero", msg),
             InterpErr::Write { msg } => write!(f, "I/O Error when printing: {}", msg),
             InterpErr::ArityErr {
                 expected_arity,
@@ -100,14 +173,46 @@ impl Display for InterpErr {
     }
 }
 
-fn get<'l, T>(stk: &'l List<(String, T)>, x: &str) -> Option<&'l T> {
+impl InterpErr {
+    // The snake_error code the compiled code fails with on the same error,
+    // None for the errors it has no counterpart of
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        match self {
+            InterpErr::ExpectedNum { who, .. } => match who.as_str() {
+                "arithmetic" => Some(ARITH_ERROR),
+                "comparison" => Some(CMP_ERROR),
+                "integer arithmetic" => Some(INT_ARITH_ERROR),
+                "index" => Some(INDEX_NOT_INT_ERROR),
+                _ => None,
+            },
+            InterpErr::ExpectedBool { who, .. } => match who.as_str() {
+                "if" => Some(IF_ERROR),
+                "logic" => Some(LOGIC_ERROR),
+                _ => None,
+            },
+            InterpErr::ExpectedFun { .. } => Some(CALL_NOT_CLOSURE_ERROR),
+            InterpErr::ExpectedTuple { .. } => Some(INDEX_NOT_TUPLE_ERROR),
+            InterpErr::ExpectedStr { .. } => Some(STRING_ERROR),
+            InterpErr::NotANumber { .. } => Some(TONUM_ERROR),
+            InterpErr::BadInput { .. } => Some(INPUT_ERROR),
+            InterpErr::IndexOutOfBounds { .. } => Some(INDEX_BOUNDS_ERROR),
+            InterpErr::Overflow { .. } => Some(OVFL_ERROR),
+            InterpErr::DivByZero { .. } => Some(DIV_ZERO_ERROR),
+            InterpErr::ArityErr { .. } => Some(ARITY_ERROR),
+            InterpErr::Write { .. } => None,
+        }
+    }
+}
+
+// Finds the innermost binding of x, either as a variable or as a function
+fn get_value<'l>(stk: &'l List<(String, SnakeVal)>, x: &str, fun_x: &str) -> Option<&'l SnakeVal> {
     match stk {
         List::Empty => None,
-        List::Cons((y, n), stk) => {
-            if x == *y {
-                Some(n)
+        List::Cons((y, v), stk) => {
+            if x == *y || fun_x == *y {
+                Some(v)
             } else {
-                get(stk, x)
+                get_value(stk, x, fun_x)
             }
         }
     }
@@ -135,31 +240,94 @@ fn num(v: SnakeVal, who: &str, msg: &str) -> Interp<i64> {
     }
 }
 
-fn print_snake_val<'e, W>(w: &mut W, v: SnakeVal, _h: &Heap) -> Interp<SnakeVal>
+fn string(v: SnakeVal, who: &str, msg: &str) -> Interp<Rc<Vec<u8>>> {
+    match v {
+        SnakeVal::Str(s) => Ok(s),
+        _ => Err(InterpErr::ExpectedStr {
+            who: String::from(who),
+            got: v.to_string(),
+            msg: String::from(msg),
+        }),
+    }
+}
+
+fn tuple(v: SnakeVal, who: &str, msg: &str) -> Interp<usize> {
+    match v {
+        SnakeVal::Tuple(addr) => Ok(addr),
+        _ => Err(InterpErr::ExpectedTuple {
+            who: String::from(who),
+            got: v.to_string(),
+            msg: String::from(msg),
+        }),
+    }
+}
+
+// checks the index against the length of the tuple at addr
+fn index(v: SnakeVal, addr: usize, h: &Heap) -> Interp<usize> {
+    let i = num(v, "index", "[]")?;
+    let len = h[addr].len();
+    if i < 0 || i as usize >= len {
+        Err(InterpErr::IndexOutOfBounds { index: i, len })
+    } else {
+        Ok(i as usize)
+    }
+}
+
+fn print_snake_val<W>(w: &mut W, v: SnakeVal, h: &Heap) -> Interp<SnakeVal>
 where
     W: std::io::Write,
 {
     fn fixup_err(e: std::io::Error) -> InterpErr {
         InterpErr::Write { msg: e.to_string() }
     }
-    fn print_loop<'e, W>(
+    // parents holds the tuples we are currently inside of, so that
+    // cyclic tuples are printed as <loop> instead of forever
+    fn print_loop<W>(
         w: &mut W,
         v: &SnakeVal,
+        h: &Heap,
+        parents: &mut Vec<usize>,
     ) -> Result<(), std::io::Error>
     where
         W: std::io::Write,
     {
         match v {
             SnakeVal::Num(n) => write!(w, "{}", n)?,
+            SnakeVal::Float(x) => write!(w, "{}", sprint_float(*x))?,
             SnakeVal::Bool(b) => write!(w, "{}", b)?,
+            SnakeVal::Tuple(addr) => {
+                if parents.contains(addr) {
+                    write!(w, "<loop>")?;
+                    return Ok(());
+                }
+                parents.push(*addr);
+                let elems = &h[*addr];
+                write!(w, "(")?;
+                for (i, elem) in elems.iter().enumerate() {
+                    if i > 0 {
+                        write!(w, ", ")?;
+                    }
+                    print_loop(w, elem, h, parents)?;
+                }
+                if elems.len() == 1 {
+                    write!(w, ",")?;
+                }
+                write!(w, ")")?;
+                parents.pop();
+            }
             SnakeVal::Closure { .. } => {
                 write!(w, "<closure>")?;
             }
+            SnakeVal::Str(s) => write!(w, "{}", sprint_str(s))?,
         }
         Ok(())
     }
 
-    print_loop(w, &v).map_err(fixup_err)?;
+    // a string on its own is printed as its bytes
+    match &v {
+        SnakeVal::Str(s) => w.write_all(s).map_err(fixup_err)?,
+        _ => print_loop(w, &v, h, &mut Vec::new()).map_err(fixup_err)?,
+    }
     writeln!(w).map_err(fixup_err)?;
     Ok(v)
 }
@@ -172,6 +340,12 @@ fn equal_snake_val(v1: &SnakeVal, v2: &SnakeVal, _h: &Heap) -> bool {
         match (v1, v2) {
             (SnakeVal::Bool(b1), SnakeVal::Bool(b2)) => b1 == b2,
             (SnakeVal::Num(n1), SnakeVal::Num(n2)) => n1 == n2,
+            (SnakeVal::Float(x1), SnakeVal::Float(x2)) => x1.to_bits() == x2.to_bits(),
+            // tuples are compared by reference, like in compiled code
+            (SnakeVal::Tuple(a1), SnakeVal::Tuple(a2)) => a1 == a2,
+            (SnakeVal::Closure(c1), SnakeVal::Closure(c2)) => c1 == c2,
+            // strings are compared by contents
+            (SnakeVal::Str(s1), SnakeVal::Str(s2)) => s1 == s2,
             _ => false,
         }
     }
@@ -183,8 +357,8 @@ where
     W: std::io::Write,
 {
     match p {
-        Prim::Add1 => snake_arith(v, SnakeVal::Num(1), |n1, n2| n1.overflowing_add(n2), "add1"),
-        Prim::Sub1 => snake_arith(v, SnakeVal::Num(1), |n1, n2| n1.overflowing_sub(n2), "sub1"),
+        Prim::Add1 => snake_arith(v, SnakeVal::Num(1), |n1, n2| n1.overflowing_add(n2), |x1, x2| x1 + x2, "add1"),
+        Prim::Sub1 => snake_arith(v, SnakeVal::Num(1), |n1, n2| n1.overflowing_sub(n2), |x1, x2| x1 - x2, "sub1"),
         Prim::Not => Ok(SnakeVal::Bool(!bool(v, "logic", "!")?)),
         Prim::Print => print_snake_val(w, v, h),
         Prim::IsBool => match v {
@@ -192,35 +366,188 @@ where
             _ => Ok(SnakeVal::Bool(false)),
         },
         Prim::IsNum => match v {
-            SnakeVal::Num(_) => Ok(SnakeVal::Bool(true)),
+            SnakeVal::Num(_) | SnakeVal::Float(_) => Ok(SnakeVal::Bool(true)),
+            _ => Ok(SnakeVal::Bool(false)),
+        },
+        Prim::IsTuple => match v {
+            SnakeVal::Tuple(_) => Ok(SnakeVal::Bool(true)),
             _ => Ok(SnakeVal::Bool(false)),
         },
+        Prim::IsStr => match v {
+            SnakeVal::Str(_) => Ok(SnakeVal::Bool(true)),
+            _ => Ok(SnakeVal::Bool(false)),
+        },
+        Prim::Len => Ok(SnakeVal::Num(string(v, "string operation", "len")?.len() as i64)),
+        Prim::ToStr => match v {
+            SnakeVal::Num(n) => Ok(snake_str(n.to_string().into_bytes())),
+            SnakeVal::Float(x) => Ok(snake_str(sprint_float(x).into_bytes())),
+            _ => Err(InterpErr::ExpectedNum {
+                who: String::from("arithmetic"),
+                got: v.to_string(),
+                msg: String::from("tostring"),
+            }),
+        },
+        Prim::Abs => {
+            let n = num(v, "integer arithmetic", "abs")?;
+            match n.checked_abs().filter(|n| !out_of_bounds(*n)) {
+                Some(n) => Ok(SnakeVal::Num(n)),
+                None => Err(InterpErr::Overflow { msg: format!("abs({})", n) }),
+            }
+        }
+        Prim::ToNum => {
+            let s = string(v, "string operation", "tonum")?;
+            match std::str::from_utf8(&s).ok().and_then(|s| s.parse::<i64>().ok()) {
+                Some(n) if !out_of_bounds(n) => Ok(SnakeVal::Num(n)),
+                _ => Err(InterpErr::NotANumber {
+                    got: sprint_str(&s),
+                }),
+            }
+        }
         _ => unreachable!(),
     }
 }
 
-static MAX_INT: i64 = 2i64.pow(62) - 1;
-static MIN_INT: i64 = -(2i64.pow(62));
+fn snake_str(bytes: Vec<u8>) -> SnakeVal {
+    SnakeVal::Str(Rc::new(bytes))
+}
+
+static MAX_INT: i64 = 2i64.pow(61) - 1;
+static MIN_INT: i64 = -(2i64.pow(61));
 fn out_of_bounds(n: i64) -> bool {
     n > MAX_INT || n < MIN_INT
 }
 
-fn snake_arith<F>(v1: SnakeVal, v2: SnakeVal, arith: F, op: &str) -> Interp<SnakeVal>
+// The program's input is the command-line argument, an integer or a
+// boolean, and false without one
+fn parse_input(arg: Option<&str>) -> Interp<SnakeVal> {
+    let arg = match arg {
+        None => return Ok(SnakeVal::Bool(false)),
+        Some(arg) => arg.trim(),
+    };
+    match (arg.parse::<i64>(), arg) {
+        (Ok(n), _) if !out_of_bounds(n) => Ok(SnakeVal::Num(n)),
+        (_, "true") => Ok(SnakeVal::Bool(true)),
+        (_, "false") => Ok(SnakeVal::Bool(false)),
+        _ => Err(InterpErr::BadInput { who: String::from("input"), got: lexer::quote(arg) }),
+    }
+}
+
+// readnum() and readbool(): a line of the reader, without the surrounding
+// whitespace
+fn read_input<R>(p: &Prim, r: &mut R) -> Interp<SnakeVal>
 where
-    F: Fn(i64, i64) -> (i64, bool),
+    R: BufRead,
 {
-    let n1 = num(v1, "arithmetic", op)?;
-    let n2 = num(v2, "arithmetic", op)?;
-    let (n3, overflow) = arith(n1, n2);
-    if overflow || out_of_bounds(n3) {
+    let who = if *p == Prim::ReadNum { "readnum" } else { "readbool" };
+    let mut line = Vec::new();
+    // a reader that fails has nothing more to read
+    if r.read_until(b'\n', &mut line).unwrap_or(0) == 0 {
+        return Err(InterpErr::BadInput { who: String::from(who), got: String::from("the end of the input") });
+    }
+    let line = String::from_utf8_lossy(&line);
+    let line = line.trim();
+    match (p, line.parse::<i64>(), line) {
+        (Prim::ReadNum, Ok(n), _) if !out_of_bounds(n) => Ok(SnakeVal::Num(n)),
+        (Prim::ReadBool, _, "true") => Ok(SnakeVal::Bool(true)),
+        (Prim::ReadBool, _, "false") => Ok(SnakeVal::Bool(false)),
+        _ => Err(InterpErr::BadInput { who: String::from(who), got: lexer::quote(line) }),
+    }
+}
+
+// converts a number operand of a mixed arithmetic operation to a float
+fn float(v: SnakeVal, who: &str, msg: &str) -> Interp<f64> {
+    match v {
+        SnakeVal::Float(x) => Ok(x),
+        SnakeVal::Num(n) => Ok(n as f64),
+        _ => Err(InterpErr::ExpectedNum {
+            who: String::from(who),
+            got: v.to_string(),
+            msg: String::from(msg),
+        }),
+    }
+}
+
+fn snake_float_arith<F>(v1: SnakeVal, v2: SnakeVal, arith: F, op: &str) -> Interp<SnakeVal>
+where
+    F: Fn(f64, f64) -> f64,
+{
+    let x1 = float(v1, "arithmetic", op)?;
+    let x2 = float(v2, "arithmetic", op)?;
+    let x3 = arith(x1, x2);
+    if x3.is_finite() {
+        Ok(snake_float(x3))
+    } else {
         Err(InterpErr::Overflow {
-            msg: format!("{} {} {} = {}", n1, op, n2, n3),
+            msg: format!("{} {} {} = {}", sprint_float(x1), op, sprint_float(x2), x3),
         })
-    } else {
-        Ok(SnakeVal::Num(n3))
     }
 }
 
+// Two integers give an integer, anything involving a float gives a float
+fn snake_arith<F, G>(v1: SnakeVal, v2: SnakeVal, arith: F, float_arith: G, op: &str) -> Interp<SnakeVal>
+where
+    F: Fn(i64, i64) -> (i64, bool),
+    G: Fn(f64, f64) -> f64,
+{
+    match (&v1, &v2) {
+        (SnakeVal::Num(n1), SnakeVal::Num(n2)) => {
+            let (n3, overflow) = arith(*n1, *n2);
+            if overflow || out_of_bounds(n3) {
+                Err(InterpErr::Overflow {
+                    msg: format!("{} {} {} = {}", n1, op, n2, n3),
+                })
+            } else {
+                Ok(SnakeVal::Num(n3))
+            }
+        }
+        _ => snake_float_arith(v1, v2, float_arith, op),
+    }
+}
+
+fn snake_div(v1: SnakeVal, v2: SnakeVal) -> Interp<SnakeVal> {
+    let x1 = float(v1, "arithmetic", "/")?;
+    let x2 = float(v2, "arithmetic", "/")?;
+    if x2 == 0.0 {
+        return Err(InterpErr::DivByZero {
+            msg: format!("{} / {}", sprint_float(x1), sprint_float(x2)),
+        });
+    }
+    snake_float_arith(SnakeVal::Float(x1), SnakeVal::Float(x2), |x1, x2| x1 / x2, "/")
+}
+
+// Operations on integers only, (arith) is None when the result overflows
+fn snake_int_arith<F>(v1: SnakeVal, v2: SnakeVal, arith: F, op: &str) -> Interp<SnakeVal>
+where
+    F: Fn(i64, i64) -> Option<i64>,
+{
+    let n1 = num(v1, "integer arithmetic", op)?;
+    let n2 = num(v2, "integer arithmetic", op)?;
+    if n2 == 0 && (op == "//" || op == "%") {
+        return Err(InterpErr::DivByZero {
+            msg: format!("{} {} {}", n1, op, n2),
+        });
+    }
+    match arith(n1, n2).filter(|n| !out_of_bounds(*n)) {
+        Some(n) => Ok(SnakeVal::Num(n)),
+        None => Err(InterpErr::Overflow {
+            msg: format!("{} {} {}", n1, op, n2),
+        }),
+    }
+}
+
+// The shift amount must be in [0, 63], and a left shift must not lose bits
+fn shift_left(n1: i64, n2: i64) -> Option<i64> {
+    if !(0..64).contains(&n2) {
+        return None;
+    }
+    let n = n1 << n2;
+    (n >> n2 == n1).then_some(n)
+}
+
+fn shift_right(n1: i64, n2: i64) -> Option<i64> {
+    (0..64).contains(&n2).then(|| n1 >> n2)
+}
+
 fn snake_log<F>(v1: SnakeVal, v2: SnakeVal, log: F, op: &str) -> Interp<SnakeVal>
 where
     F: Fn(bool, bool) -> bool,
@@ -245,9 +572,19 @@ fn interpret_prim2(p: &Prim, v1: SnakeVal, v2: SnakeVal, heap: &Heap) -> Interp<
 where
 {
     match p {
-        Prim::Add => snake_arith(v1, v2, |n1, n2| n1.overflowing_add(n2), "+"),
-        Prim::Sub => snake_arith(v1, v2, |n1, n2| n1.overflowing_sub(n2), "-"),
-        Prim::Mul => snake_arith(v1, v2, |n1, n2| n1.overflowing_mul(n2), "*"),
+        Prim::Add => snake_arith(v1, v2, |n1, n2| n1.overflowing_add(n2), |x1, x2| x1 + x2, "+"),
+        Prim::Sub => snake_arith(v1, v2, |n1, n2| n1.overflowing_sub(n2), |x1, x2| x1 - x2, "-"),
+        Prim::Mul => snake_arith(v1, v2, |n1, n2| n1.overflowing_mul(n2), |x1, x2| x1 * x2, "*"),
+        Prim::Div => snake_div(v1, v2),
+        Prim::IntDiv => snake_int_arith(v1, v2, |n1, n2| n1.checked_div(n2), "//"),
+        Prim::Rem => snake_int_arith(v1, v2, |n1, n2| n1.checked_rem(n2), "%"),
+        Prim::BitAnd => snake_int_arith(v1, v2, |n1, n2| Some(n1 & n2), "&"),
+        Prim::BitOr => snake_int_arith(v1, v2, |n1, n2| Some(n1 | n2), "|"),
+        Prim::BitXor => snake_int_arith(v1, v2, |n1, n2| Some(n1 ^ n2), "^"),
+        Prim::Shl => snake_int_arith(v1, v2, shift_left, "<<"),
+        Prim::Shr => snake_int_arith(v1, v2, shift_right, ">>"),
+        Prim::Min => snake_int_arith(v1, v2, |n1, n2| Some(n1.min(n2)), "min"),
+        Prim::Max => snake_int_arith(v1, v2, |n1, n2| Some(n1.max(n2)), "max"),
 
         Prim::And => snake_log(v1, v2, |b1, b2| b1 && b2, "&&"),
         Prim::Or => snake_log(v1, v2, |b1, b2| b1 || b2, "||"),
@@ -259,6 +596,53 @@ where
 
         Prim::Eq => Ok(SnakeVal::Bool(equal_snake_val(&v1, &v2, heap))),
         Prim::Neq => Ok(SnakeVal::Bool(!equal_snake_val(&v1, &v2, heap))),
+
+        Prim::GetIndex => {
+            let addr = tuple(v1, "indexing", "[]")?;
+            let i = index(v2, addr, heap)?;
+            Ok(heap[addr][i].clone())
+        }
+        Prim::Concat => {
+            let s1 = string(v1, "string operation", "++")?;
+            let s2 = string(v2, "string operation", "++")?;
+            Ok(snake_str([&s1[..], &s2[..]].concat()))
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn interpret_primn(p: &Prim, mut vs: Vec<SnakeVal>, heap: &mut Heap) -> Interp<SnakeVal>
+where
+{
+    match p {
+        Prim::MakeTuple => {
+            heap.push(vs);
+            Ok(SnakeVal::Tuple(heap.len() - 1))
+        }
+        Prim::SetIndex => {
+            let v = vs.pop().unwrap();
+            let i = vs.pop().unwrap();
+            let t = vs.pop().unwrap();
+            let addr = tuple(t.clone(), "indexing", "[] :=")?;
+            let i = index(i, addr, heap)?;
+            heap[addr][i] = v;
+            Ok(t)
+        }
+        Prim::Substr => {
+            let end = vs.pop().unwrap();
+            let start = vs.pop().unwrap();
+            let s = string(vs.pop().unwrap(), "string operation", "substr")?;
+            let start = num(start, "index", "substr")?;
+            let end = num(end, "index", "substr")?;
+            let len = s.len();
+            if start < 0 {
+                return Err(InterpErr::IndexOutOfBounds { index: start, len });
+            }
+            if end < start || end as usize > len {
+                return Err(InterpErr::IndexOutOfBounds { index: end, len });
+            }
+            Ok(snake_str(s[start as usize..end as usize].to_vec()))
+        }
         _ => unreachable!(),
     }
 }
@@ -294,6 +678,13 @@ enum Stack<'exp, Ann> {
     Prim1(Prim, Box<Stack<'exp, Ann>>),
     Prim2L(Prim, Closure<'exp, Ann>, Box<Stack<'exp, Ann>>),
     Prim2R(Prim, SnakeVal, Box<Stack<'exp, Ann>>),
+    PrimArgs {
+        prim: Prim,
+        evaled_args: Vec<SnakeVal>,
+        env: Env,
+        remaining_args: Vec<&'exp Exp<Ann>>,
+        stk: Box<Stack<'exp, Ann>>,
+    },
     If {
         thn: &'exp Exp<Ann>,
         els: &'exp Exp<Ann>,
@@ -307,8 +698,13 @@ enum Stack<'exp, Ann> {
         body: &'exp Exp<Ann>,
         stk: Box<Stack<'exp, Ann>>,
     },
+    CallFun {
+        args: &'exp [Exp<Ann>],
+        env: Env,
+        stk: Box<Stack<'exp, Ann>>,
+    },
     CallArgs {
-        fun: usize, // the closure
+        fun: SnakeVal, // the closure
         evaled_args: Vec<SnakeVal>,
         env: Env,
         remaining_args: Vec<&'exp Exp<Ann>>,
@@ -316,9 +712,11 @@ enum Stack<'exp, Ann> {
     },
 }
 
-// No heap for now!
-type Heap = ();
-type Funs<'e, Ann> = Vec<(Env, &'e SurfFunDecl<Ann>)>;
+// Each tuple is a vector of its elements, addressed by its index
+type Heap = Vec<Vec<SnakeVal>>;
+// A closure is the captured environment with the parameters and body of
+// a function or lambda
+type Funs<'e, Ann> = Vec<(Env, &'e [String], &'e Exp<Ann>)>;
 struct State<'e, Ann> {
     funs: Funs<'e, Ann>,
     heap: Heap,
@@ -328,11 +726,33 @@ impl<'e, Ann> State<'e, Ann> {
     fn new() -> Self {
         State {
             funs: vec![],
-            heap: (),
+            heap: Vec::new(),
         }
     }
 }
 
+fn mangle_fun_name(s: &str) -> String {
+    format!("{}#fun", s)
+}
+
+// Allocate closures for a mutually recursive sequence of function
+// declarations, returning an environment where the functions'
+// names (mangled to avoid clashing with value variables) are
+// associated to the address of their closure.
+fn alloc_funs<'e, Ann>(decls: &'e [SurfFunDecl<Ann>], mut env: Env, funs: &mut Funs<'e, Ann>) -> Env {
+    // Each of the closures captures the same environment: the
+    // current environment extended with all of their names
+    // i.e., the env we return.
+    let i = funs.len();
+    for (j, d) in decls.iter().enumerate() {
+        env = push_local(&env, mangle_fun_name(&d.name), SnakeVal::Closure(i + j));
+    }
+    for d in decls.iter() {
+        funs.push((env.clone(), &d.parameters, &d.body));
+    }
+    env
+}
+
 /*
  *  Abstract machine-style interpreter.
  *
@@ -340,13 +760,14 @@ impl<'e, Ann> State<'e, Ann> {
  *  so that we don't blow the Rust stack/rely on Rust TCE.
  *
 */
-fn machine<'exp, Ann, W>(e: &'exp Exp<Ann>, buf: &mut W, store: &mut State<'exp, Ann>) -> Interp<()>
+fn machine<'exp, Ann, R, W>(e: &'exp Exp<Ann>, env: Env, reader: &mut R, buf: &mut W, store: &mut State<'exp, Ann>) -> Interp<SnakeVal>
 where
+    R: BufRead,
     W: std::io::Write,
     Ann: Clone,
 {
     fn call<'exp, Ann>(
-        fun: usize,
+        fun: SnakeVal,
         args: Vec<SnakeVal>,
         stk: Stack<'exp, Ann>,
         funs: &Funs<'exp, Ann>,
@@ -354,81 +775,103 @@ where
     where
         Ann: Clone,
     {
-        let (orig_env, d) = &funs[fun];
+        let ix = match fun {
+            SnakeVal::Closure(ix) => ix,
+            _ => {
+                return Err(InterpErr::ExpectedFun {
+                    who: String::from("call"),
+                    got: fun.to_string(),
+                    msg: String::from("function call"),
+                })
+            }
+        };
+        let (orig_env, parameters, body) = &funs[ix];
 	let mut env = orig_env.clone();
 
-        if args.len() != d.parameters.len() {
+        if args.len() != parameters.len() {
             return Err(InterpErr::ArityErr {
-                expected_arity: d.parameters.len(),
+                expected_arity: parameters.len(),
                 num_provided: args.len(),
             });
         }
 	// environment for the body should consist of the captured env
 	// extended with the new parameters
-	for (v, x) in args.iter().zip(d.parameters.iter()) {
+	for (v, x) in args.iter().zip(parameters.iter()) {
 	    env = push_local(&env, x.to_string(), v.clone())
 	}
 	Ok(Machine::Descending {
-            e: &d.body,
+            e: body,
             env,
             stk,
         })
     }
 
-    fn mangle_fun_name(s: &str) -> String {
-	format!("{}#fun", s)
-    }
-    
-    // Allocate closures for a mutually recursive sequence of function
-    // declarations, returning an environment where the functions'
-    // names (mangled to avoid clashing with value variables) are
-    // associated to the address of their closure.
-    fn alloc_funs<'e, Ann>(decls: &'e [SurfFunDecl<Ann>], mut env: Env, funs: &mut Funs<'e, Ann>) -> Env {
-	// Each of the closures captures the same environment: the
-	// current environment extended with all of their names
-	// i.e., the env we return.
-	let i = funs.len();
-	for (j, d) in decls.iter().enumerate() {
-	    env = push_local(&env, mangle_fun_name(&d.name), SnakeVal::Closure(i + j));
-	}
-	for d in decls.iter() {
-	    funs.push((env.clone(), &d));
-	}
-	env
-    }
-
     let mut machine = Machine::Descending {
         e,
         stk: Stack::Done,
-        env: Rc::new(List::Empty),
+        env,
     };
     loop {
         match machine {
             Machine::Descending { e, stk, env } => match e {
-                Exp::InternalTailCall(..) | Exp::ExternalCall { .. } => {
+                Exp::InternalTailCall(..) | Exp::ExternalCall { .. } | Exp::MakeClosure { .. } => {
                     panic!("Should never happen: interpreter called with internal compiler forms")
                 }
+                Exp::Seq { .. } => {
+                    panic!("Should never happen: interpreter called before desugaring")
+                }
                 Exp::Num(n, _) => {
                     machine = Machine::Returning {
                         v: SnakeVal::Num(*n),
                         stk,
                     }
                 }
+                Exp::Float(x, _) => {
+                    machine = Machine::Returning {
+                        v: snake_float(*x),
+                        stk,
+                    }
+                }
                 Exp::Bool(b, _) => {
                     machine = Machine::Returning {
                         v: SnakeVal::Bool(*b),
                         stk,
                     }
                 }
+                Exp::Str(s, _) => {
+                    machine = Machine::Returning {
+                        v: snake_str(s.clone().into_bytes()),
+                        stk,
+                    }
+                }
                 Exp::Var(x, _) => {
-                    let v = get(&*env, x).expect("Unbound variable in interpreter! You should catch this in the check function!");
+                    let v = get_value(&env, x, &mangle_fun_name(x)).expect("Unbound variable in interpreter! You should catch this in the check function!");
                     machine = Machine::Returning { v: v.clone(), stk }
                 }
+                Exp::Lambda { parameters, body, .. } => {
+                    store.funs.push((env.clone(), parameters, body));
+                    machine = Machine::Returning {
+                        v: SnakeVal::Closure(store.funs.len() - 1),
+                        stk,
+                    }
+                }
+                Exp::ClosureCall { fun, args, .. } => {
+                    machine = Machine::Descending {
+                        e: fun,
+                        env: env.clone(),
+                        stk: Stack::CallFun {
+                            args,
+                            env,
+                            stk: Box::new(stk),
+                        },
+                    }
+                }
                 Exp::Prim(op, es, _) => {
                     match op {
                         Prim::Add1 | Prim::Sub1 | Prim::Not |
                         Prim::Print | Prim::IsBool |
-                        Prim::IsNum => {
+                        Prim::IsNum | Prim::IsTuple | Prim::IsStr |
+                        Prim::Len | Prim::ToStr | Prim::ToNum | Prim::Abs => {
                             let e = &es[0];
                             machine = Machine::Descending {
                                 e,
@@ -436,10 +879,12 @@ where
                                 env,
                             };
                         },
-                        Prim::Add | Prim::Sub | Prim::Mul |
+                        Prim::Add | Prim::Sub | Prim::Mul | Prim::Div |
                         Prim::And | Prim::Or | Prim::Lt |
                         Prim::Gt | Prim::Le | Prim::Ge |
-                        Prim::Eq | Prim::Neq => {
+                        Prim::Eq | Prim::Neq | Prim::GetIndex | Prim::Concat |
+                        Prim::IntDiv | Prim::Rem | Prim::BitAnd | Prim::BitOr | Prim::BitXor |
+                        Prim::Shl | Prim::Shr | Prim::Min | Prim::Max => {
                             let e1 = &es[0];
                             let e2 = &es[1];
                             machine = Machine::Descending {
@@ -455,6 +900,33 @@ where
                                 env,
                             };
                         }
+                        Prim::ReadNum | Prim::ReadBool => {
+                            let v = read_input(op, reader)?;
+                            machine = Machine::Returning { v, stk };
+                        }
+                        Prim::SetIndex | Prim::Substr | Prim::MakeTuple => {
+                            let mut remaining_args: Vec<&Exp<_>> = es.iter().map(|e| e.as_ref()).collect();
+                            remaining_args.reverse();
+                            match remaining_args.pop() {
+                                None => {
+                                    let v = interpret_primn(op, Vec::new(), &mut store.heap)?;
+                                    machine = Machine::Returning { v, stk };
+                                }
+                                Some(e) => {
+                                    machine = Machine::Descending {
+                                        e,
+                                        env: env.clone(),
+                                        stk: Stack::PrimArgs {
+                                            prim: *op,
+                                            evaled_args: Vec::new(),
+                                            env,
+                                            remaining_args,
+                                            stk: Box::new(stk),
+                                        },
+                                    }
+                                }
+                            }
+                        }
                     }
                 }
                 Exp::Let { bindings, body, .. } => {
@@ -491,23 +963,24 @@ where
                     }
                 }
                 Exp::Call(fun, args, _) => {
-		    let ix = match get(&*env, &mangle_fun_name(fun)) {
-			Some(SnakeVal::Closure(ix)) => *ix,
-			_ => panic!("bug in interpreter?"),
+		    // either a function or a variable holding a closure
+		    let fun_v = match get_value(&env, fun, &mangle_fun_name(fun)) {
+			Some(v) => v.clone(),
+			None => panic!("bug in interpreter?"),
 		    };
                     let mut remaining_args: Vec<&Exp<_>> = args.iter().collect();
                     remaining_args.reverse();
                     match remaining_args.pop() {
                         None => {
                             machine =
-                                call(ix, Vec::new(), stk, &store.funs)?;
+                                call(fun_v, Vec::new(), stk, &store.funs)?;
                         }
                         Some(e) => {
                             machine = Machine::Descending {
-                                e: &e,
+                                e,
                                 env: env.clone(),
                                 stk: Stack::CallArgs {
-                                    fun: ix,
+                                    fun: fun_v,
                                     evaled_args: Vec::new(),
                                     env,
                                     remaining_args,
@@ -518,9 +991,9 @@ where
                     }
                 }
 		Exp::FunDefs { decls, body, .. } => {
-		    let env = alloc_funs(&decls, env, &mut store.funs);
+		    let env = alloc_funs(decls, env, &mut store.funs);
 		    machine = Machine::Descending {
-			e: &body,
+			e: body,
 			env: env.clone(),
 			stk			
 		    }
@@ -528,25 +1001,61 @@ where
 		}
             },
             Machine::Returning { v, stk } => match stk {
-                Stack::Done => {
-                    print_snake_val(buf, v, &store.heap)?;
-                    return Ok(());
-                }
+                Stack::Done => return Ok(v),
                 Stack::Prim1(op, stk) => {
                     let v = interpret_prim1(&op, buf, v, &store.heap)?;
                     machine = Machine::Returning { v, stk: *stk }
                 }
                 Stack::Prim2L(op, r, stk) => {
-                    machine = Machine::Descending {
-                        e: r.exp,
-                        env: r.env,
-                        stk: Stack::Prim2R(op, v, stk),
+                    // && and || only evaluate their right operand when the
+                    // left one doesn't already decide the result
+                    let decided = match op {
+                        Prim::And => !bool(v.clone(), "logic", "&&")?,
+                        Prim::Or => bool(v.clone(), "logic", "||")?,
+                        _ => false,
+                    };
+                    machine = if decided {
+                        Machine::Returning { v, stk: *stk }
+                    } else {
+                        Machine::Descending {
+                            e: r.exp,
+                            env: r.env,
+                            stk: Stack::Prim2R(op, v, stk),
+                        }
                     };
                 }
                 Stack::Prim2R(op, vl, stk) => {
                     let v = interpret_prim2(&op, vl, v, &store.heap)?;
                     machine = Machine::Returning { v, stk: *stk };
                 }
+                Stack::PrimArgs {
+                    prim,
+                    mut evaled_args,
+                    env,
+                    mut remaining_args,
+                    stk,
+                } => {
+                    evaled_args.push(v);
+                    match remaining_args.pop() {
+                        None => {
+                            let v = interpret_primn(&prim, evaled_args, &mut store.heap)?;
+                            machine = Machine::Returning { v, stk: *stk };
+                        }
+                        Some(e) => {
+                            machine = Machine::Descending {
+                                e,
+                                env: env.clone(),
+                                stk: Stack::PrimArgs {
+                                    prim,
+                                    evaled_args,
+                                    env,
+                                    remaining_args,
+                                    stk,
+                                },
+                            }
+                        }
+                    }
+                }
                 Stack::Let {
                     var,
                     mut env,
@@ -579,6 +1088,28 @@ where
                     let e = if bool(v, "if", "if")? { thn } else { els };
                     machine = Machine::Descending { e, env, stk: *stk }
                 }
+                Stack::CallFun { args, env, stk } => {
+                    let mut remaining_args: Vec<&Exp<_>> = args.iter().collect();
+                    remaining_args.reverse();
+                    match remaining_args.pop() {
+                        None => {
+                            machine = call(v, Vec::new(), *stk, &store.funs)?;
+                        }
+                        Some(e) => {
+                            machine = Machine::Descending {
+                                e,
+                                env: env.clone(),
+                                stk: Stack::CallArgs {
+                                    fun: v,
+                                    evaled_args: Vec::new(),
+                                    env,
+                                    remaining_args,
+                                    stk,
+                                },
+                            }
+                        }
+                    }
+                }
                 Stack::CallArgs {
                     fun: fun_v,
                     mut evaled_args,
@@ -616,19 +1147,78 @@ where
     }
 }
 
-// Runs the reference interpreter.
-pub fn exp<Ann, W>(e: &Exp<Ann>, w: &mut W) -> Interp<()>
+// Runs the reference interpreter on (e), whose input is the command-line
+// argument (arg) and whose readnum() and readbool() read (r).
+pub fn exp<Ann, R, W>(e: &Exp<Ann>, arg: Option<&str>, r: &mut R, w: &mut W) -> Interp<()>
 where
     Ann: Clone,
+    R: BufRead,
     W: std::io::Write,
 {
-    machine(e, w, &mut State::new())
+    let mut store = State::new();
+    let env = push_local(&Rc::new(List::Empty), INPUT.to_string(), parse_input(arg)?);
+    let v = machine(e, env, r, w, &mut store)?;
+    print_snake_val(w, v, &store.heap)?;
+    Ok(())
 }
 
-pub fn prog<Ann, W>(p: &SurfProg<Ann>, w: &mut W) -> Interp<()>
+pub fn prog<Ann, R, W>(p: &SurfProg<Ann>, arg: Option<&str>, r: &mut R, w: &mut W) -> Interp<()>
 where
+    R: BufRead,
     W: std::io::Write,
     Ann: Clone,
 {
-    machine(&p, w, &mut State::new())
+    exp(p, arg, r, w)
+}
+
+// The state of an interactive session: the functions, variables and heap
+// of the entries evaluated so far. The entries are leaked, since the
+// closures in the state refer to them for the rest of the session.
+// A session has no input: input is false and stdin is empty.
+pub struct Session<Ann: 'static> {
+    env: Env,
+    store: State<'static, Ann>,
+}
+
+impl<Ann: Clone> Session<Ann> {
+    pub fn new() -> Self {
+        Session {
+            env: push_local(&Rc::new(List::Empty), INPUT.to_string(), SnakeVal::Bool(false)),
+            store: State::new(),
+        }
+    }
+
+    // Makes the functions visible to the following entries
+    pub fn declare(&mut self, decls: Vec<SurfFunDecl<Ann>>) {
+        let decls: &'static [SurfFunDecl<Ann>] = Box::leak(decls.into_boxed_slice());
+        self.env = alloc_funs(decls, self.env.clone(), &mut self.store.funs);
+    }
+
+    // Evaluates e and binds its value to var for the following entries
+    pub fn bind<W>(&mut self, var: String, e: Exp<Ann>, w: &mut W) -> Interp<()>
+    where
+        W: std::io::Write,
+    {
+        let e: &'static Exp<Ann> = Box::leak(Box::new(e));
+        let v = machine(e, self.env.clone(), &mut std::io::empty(), w, &mut self.store)?;
+        self.env = push_local(&self.env, var, v);
+        Ok(())
+    }
+
+    // Evaluates e and prints its value
+    pub fn eval<W>(&mut self, e: Exp<Ann>, w: &mut W) -> Interp<()>
+    where
+        W: std::io::Write,
+    {
+        let e: &'static Exp<Ann> = Box::leak(Box::new(e));
+        let v = machine(e, self.env.clone(), &mut std::io::empty(), w, &mut self.store)?;
+        print_snake_val(w, v, &self.store.heap)?;
+        Ok(())
+    }
+}
+
+impl<Ann: Clone> Default for Session<Ann> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
@@ -0,0 +1,318 @@
+use std::cell::RefCell;
+use std::ffi::c_void;
+use std::io::BufRead;
+
+use crate::asm::{Arg32, Arg64, BinArgs, Instr, Loc, MemRef, MovArgs, Reg, Reg32};
+use crate::compile::{
+    ErrorCode, ARITH_ERROR, ARITY_ERROR, CALL_NOT_CLOSURE_ERROR, CMP_ERROR, DIV_ZERO_ERROR, IF_ERROR, INDEX_BOUNDS_ERROR,
+    INDEX_NOT_INT_ERROR, INDEX_NOT_TUPLE_ERROR, INPUT_ERROR, INT_ARITH_ERROR, LOGIC_ERROR, OOM_ERROR, OVFL_ERROR,
+    STRING_ERROR, TONUM_ERROR,
+};
+use crate::encode::{encode, RelocKind};
+
+// ********************************************************************
+//
+//                       in-process execution
+//
+// ********************************************************************
+
+// The compiled code runs in memory mapped executable in this process. The
+// functions of runtime/stub.rs it calls are replaced by the ones below,
+// which write to a buffer instead of stdout, and snake_error returns to
+// the caller of the code instead of exiting the process. The rest of the
+// runtime is shared with runtime/stub.rs.
+
+include!("../runtime/common.rs");
+
+// the same stack size as the main thread of stub.exe
+static STACK_SIZE: usize = 8 << 20;
+
+static PAGE_SIZE: usize = 4096;
+static PROT_READ: i32 = 0x1;
+static PROT_WRITE: i32 = 0x2;
+static PROT_EXEC: i32 = 0x4;
+static MAP_PRIVATE: i32 = 0x02;
+static MAP_ANONYMOUS: i32 = 0x20;
+
+extern "C" {
+    fn mmap(addr: *mut c_void, len: usize, prot: i32, flags: i32, fd: i32, offset: i64) -> *mut c_void;
+    fn mprotect(addr: *mut c_void, len: usize, prot: i32) -> i32;
+    fn munmap(addr: *mut c_void, len: usize) -> i32;
+}
+
+// The words of the data page that follows the code. HEAP_END is read by
+// the heap checks of the compiled code, SAVED_RSP holds the stack pointer
+// of jit_entry that snake_error goes back to.
+static DATA_SYMBOLS: [&str; 2] = ["HEAP_END", "SAVED_RSP"];
+
+// The state of the program running on this thread
+struct Runtime {
+    from_space: *mut u64,
+    to_space: *mut u64,
+    heap_end: *mut u64, // the HEAP_END word of the data page
+    output: Vec<u8>,
+    error: Option<String>,
+    stdin: Box<dyn BufRead + Send>,
+    // the message of the INPUT_ERROR the last read failed with
+    read_error: Option<String>,
+}
+
+thread_local! {
+    static RUNTIME: RefCell<Option<Runtime>> = const { RefCell::new(None) };
+}
+
+fn with_runtime<T>(f: impl FnOnce(&mut Runtime) -> T) -> T {
+    RUNTIME.with(|rt| f(rt.borrow_mut().as_mut().expect("no snake program is running on this thread")))
+}
+
+extern "sysv64" fn print_snake_val(v: SnakeVal) -> SnakeVal {
+    let bytes = printed_bytes(v);
+    with_runtime(|rt| {
+        rt.output.extend(bytes);
+        rt.output.push(b'\n');
+    });
+    v
+}
+
+extern "sysv64" fn snake_str_concat(dest: *mut u64, a: SnakeVal, b: SnakeVal) -> SnakeVal {
+    unsafe { write_str(dest, &[str_bytes(a), str_bytes(b)].concat()) }
+}
+
+extern "sysv64" fn snake_str_slice(dest: *mut u64, s: SnakeVal, start: SnakeVal, end: SnakeVal) -> SnakeVal {
+    let start = (start.0 >> 2) as usize;
+    let end = (end.0 >> 2) as usize;
+    unsafe { write_str(dest, &str_bytes(s)[start..end]) }
+}
+
+extern "sysv64" fn snake_num_to_str(dest: *mut u64, n: SnakeVal) -> SnakeVal {
+    unsafe { write_str(dest, sprint_snake_val(n).as_bytes()) }
+}
+
+extern "sysv64" fn snake_str_to_num(s: SnakeVal) -> SnakeVal {
+    let n = std::str::from_utf8(unsafe { str_bytes(s) }).ok().and_then(|s| s.parse::<i64>().ok());
+    match n {
+        Some(n) if (-(1 << 61)..(1 << 61)).contains(&n) => SnakeVal((n << 2) as u64),
+        _ => s,
+    }
+}
+
+extern "sysv64" fn snake_str_equal(a: SnakeVal, b: SnakeVal) -> SnakeVal {
+    if unsafe { str_bytes(a) == str_bytes(b) } { SNAKE_TRU } else { SNAKE_FLS }
+}
+
+// Reads the next line of the program's stdin
+fn read_input(who: &str, expected: &str, parse: fn(&str) -> Option<SnakeVal>) -> SnakeVal {
+    with_runtime(|rt| match read_value(&mut rt.stdin, who, expected, parse) {
+        Ok(v) => v,
+        Err(msg) => {
+            rt.read_error = Some(msg);
+            READ_FAILED
+        }
+    })
+}
+
+extern "sysv64" fn snake_read_num() -> SnakeVal {
+    read_input("readnum", "an integer", parse_num)
+}
+
+extern "sysv64" fn snake_read_bool() -> SnakeVal {
+    read_input("readbool", "a boolean", parse_bool)
+}
+
+// Records the error, the snake_error of the compiled code then unwinds to
+// jit_entry
+extern "sysv64" fn record_error(err_code: ErrorCode, v: SnakeVal) {
+    with_runtime(|rt| {
+        let msg = match rt.read_error.take() {
+            Some(msg) if err_code == INPUT_ERROR && v == READ_FAILED => msg,
+            _ => error_message(err_code, v),
+        };
+        rt.error = Some(msg)
+    });
+}
+
+extern "sysv64" fn snake_try_gc(base_pointer: *mut u64, stack_pointer: *mut u64) -> *mut u64 {
+    with_runtime(|rt| unsafe {
+        let free = copy_live(base_pointer, stack_pointer, rt.to_space);
+        std::mem::swap(&mut rt.from_space, &mut rt.to_space);
+        *rt.heap_end = rt.from_space.add(HEAP_SIZE) as u64;
+        free
+    })
+}
+
+// jit_entry(heap, input) calls start_here like the main of runtime/stub.rs. It
+// saves every callee-saved register itself, so that snake_error can
+// return from it with the stack pointer saved before the call.
+fn entry_instrs() -> Vec<Instr> {
+    let saved = [Reg::Rbx, Reg::Rbp, Reg::R12, Reg::R13, Reg::R14, Reg::R15];
+    let saved_rsp = MemRef { reg: Reg::R11, offset: 0 };
+    let mut is = vec![Instr::Label(String::from("jit_entry"))];
+    for reg in saved.iter() {
+        is.push(Instr::Push(Arg32::Reg(*reg)));
+    }
+    is.push(Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(8))));
+    is.push(Instr::Lea(Reg::R11, String::from("SAVED_RSP")));
+    is.push(Instr::Mov(MovArgs::ToMem(saved_rsp, Reg32::Reg(Reg::Rsp))));
+    is.push(Instr::Call(String::from("start_here")));
+    is.push(Instr::Label(String::from("jit_exit")));
+    is.push(Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(8))));
+    for reg in saved.iter().rev() {
+        is.push(Instr::Pop(Loc::Reg(*reg)));
+    }
+    is.push(Instr::Ret);
+
+    // snake_error(code, value) never returns to the compiled code
+    is.push(Instr::Label(String::from("snake_error")));
+    is.push(Instr::And(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(-16))));
+    is.push(Instr::Call(String::from("record_error")));
+    is.push(Instr::Lea(Reg::R11, String::from("SAVED_RSP")));
+    is.push(Instr::Mov(MovArgs::ToReg(Reg::Rsp, Arg64::Mem(saved_rsp))));
+    is.push(Instr::Jmp(String::from("jit_exit")));
+    is
+}
+
+// Jumps to the runtime functions, which are too far away for a rel32
+fn trampolines() -> Vec<Instr> {
+    let funs: [(&str, u64); 10] = [
+        ("print_snake_val", print_snake_val as *const () as u64),
+        ("snake_str_concat", snake_str_concat as *const () as u64),
+        ("snake_str_slice", snake_str_slice as *const () as u64),
+        ("snake_num_to_str", snake_num_to_str as *const () as u64),
+        ("snake_str_to_num", snake_str_to_num as *const () as u64),
+        ("snake_str_equal", snake_str_equal as *const () as u64),
+        ("snake_read_num", snake_read_num as *const () as u64),
+        ("snake_read_bool", snake_read_bool as *const () as u64),
+        ("snake_try_gc", snake_try_gc as *const () as u64),
+        ("record_error", record_error as *const () as u64),
+    ];
+    let mut is = Vec::new();
+    for (name, addr) in funs.iter() {
+        is.push(Instr::Label(name.to_string()));
+        is.push(Instr::Mov(MovArgs::ToReg(Reg::R11, Arg64::Unsigned(*addr))));
+        is.push(Instr::JmpIndirect(Reg::R11));
+    }
+    is
+}
+
+// Code and data mapped into memory, unmapped when dropped. (entry) is the
+// offset of jit_entry and (data) the one of the data page.
+struct Mapping {
+    addr: *mut u8,
+    len: usize,
+    entry: usize,
+    data: usize,
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        unsafe {
+            munmap(self.addr as *mut c_void, self.len);
+        }
+    }
+}
+
+// Maps (code) followed by a page for the data symbols, patches the
+// references to them and makes the code executable but not writable
+fn load(instrs: &[Instr]) -> Result<Mapping, String> {
+    let obj = encode(instrs);
+    let code_len = obj.code.len().div_ceil(PAGE_SIZE) * PAGE_SIZE;
+    let len = code_len + PAGE_SIZE;
+    let addr = unsafe {
+        mmap(std::ptr::null_mut(), len, PROT_READ | PROT_WRITE, MAP_PRIVATE | MAP_ANONYMOUS, -1, 0)
+    };
+    if addr as isize == -1 {
+        return Err(String::from("failed to map memory for the compiled code"));
+    }
+    let mapping = Mapping {
+        addr: addr as *mut u8,
+        len,
+        entry: obj.labels["jit_entry"],
+        data: code_len,
+    };
+    let mut code = obj.code;
+    for reloc in obj.relocations.iter() {
+        let slot = match DATA_SYMBOLS.iter().position(|s| *s == reloc.symbol) {
+            Some(slot) if reloc.kind == RelocKind::Pc32 => slot,
+            _ => panic!("the compiled code refers to the unknown symbol {}", reloc.symbol),
+        };
+        let target = (code_len + 8 * slot) as i64;
+        let rel = target + reloc.addend - reloc.offset as i64;
+        code[reloc.offset..reloc.offset + 4].copy_from_slice(&(rel as i32).to_le_bytes());
+    }
+    unsafe {
+        std::ptr::copy_nonoverlapping(code.as_ptr(), mapping.addr, code.len());
+        if mprotect(addr, code_len, PROT_READ | PROT_EXEC) != 0 {
+            return Err(String::from("failed to make the compiled code executable"));
+        }
+    }
+    Ok(mapping)
+}
+
+// Runs (instrs), the body of start_here, on (input) and returns what it
+// prints followed by its value, or what it printed before it failed and
+// the message of the error
+fn run_on_this_thread(instrs: &[Instr], input: SnakeVal, stdin: Box<dyn BufRead + Send>) -> (Vec<u8>, Option<String>) {
+    let mut all = entry_instrs();
+    all.push(Instr::Label(String::from("start_here")));
+    all.extend(instrs.iter().cloned());
+    all.extend(trampolines());
+    let mapping = match load(&all) {
+        Ok(mapping) => mapping,
+        Err(e) => return (Vec::new(), Some(e)),
+    };
+
+    // the semispaces live until the code is done running
+    let mut from_space: Vec<u64> = vec![0; HEAP_SIZE];
+    let mut to_space: Vec<u64> = vec![0; HEAP_SIZE];
+    let heap_end = unsafe { mapping.addr.add(mapping.data) } as *mut u64;
+    unsafe {
+        *heap_end = from_space.as_mut_ptr().add(HEAP_SIZE) as u64;
+    }
+    RUNTIME.with(|rt| {
+        *rt.borrow_mut() = Some(Runtime {
+            from_space: from_space.as_mut_ptr(),
+            to_space: to_space.as_mut_ptr(),
+            heap_end,
+            output: Vec::new(),
+            error: None,
+            stdin,
+            read_error: None,
+        })
+    });
+    let v = unsafe {
+        let jit_entry: extern "sysv64" fn(*mut u64, SnakeVal) -> SnakeVal = std::mem::transmute(mapping.addr.add(mapping.entry));
+        jit_entry(from_space.as_mut_ptr(), input)
+    };
+    let mut rt = RUNTIME.with(|rt| rt.borrow_mut().take()).unwrap();
+    if rt.error.is_none() {
+        rt.output.extend(printed_bytes(v));
+        rt.output.push(b'\n');
+    }
+    (rt.output, rt.error)
+}
+
+// Runs the instructions of start_here from compile_prog_to_instrs in this
+// process, writing its output to (out). The program's input is parsed from
+// (arg) like the command-line argument of stub.exe, and it reads (stdin).
+// The code runs on its own thread, with the stack size it would have as a
+// program.
+pub fn run<W>(instrs: Vec<Instr>, arg: Option<&str>, stdin: Box<dyn BufRead + Send>, out: &mut W) -> Result<(), String>
+where
+    W: std::io::Write,
+{
+    if !cfg!(all(target_os = "linux", target_arch = "x86_64")) {
+        return Err(String::from("the jit only runs on x86-64 linux"));
+    }
+    let input = parse_input(arg)?;
+    let (output, error) = std::thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || run_on_this_thread(&instrs, input, stdin))
+        .map_err(|e| e.to_string())?
+        .join()
+        .map_err(|_| String::from("the compiled code crashed"))?;
+    out.write_all(&output).map_err(|e| format!("I/O error: {}", e))?;
+    match error {
+        None => Ok(()),
+        Some(e) => Err(e),
+    }
+}
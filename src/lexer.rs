@@ -0,0 +1,449 @@
+// Hand-written lexer for the Snake grammar.
+//
+// It produces the same tokens as the regular expressions that used to
+// live in parser.lalrpop, but also skips whitespace, `# ...` line
+// comments and nested `/* ... */` block comments, which lalrpop's
+// generated lexer cannot express. Token locations are byte offsets into
+// the source, so Span1 keeps pointing at the right place.
+use std::fmt::{self, Display};
+use std::str::CharIndices;
+
+pub type Spanned<Tok, Loc, Error> = Result<(Loc, Tok, Loc), Error>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Tok {
+    Num(i64),
+    Float(f64),
+    Str(String),
+    Id(String),
+
+    // keywords
+    Let,
+    In,
+    If,
+    Else,
+    Def,
+    And,
+    True,
+    False,
+    Add1,
+    Sub1,
+    Print,
+    IsNum,
+    IsBool,
+    IsTuple,
+    IsStr,
+    Len,
+    ToStr,
+    ToNum,
+    Substr,
+    Abs,
+    Min,
+    Max,
+    ReadNum,
+    ReadBool,
+    Lambda,
+    Block,
+    End,
+
+    // punctuation and operators
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Comma,
+    Semicolon,
+    Colon,
+    ColonEqual,
+    Equal,
+    Plus,
+    PlusPlus,
+    Minus,
+    Times,
+    Divide,
+    SlashSlash,
+    Percent,
+    Bang,
+    Amp,
+    AndAnd,
+    Pipe,
+    OrOr,
+    Caret,
+    LtLt,
+    GtGt,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    EqEq,
+    Neq,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LexicalError {
+    UnexpectedChar { ch: char, location: usize },
+    UnterminatedComment { location: usize },
+    UnterminatedString { location: usize },
+    InvalidEscape { escape: char, location: usize },
+    InvalidNumber { literal: String, location: usize },
+}
+
+impl Display for Tok {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Tok::Num(n) => write!(f, "{}", n),
+            Tok::Float(x) => write!(f, "{:?}", x),
+            Tok::Str(s) => write!(f, "{}", quote(s)),
+            Tok::Id(x) => write!(f, "{}", x),
+            Tok::Let => write!(f, "let"),
+            Tok::In => write!(f, "in"),
+            Tok::If => write!(f, "if"),
+            Tok::Else => write!(f, "else"),
+            Tok::Def => write!(f, "def"),
+            Tok::And => write!(f, "and"),
+            Tok::True => write!(f, "true"),
+            Tok::False => write!(f, "false"),
+            Tok::Add1 => write!(f, "add1"),
+            Tok::Sub1 => write!(f, "sub1"),
+            Tok::Print => write!(f, "print"),
+            Tok::IsNum => write!(f, "isnum"),
+            Tok::IsBool => write!(f, "isbool"),
+            Tok::IsTuple => write!(f, "istuple"),
+            Tok::IsStr => write!(f, "isstring"),
+            Tok::Len => write!(f, "len"),
+            Tok::ToStr => write!(f, "tostring"),
+            Tok::ToNum => write!(f, "tonum"),
+            Tok::Substr => write!(f, "substr"),
+            Tok::Abs => write!(f, "abs"),
+            Tok::Min => write!(f, "min"),
+            Tok::Max => write!(f, "max"),
+            Tok::ReadNum => write!(f, "readnum"),
+            Tok::ReadBool => write!(f, "readbool"),
+            Tok::Lambda => write!(f, "lambda"),
+            Tok::Block => write!(f, "block"),
+            Tok::End => write!(f, "end"),
+            Tok::LParen => write!(f, "("),
+            Tok::RParen => write!(f, ")"),
+            Tok::LBracket => write!(f, "["),
+            Tok::RBracket => write!(f, "]"),
+            Tok::Comma => write!(f, ","),
+            Tok::Semicolon => write!(f, ";"),
+            Tok::Colon => write!(f, ":"),
+            Tok::ColonEqual => write!(f, ":="),
+            Tok::Equal => write!(f, "="),
+            Tok::Plus => write!(f, "+"),
+            Tok::PlusPlus => write!(f, "++"),
+            Tok::Minus => write!(f, "-"),
+            Tok::Times => write!(f, "*"),
+            Tok::Divide => write!(f, "/"),
+            Tok::SlashSlash => write!(f, "//"),
+            Tok::Percent => write!(f, "%"),
+            Tok::Bang => write!(f, "!"),
+            Tok::Amp => write!(f, "&"),
+            Tok::AndAnd => write!(f, "&&"),
+            Tok::Pipe => write!(f, "|"),
+            Tok::OrOr => write!(f, "||"),
+            Tok::Caret => write!(f, "^"),
+            Tok::LtLt => write!(f, "<<"),
+            Tok::GtGt => write!(f, ">>"),
+            Tok::Lt => write!(f, "<"),
+            Tok::Le => write!(f, "<="),
+            Tok::Gt => write!(f, ">"),
+            Tok::Ge => write!(f, ">="),
+            Tok::EqEq => write!(f, "=="),
+            Tok::Neq => write!(f, "!="),
+        }
+    }
+}
+
+impl LexicalError {
+    // the offset of the error in the input
+    pub fn location(&self) -> usize {
+        match self {
+            LexicalError::UnexpectedChar { location, .. }
+            | LexicalError::UnterminatedComment { location }
+            | LexicalError::UnterminatedString { location }
+            | LexicalError::InvalidEscape { location, .. }
+            | LexicalError::InvalidNumber { location, .. } => *location,
+        }
+    }
+}
+
+impl Display for LexicalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LexicalError::UnexpectedChar { ch, .. } => {
+                write!(f, "unexpected character {:?}", ch)
+            }
+            LexicalError::UnterminatedComment { .. } => {
+                write!(f, "unterminated block comment")
+            }
+            LexicalError::UnterminatedString { .. } => {
+                write!(f, "unterminated string literal")
+            }
+            LexicalError::InvalidEscape { escape, .. } => {
+                write!(f, "invalid escape sequence \\{} in string literal", escape)
+            }
+            LexicalError::InvalidNumber { literal, .. } => {
+                write!(f, "invalid number literal {}", literal)
+            }
+        }
+    }
+}
+
+// The escape sequence of the characters string literals can't contain as
+// they are
+fn escape(c: char) -> Option<&'static str> {
+    match c {
+        '"' => Some("\\\""),
+        '\\' => Some("\\\\"),
+        '\n' => Some("\\n"),
+        '\t' => Some("\\t"),
+        _ => None,
+    }
+}
+
+// (s) as a string literal, which the lexer reads back as (s)
+pub fn quote(s: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in s.chars() {
+        match escape(c) {
+            Some(e) => quoted.push_str(e),
+            None => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn keyword(s: &str) -> Option<Tok> {
+    match s {
+        "let" => Some(Tok::Let),
+        "in" => Some(Tok::In),
+        "if" => Some(Tok::If),
+        "else" => Some(Tok::Else),
+        "def" => Some(Tok::Def),
+        "and" => Some(Tok::And),
+        "true" => Some(Tok::True),
+        "false" => Some(Tok::False),
+        "add1" => Some(Tok::Add1),
+        "sub1" => Some(Tok::Sub1),
+        "print" => Some(Tok::Print),
+        "isnum" => Some(Tok::IsNum),
+        "isbool" => Some(Tok::IsBool),
+        "istuple" => Some(Tok::IsTuple),
+        "isstring" => Some(Tok::IsStr),
+        "len" => Some(Tok::Len),
+        "tostring" => Some(Tok::ToStr),
+        "tonum" => Some(Tok::ToNum),
+        "substr" => Some(Tok::Substr),
+        "abs" => Some(Tok::Abs),
+        "min" => Some(Tok::Min),
+        "max" => Some(Tok::Max),
+        "readnum" => Some(Tok::ReadNum),
+        "readbool" => Some(Tok::ReadBool),
+        "lambda" => Some(Tok::Lambda),
+        "block" => Some(Tok::Block),
+        "end" => Some(Tok::End),
+        _ => None,
+    }
+}
+
+pub struct Lexer<'input> {
+    src: &'input str,
+    chars: CharIndices<'input>,
+}
+
+impl<'input> Lexer<'input> {
+    pub fn new(src: &'input str) -> Self {
+        Lexer {
+            src,
+            chars: src.char_indices(),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.clone().next().map(|(_, c)| c)
+    }
+
+    fn peek2(&self) -> Option<char> {
+        self.chars.clone().nth(1).map(|(_, c)| c)
+    }
+
+    // byte offset of the next unconsumed character
+    fn offset(&self) -> usize {
+        self.chars.clone().next().map(|(i, _)| i).unwrap_or(self.src.len())
+    }
+
+    fn bump(&mut self) -> Option<(usize, char)> {
+        self.chars.next()
+    }
+
+    // Skips whitespace and comments, fails on an unterminated block comment
+    fn skip_trivia(&mut self) -> Result<(), LexicalError> {
+        loop {
+            match (self.peek(), self.peek2()) {
+                (Some(c), _) if c.is_whitespace() => {
+                    self.bump();
+                }
+                (Some('#'), _) => {
+                    while let Some(c) = self.peek() {
+                        if c == '\n' {
+                            break;
+                        }
+                        self.bump();
+                    }
+                }
+                (Some('/'), Some('*')) => {
+                    let start = self.offset();
+                    self.bump();
+                    self.bump();
+                    let mut depth = 1;
+                    while depth > 0 {
+                        match (self.peek(), self.peek2()) {
+                            (Some('/'), Some('*')) => {
+                                self.bump();
+                                self.bump();
+                                depth += 1;
+                            }
+                            (Some('*'), Some('/')) => {
+                                self.bump();
+                                self.bump();
+                                depth -= 1;
+                            }
+                            (Some(_), _) => {
+                                self.bump();
+                            }
+                            (None, _) => {
+                                return Err(LexicalError::UnterminatedComment { location: start })
+                            }
+                        }
+                    }
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    // [+-]?[0-9]+ or [+-]?[0-9]+\.[0-9]+
+    fn number(&mut self, start: usize) -> Spanned<Tok, usize, LexicalError> {
+        while let Some(c) = self.peek() {
+            if !c.is_ascii_digit() {
+                break;
+            }
+            self.bump();
+        }
+        let is_float = self.peek() == Some('.') && self.peek2().is_some_and(|c| c.is_ascii_digit());
+        if is_float {
+            self.bump();
+            while let Some(c) = self.peek() {
+                if !c.is_ascii_digit() {
+                    break;
+                }
+                self.bump();
+            }
+        }
+        let end = self.offset();
+        let literal = &self.src[start..end];
+        let invalid = || LexicalError::InvalidNumber {
+            literal: literal.to_string(),
+            location: start,
+        };
+        let tok = if is_float {
+            Tok::Float(literal.parse().map_err(|_| invalid())?)
+        } else {
+            Tok::Num(literal.parse().map_err(|_| invalid())?)
+        };
+        Ok((start, tok, end))
+    }
+
+    // "..." with the escape sequences \" \\ \n and \t, the opening quote
+    // was consumed
+    fn string(&mut self, start: usize) -> Spanned<Tok, usize, LexicalError> {
+        let mut s = String::new();
+        loop {
+            match self.bump() {
+                Some((_, '"')) => break,
+                Some((location, '\\')) => match self.bump() {
+                    Some((_, '"')) => s.push('"'),
+                    Some((_, '\\')) => s.push('\\'),
+                    Some((_, 'n')) => s.push('\n'),
+                    Some((_, 't')) => s.push('\t'),
+                    Some((_, escape)) => return Err(LexicalError::InvalidEscape { escape, location }),
+                    None => return Err(LexicalError::UnterminatedString { location: start }),
+                },
+                Some((_, c)) => s.push(c),
+                None => return Err(LexicalError::UnterminatedString { location: start }),
+            }
+        }
+        Ok((start, Tok::Str(s), self.offset()))
+    }
+
+    // [a-zA-Z][a-zA-Z0-9_]*
+    fn identifier(&mut self, start: usize) -> Spanned<Tok, usize, LexicalError> {
+        while let Some(c) = self.peek() {
+            if !(c.is_ascii_alphanumeric() || c == '_') {
+                break;
+            }
+            self.bump();
+        }
+        let end = self.offset();
+        let s = &self.src[start..end];
+        let tok = keyword(s).unwrap_or_else(|| Tok::Id(s.to_string()));
+        Ok((start, tok, end))
+    }
+}
+
+impl<'input> Iterator for Lexer<'input> {
+    type Item = Spanned<Tok, usize, LexicalError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Err(e) = self.skip_trivia() {
+            return Some(Err(e));
+        }
+        let (start, c) = self.bump()?;
+        let next = self.peek();
+        let single = |tok| Some(Ok((start, tok, start + 1)));
+        let double = |lexer: &mut Self, tok| {
+            lexer.bump();
+            Some(Ok((start, tok, start + 2)))
+        };
+        match c {
+            '0'..='9' => Some(self.number(start)),
+            '+' | '-' if next.is_some_and(|c| c.is_ascii_digit()) => Some(self.number(start)),
+            'a'..='z' | 'A'..='Z' => Some(self.identifier(start)),
+            '"' => Some(self.string(start)),
+            '(' => single(Tok::LParen),
+            ')' => single(Tok::RParen),
+            '[' => single(Tok::LBracket),
+            ']' => single(Tok::RBracket),
+            ',' => single(Tok::Comma),
+            ';' => single(Tok::Semicolon),
+            ':' if next == Some('=') => double(self, Tok::ColonEqual),
+            ':' => single(Tok::Colon),
+            '+' if next == Some('+') => double(self, Tok::PlusPlus),
+            '+' => single(Tok::Plus),
+            '-' => single(Tok::Minus),
+            '*' => single(Tok::Times),
+            '/' if next == Some('/') => double(self, Tok::SlashSlash),
+            '/' => single(Tok::Divide),
+            '%' => single(Tok::Percent),
+            '&' if next == Some('&') => double(self, Tok::AndAnd),
+            '&' => single(Tok::Amp),
+            '|' if next == Some('|') => double(self, Tok::OrOr),
+            '|' => single(Tok::Pipe),
+            '^' => single(Tok::Caret),
+            '<' if next == Some('<') => double(self, Tok::LtLt),
+            '<' if next == Some('=') => double(self, Tok::Le),
+            '<' => single(Tok::Lt),
+            '>' if next == Some('>') => double(self, Tok::GtGt),
+            '>' if next == Some('=') => double(self, Tok::Ge),
+            '>' => single(Tok::Gt),
+            '=' if next == Some('=') => double(self, Tok::EqEq),
+            '=' => single(Tok::Equal),
+            '!' if next == Some('=') => double(self, Tok::Neq),
+            '!' => single(Tok::Bang),
+            _ => Some(Err(LexicalError::UnexpectedChar { ch: c, location: start })),
+        }
+    }
+}
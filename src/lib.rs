@@ -1,7 +1,20 @@
 pub mod asm;
 pub mod compile;
+pub mod desugar;
+pub mod diff;
+pub mod elf;
+pub mod encode;
+pub mod fuzz;
 pub mod interp;
+pub mod jit;
+pub mod lexer;
+pub mod optimize;
+#[allow(clippy::all)]
 pub mod parser;
+pub mod pretty;
+pub mod regalloc;
 pub mod runner;
 pub mod span;
 pub mod syntax;
+pub mod typecheck;
+pub mod warnings;
@@ -1,5 +1,8 @@
+use snake::compile::Stage;
+use snake::pretty::Style;
 use snake::runner::*;
-use std::path::Path;
+use snake::warnings::{Lint, Lints};
+use std::path::{Path, PathBuf};
 
 static USAGE_MSG: &str = "\
 To compile a program and emit assembly code to stdout use
@@ -10,29 +13,163 @@ To compile a program, link it and run the produced binary use
 
     snake --run INPUT_FILE
 
+To type check a program and emit assembly code without runtime tag checks use
+
+    snake --typecheck INPUT_FILE
+
+To type check, compile, link and run a program use
+
+    snake --typecheck --run INPUT_FILE
+
+To print the program after a stage of the compiler use
+
+    snake --emit=STAGE INPUT_FILE
+
+where STAGE is one of ast (after the well-formedness checks), uniq (after
+renaming the variables apart), lifted (after lambda lifting), seq (after
+sequentialization and optimization) or asm. --typecheck also type checks
+the program and leaves the tag checks out of the assembly.
+
+On linux, --elf makes --run encode the program into an object file
+directly instead of assembling it with nasm, e.g.
+
+    snake --elf --run INPUT_FILE
+
+To compile a program and run it in memory, without linking it, use
+
+    snake --jit INPUT_FILE
+
+Programs are built in a fresh temporary directory that is deleted
+afterwards, --keep-artifacts keeps it and prints where it is. To link a
+program into a standalone executable OUTPUT instead of emitting assembly,
+or in addition to running it, use
+
+    snake -o OUTPUT INPUT_FILE
+    snake --run -o OUTPUT INPUT_FILE
+
+and --typecheck -o OUTPUT INPUT_FILE to leave the tag checks out of it.
+The other modes produce no executable and refuse -o.
+
 To run the reference interpreter use
 
     snake --interp INPUT_FILE
 
+Programs read their input from the reserved variable input, an integer or
+a boolean given as the argument of the executable, and false without one.
+--input VALUE passes VALUE to the programs that --run, --jit and --interp
+run, e.g.
+
+    snake --input 10 --run INPUT_FILE
+
+readnum() and readbool() read a line of stdin.
+
+To check that the reference interpreter and the compiled code print the
+same output and fail with the same errors use
+
+    snake --diff INPUT_FILE
+
+To compare them on RUNS random programs, starting from the optional
+SEED, and shrink the first program they disagree on and save it in the
+temporary directory use
+
+    snake --fuzz RUNS [SEED]
+
+--diff and --fuzz build the compiled code with nasm, or without it with
+--elf as for --run.
+
+To format a program in place, or only check that it is formatted, use
+
+    snake fmt INPUT_FILE
+    snake fmt --check INPUT_FILE
+
+--indent N sets the number of spaces per level of nesting, 2 by default.
+A comment after code is kept at the end of the line of the expression
+before it, and a comment on its own line on a line before the expression
+after it.
+
+To evaluate expressions interactively with the reference interpreter use
+
+    snake --repl
+
+Entries are read line by line; top-level def declarations and let
+bindings without `in` are kept for the following entries.
+
+Warnings are printed for the commands that take an INPUT_FILE. A group
+of warnings is turned off with -A LINT and back on with -W LINT, where
+LINT is one of unused-variables, unused-parameters, unused-functions,
+shadowing, dead-code or all, e.g.
+
+    snake -A shadowing --run INPUT_FILE
+
 To see this usage message run
 
     snake --help
 ";
 
 fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() == 2 {
+    let (args, lints) = lint_flags(std::env::args().collect());
+    let (args, mut opts) = build_flags(args);
+    let (args, stage) = emit_flag(args);
+    let (args, input) = input_flag(args);
+    if opts.output.is_some() {
+        if stage.is_some() {
+            usage(Some("-o only combines with building or running a program, --emit produces no executable"));
+        }
+        if let Some(mode) = args.get(1).filter(|arg| ["--interp", "--jit", "--diff", "--repl", "--fuzz", "fmt"].contains(&arg.as_str())) {
+            usage(Some(&format!("-o only combines with building or running a program, {} produces no executable", mode)));
+        }
+    }
+    if let Some(stage) = stage {
+        match &args[1..] {
+            [path] => emit(Path::new(path), stage, false),
+            [typecheck, path] if typecheck == "--typecheck" => emit(Path::new(path), stage, true),
+            _ => usage(Some("--emit only combines with --typecheck and an input file")),
+        }
+    } else if args.len() >= 2 && args[1] == "--fuzz" {
+        fuzz_command(&args[2..], opts.backend)
+    } else if args.len() >= 2 && args[1] == "fmt" {
+        fmt_command(&args[2..])
+    } else if args.len() == 2 {
         match args[1].as_str() {
-            "--interp" | "--run" => usage(Some("interp and run comands require an input file")),
+            "--interp" | "--run" | "--jit" | "--diff" | "--typecheck" => {
+                usage(Some("interp, run, jit, diff and typecheck comands require an input file"))
+            }
             "--help" => usage(None),
-            path => emit_assembly(Path::new(&path)),
+            "--repl" => repl(),
+            path => {
+                report_warnings(Path::new(&path), &lints);
+                if opts.output.is_some() {
+                    build(Path::new(&path), &opts)
+                } else {
+                    emit_assembly(Path::new(&path))
+                }
+            }
         }
     } else if args.len() == 3 {
+        let path = Path::new(&args[2]);
         match args[1].as_str() {
-            "--interp" => interp(Path::new(&args[2]), &mut std::io::stdout()),
-            "--run" => run(Path::new(&args[2])),
+            "--interp" | "--run" | "--jit" | "--diff" | "--typecheck" => report_warnings(path, &lints),
             _ => usage(Some("Failed to parse input")),
         }
+        match args[1].as_str() {
+            "--interp" => interp(path, &input, &mut std::io::stdout()),
+            "--run" => run(path, &opts, &input),
+            "--jit" => jit(path, &input),
+            "--diff" => diff(path, &opts),
+            "--typecheck" => {
+                opts.typed = true;
+                if opts.output.is_some() {
+                    build(path, &opts)
+                } else {
+                    emit_typed_assembly(path)
+                }
+            }
+            _ => unreachable!(),
+        }
+    } else if args.len() == 4 && args[1] == "--typecheck" && args[2] == "--run" {
+        report_warnings(Path::new(&args[3]), &lints);
+        opts.typed = true;
+        run(Path::new(&args[3]), &opts, &input)
     } else if args.len() <= 1 {
         usage(Some("Too few arguments"))
     } else {
@@ -40,6 +177,127 @@ fn main() {
     }
 }
 
+// snake fmt FILE [--check] [--indent N]
+fn fmt_command(args: &[String]) {
+    let mut style = Style::default();
+    let mut check = false;
+    let mut files = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--check" => check = true,
+            "--indent" => match args.next().map(|n| n.parse::<usize>()) {
+                Some(Ok(n)) => style.indent = n,
+                _ => usage(Some("--indent expects a number of spaces")),
+            },
+            _ => files.push(arg),
+        }
+    }
+    match files[..] {
+        [file] => fmt(Path::new(file), check, &style),
+        [] => usage(Some("fmt requires an input file")),
+        _ => usage(Some("fmt formats one file at a time")),
+    }
+}
+
+// snake --fuzz RUNS [SEED], the seed defaults to the current time
+fn fuzz_command(args: &[String], backend: Backend) {
+    let number = |arg: &String| match arg.parse::<u64>() {
+        Ok(n) => n,
+        Err(_) => {
+            usage(Some(&format!("--fuzz expects numbers, not \"{}\"", arg)));
+            0
+        }
+    };
+    match args {
+        [runs] => {
+            let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default();
+            fuzz(number(runs), now.as_secs(), backend)
+        }
+        [runs, seed] => fuzz(number(runs), number(seed), backend),
+        _ => usage(Some("--fuzz expects the number of programs to run and optionally a seed")),
+    }
+}
+
+// Takes the -A LINT and -W LINT flags out of (args), applied in order
+fn lint_flags(args: Vec<String>) -> (Vec<String>, Lints) {
+    let mut lints = Lints::default();
+    let mut rest = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg != "-A" && arg != "-W" {
+            rest.push(arg);
+            continue;
+        }
+        let name = args.next().unwrap_or_default();
+        let selected: Vec<Lint> = match Lint::from_name(&name) {
+            Some(lint) => vec![lint],
+            None if name == "all" => Lint::ALL.to_vec(),
+            None => {
+                usage(Some(&format!("Unknown lint \"{}\"", name)));
+                vec![]
+            }
+        };
+        for lint in selected {
+            if arg == "-A" { lints.allow(lint) } else { lints.warn(lint) }
+        }
+    }
+    (rest, lints)
+}
+
+// Takes the --emit=STAGE flag out of (args), the last one wins
+fn emit_flag(args: Vec<String>) -> (Vec<String>, Option<Stage>) {
+    let mut stage = None;
+    let mut rest = Vec::new();
+    for arg in args {
+        match arg.strip_prefix("--emit=") {
+            Some(name) => match Stage::from_name(name) {
+                Some(s) => stage = Some(s),
+                None => usage(Some(&format!("Unknown stage \"{}\"", name))),
+            },
+            None => rest.push(arg),
+        }
+    }
+    (rest, stage)
+}
+
+// Takes the --input VALUE flag out of (args), the last one wins. The
+// programs read the stdin of snake.
+fn input_flag(args: Vec<String>) -> (Vec<String>, ProgramInput) {
+    let mut input = ProgramInput::default();
+    let mut rest = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => match args.next() {
+                Some(value) => input.arg = Some(value),
+                None => usage(Some("--input requires a value")),
+            },
+            _ => rest.push(arg),
+        }
+    }
+    (rest, input)
+}
+
+// Takes the --elf, --keep-artifacts and -o OUTPUT flags out of (args)
+fn build_flags(args: Vec<String>) -> (Vec<String>, BuildOptions) {
+    let mut opts = BuildOptions::default();
+    let mut rest = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--elf" => opts.backend = Backend::Elf,
+            "--keep-artifacts" => opts.keep_artifacts = true,
+            "-o" => match args.next() {
+                Some(output) => opts.output = Some(PathBuf::from(output)),
+                None => usage(Some("-o requires an output file")),
+            },
+            _ => rest.push(arg),
+        }
+    }
+    (rest, opts)
+}
+
 fn usage(err_msg: Option<&str>) {
     match err_msg {
         None => println!("{}", USAGE_MSG),
@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+
+use crate::syntax::{FunDecl, ImmExp, Prim, SeqExp, SeqProg};
+
+// ********************************************************************
+//
+//                 constant folding & propagation
+//
+// ********************************************************************
+
+// The range of the 62-bit integers of the compiled code
+static MAX_INT: i64 = (1 << 61) - 1;
+static MIN_INT: i64 = -(1 << 61);
+
+fn in_range(n: Option<i64>) -> Option<i64> {
+    n.filter(|n| (MIN_INT..=MAX_INT).contains(n))
+}
+
+// Evaluates a primitive on constant arguments. Returns None when the
+// result is not known at compile time or the operation fails, so that
+// overflows and type errors still happen at runtime. Floats are left
+// alone since their result depends on the rounding of the x87 unit.
+fn fold_prim(p: &Prim, args: &[ImmExp]) -> Option<ImmExp> {
+    use ImmExp::{Bool, Num};
+    match (p, args) {
+        (Prim::Add1, [Num(n)]) => in_range(n.checked_add(1)).map(Num),
+        (Prim::Sub1, [Num(n)]) => in_range(n.checked_sub(1)).map(Num),
+        (Prim::Abs, [Num(n)]) => in_range(n.checked_abs()).map(Num),
+        (Prim::Not, [Bool(b)]) => Some(Bool(!b)),
+        (Prim::IsBool, [Num(_)]) | (Prim::IsBool, [ImmExp::Float(_)]) => Some(Bool(false)),
+        (Prim::IsBool, [Bool(_)]) => Some(Bool(true)),
+        (Prim::IsNum, [Num(_)]) | (Prim::IsNum, [ImmExp::Float(_)]) => Some(Bool(true)),
+        (Prim::IsNum, [Bool(_)]) => Some(Bool(false)),
+        (Prim::IsTuple, [Num(_)]) | (Prim::IsTuple, [ImmExp::Float(_)]) | (Prim::IsTuple, [Bool(_)]) => Some(Bool(false)),
+        (Prim::IsStr, [Num(_)]) | (Prim::IsStr, [ImmExp::Float(_)]) | (Prim::IsStr, [Bool(_)]) => Some(Bool(false)),
+
+        (Prim::Add, [Num(n1), Num(n2)]) => in_range(n1.checked_add(*n2)).map(Num),
+        (Prim::Sub, [Num(n1), Num(n2)]) => in_range(n1.checked_sub(*n2)).map(Num),
+        (Prim::Mul, [Num(n1), Num(n2)]) => in_range(n1.checked_mul(*n2)).map(Num),
+        (Prim::IntDiv, [Num(n1), Num(n2)]) => in_range(n1.checked_div(*n2)).map(Num),
+        (Prim::Rem, [Num(n1), Num(n2)]) => n1.checked_rem(*n2).map(Num),
+        (Prim::BitAnd, [Num(n1), Num(n2)]) => Some(Num(n1 & n2)),
+        (Prim::BitOr, [Num(n1), Num(n2)]) => Some(Num(n1 | n2)),
+        (Prim::BitXor, [Num(n1), Num(n2)]) => Some(Num(n1 ^ n2)),
+        (Prim::Min, [Num(n1), Num(n2)]) => Some(Num(*n1.min(n2))),
+        (Prim::Max, [Num(n1), Num(n2)]) => Some(Num(*n1.max(n2))),
+        (Prim::And, [Bool(b1), Bool(b2)]) => Some(Bool(*b1 && *b2)),
+        (Prim::Or, [Bool(b1), Bool(b2)]) => Some(Bool(*b1 || *b2)),
+        (Prim::Lt, [Num(n1), Num(n2)]) => Some(Bool(n1 < n2)),
+        (Prim::Gt, [Num(n1), Num(n2)]) => Some(Bool(n1 > n2)),
+        (Prim::Le, [Num(n1), Num(n2)]) => Some(Bool(n1 <= n2)),
+        (Prim::Ge, [Num(n1), Num(n2)]) => Some(Bool(n1 >= n2)),
+        (Prim::Eq, [Num(n1), Num(n2)]) => Some(Bool(n1 == n2)),
+        (Prim::Eq, [Bool(b1), Bool(b2)]) => Some(Bool(b1 == b2)),
+        (Prim::Eq, [Num(_), Bool(_)]) | (Prim::Eq, [Bool(_), Num(_)]) => Some(Bool(false)),
+        (Prim::Neq, _) => match fold_prim(&Prim::Eq, args) {
+            Some(Bool(b)) => Some(Bool(!b)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// Whether evaluating e can be skipped when its value is unused: it
+// neither prints, calls, mutates nor fails at runtime.
+fn is_pure<Ann>(e: &SeqExp<Ann>) -> bool {
+    match e {
+        SeqExp::Imm(_, _) | SeqExp::MakeClosure { .. } | SeqExp::Str(_, _) => true,
+        SeqExp::Prim(p, _, _) => matches!(
+            p,
+            Prim::IsBool | Prim::IsNum | Prim::IsTuple | Prim::IsStr | Prim::Eq | Prim::Neq | Prim::MakeTuple
+        ),
+        SeqExp::Let { bound_exp, body, .. } => is_pure(bound_exp) && is_pure(body),
+        SeqExp::If { cond: ImmExp::Bool(_), thn, els, .. } => is_pure(thn) && is_pure(els),
+        SeqExp::If { .. }
+        | SeqExp::FunDefs { .. }
+        | SeqExp::InternalTailCall(..)
+        | SeqExp::ExternalCall { .. }
+        | SeqExp::ClosureCall { .. } => false,
+    }
+}
+
+fn imm_mentions(x: &str, imms: &[ImmExp]) -> bool {
+    imms.iter().any(|imm| matches!(imm, ImmExp::Var(y) if y == x))
+}
+
+fn mentions<Ann>(x: &str, e: &SeqExp<Ann>) -> bool {
+    match e {
+        SeqExp::Imm(imm, _) => imm_mentions(x, std::slice::from_ref(imm)),
+        SeqExp::Prim(_, args, _) | SeqExp::InternalTailCall(_, args, _) | SeqExp::ExternalCall { args, .. } => {
+            imm_mentions(x, args)
+        }
+        SeqExp::ClosureCall { fun, args, .. } => imm_mentions(x, std::slice::from_ref(fun)) || imm_mentions(x, args),
+        SeqExp::MakeClosure { env, .. } => imm_mentions(x, std::slice::from_ref(env)),
+        SeqExp::Str(_, _) => false,
+        SeqExp::Let { bound_exp, body, .. } => mentions(x, bound_exp) || mentions(x, body),
+        SeqExp::If { cond, thn, els, .. } => {
+            imm_mentions(x, std::slice::from_ref(cond)) || mentions(x, thn) || mentions(x, els)
+        }
+        SeqExp::FunDefs { decls, body, .. } => {
+            mentions(x, body) || decls.iter().any(|decl| mentions(x, &decl.body))
+        }
+    }
+}
+
+fn subst_imm(imm: &ImmExp, consts: &HashMap<String, ImmExp>) -> ImmExp {
+    match imm {
+        ImmExp::Var(x) => consts.get(x).cloned().unwrap_or_else(|| imm.clone()),
+        _ => imm.clone(),
+    }
+}
+
+fn subst_imms(imms: &[ImmExp], consts: &HashMap<String, ImmExp>) -> Vec<ImmExp> {
+    imms.iter().map(|imm| subst_imm(imm, consts)).collect()
+}
+
+// (consts) maps the variables bound to constants or other variables to
+// their value. Variable names are unique at this point, so substituting
+// never captures.
+fn optimize_exp<Ann: Clone>(e: &SeqExp<Ann>, consts: &mut HashMap<String, ImmExp>) -> SeqExp<Ann> {
+    match e {
+        SeqExp::Imm(imm, ann) => SeqExp::Imm(subst_imm(imm, consts), ann.clone()),
+        SeqExp::Prim(p, args, ann) => {
+            let args = subst_imms(args, consts);
+            match fold_prim(p, &args) {
+                Some(imm) => SeqExp::Imm(imm, ann.clone()),
+                None => SeqExp::Prim(*p, args, ann.clone()),
+            }
+        }
+        SeqExp::Let { var, bound_exp, body, ann } => {
+            let bound_exp = optimize_exp(bound_exp, consts);
+            if let SeqExp::Imm(imm, _) = bound_exp {
+                consts.insert(var.clone(), imm);
+                return optimize_exp(body, consts);
+            }
+            let body = optimize_exp(body, consts);
+            if is_pure(&bound_exp) && !mentions(var, &body) {
+                body
+            } else {
+                SeqExp::Let {
+                    var: var.clone(),
+                    bound_exp: Box::new(bound_exp),
+                    body: Box::new(body),
+                    ann: ann.clone(),
+                }
+            }
+        }
+        SeqExp::If { cond, thn, els, ann } => match subst_imm(cond, consts) {
+            ImmExp::Bool(true) => optimize_exp(thn, consts),
+            ImmExp::Bool(false) => optimize_exp(els, consts),
+            cond => SeqExp::If {
+                cond,
+                thn: Box::new(optimize_exp(thn, consts)),
+                els: Box::new(optimize_exp(els, consts)),
+                ann: ann.clone(),
+            },
+        },
+        SeqExp::FunDefs { decls, body, ann } => SeqExp::FunDefs {
+            decls: decls.iter().map(|decl| optimize_decl(decl, consts)).collect(),
+            body: Box::new(optimize_exp(body, consts)),
+            ann: ann.clone(),
+        },
+        SeqExp::InternalTailCall(fun_name, args, ann) => {
+            SeqExp::InternalTailCall(fun_name.clone(), subst_imms(args, consts), ann.clone())
+        }
+        SeqExp::ExternalCall { fun_name, args, is_tail, ann } => SeqExp::ExternalCall {
+            fun_name: fun_name.clone(),
+            args: subst_imms(args, consts),
+            is_tail: *is_tail,
+            ann: ann.clone(),
+        },
+        SeqExp::ClosureCall { fun, args, is_tail, ann } => SeqExp::ClosureCall {
+            fun: subst_imm(fun, consts),
+            args: subst_imms(args, consts),
+            is_tail: *is_tail,
+            ann: ann.clone(),
+        },
+        SeqExp::MakeClosure { fun_name, arity, env, ann } => SeqExp::MakeClosure {
+            fun_name: fun_name.clone(),
+            arity: *arity,
+            env: subst_imm(env, consts),
+            ann: ann.clone(),
+        },
+        SeqExp::Str(s, ann) => SeqExp::Str(s.clone(), ann.clone()),
+    }
+}
+
+fn optimize_decl<Ann: Clone>(
+    decl: &FunDecl<SeqExp<Ann>, Ann>,
+    consts: &mut HashMap<String, ImmExp>,
+) -> FunDecl<SeqExp<Ann>, Ann> {
+    FunDecl {
+        name: decl.name.clone(),
+        parameters: decl.parameters.clone(),
+        body: optimize_exp(&decl.body, consts),
+        ann: decl.ann.clone(),
+    }
+}
+
+// Folds constant primitives, propagates constants and copies through
+// lets and removes the lets whose value is unused and that have no effect
+pub fn optimize_prog<Ann: Clone>(p: &SeqProg<Ann>) -> SeqProg<Ann> {
+    SeqProg {
+        funs: p.funs.iter().map(|decl| optimize_decl(decl, &mut HashMap::new())).collect(),
+        main: optimize_exp(&p.main, &mut HashMap::new()),
+        ann: p.ann.clone(),
+    }
+}
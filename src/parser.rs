@@ -1,8 +1,8 @@
 // auto-generated: "lalrpop 0.19.8"
-// sha3: aa5bdd41184737f2c0377cc3b3937308ed06f345a9108ed0ddc4ffa9f567b0c1
-use std::str::FromStr;
+// sha3: d2af22469706369f46defd5885888cbc72867209cdda6e9f55109474373676b7
 use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Prim};
 use crate::span::Span1;
+use crate::lexer::{LexicalError, Tok};
 #[allow(unused_extern_crates)]
 extern crate lalrpop_util as __lalrpop_util;
 #[allow(unused_imports)]
@@ -14,273 +14,434 @@ extern crate alloc;
 mod __parse__Exp {
     #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::all)]
 
-    use std::str::FromStr;
     use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Prim};
     use crate::span::Span1;
+    use crate::lexer::{LexicalError, Tok};
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
     #[allow(unused_imports)]
     use self::__lalrpop_util::state_machine as __state_machine;
     extern crate core;
     extern crate alloc;
-    use self::__lalrpop_util::lexer::Token;
+    use super::__ToTriple;
     #[allow(dead_code)]
-    pub(crate) enum __Symbol<'input>
+    pub(crate) enum __Symbol<>
      {
-        Variant0(&'input str),
-        Variant1(Exp<Span1>),
-        Variant2(alloc::vec::Vec<Exp<Span1>>),
-        Variant3(String),
-        Variant4(alloc::vec::Vec<String>),
-        Variant5((SurfFunDecl<Span1>, &'input str)),
-        Variant6(alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>),
-        Variant7(usize),
-        Variant8(Vec<(String, Exp<Span1>)>),
-        Variant9(bool),
-        Variant10(Box<Exp<Span1>>),
+        Variant0(Tok),
+        Variant1(f64),
+        Variant2(String),
+        Variant3(i64),
+        Variant4(Exp<Span1>),
+        Variant5(alloc::vec::Vec<Exp<Span1>>),
+        Variant6(alloc::vec::Vec<String>),
+        Variant7((SurfFunDecl<Span1>, Tok)),
+        Variant8(alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>),
+        Variant9(usize),
+        Variant10(Vec<(String, Exp<Span1>)>),
         Variant11(Prim),
-        Variant12(Vec<Exp<Span1>>),
-        Variant13(Vec<String>),
-        Variant14(core::option::Option<Exp<Span1>>),
-        Variant15(SurfFunDecl<Span1>),
-        Variant16(core::option::Option<String>),
-        Variant17(i64),
-        Variant18(SurfProg<Span1>),
-    }
-    const __ACTION: &[i8] = &[
+        Variant12(bool),
+        Variant13(Box<Exp<Span1>>),
+        Variant14(Vec<Exp<Span1>>),
+        Variant15(Vec<String>),
+        Variant16(core::option::Option<Exp<Span1>>),
+        Variant17(SurfFunDecl<Span1>),
+        Variant18(core::option::Option<String>),
+        Variant19(SurfProg<Span1>),
+    }
+    const __ACTION: &[i16] = &[
         // State 0
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 2
-        0, 0, 56, 0, -81, 0, 0, -81, 0, -81, 0, 0, 0, 0, 0, 0, 0, -81, 0, -81, 0, 0, -81, 0, 0, 0, 0, 0, 0, 57, 0, 0,
+        0, 0, 0, 0, 96, 0, -115, 0, 0, 0, -115, 0, 0, 0, -115, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, 0, 0, 0, -115, 0, 0, -115, -115, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 97, 0, 0, 0, 0,
         // State 3
-        0, 59, -36, 0, -36, 0, 0, -36, 0, -36, 60, 61, 0, 62, 63, 64, 0, -36, 0, -36, 0, 0, -36, 0, 0, 0, 0, 0, 0, -36, 0, 0,
+        0, -38, 0, 99, -38, 0, -38, 0, 0, 0, -38, 0, 0, 0, -38, 0, -38, -38, 0, -38, 0, -38, -38, -38, 0, 0, -38, -38, 0, 0, -38, 0, 0, -38, -38, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38, -38, 0, 0, 0, 0,
         // State 4
-        0, -82, -82, 0, -82, 0, 65, -82, 66, -82, -82, -82, 0, -82, -82, -82, 0, -82, 0, -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, -82, 0, 0,
+        0, -40, 0, 0, -40, 0, -40, 0, 0, 0, -40, 0, 0, 0, -40, 0, -40, -40, 0, -40, 0, -40, -40, -40, 0, 0, -40, 0, 0, 0, -40, 0, 0, -40, -40, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100, -40, 0, 0, 0, 0,
         // State 5
-        0, -78, -78, 0, -78, 67, -78, -78, -78, -78, -78, -78, 0, -78, -78, -78, 0, -78, 0, -78, 0, 0, -78, 0, 0, 0, 0, 0, 0, -78, 0, 0,
+        0, -42, 0, 0, -42, 0, -42, 0, 0, 0, -42, 0, 0, 0, -42, 0, -42, -42, 0, -42, 0, -42, -42, -42, 0, 0, -42, 101, 0, 0, -42, 0, 0, -42, -42, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, -42, 0, 0, 0, 0,
         // State 6
-        0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 47, 0, 0, 48, 49, 0, 50, 51, 52, 0, 53, 54,
+        0, 102, 0, 0, -52, 0, -52, 0, 0, 0, -52, 0, 0, 0, -52, 0, -52, 103, 0, 104, 0, 105, 106, 107, 0, 0, -52, 0, 0, 0, -52, 0, 0, -52, -52, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, 0, 0, 0, 0,
         // State 7
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        0, -122, 0, -122, -122, 0, -122, 0, 108, 109, -122, 110, 0, 0, -122, 0, -122, -122, -122, -122, 0, -122, -122, -122, -122, 0, -122, -122, 0, 0, -122, 0, 0, -122, -122, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -122, -122, 0, 0, 0, 0,
         // State 8
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54,
+        0, -119, 0, -119, -119, 0, -119, 0, 0, 0, -119, 0, 0, 0, -119, 0, -119, -119, 111, -119, 0, -119, -119, -119, 112, 0, -119, -119, 0, 0, -119, 0, 0, -119, -119, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119, -119, 0, 0, 0, 0,
         // State 9
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        0, -112, 113, -112, -112, 0, -112, 114, -112, -112, -112, -112, 115, 116, -112, 0, -112, -112, -112, -112, 0, -112, -112, -112, -112, 0, -112, -112, 0, 0, -112, 0, 0, -112, -112, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, -112, 0, 0, 0, 0,
         // State 10
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54,
+        0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 0, 0, 0, 74, 0, 0, 75, 76, 77, 78, 79, 80, 0, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 11
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 47, 0, 0, 48, 49, 0, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 12
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 13
-        7, 0, 0, 8, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0,
         // State 14
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 47, 0, 0, 48, 49, 0, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 15
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 47, 0, 0, 48, 49, 0, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 16
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 47, 0, 0, 48, 49, 0, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 0, 0, 0, 74, 0, 0, 75, 76, 77, 78, 79, 80, 0, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 17
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 18
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 0, 0, 0, 74, 0, 0, 75, 76, 77, 78, 79, 80, 0, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 19
-        7, 0, 0, 8, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 0, 0, 0, 74, 0, 0, 75, 76, 77, 78, 79, 80, 0, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 20
-        0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 0, 0, 0, 74, 0, 0, 75, 76, 77, 78, 79, 80, 0, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 21
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 0, 0, 0, 74, 0, 0, 75, 76, 77, 78, 79, 80, 0, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 22
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 0, 0, 0, 74, 0, 0, 75, 76, 77, 78, 79, 80, 0, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 23
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 0, 0, 0, 74, 0, 0, 75, 76, 77, 78, 79, 80, 0, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 24
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 0, 0, 0, 74, 0, 0, 75, 76, 77, 78, 79, 80, 0, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 25
-        0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 26
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 27
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 28
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 29
-        0, -69, -69, 0, -69, -69, -69, -69, -69, -69, -69, -69, 0, -69, -69, -69, 0, -69, 0, -69, 0, 0, -69, 0, 0, 0, 0, 0, 0, -69, 0, 0,
+        0, 0, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 148, 0, 0,
         // State 30
-        0, 0, 0, 0, -48, 0, 0, -48, 0, -48, 0, 0, 0, 0, 0, 0, 0, -48, 0, -48, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 31
-        0, -19, -19, 0, -19, -19, -19, -19, -19, -19, -19, -19, 0, -19, -19, -19, 0, -19, 0, -19, 0, 0, -19, 0, 0, 0, 0, 0, 0, -19, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 32
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 33
-        0, 0, 0, 0, -47, 0, 0, -47, 0, -47, 0, 0, 0, 0, 0, 0, 0, -47, 0, -47, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 34
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 35
-        0, 0, 0, 0, -45, 0, 0, -45, 0, -45, 0, 0, 0, 0, 0, 0, 0, -45, 0, -45, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 148, 0, 0,
         // State 36
-        0, -18, -18, 14, -18, -18, -18, -18, -18, -18, -18, -18, 0, -18, -18, -18, 0, -18, 0, -18, 0, 0, -18, 0, 0, 0, 0, 0, 0, -18, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 37
-        0, 0, 0, 0, -46, 0, 0, -46, 0, -46, 0, 0, 0, 0, 0, 0, 0, -46, 0, -46, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 38
-        0, 0, 0, 0, -24, 0, 0, -24, 0, -24, 0, 0, 0, 0, 0, 0, 0, -24, 0, -24, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 39
-        0, -63, -63, 0, -63, -63, -63, -63, -63, -63, -63, -63, 0, -63, -63, -63, 0, -63, 0, -63, 0, 0, -63, 0, 0, 0, 0, 0, 0, -63, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 40
-        0, -20, -20, 0, -20, -20, -20, -20, -20, -20, -20, -20, 0, -20, -20, -20, 0, -20, 0, -20, 0, 0, -20, 0, 0, 0, 0, 0, 0, -20, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 41
-        0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 0, 0, 0, 74, 0, 0, 75, 76, 77, 78, 79, 80, 0, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 42
-        0, -61, -61, 0, -61, 0, -61, -61, -61, -61, -61, -61, 0, -61, -61, -61, 0, -61, 0, -61, 0, 0, -61, 0, 0, 0, 0, 0, 0, -61, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 43
-        0, 0, 0, 0, -67, 0, 0, -67, 0, -67, 0, 0, 0, 0, 0, 0, 0, -67, 0, -67, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 44
-        0, -59, -59, 0, -59, 0, 0, -59, 0, -59, -59, -59, 0, -59, -59, -59, 0, -59, 0, -59, 0, 0, -59, 0, 0, 0, 0, 0, 0, -59, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 45
-        0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 46
-        0, -28, -28, 0, -28, -28, -28, -28, -28, -28, -28, -28, 0, -28, -28, -28, 0, -28, 0, -28, 0, 0, -28, 0, 0, 0, 0, 0, 0, -28, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 71, 0, 72, 73, 0, 0, 74, 13, 0, 75, 76, 77, 78, 79, 80, 14, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 0, 0, 91, 92, 93, 94,
         // State 47
-        0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -93, -93, -93, -93, 15, -93, -93, -93, -93, -93, -93, -93, -93, -93, 0, -93, -93, -93, -93, 0, -93, -93, -93, -93, 16, -93, -93, 0, 0, -93, 0, 0, -93, -93, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, -93, 0, 0, 0, 0,
         // State 48
-        0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -121, 0, 0, 0, -121, 0, 0, 0, -121, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, -121, 0, 0, 0, -121, 0, 0, -121, -121, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 49
-        0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -79, 0, 0, -79, 0, -79, 0, 0, 0, -79, 0, 0, 0, -79, 0, -79, -79, 0, -79, 0, -79, -79, -79, 0, 0, -79, -79, 0, 0, -79, 0, 0, -79, -79, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, -79, 0, 0, 0, 0,
         // State 50
-        0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -81, 0, 0, -81, 0, -81, 0, 0, 0, -81, 0, 0, 0, -81, 0, -81, -81, 0, -81, 0, -81, -81, -81, 0, 0, -81, 0, 0, 0, -81, 0, 0, -81, -81, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, 0, 0,
         // State 51
-        0, -27, -27, 0, -27, -27, -27, -27, -27, -27, -27, -27, 0, -27, -27, -27, 0, -27, 0, -27, 0, 0, -27, 0, 0, 0, 0, 0, 0, -27, 0, 0,
+        0, -77, 0, 0, -77, 0, -77, 0, 0, 0, -77, 0, 0, 0, -77, 0, -77, -77, 0, -77, 0, -77, -77, -77, 0, 0, -77, 0, 0, 0, -77, 0, 0, -77, -77, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, -77, 0, 0, 0, 0,
         // State 52
-        0, -70, -70, 0, -70, -70, -70, -70, -70, -70, -70, -70, 0, -70, -70, -70, 0, -70, 0, -70, 0, 0, -70, 0, 0, 0, 0, 0, 0, -70, 0, 0,
+        0, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, 0, -19, -19, -19, -19, 0, -19, -19, -19, -19, -19, -19, -19, 0, 0, -19, 0, 0, -19, -19, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, -19, 0, 0, 0, 0,
         // State 53
-        0, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, 0, -55, 0, -55, 0, 0, -55, 0, 0, 0, 0, 0, 0, -55, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 54
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -64, 0, 0, 0, -64, 0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64, 0, 0, 0, -64, 0, 0, -64, -64, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 55
-        -65, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, -65, 0, 0, -65, -65, 0, -65, -65, -65, 0, -65, -65,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 98, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 56
-        -66, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, 0, 0, 0, -66, 0, 0, -66, -66, 0, -66, -66, -66, 0, -66, -66,
+        0, 0, 0, 0, 0, 0, -62, 0, 0, 0, -62, 0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0, -62, 0, 0, -62, -62, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 57
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -63, 0, 0, 0, -63, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63, 0, 0, 0, -63, 0, 0, -63, -63, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 58
-        -35, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, -35, 0, 0, -35, -35, 0, -35, -35, -35, 0, -35, -35,
+        0, 0, 0, 0, 0, 0, -34, 0, 0, 0, -34, 0, 0, 0, -34, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, -34, 0, 0, -34, -34, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 59
-        -30, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, -30, 0, 0, -30, -30, 0, -30, -30, -30, 0, -30, -30,
+        0, -87, -87, -87, -87, 0, -87, -87, -87, -87, -87, -87, -87, -87, -87, 0, -87, -87, -87, -87, 0, -87, -87, -87, -87, 0, -87, -87, 0, 0, -87, 0, 0, -87, -87, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, -87, 0, 0, 0, 0,
         // State 60
-        -31, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, -31, 0, 0, -31, -31, 0, -31, -31, -31, 0, -31, -31,
+        0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 61
-        -34, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, -34, 0, 0, -34, -34, 0, -34, -34, -34, 0, -34, -34,
+        0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 62
-        -32, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, -32, 0, 0, -32, -32, 0, -32, -32, -32, 0, -32, -32,
+        0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 63
-        -33, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, -33, 0, 0, -33, -33, 0, -33, -33, -33, 0, -33, -33,
+        0, -83, 0, -83, -83, 0, -83, 0, -83, -83, -83, -83, 0, 0, -83, 0, -83, -83, -83, -83, 0, -83, -83, -83, -83, 0, -83, -83, 0, 0, -83, 0, 0, -83, -83, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, -83, 0, 0, 0, 0,
         // State 64
-        -71, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, -71, 0, 0, -71, -71, 0, -71, -71, -71, 0, -71, -71,
+        0, 0, 0, 0, 0, 0, -91, 0, 0, 0, -91, 0, 0, 0, -91, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, -91, 0, 0, -91, -91, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 65
-        -72, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, -72, 0, 0, -72, -72, 0, -72, -72, -72, 0, -72, -72,
+        0, 0, 0, 0, 0, 0, -120, 0, 0, 0, -120, 0, 0, 0, -120, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120, 0, 0, 0, -120, 0, 0, -120, -120, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 66
-        -83, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, 0, -83, 0, 0, -83, -83, 0, -83, -83, -83, 0, -83, -83,
+        0, -75, 0, -75, -75, 0, -75, 0, 0, 0, -75, 0, 0, 0, -75, 0, -75, -75, 0, -75, 0, -75, -75, -75, 0, 0, -75, -75, 0, 0, -75, 0, 0, -75, -75, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, -75, 0, 0, 0, 0,
         // State 67
-        0, -68, -68, 0, -68, -68, -68, -68, -68, -68, -68, -68, 0, -68, -68, -68, 0, -68, 0, -68, 0, 0, -68, 0, 0, 0, 0, 0, 0, -68, 0, 0,
+        0, 0, 0, 0, 0, 0, -65, 0, 0, 0, -65, 0, 0, 0, -65, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, -65, 0, 0, -65, -65, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 68
-        0, 0, 0, 0, 84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -85, 0, -85, -85, 0, -85, 0, 0, 0, -85, 0, 0, 0, -85, 0, -85, -85, -85, -85, 0, -85, -85, -85, -85, 0, -85, -85, 0, 0, -85, 0, 0, -85, -85, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, -85, 0, 0, 0, 0,
         // State 69
-        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 70
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 71
-        0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 72
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121, 0, 0,
         // State 73
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, 0, -44, -44, -44, -44, 0, -44, -44, -44, -44, -44, -44, -44, 0, 0, -44, 0, 0, -44, -44, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, -44, 0, 0, 0, 0,
         // State 74
-        0, 0, 0, 0, -80, 0, 0, -80, 0, -80, 0, 0, 0, 0, 0, 0, 0, -80, 0, -80, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 75
-        0, 0, 0, 0, -51, 0, 0, -51, 0, -51, 0, 0, 0, 0, 0, 0, 0, -51, 0, -51, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 76
-        0, 0, 0, 0, -29, 0, 0, -29, 0, -29, 0, 0, 0, 0, 0, 0, 0, -29, 0, -29, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 77
-        0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 78
-        0, 0, 0, 0, -37, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 79
-        0, -58, -58, 0, -58, 0, 0, -58, 0, -58, -58, -58, 0, -58, -58, -58, 0, -58, 0, -58, 0, 0, -58, 0, 0, 0, 0, 0, 0, -58, 0, 0,
+        0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 80
-        0, -60, -60, 0, -60, 0, -60, -60, -60, -60, -60, -60, 0, -60, -60, -60, 0, -60, 0, -60, 0, 0, -60, 0, 0, 0, 0, 0, 0, -60, 0, 0,
+        0, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 81
-        0, -62, -62, 0, -62, -62, -62, -62, -62, -62, -62, -62, 0, -62, -62, -62, 0, -62, 0, -62, 0, 0, -62, 0, 0, 0, 0, 0, 0, -62, 0, 0,
+        0, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 82
-        0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 83
-        0, -22, -22, 0, -22, -22, -22, -22, -22, -22, -22, -22, 0, -22, -22, -22, 0, -22, 0, -22, 0, 0, -22, 0, 0, 0, 0, 0, 0, -22, 0, 0,
+        0, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 84
-        0, 0, 0, 0, -52, 0, 0, -52, 0, -52, 0, 0, 0, 0, 0, 0, 0, -52, 0, -52, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 85
-        0, 0, 0, 0, -39, 0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 86
-        0, -23, -23, 0, -23, -23, -23, -23, -23, -23, -23, -23, 0, -23, -23, -23, 0, -23, 0, -23, 0, 0, -23, 0, 0, 0, 0, 0, 0, -23, 0, 0,
+        0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 87
-        -4, 0, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4, 0, -4, 0, -4, -4, 0, -4, -4, -4, -4, -4, -4, 0, -4, -4,
+        0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 88
-        0, -21, -21, 0, -21, -21, -21, -21, -21, -21, -21, -21, 0, -21, -21, -21, 0, -21, 0, -21, 0, 0, -21, 0, 0, 0, 0, 0, 0, -21, 0, 0,
+        0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 89
-        0, 0, 0, 0, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, 0, -43, -43, -43, -43, 0, -43, -43, -43, -43, -43, -43, -43, 0, 0, -43, 0, 0, -43, -43, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, -43, 0, 0, 0, 0,
         // State 90
-        0, 0, 0, 0, -41, 0, 0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, 0, -21, -21, -21, -21, 0, -21, -21, -21, -21, -21, -21, -21, 0, 0, -21, 0, 0, -21, -21, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, -21, 0, 0, 0, 0,
         // State 91
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, 0, -18, -18, -18, -18, 0, -18, -18, -18, -18, -18, -18, -18, 0, 0, -18, 0, 0, -18, -18, 0, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, -18, 0, 0, 0, 0,
         // State 92
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, 0, -20, -20, -20, -20, 0, -20, -20, -20, -20, -20, -20, -20, 0, 0, -20, 0, 0, -20, -20, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, -20, 0, 0, 0, 0,
         // State 93
-        0, 0, 0, 0, -64, 0, 0, -64, 0, -64, 0, 0, 0, 0, 0, 0, 0, -64, 0, -64, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, 0, -22, -22, -22, -22, 0, -22, -22, -22, -22, -22, -22, -22, 0, 0, -22, 0, 0, -22, -22, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, -22, 0, 0, 0, 0,
         // State 94
-        0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 95
-        -5, 0, 0, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5, 0, -5, 0, -5, -5, 0, -5, -5, -5, -5, -5, -5, 0, -5, -5,
+        -89, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, -89, 0, -89, 0, 0, 0, -89, 0, 0, -89, -89, -89, -89, -89, -89, 0, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, 0, 0, -89, -89, -89, -89,
         // State 96
-        0, 0, 0, 0, -43, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -90, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, -90, 0, -90, 0, 0, 0, -90, 0, 0, -90, -90, -90, -90, -90, -90, 0, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, 0, 0, -90, -90, -90, -90,
         // State 97
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 98
-        0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9,
+        -37, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, -37, 0, -37, 0, 0, 0, -37, 0, 0, -37, -37, -37, -37, -37, -37, 0, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, 0, 0, -37, -37, -37, -37,
         // State 99
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -39, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, -39, 0, -39, 0, 0, 0, -39, 0, 0, -39, -39, -39, -39, -39, -39, 0, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, 0, 0, -39, -39, -39, -39,
         // State 100
-        0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10,
+        -41, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, -41, 0, -41, 0, 0, 0, -41, 0, 0, -41, -41, -41, -41, -41, -41, 0, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, 0, 0, -41, -41, -41, -41,
         // State 101
-        0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -51, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, -51, 0, -51, 0, 0, 0, -51, 0, 0, -51, -51, -51, -51, -51, -51, 0, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, 0, 0, -51, -51, -51, -51,
         // State 102
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -46, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, -46, 0, -46, 0, 0, 0, -46, 0, 0, -46, -46, -46, -46, -46, -46, 0, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, 0, 0, -46, -46, -46, -46,
         // State 103
-        0, 0, 0, 0, -54, 0, 0, -54, 0, -54, 0, 0, 0, 0, 0, 0, 0, -54, 0, -54, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -47, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, -47, 0, -47, 0, 0, 0, -47, 0, 0, -47, -47, -47, -47, -47, -47, 0, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, 0, 0, -47, -47, -47, -47,
+        // State 104
+        -50, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, -50, 0, -50, 0, 0, 0, -50, 0, 0, -50, -50, -50, -50, -50, -50, 0, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, 0, 0, -50, -50, -50, -50,
+        // State 105
+        -48, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48, -48, 0, -48, 0, 0, 0, -48, 0, 0, -48, -48, -48, -48, -48, -48, 0, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, 0, 0, -48, -48, -48, -48,
+        // State 106
+        -49, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, -49, 0, -49, 0, 0, 0, -49, 0, 0, -49, -49, -49, -49, -49, -49, 0, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, 0, 0, -49, -49, -49, -49,
+        // State 107
+        -94, 0, 0, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, -94, 0, -94, 0, 0, 0, -94, 0, 0, -94, -94, -94, -94, -94, -94, 0, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, 0, 0, -94, -94, -94, -94,
+        // State 108
+        -96, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, -96, 0, -96, 0, 0, 0, -96, 0, 0, -96, -96, -96, -96, -96, -96, 0, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, 0, 0, -96, -96, -96, -96,
+        // State 109
+        -95, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, -95, 0, -95, 0, 0, 0, -95, 0, 0, -95, -95, -95, -95, -95, -95, 0, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, 0, 0, -95, -95, -95, -95,
+        // State 110
+        -117, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, -117, 0, -117, 0, 0, 0, -117, 0, 0, -117, -117, -117, -117, -117, -117, 0, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, 0, 0, -117, -117, -117, -117,
+        // State 111
+        -118, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, -118, 0, -118, 0, 0, 0, -118, 0, 0, -118, -118, -118, -118, -118, -118, 0, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, 0, 0, -118, -118, -118, -118,
+        // State 112
+        -126, 0, 0, 0, 0, -126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -126, -126, 0, -126, 0, 0, 0, -126, 0, 0, -126, -126, -126, -126, -126, -126, 0, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, 0, 0, -126, -126, -126, -126,
+        // State 113
+        -123, 0, 0, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -123, -123, 0, -123, 0, 0, 0, -123, 0, 0, -123, -123, -123, -123, -123, -123, 0, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, 0, 0, -123, -123, -123, -123,
+        // State 114
+        -124, 0, 0, 0, 0, -124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -124, -124, 0, -124, 0, 0, 0, -124, 0, 0, -124, -124, -124, -124, -124, -124, 0, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, 0, 0, -124, -124, -124, -124,
+        // State 115
+        -125, 0, 0, 0, 0, -125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -125, -125, 0, -125, 0, 0, 0, -125, 0, 0, -125, -125, -125, -125, -125, -125, 0, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, 0, 0, -125, -125, -125, -125,
+        // State 116
+        0, 0, 0, 0, 0, 0, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 117
+        0, -92, -92, -92, -92, 15, -92, -92, -92, -92, -92, -92, -92, -92, -92, 0, -92, -92, -92, -92, 0, -92, -92, -92, -92, 34, -92, -92, 0, 0, -92, 0, 0, -92, -92, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, -92, 0, 0, 0, 0,
+        // State 118
+        0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 119
+        0, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, 0, -29, -29, -29, -29, 0, -29, -29, -29, -29, -29, -29, -29, 0, 0, -29, 0, 0, -29, -29, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, -29, 0, 0, 0, 0,
+        // State 120
+        0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 121
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 122
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 123
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 124
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 125
+        0, 0, 0, 0, 0, 0, 153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 126
+        0, 0, 0, 0, 0, 0, -53, 0, 0, 0, 154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 127
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 128
+        0, -93, -93, -93, -93, 15, -93, -93, -93, -93, -93, -93, -93, -93, -93, 0, -93, -93, -93, -93, 0, -93, -93, -93, -93, 34, -93, -93, 0, 0, -93, 0, 0, -93, -93, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, -93, 0, 0, 0, 0,
+        // State 129
+        0, 0, 0, 0, 0, 0, -114, 0, 0, 0, -114, 0, 0, 0, -114, 0, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, 0, 0, 0, -114, 0, 0, -114, -114, 0, 0, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 130
+        0, 0, 0, 0, 0, 0, -68, 0, 0, 0, -68, 0, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0, 0, 0, -68, 0, 0, -68, -68, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 131
+        0, 0, 0, 0, 0, 0, -45, 0, 0, 0, -45, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, -45, 0, 0, -45, -45, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 132
+        0, -74, 0, -74, -74, 0, -74, 0, 0, 0, -74, 0, 0, 0, -74, 0, -74, -74, 0, -74, 0, -74, -74, -74, 0, 0, -74, -74, 0, 0, -74, 0, 0, -74, -74, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, -74, 0, 0, 0, 0,
+        // State 133
+        0, -76, 0, 0, -76, 0, -76, 0, 0, 0, -76, 0, 0, 0, -76, 0, -76, -76, 0, -76, 0, -76, -76, -76, 0, 0, -76, 0, 0, 0, -76, 0, 0, -76, -76, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, -76, 0, 0, 0, 0,
+        // State 134
+        0, -78, 0, 0, -78, 0, -78, 0, 0, 0, -78, 0, 0, 0, -78, 0, -78, -78, 0, -78, 0, -78, -78, -78, 0, 0, -78, -78, 0, 0, -78, 0, 0, -78, -78, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, -78, 0, 0, 0, 0,
+        // State 135
+        0, -80, 0, 0, -80, 0, -80, 0, 0, 0, -80, 0, 0, 0, -80, 0, -80, -80, 0, -80, 0, -80, -80, -80, 0, 0, -80, 0, 0, 0, -80, 0, 0, -80, -80, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, 0,
+        // State 136
+        0, -82, 0, -82, -82, 0, -82, 0, -82, -82, -82, -82, 0, 0, -82, 0, -82, -82, -82, -82, 0, -82, -82, -82, -82, 0, -82, -82, 0, 0, -82, 0, 0, -82, -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, -82, 0, 0, 0, 0,
+        // State 137
+        0, -84, 0, -84, -84, 0, -84, 0, 0, 0, -84, 0, 0, 0, -84, 0, -84, -84, -84, -84, 0, -84, -84, -84, -84, 0, -84, -84, 0, 0, -84, 0, 0, -84, -84, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84, -84, 0, 0, 0, 0,
+        // State 138
+        0, -86, -86, -86, -86, 0, -86, -86, -86, -86, -86, -86, -86, -86, -86, 0, -86, -86, -86, -86, 0, -86, -86, -86, -86, 0, -86, -86, 0, 0, -86, 0, 0, -86, -86, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, -86, 0, 0, 0, 0,
+        // State 139
+        0, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, 0, -26, -26, -26, -26, 0, -26, -26, -26, -26, -26, -26, -26, 0, 0, -26, 0, 0, -26, -26, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, -26, 0, 0, 0, 0,
+        // State 140
+        0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 141
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 142
+        0, 0, 0, 0, 0, 0, -61, 0, 0, 0, -61, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0, -61, 0, 0, -61, -61, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 143
+        0, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, 0, -27, -27, -27, -27, 0, -27, -27, -27, -27, -27, -27, -27, 0, 0, -27, 0, 0, -27, -27, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, -27, 0, 0, 0, 0,
+        // State 144
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 145
+        0, 0, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 162, 0, 0,
+        // State 146
+        0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 147
+        0, 0, 0, 0, 0, 0, -57, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 148
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 165, 0, 0,
+        // State 149
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 150
+        0, 0, 0, 0, 0, 0, -69, 0, 0, 0, -69, 0, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, 0, 0, -69, 0, 0, -69, -69, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 151
+        0, 0, 0, 0, 0, 0, -55, 0, 0, 0, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 152
+        0, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, 0, -32, -32, -32, -32, 0, -32, -32, -32, -32, -32, -32, -32, 0, 0, -32, 0, 0, -32, -32, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, -32, 0, 0, 0, 0,
+        // State 153
+        -4, 0, 0, 0, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4, -4, 0, -4, -4, 0, 0, -4, -4, 0, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, 0, 0, -4, -4, -4, -4,
+        // State 154
+        0, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, 42, -31, -31, -31, -31, 0, -31, -31, -31, -31, -31, -31, -31, 0, 0, -31, 0, 0, -31, -31, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, -31, 0, 0, 0, 0,
+        // State 155
+        0, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, 0, -23, -23, -23, -23, 0, -23, -23, -23, -23, -23, -23, -23, 0, 0, -23, 0, 0, -23, -23, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23, -23, 0, 0, 0, 0,
+        // State 156
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 170, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 157
+        0, 0, 0, 0, 0, 0, 171, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 158
+        0, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, 0, -28, -28, -28, -28, 0, -28, -28, -28, -28, -28, -28, -28, 0, 0, -28, 0, 0, -28, -28, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, -28, 0, 0, 0, 0,
+        // State 159
+        0, 0, 0, 0, 0, 0, 172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 160
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 161
+        0, 0, 0, 0, 0, 0, -59, 0, 0, 0, 174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 162
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 163
+        0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, 0,
+        // State 164
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 165
+        0, 0, 0, 0, 0, 0, -88, 0, 0, 0, -88, 0, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, 0, 0, 0, -88, 0, 0, -88, -88, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 166
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 167
+        -5, 0, 0, 0, 0, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5, -5, 0, -5, -5, 0, 0, -5, -5, 0, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, 0, 0, -5, -5, -5, -5,
+        // State 168
+        0, 0, 0, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 169
+        0, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, 0, -31, -31, -31, -31, 0, -31, -31, -31, -31, -31, -31, -31, 0, 0, -31, 0, 0, -31, -31, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, -31, 0, 0, 0, 0,
+        // State 170
+        0, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, 0, -30, -30, -30, -30, 0, -30, -30, -30, -30, -30, -30, -30, 0, 0, -30, 0, 0, -30, -30, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, -30, 0, 0, 0, 0,
+        // State 171
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 172
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 173
+        0, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, 0,
+        // State 174
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 175
+        0, 0, 0, 0, 0, 0, -116, 0, 0, 0, -116, 0, 0, 0, -116, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, 0, 0, 0, -116, 0, 0, -116, -116, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 176
+        0, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, 0, -24, -24, -24, -24, 0, -24, -24, -24, -24, -24, -24, -24, 0, 0, -24, 0, 0, -24, -24, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24, -24, 0, 0, 0, 0,
+        // State 177
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 182, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 178
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 179
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 180
+        0, 0, 0, 0, 0, 0, -71, 0, 0, 0, -71, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, -71, 0, 0, -71, -71, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 181
+        0, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, 0, -33, -33, -33, -33, 0, -33, -33, -33, -33, -33, -33, -33, 0, 0, -33, 0, 0, -33, -33, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, -33, 0, 0, 0, 0,
+        // State 182
+        0, 0, 0, 0, 0, 0, 184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 183
+        0, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, 0, -25, -25, -25, -25, 0, -25, -25, -25, -25, -25, -25, -25, 0, 0, -25, 0, 0, -25, -25, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, -25, 0, 0, 0, 0,
     ];
-    fn __action(state: i8, integer: usize) -> i8 {
-        __ACTION[(state as usize) * 32 + integer]
+    fn __action(state: i16, integer: usize) -> i16 {
+        __ACTION[(state as usize) * 61 + integer]
     }
-    const __EOF_ACTION: &[i8] = &[
+    const __EOF_ACTION: &[i16] = &[
         // State 0
         0,
         // State 1
         0,
         // State 2
-        -81,
+        -115,
         // State 3
-        -36,
+        -38,
         // State 4
-        -82,
+        -40,
         // State 5
-        -78,
+        -42,
         // State 6
-        0,
+        -52,
         // State 7
-        0,
+        -122,
         // State 8
-        0,
+        -119,
         // State 9
-        0,
+        -112,
         // State 10
         0,
         // State 11
@@ -320,67 +481,67 @@ mod __parse__Exp {
         // State 28
         0,
         // State 29
-        -69,
+        0,
         // State 30
-        -48,
+        0,
         // State 31
-        -19,
+        0,
         // State 32
-        -84,
+        0,
         // State 33
-        -47,
+        0,
         // State 34
         0,
         // State 35
-        -45,
+        0,
         // State 36
-        -18,
+        0,
         // State 37
-        -46,
+        0,
         // State 38
-        -24,
+        0,
         // State 39
-        -63,
+        0,
         // State 40
-        -20,
+        0,
         // State 41
         0,
         // State 42
-        -61,
+        0,
         // State 43
-        -67,
+        0,
         // State 44
-        -59,
+        0,
         // State 45
         0,
         // State 46
-        -28,
-        // State 47
         0,
+        // State 47
+        -93,
         // State 48
-        0,
+        -121,
         // State 49
-        0,
+        -79,
         // State 50
-        0,
+        -81,
         // State 51
-        -27,
+        -77,
         // State 52
-        -70,
+        -19,
         // State 53
-        -55,
+        -127,
         // State 54
-        0,
+        -64,
         // State 55
         0,
         // State 56
-        0,
+        -62,
         // State 57
-        0,
+        -63,
         // State 58
-        0,
+        -34,
         // State 59
-        0,
+        -87,
         // State 60
         0,
         // State 61
@@ -388,17 +549,17 @@ mod __parse__Exp {
         // State 62
         0,
         // State 63
-        0,
+        -83,
         // State 64
-        0,
+        -91,
         // State 65
-        0,
+        -120,
         // State 66
-        0,
+        -75,
         // State 67
-        -68,
+        -65,
         // State 68
-        0,
+        -85,
         // State 69
         0,
         // State 70
@@ -408,47 +569,47 @@ mod __parse__Exp {
         // State 72
         0,
         // State 73
-        0,
+        -44,
         // State 74
-        -80,
+        0,
         // State 75
-        -51,
+        0,
         // State 76
-        -29,
+        0,
         // State 77
         0,
         // State 78
         0,
         // State 79
-        -58,
+        0,
         // State 80
-        -60,
+        0,
         // State 81
-        -62,
+        0,
         // State 82
         0,
         // State 83
-        -22,
+        0,
         // State 84
-        -52,
+        0,
         // State 85
         0,
         // State 86
-        -23,
+        0,
         // State 87
         0,
         // State 88
-        -21,
-        // State 89
         0,
+        // State 89
+        -43,
         // State 90
-        0,
+        -21,
         // State 91
-        0,
+        -18,
         // State 92
-        0,
+        -20,
         // State 93
-        -64,
+        -22,
         // State 94
         0,
         // State 95
@@ -468,120 +629,348 @@ mod __parse__Exp {
         // State 102
         0,
         // State 103
-        -54,
+        0,
+        // State 104
+        0,
+        // State 105
+        0,
+        // State 106
+        0,
+        // State 107
+        0,
+        // State 108
+        0,
+        // State 109
+        0,
+        // State 110
+        0,
+        // State 111
+        0,
+        // State 112
+        0,
+        // State 113
+        0,
+        // State 114
+        0,
+        // State 115
+        0,
+        // State 116
+        0,
+        // State 117
+        -92,
+        // State 118
+        0,
+        // State 119
+        -29,
+        // State 120
+        0,
+        // State 121
+        0,
+        // State 122
+        0,
+        // State 123
+        0,
+        // State 124
+        0,
+        // State 125
+        0,
+        // State 126
+        0,
+        // State 127
+        0,
+        // State 128
+        -93,
+        // State 129
+        -114,
+        // State 130
+        -68,
+        // State 131
+        -45,
+        // State 132
+        -74,
+        // State 133
+        -76,
+        // State 134
+        -78,
+        // State 135
+        -80,
+        // State 136
+        -82,
+        // State 137
+        -84,
+        // State 138
+        -86,
+        // State 139
+        -26,
+        // State 140
+        0,
+        // State 141
+        0,
+        // State 142
+        -61,
+        // State 143
+        -27,
+        // State 144
+        0,
+        // State 145
+        0,
+        // State 146
+        0,
+        // State 147
+        0,
+        // State 148
+        0,
+        // State 149
+        0,
+        // State 150
+        -69,
+        // State 151
+        0,
+        // State 152
+        -32,
+        // State 153
+        0,
+        // State 154
+        -31,
+        // State 155
+        -23,
+        // State 156
+        0,
+        // State 157
+        0,
+        // State 158
+        -28,
+        // State 159
+        0,
+        // State 160
+        0,
+        // State 161
+        0,
+        // State 162
+        0,
+        // State 163
+        0,
+        // State 164
+        0,
+        // State 165
+        -88,
+        // State 166
+        0,
+        // State 167
+        0,
+        // State 168
+        0,
+        // State 169
+        -31,
+        // State 170
+        -30,
+        // State 171
+        0,
+        // State 172
+        0,
+        // State 173
+        0,
+        // State 174
+        0,
+        // State 175
+        -116,
+        // State 176
+        -24,
+        // State 177
+        0,
+        // State 178
+        0,
+        // State 179
+        0,
+        // State 180
+        -71,
+        // State 181
+        -33,
+        // State 182
+        0,
+        // State 183
+        -25,
     ];
-    fn __goto(state: i8, nt: usize) -> i8 {
+    fn __goto(state: i16, nt: usize) -> i16 {
         match nt {
-            2 => 19,
-            5 => 25,
+            2 => 32,
+            5 => 145,
             8 => 1,
             11 => match state {
-                6 => 67,
-                _ => 29,
+                10 => 117,
+                16 | 18..=24 => 128,
+                _ => 47,
             },
-            12 => 30,
-            13 => 71,
-            14 => 31,
+            12 => 48,
+            13 => 122,
+            14 => 18,
             15 => match state {
-                18 => 84,
-                _ => 75,
+                20 => 134,
+                _ => 49,
+            },
+            16 => 19,
+            17 => match state {
+                21 => 135,
+                _ => 50,
+            },
+            18 => 20,
+            19 => match state {
+                19 => 133,
+                _ => 51,
             },
-            16 => 14,
-            17 => 2,
-            18 => 77,
-            19 => 89,
-            20 => match state {
-                0 => 32,
-                7 => 68,
-                9 => 70,
-                13 => 78,
-                17 => 82,
-                19 => 85,
-                21 => 91,
-                23 => 93,
-                24 => 94,
-                26 => 101,
-                27 => 102,
-                28 => 103,
-                _ => 76,
+            20 => 52,
+            21 => match state {
+                31 => 150,
+                42 => 177,
+                _ => 130,
             },
-            22 => 33,
-            23 => match state {
-                1 => 54,
-                _ => 34,
+            22 => 21,
+            23 => 2,
+            24 => match state {
+                34 => 157,
+                _ => 125,
             },
-            24 => 35,
             25 => match state {
-                8 => 69,
-                10 => 72,
-                20 => 90,
-                22 => 92,
-                25 => 96,
-                _ => 36,
+                35 => 159,
+                _ => 146,
             },
-            27 => 3,
-            28 => 4,
-            29 => 5,
-            30 => 37,
-            31 => 11,
-            32 => 38,
-            33 => match state {
-                16 => 81,
-                _ => 39,
+            26 => match state {
+                0 => 53,
+                11 => 118,
+                12 => 121,
+                14 | 34 => 126,
+                15 => 127,
+                25 => 140,
+                26 => 141,
+                27 => 142,
+                28 => 144,
+                30 => 149,
+                32 => 151,
+                33 => 156,
+                36 => 160,
+                37 => 165,
+                38 => 166,
+                39 => 168,
+                40 => 174,
+                43 => 178,
+                44 => 179,
+                45 => 180,
+                46 => 182,
+                _ => 131,
             },
-            34 => 40,
-            35 => 15,
-            36 => 41,
-            37 => match state {
-                15 => 80,
-                _ => 42,
+            28 => 54,
+            29 => match state {
+                1 => 94,
+                _ => 55,
             },
-            39 => match state {
-                11 => 74,
-                _ => 43,
+            30 => 56,
+            32 => 3,
+            33 => 4,
+            34 => 5,
+            35 => 6,
+            36 => 7,
+            37 => 8,
+            38 => 9,
+            39 => 57,
+            40 => 16,
+            41 => 58,
+            42 => match state {
+                24 => 138,
+                _ => 59,
             },
-            40 => match state {
-                14 => 79,
-                _ => 44,
+            43 => 22,
+            44 => 60,
+            45 => 61,
+            46 => 62,
+            47 => match state {
+                22 => 136,
+                _ => 63,
             },
-            41 => 16,
+            49 => match state {
+                16 => 129,
+                _ => 64,
+            },
+            50 => 65,
+            51 => 23,
+            52 => match state {
+                18 => 132,
+                _ => 66,
+            },
+            53 => match state {
+                41 => 175,
+                _ => 67,
+            },
+            54 => match state {
+                23 => 137,
+                _ => 68,
+            },
+            55 => 24,
             _ => 0,
         }
     }
-    fn __expected_tokens(__state: i8) -> alloc::vec::Vec<alloc::string::String> {
+    fn __expected_tokens(__state: i16) -> alloc::vec::Vec<alloc::string::String> {
         const __TERMINAL: &[&str] = &[
             r###""!""###,
             r###""!=""###,
+            r###""%""###,
+            r###""&""###,
             r###""&&""###,
             r###""(""###,
             r###"")""###,
             r###""*""###,
             r###""+""###,
+            r###""++""###,
             r###"",""###,
             r###""-""###,
+            r###""/""###,
+            r###""//""###,
             r###"":""###,
+            r###"":=""###,
+            r###"";""###,
             r###""<""###,
+            r###""<<""###,
             r###""<=""###,
             r###""=""###,
             r###""==""###,
             r###"">""###,
             r###"">=""###,
+            r###"">>""###,
+            r###""[""###,
+            r###""]""###,
+            r###""^""###,
+            r###""abs""###,
             r###""add1""###,
             r###""and""###,
+            r###""block""###,
             r###""def""###,
             r###""else""###,
+            r###""end""###,
             r###""false""###,
             r###""if""###,
             r###""in""###,
             r###""isbool""###,
             r###""isnum""###,
+            r###""isstring""###,
+            r###""istuple""###,
+            r###""lambda""###,
+            r###""len""###,
             r###""let""###,
+            r###""max""###,
+            r###""min""###,
             r###""print""###,
+            r###""readbool""###,
+            r###""readnum""###,
             r###""sub1""###,
+            r###""substr""###,
+            r###""tonum""###,
+            r###""tostring""###,
             r###""true""###,
+            r###""|""###,
             r###""||""###,
-            r###"r#"[+-]?[0-9]+"#"###,
-            r###"r#"[a-zA-Z][a-zA-Z0-9_]*"#"###,
+            r###"Float"###,
+            r###"Id"###,
+            r###"Num"###,
+            r###"Str"###,
         ];
         __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
             let next_state = __action(__state, index);
@@ -592,24 +981,23 @@ mod __parse__Exp {
             }
         }).collect()
     }
-    pub(crate) struct __StateMachine<'input>
+    pub(crate) struct __StateMachine<>
     where 
     {
-        input: &'input str,
-        __phantom: core::marker::PhantomData<(&'input ())>,
+        __phantom: core::marker::PhantomData<()>,
     }
-    impl<'input> __state_machine::ParserDefinition for __StateMachine<'input>
+    impl<> __state_machine::ParserDefinition for __StateMachine<>
     where 
     {
         type Location = usize;
-        type Error = &'static str;
-        type Token = Token<'input>;
+        type Error = LexicalError;
+        type Token = Tok;
         type TokenIndex = usize;
-        type Symbol = __Symbol<'input>;
+        type Symbol = __Symbol<>;
         type Success = Exp<Span1>;
-        type StateIndex = i8;
-        type Action = i8;
-        type ReduceIndex = i8;
+        type StateIndex = i16;
+        type Action = i16;
+        type ReduceIndex = i16;
         type NonterminalIndex = usize;
 
         #[inline]
@@ -624,34 +1012,34 @@ mod __parse__Exp {
 
         #[inline]
         fn token_to_index(&self, token: &Self::Token) -> Option<usize> {
-            __token_to_integer(token, core::marker::PhantomData::<(&())>)
+            __token_to_integer(token, core::marker::PhantomData::<()>)
         }
 
         #[inline]
-        fn action(&self, state: i8, integer: usize) -> i8 {
+        fn action(&self, state: i16, integer: usize) -> i16 {
             __action(state, integer)
         }
 
         #[inline]
-        fn error_action(&self, state: i8) -> i8 {
-            __action(state, 32 - 1)
+        fn error_action(&self, state: i16) -> i16 {
+            __action(state, 61 - 1)
         }
 
         #[inline]
-        fn eof_action(&self, state: i8) -> i8 {
+        fn eof_action(&self, state: i16) -> i16 {
             __EOF_ACTION[state as usize]
         }
 
         #[inline]
-        fn goto(&self, state: i8, nt: usize) -> i8 {
+        fn goto(&self, state: i16, nt: usize) -> i16 {
             __goto(state, nt)
         }
 
         fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
-            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&())>)
+            __token_to_symbol(token_index, token, core::marker::PhantomData::<()>)
         }
 
-        fn expected_tokens(&self, state: i8) -> alloc::vec::Vec<alloc::string::String> {
+        fn expected_tokens(&self, state: i16) -> alloc::vec::Vec<alloc::string::String> {
             __expected_tokens(state)
         }
 
@@ -670,387 +1058,547 @@ mod __parse__Exp {
 
         fn reduce(
             &mut self,
-            action: i8,
+            action: i16,
             start_location: Option<&Self::Location>,
-            states: &mut alloc::vec::Vec<i8>,
+            states: &mut alloc::vec::Vec<i16>,
             symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
         ) -> Option<__state_machine::ParseResult<Self>> {
             __reduce(
-                self.input,
                 action,
                 start_location,
                 states,
                 symbols,
-                core::marker::PhantomData::<(&())>,
+                core::marker::PhantomData::<()>,
             )
         }
 
-        fn simulate_reduce(&self, action: i8) -> __state_machine::SimulatedReduce<Self> {
+        fn simulate_reduce(&self, action: i16) -> __state_machine::SimulatedReduce<Self> {
             panic!("error recovery not enabled for this grammar")
         }
     }
     fn __token_to_integer<
-        'input,
     >(
-        __token: &Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __token: &Tok,
+        _: core::marker::PhantomData<()>,
     ) -> Option<usize>
     {
         match *__token {
-            Token(2, _) if true => Some(0),
-            Token(3, _) if true => Some(1),
-            Token(4, _) if true => Some(2),
-            Token(5, _) if true => Some(3),
-            Token(6, _) if true => Some(4),
-            Token(7, _) if true => Some(5),
-            Token(8, _) if true => Some(6),
-            Token(9, _) if true => Some(7),
-            Token(10, _) if true => Some(8),
-            Token(11, _) if true => Some(9),
-            Token(12, _) if true => Some(10),
-            Token(13, _) if true => Some(11),
-            Token(14, _) if true => Some(12),
-            Token(15, _) if true => Some(13),
-            Token(16, _) if true => Some(14),
-            Token(17, _) if true => Some(15),
-            Token(18, _) if true => Some(16),
-            Token(19, _) if true => Some(17),
-            Token(20, _) if true => Some(18),
-            Token(21, _) if true => Some(19),
-            Token(22, _) if true => Some(20),
-            Token(23, _) if true => Some(21),
-            Token(24, _) if true => Some(22),
-            Token(25, _) if true => Some(23),
-            Token(26, _) if true => Some(24),
-            Token(27, _) if true => Some(25),
-            Token(28, _) if true => Some(26),
-            Token(29, _) if true => Some(27),
-            Token(30, _) if true => Some(28),
-            Token(31, _) if true => Some(29),
-            Token(0, _) if true => Some(30),
-            Token(1, _) if true => Some(31),
+            Tok::Bang if true => Some(0),
+            Tok::Neq if true => Some(1),
+            Tok::Percent if true => Some(2),
+            Tok::Amp if true => Some(3),
+            Tok::AndAnd if true => Some(4),
+            Tok::LParen if true => Some(5),
+            Tok::RParen if true => Some(6),
+            Tok::Times if true => Some(7),
+            Tok::Plus if true => Some(8),
+            Tok::PlusPlus if true => Some(9),
+            Tok::Comma if true => Some(10),
+            Tok::Minus if true => Some(11),
+            Tok::Divide if true => Some(12),
+            Tok::SlashSlash if true => Some(13),
+            Tok::Colon if true => Some(14),
+            Tok::ColonEqual if true => Some(15),
+            Tok::Semicolon if true => Some(16),
+            Tok::Lt if true => Some(17),
+            Tok::LtLt if true => Some(18),
+            Tok::Le if true => Some(19),
+            Tok::Equal if true => Some(20),
+            Tok::EqEq if true => Some(21),
+            Tok::Gt if true => Some(22),
+            Tok::Ge if true => Some(23),
+            Tok::GtGt if true => Some(24),
+            Tok::LBracket if true => Some(25),
+            Tok::RBracket if true => Some(26),
+            Tok::Caret if true => Some(27),
+            Tok::Abs if true => Some(28),
+            Tok::Add1 if true => Some(29),
+            Tok::And if true => Some(30),
+            Tok::Block if true => Some(31),
+            Tok::Def if true => Some(32),
+            Tok::Else if true => Some(33),
+            Tok::End if true => Some(34),
+            Tok::False if true => Some(35),
+            Tok::If if true => Some(36),
+            Tok::In if true => Some(37),
+            Tok::IsBool if true => Some(38),
+            Tok::IsNum if true => Some(39),
+            Tok::IsStr if true => Some(40),
+            Tok::IsTuple if true => Some(41),
+            Tok::Lambda if true => Some(42),
+            Tok::Len if true => Some(43),
+            Tok::Let if true => Some(44),
+            Tok::Max if true => Some(45),
+            Tok::Min if true => Some(46),
+            Tok::Print if true => Some(47),
+            Tok::ReadBool if true => Some(48),
+            Tok::ReadNum if true => Some(49),
+            Tok::Sub1 if true => Some(50),
+            Tok::Substr if true => Some(51),
+            Tok::ToNum if true => Some(52),
+            Tok::ToStr if true => Some(53),
+            Tok::True if true => Some(54),
+            Tok::Pipe if true => Some(55),
+            Tok::OrOr if true => Some(56),
+            Tok::Float(_) if true => Some(57),
+            Tok::Id(_) if true => Some(58),
+            Tok::Num(_) if true => Some(59),
+            Tok::Str(_) if true => Some(60),
             _ => None,
         }
     }
     fn __token_to_symbol<
-        'input,
     >(
         __token_index: usize,
-        __token: Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> __Symbol<'input>
+        __token: Tok,
+        _: core::marker::PhantomData<()>,
+    ) -> __Symbol<>
     {
         match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 => match __token {
-                Token(2, __tok0) | Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) | Token(30, __tok0) | Token(31, __tok0) | Token(0, __tok0) | Token(1, __tok0) if true => __Symbol::Variant0(__tok0),
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 49 | 50 | 51 | 52 | 53 | 54 | 55 | 56 => __Symbol::Variant0(__token),
+            57 => match __token {
+                Tok::Float(__tok0) if true => __Symbol::Variant1(__tok0),
+                _ => unreachable!(),
+            },
+            58 | 60 => match __token {
+                Tok::Id(__tok0) | Tok::Str(__tok0) if true => __Symbol::Variant2(__tok0),
+                _ => unreachable!(),
+            },
+            59 => match __token {
+                Tok::Num(__tok0) if true => __Symbol::Variant3(__tok0),
                 _ => unreachable!(),
             },
             _ => unreachable!(),
         }
     }
     pub struct ExpParser {
-        builder: __lalrpop_util::lexer::MatcherBuilder,
         _priv: (),
     }
 
     impl ExpParser {
         pub fn new() -> ExpParser {
-            let __builder = super::__intern_token::new_builder();
             ExpParser {
-                builder: __builder,
                 _priv: (),
             }
         }
 
         #[allow(dead_code)]
         pub fn parse<
-            'input,
+            __TOKEN: __ToTriple<>,
+            __TOKENS: IntoIterator<Item=__TOKEN>,
         >(
             &self,
-            input: &'input str,
-        ) -> Result<Exp<Span1>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            __tokens0: __TOKENS,
+        ) -> Result<Exp<Span1>, __lalrpop_util::ParseError<usize, Tok, LexicalError>>
         {
-            let mut __tokens = self.builder.matcher(input);
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
             __state_machine::Parser::drive(
                 __StateMachine {
-                    input,
-                    __phantom: core::marker::PhantomData::<(&())>,
+                    __phantom: core::marker::PhantomData::<()>,
                 },
                 __tokens,
             )
         }
     }
     pub(crate) fn __reduce<
-        'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut alloc::vec::Vec<i8>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> Option<Result<Exp<Span1>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+        __states: &mut alloc::vec::Vec<i16>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> Option<Result<Exp<Span1>,__lalrpop_util::ParseError<usize, Tok, LexicalError>>>
     {
         let (__pop_states, __nonterminal) = match __action {
             0 => {
-                __reduce0(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce0(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             1 => {
-                __reduce1(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce1(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             2 => {
-                __reduce2(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce2(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             3 => {
-                __reduce3(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce3(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             4 => {
-                __reduce4(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce4(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             5 => {
-                __reduce5(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce5(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             6 => {
-                __reduce6(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce6(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             7 => {
-                __reduce7(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce7(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             8 => {
-                __reduce8(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce8(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             9 => {
-                __reduce9(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce9(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             10 => {
-                __reduce10(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce10(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             11 => {
-                __reduce11(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce11(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             12 => {
-                __reduce12(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce12(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             13 => {
-                __reduce13(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce13(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             14 => {
-                __reduce14(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce14(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             15 => {
-                __reduce15(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce15(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             16 => {
-                __reduce16(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce16(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             17 => {
-                __reduce17(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce17(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             18 => {
-                __reduce18(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce18(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             19 => {
-                __reduce19(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce19(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             20 => {
-                __reduce20(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce20(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             21 => {
-                __reduce21(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce21(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             22 => {
-                __reduce22(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce22(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             23 => {
-                __reduce23(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce23(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             24 => {
-                __reduce24(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce24(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             25 => {
-                __reduce25(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce25(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             26 => {
-                __reduce26(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce26(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             27 => {
-                __reduce27(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce27(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             28 => {
-                __reduce28(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce28(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             29 => {
-                __reduce29(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce29(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             30 => {
-                __reduce30(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce30(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             31 => {
-                __reduce31(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce31(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             32 => {
-                __reduce32(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce32(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             33 => {
-                __reduce33(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce33(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             34 => {
-                __reduce34(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce34(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             35 => {
-                __reduce35(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce35(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             36 => {
-                __reduce36(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce36(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             37 => {
-                __reduce37(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce37(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             38 => {
-                __reduce38(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce38(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             39 => {
-                __reduce39(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce39(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             40 => {
-                __reduce40(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce40(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             41 => {
-                __reduce41(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce41(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             42 => {
-                __reduce42(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce42(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             43 => {
-                __reduce43(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce43(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             44 => {
-                __reduce44(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce44(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             45 => {
-                __reduce45(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce45(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             46 => {
-                __reduce46(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce46(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             47 => {
-                __reduce47(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce47(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             48 => {
-                __reduce48(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce48(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             49 => {
-                __reduce49(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce49(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             50 => {
-                __reduce50(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce50(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             51 => {
-                __reduce51(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce51(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             52 => {
-                __reduce52(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce52(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             53 => {
-                __reduce53(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce53(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             54 => {
-                __reduce54(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce54(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             55 => {
-                __reduce55(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce55(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             56 => {
-                __reduce56(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce56(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             57 => {
-                __reduce57(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce57(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             58 => {
-                __reduce58(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce58(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             59 => {
-                __reduce59(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce59(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             60 => {
-                __reduce60(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce60(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             61 => {
-                __reduce61(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce61(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             62 => {
-                __reduce62(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce62(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             63 => {
-                __reduce63(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce63(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             64 => {
-                __reduce64(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce64(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             65 => {
-                __reduce65(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce65(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             66 => {
-                __reduce66(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce66(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             67 => {
-                __reduce67(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce67(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             68 => {
-                __reduce68(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce68(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             69 => {
-                __reduce69(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce69(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             70 => {
-                __reduce70(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce70(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             71 => {
-                __reduce71(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce71(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             72 => {
-                __reduce72(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce72(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             73 => {
-                __reduce73(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce73(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             74 => {
-                __reduce74(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce74(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             75 => {
-                __reduce75(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce75(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             76 => {
-                __reduce76(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce76(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             77 => {
-                __reduce77(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce77(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             78 => {
-                __reduce78(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce78(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             79 => {
-                __reduce79(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce79(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             80 => {
-                __reduce80(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce80(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             81 => {
-                __reduce81(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce81(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             82 => {
-                __reduce82(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce82(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             83 => {
+                __reduce83(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            84 => {
+                __reduce84(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            85 => {
+                __reduce85(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            86 => {
+                __reduce86(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            87 => {
+                __reduce87(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            88 => {
+                __reduce88(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            89 => {
+                __reduce89(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            90 => {
+                __reduce90(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            91 => {
+                __reduce91(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            92 => {
+                __reduce92(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            93 => {
+                __reduce93(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            94 => {
+                __reduce94(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            95 => {
+                __reduce95(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            96 => {
+                __reduce96(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            97 => {
+                __reduce97(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            98 => {
+                __reduce98(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            99 => {
+                __reduce99(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            100 => {
+                __reduce100(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            101 => {
+                __reduce101(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            102 => {
+                __reduce102(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            103 => {
+                __reduce103(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            104 => {
+                __reduce104(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            105 => {
+                __reduce105(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            106 => {
+                __reduce106(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            107 => {
+                __reduce107(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            108 => {
+                __reduce108(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            109 => {
+                __reduce109(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            110 => {
+                __reduce110(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            111 => {
+                __reduce111(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            112 => {
+                __reduce112(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            113 => {
+                __reduce113(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            114 => {
+                __reduce114(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            115 => {
+                __reduce115(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            116 => {
+                __reduce116(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            117 => {
+                __reduce117(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            118 => {
+                __reduce118(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            119 => {
+                __reduce119(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            120 => {
+                __reduce120(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            121 => {
+                __reduce121(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            122 => {
+                __reduce122(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            123 => {
+                __reduce123(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            124 => {
+                __reduce124(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            125 => {
+                __reduce125(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            126 => {
                 // __Exp = Exp => ActionFn(1);
-                let __sym0 = __pop_Variant1(__symbols);
+                let __sym0 = __pop_Variant4(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action1::<>(input, __sym0);
+                let __nt = super::__action1::<>(__sym0);
                 return Some(Ok(__nt));
             }
-            84 => {
-                __reduce84(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            127 => {
+                __reduce127(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             _ => panic!("invalid action code {}", __action)
         };
@@ -1065,43 +1613,39 @@ mod __parse__Exp {
     fn __symbol_type_mismatch() -> ! {
         panic!("symbol type mismatch")
     }
-    fn __pop_Variant5<
-      'input,
+    fn __pop_Variant7<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, (SurfFunDecl<Span1>, &'input str), usize)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, (SurfFunDecl<Span1>, Tok), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant10<
-      'input,
+    fn __pop_Variant13<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, Box<Exp<Span1>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant1<
-      'input,
+    fn __pop_Variant4<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, Exp<Span1>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
     fn __pop_Variant11<
-      'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, Prim, usize)
      {
         match __symbols.pop() {
@@ -1109,5723 +1653,8123 @@ mod __parse__Exp {
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant3<
-      'input,
+    fn __pop_Variant2<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, String, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant15<
-      'input,
+    fn __pop_Variant17<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, SurfFunDecl<Span1>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant18<
-      'input,
+    fn __pop_Variant19<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, SurfProg<Span1>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant8<
-      'input,
+    fn __pop_Variant0<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Tok, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant10<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, Vec<(String, Exp<Span1>)>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant12<
-      'input,
+    fn __pop_Variant14<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, Vec<Exp<Span1>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant13<
-      'input,
+    fn __pop_Variant15<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, Vec<String>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant6<
-      'input,
+    fn __pop_Variant8<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant2<
-      'input,
+    fn __pop_Variant5<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, alloc::vec::Vec<Exp<Span1>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant4<
-      'input,
+    fn __pop_Variant6<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, alloc::vec::Vec<String>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant9<
-      'input,
+    fn __pop_Variant12<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, bool, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant14<
-      'input,
+    fn __pop_Variant16<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, core::option::Option<Exp<Span1>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant16<
-      'input,
+    fn __pop_Variant18<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, core::option::Option<String>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant17<
-      'input,
+    fn __pop_Variant1<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, i64, usize)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, f64, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant7<
-      'input,
+    fn __pop_Variant3<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, usize, usize)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, i64, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant0<
-      'input,
+    fn __pop_Variant9<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, usize, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
     pub(crate) fn __reduce0<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",") = Exp, "," => ActionFn(73);
+        // (<Exp> ",") = Exp, "," => ActionFn(116);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action73::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action116::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (2, 0)
     }
     pub(crate) fn __reduce1<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")* =  => ActionFn(71);
+        // (<Exp> ",")* =  => ActionFn(114);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action71::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        let __nt = super::__action114::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
         (0, 1)
     }
     pub(crate) fn __reduce2<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")* = (<Exp> ",")+ => ActionFn(72);
-        let __sym0 = __pop_Variant2(__symbols);
+        // (<Exp> ",")* = (<Exp> ",")+ => ActionFn(115);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action72::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        let __nt = super::__action115::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
         (1, 1)
     }
     pub(crate) fn __reduce3<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")+ = Exp, "," => ActionFn(78);
+        // (<Exp> ",")+ = Exp, "," => ActionFn(121);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action78::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        let __nt = super::__action121::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
         (2, 2)
     }
     pub(crate) fn __reduce4<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")+ = (<Exp> ",")+, Exp, "," => ActionFn(79);
+        // (<Exp> ",")+ = (<Exp> ",")+, Exp, "," => ActionFn(122);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action79::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        let __nt = super::__action122::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
         (3, 2)
     }
     pub(crate) fn __reduce5<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Id> ",") = Id, "," => ActionFn(68);
+        // (<Id> ",") = Id, "," => ActionFn(111);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action68::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        let __nt = super::__action111::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
         (2, 3)
     }
     pub(crate) fn __reduce6<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")* =  => ActionFn(66);
+        // (<Id> ",")* =  => ActionFn(109);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action66::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        let __nt = super::__action109::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (0, 4)
     }
     pub(crate) fn __reduce7<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")* = (<Id> ",")+ => ActionFn(67);
-        let __sym0 = __pop_Variant4(__symbols);
+        // (<Id> ",")* = (<Id> ",")+ => ActionFn(110);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action67::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        let __nt = super::__action110::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (1, 4)
     }
     pub(crate) fn __reduce8<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")+ = Id, "," => ActionFn(82);
+        // (<Id> ",")+ = Id, "," => ActionFn(125);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action82::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        let __nt = super::__action125::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (2, 5)
     }
     pub(crate) fn __reduce9<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")+ = (<Id> ",")+, Id, "," => ActionFn(83);
+        // (<Id> ",")+ = (<Id> ",")+, Id, "," => ActionFn(126);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action83::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        let __nt = super::__action126::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (3, 5)
     }
     pub(crate) fn __reduce10<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and") = FunDecl, "and" => ActionFn(60);
+        // (FunDecl "and") = FunDecl, "and" => ActionFn(103);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant17(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action60::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        let __nt = super::__action103::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
         (2, 6)
     }
     pub(crate) fn __reduce11<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")* =  => ActionFn(58);
+        // (FunDecl "and")* =  => ActionFn(101);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action58::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        let __nt = super::__action101::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
         (0, 7)
     }
     pub(crate) fn __reduce12<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")* = (FunDecl "and")+ => ActionFn(59);
-        let __sym0 = __pop_Variant6(__symbols);
+        // (FunDecl "and")* = (FunDecl "and")+ => ActionFn(102);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action59::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        let __nt = super::__action102::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
         (1, 7)
     }
     pub(crate) fn __reduce13<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")+ = FunDecl, "and" => ActionFn(86);
+        // (FunDecl "and")+ = FunDecl, "and" => ActionFn(129);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant17(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action86::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        let __nt = super::__action129::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
         (2, 8)
     }
     pub(crate) fn __reduce14<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")+ = (FunDecl "and")+, FunDecl, "and" => ActionFn(87);
+        // (FunDecl "and")+ = (FunDecl "and")+, FunDecl, "and" => ActionFn(130);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant15(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
+        let __sym1 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action87::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        let __nt = super::__action130::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
         (3, 8)
     }
     pub(crate) fn __reduce15<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // @L =  => ActionFn(61);
+        // @L =  => ActionFn(104);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action61::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        let __nt = super::__action104::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (0, 9)
     }
     pub(crate) fn __reduce16<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // @R =  => ActionFn(56);
+        // @R =  => ActionFn(99);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action56::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        let __nt = super::__action99::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (0, 10)
     }
     pub(crate) fn __reduce17<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BaseExp = Id => ActionFn(105);
-        let __sym0 = __pop_Variant3(__symbols);
+        // BaseExp = Id => ActionFn(163);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action105::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action163::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (1, 11)
     }
     pub(crate) fn __reduce18<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BaseExp = Bool => ActionFn(106);
-        let __sym0 = __pop_Variant9(__symbols);
+        // BaseExp = Bool => ActionFn(164);
+        let __sym0 = __pop_Variant12(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action106::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action164::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (1, 11)
     }
     pub(crate) fn __reduce19<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BaseExp = Num => ActionFn(107);
-        let __sym0 = __pop_Variant17(__symbols);
+        // BaseExp = Num => ActionFn(165);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action107::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action165::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (1, 11)
     }
     pub(crate) fn __reduce20<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BaseExp = Prim1, "(", Exp, ")" => ActionFn(108);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant11(__symbols);
+        // BaseExp = Float => ActionFn(166);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action108::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 11)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action166::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce21<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BaseExp = "(", Exp, ")" => ActionFn(33);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // BaseExp = Str => ActionFn(167);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action33::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 11)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action167::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 11)
     }
     pub(crate) fn __reduce22<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BaseExp = Id, "(", Comma<Exp>, ")" => ActionFn(109);
+        // BaseExp = Prim1, "(", Exp, ")" => ActionFn(168);
         assert!(__symbols.len() >= 4);
         let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant12(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym3.2.clone();
-        let __nt = super::__action109::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action168::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (4, 11)
     }
     pub(crate) fn __reduce23<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BinOps = LogExp => ActionFn(11);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = Prim2, "(", Exp, ",", Exp, ")" => ActionFn(169);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant4(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action11::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 12)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action169::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (6, 11)
     }
     pub(crate) fn __reduce24<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Bindings = Id, "=", Exp => ActionFn(40);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
+        // BaseExp = "substr", "(", Exp, ",", Exp, ",", Exp, ")" => ActionFn(170);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant4(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant4(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action40::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (3, 13)
+        let __end = __sym7.2.clone();
+        let __nt = super::__action170::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (8, 11)
     }
     pub(crate) fn __reduce25<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Bindings = Bindings, ",", Id, "=", Exp => ActionFn(41);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant1(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
+        // BaseExp = Prim0, "(", ")" => ActionFn(171);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action41::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (5, 13)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action171::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 11)
     }
     pub(crate) fn __reduce26<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Bool = "true" => ActionFn(44);
+        // BaseExp = "(", Exp, ")" => ActionFn(55);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action44::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 14)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action55::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 11)
     }
     pub(crate) fn __reduce27<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Bool = "false" => ActionFn(45);
+        // BaseExp = "block", ":", Exp, "end" => ActionFn(56);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action45::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 14)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action56::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 11)
     }
     pub(crate) fn __reduce28<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Boxed<Exp> = Exp => ActionFn(57);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = "(", ")" => ActionFn(172);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action57::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 15)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action172::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 11)
     }
     pub(crate) fn __reduce29<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Cmp = "<" => ActionFn(21);
+        // BaseExp = "(", Exp, ",", Comma<Exp>, ")" => ActionFn(173);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant14(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action21::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action173::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (5, 11)
     }
     pub(crate) fn __reduce30<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Cmp = "<=" => ActionFn(22);
-        let __sym0 = __pop_Variant0(__symbols);
+        // BaseExp = BaseExp, "[", Exp, "]" => ActionFn(174);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action22::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action174::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 11)
     }
     pub(crate) fn __reduce31<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Cmp = ">" => ActionFn(23);
-        let __sym0 = __pop_Variant0(__symbols);
+        // BaseExp = BaseExp, "(", Comma<Exp>, ")" => ActionFn(175);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant14(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action23::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action175::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 11)
     }
     pub(crate) fn __reduce32<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Cmp = ">=" => ActionFn(24);
+        // BaseExp = "lambda", "(", Comma<Id>, ")", ":", Boxed<Exp>, "end" => ActionFn(176);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant13(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action24::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action176::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (7, 11)
     }
     pub(crate) fn __reduce33<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Cmp = "==" => ActionFn(25);
-        let __sym0 = __pop_Variant0(__symbols);
+        // BinOps = LogExp => ActionFn(15);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action25::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        let __nt = super::__action15::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 12)
     }
     pub(crate) fn __reduce34<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Cmp = "!=" => ActionFn(26);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Bindings = Id, "=", Exp => ActionFn(77);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action26::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action77::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 13)
     }
     pub(crate) fn __reduce35<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // CmpExp = LAssoc<Cmp, Sum> => ActionFn(13);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Bindings = Bindings, ",", Id, "=", Exp => ActionFn(78);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant4(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action13::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action78::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (5, 13)
     }
     pub(crate) fn __reduce36<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = Exp => ActionFn(120);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BitAnd = "&" => ActionFn(33);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action120::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 18)
+        let __nt = super::__action33::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 14)
     }
     pub(crate) fn __reduce37<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> =  => ActionFn(121);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action121::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (0, 18)
+        // BitAndExp = LAssoc<BitAnd, ShiftExp> => ActionFn(20);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action20::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 15)
     }
     pub(crate) fn __reduce38<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = (<Exp> ",")+, Exp => ActionFn(122);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        // BitOr = "|" => ActionFn(31);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action122::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (2, 18)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action31::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 16)
     }
     pub(crate) fn __reduce39<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = (<Exp> ",")+ => ActionFn(123);
-        let __sym0 = __pop_Variant2(__symbols);
+        // BitOrExp = LAssoc<BitOr, BitXorExp> => ActionFn(18);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action123::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 18)
+        let __nt = super::__action18::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 17)
     }
     pub(crate) fn __reduce40<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = Id => ActionFn(124);
-        let __sym0 = __pop_Variant3(__symbols);
+        // BitXor = "^" => ActionFn(32);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action124::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 19)
+        let __nt = super::__action32::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 18)
     }
     pub(crate) fn __reduce41<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Id> =  => ActionFn(125);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action125::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (0, 19)
+        // BitXorExp = LAssoc<BitXor, BitAndExp> => ActionFn(19);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action19::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 19)
     }
     pub(crate) fn __reduce42<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = (<Id> ",")+, Id => ActionFn(126);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Bool = "true" => ActionFn(79);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action126::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (2, 19)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action79::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 20)
     }
     pub(crate) fn __reduce43<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = (<Id> ",")+ => ActionFn(127);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Bool = "false" => ActionFn(80);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action127::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 19)
+        let __nt = super::__action80::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 20)
     }
     pub(crate) fn __reduce44<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp = IF => ActionFn(5);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Boxed<Exp> = Exp => ActionFn(100);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action5::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        let __nt = super::__action100::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 21)
     }
     pub(crate) fn __reduce45<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp = LET => ActionFn(6);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Cmp = "<" => ActionFn(38);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action6::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        let __nt = super::__action38::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 22)
     }
     pub(crate) fn __reduce46<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp = FUNDEFS => ActionFn(7);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Cmp = "<=" => ActionFn(39);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action7::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        let __nt = super::__action39::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 22)
     }
     pub(crate) fn __reduce47<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp = BinOps => ActionFn(8);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Cmp = ">" => ActionFn(40);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action8::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        let __nt = super::__action40::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 22)
     }
     pub(crate) fn __reduce48<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp? = Exp => ActionFn(69);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Cmp = ">=" => ActionFn(41);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action69::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 21)
+        let __nt = super::__action41::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 22)
     }
     pub(crate) fn __reduce49<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp? =  => ActionFn(70);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action70::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (0, 21)
+        // Cmp = "==" => ActionFn(42);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action42::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 22)
     }
     pub(crate) fn __reduce50<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // FUNDEFS = FunDecl, "in", Boxed<Exp> => ActionFn(110);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
+        // Cmp = "!=" => ActionFn(43);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action110::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 22)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action43::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 22)
     }
     pub(crate) fn __reduce51<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // FUNDEFS = (FunDecl "and")+, FunDecl, "in", Boxed<Exp> => ActionFn(111);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant15(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
+        // CmpExp = LAssoc<Cmp, BitOrExp> => ActionFn(17);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action111::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 22)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action17::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 23)
     }
     pub(crate) fn __reduce52<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // FunDecl = "def", Id, "(", Comma<Id>, ")", ":", Exp => ActionFn(112);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant1(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant13(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Exp> = Exp => ActionFn(193);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action112::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (7, 23)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action193::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 24)
     }
     pub(crate) fn __reduce53<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // IF = "if", Exp, ":", Exp, "else", ":", Exp => ActionFn(113);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant1(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant1(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action113::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (7, 24)
+        // Comma<Exp> =  => ActionFn(194);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action194::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (0, 24)
     }
     pub(crate) fn __reduce54<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Id = r#"[a-zA-Z][a-zA-Z0-9_]*"# => ActionFn(43);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Exp> = (<Exp> ",")+, Exp => ActionFn(195);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action43::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 25)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action195::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (2, 24)
     }
     pub(crate) fn __reduce55<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Id? = Id => ActionFn(64);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Comma<Exp> = (<Exp> ",")+ => ActionFn(196);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action64::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 26)
+        let __nt = super::__action196::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 24)
     }
     pub(crate) fn __reduce56<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Id? =  => ActionFn(65);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action65::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (0, 26)
+        // Comma<Id> = Id => ActionFn(197);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action197::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 25)
     }
     pub(crate) fn __reduce57<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAssoc<Cmp, Sum> = LAssoc<Cmp, Sum>, Cmp, Sum => ActionFn(114);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action114::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 27)
+        // Comma<Id> =  => ActionFn(198);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action198::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (0, 25)
     }
     pub(crate) fn __reduce58<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAssoc<Cmp, Sum> = Sum => ActionFn(52);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Comma<Id> = (<Id> ",")+, Id => ActionFn(199);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action52::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 27)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action199::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (2, 25)
     }
     pub(crate) fn __reduce59<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAssoc<PlusMinus, Product> = LAssoc<PlusMinus, Product>, PlusMinus, Product => ActionFn(115);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Comma<Id> = (<Id> ",")+ => ActionFn(200);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action115::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 28)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action200::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 25)
     }
     pub(crate) fn __reduce60<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAssoc<PlusMinus, Product> = Product => ActionFn(50);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Exp = Stmt, ";", Exp => ActionFn(177);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action50::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 28)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action177::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 26)
     }
     pub(crate) fn __reduce61<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAssoc<Times, NotExp> = LAssoc<Times, NotExp>, Times, NotExp => ActionFn(116);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Exp = IF => ActionFn(6);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action116::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 29)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action6::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 26)
     }
     pub(crate) fn __reduce62<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAssoc<Times, NotExp> = NotExp => ActionFn(48);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Exp = LET => ActionFn(7);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action48::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        let __nt = super::__action7::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 26)
     }
     pub(crate) fn __reduce63<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LET = "let", Bindings, "in", Exp => ActionFn(117);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant1(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant8(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Exp = FUNDEFS => ActionFn(8);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action117::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 30)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action8::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 26)
     }
     pub(crate) fn __reduce64<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Log = "&&" => ActionFn(19);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Exp = Stmt => ActionFn(9);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action19::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 31)
+        let __nt = super::__action9::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 26)
     }
     pub(crate) fn __reduce65<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Log = "||" => ActionFn(20);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Exp? = Exp => ActionFn(112);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action20::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 31)
+        let __nt = super::__action112::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
     }
     pub(crate) fn __reduce66<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LogExp = RAssoc<Log, CmpExp> => ActionFn(12);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action12::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 32)
+        // Exp? =  => ActionFn(113);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action113::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (0, 27)
     }
     pub(crate) fn __reduce67<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // NotExp = "!", BaseExp => ActionFn(118);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // FUNDEFS = FunDecl, "in", Boxed<Exp> => ActionFn(178);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant17(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action118::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 33)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action178::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 28)
     }
     pub(crate) fn __reduce68<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // NotExp = BaseExp => ActionFn(28);
-        let __sym0 = __pop_Variant1(__symbols);
+        // FUNDEFS = (FunDecl "and")+, FunDecl, "in", Boxed<Exp> => ActionFn(179);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant13(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action28::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 33)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action179::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 28)
     }
     pub(crate) fn __reduce69<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Num = r#"[+-]?[0-9]+"# => ActionFn(42);
+        // FunDecl = "def", Id, "(", Comma<Id>, ")", ":", Exp => ActionFn(180);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant4(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action42::<>(input, __sym0);
+        let __end = __sym6.2.clone();
+        let __nt = super::__action180::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
         __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 34)
+        (7, 29)
     }
     pub(crate) fn __reduce70<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // PlusMinus = "+" => ActionFn(16);
+        // IF = "if", Exp, ":", Exp, "else", ":", Exp => ActionFn(181);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant4(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant4(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action16::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 35)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action181::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (7, 30)
     }
     pub(crate) fn __reduce71<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // PlusMinus = "-" => ActionFn(17);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Id? = Id => ActionFn(107);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action17::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 35)
+        let __nt = super::__action107::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 31)
     }
     pub(crate) fn __reduce72<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prim1 = "add1" => ActionFn(35);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action35::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        // Id? =  => ActionFn(108);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action108::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (0, 31)
     }
     pub(crate) fn __reduce73<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prim1 = "sub1" => ActionFn(36);
-        let __sym0 = __pop_Variant0(__symbols);
+        // LAssoc<BitAnd, ShiftExp> = LAssoc<BitAnd, ShiftExp>, BitAnd, ShiftExp => ActionFn(182);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action36::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action182::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 32)
     }
     pub(crate) fn __reduce74<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prim1 = "print" => ActionFn(37);
-        let __sym0 = __pop_Variant0(__symbols);
+        // LAssoc<BitAnd, ShiftExp> = ShiftExp => ActionFn(89);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action37::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        let __nt = super::__action89::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 32)
     }
     pub(crate) fn __reduce75<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prim1 = "isnum" => ActionFn(38);
-        let __sym0 = __pop_Variant0(__symbols);
+        // LAssoc<BitOr, BitXorExp> = LAssoc<BitOr, BitXorExp>, BitOr, BitXorExp => ActionFn(183);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action38::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action183::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 33)
     }
     pub(crate) fn __reduce76<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prim1 = "isbool" => ActionFn(39);
-        let __sym0 = __pop_Variant0(__symbols);
+        // LAssoc<BitOr, BitXorExp> = BitXorExp => ActionFn(93);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action39::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        let __nt = super::__action93::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 33)
     }
     pub(crate) fn __reduce77<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Product = LAssoc<Times, NotExp> => ActionFn(15);
-        let __sym0 = __pop_Variant1(__symbols);
+        // LAssoc<BitXor, BitAndExp> = LAssoc<BitXor, BitAndExp>, BitXor, BitAndExp => ActionFn(184);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action15::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 37)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action184::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 34)
     }
     pub(crate) fn __reduce78<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prog = Exp => ActionFn(2);
-        let __sym0 = __pop_Variant1(__symbols);
+        // LAssoc<BitXor, BitAndExp> = BitAndExp => ActionFn(91);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action2::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 38)
+        let __nt = super::__action91::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 34)
     }
     pub(crate) fn __reduce79<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // RAssoc<Log, CmpExp> = CmpExp, Log, RAssoc<Log, CmpExp> => ActionFn(119);
+        // LAssoc<Cmp, BitOrExp> = LAssoc<Cmp, BitOrExp>, Cmp, BitOrExp => ActionFn(185);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
         let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action119::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 39)
+        let __nt = super::__action185::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 35)
     }
     pub(crate) fn __reduce80<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // RAssoc<Log, CmpExp> = CmpExp => ActionFn(54);
-        let __sym0 = __pop_Variant1(__symbols);
+        // LAssoc<Cmp, BitOrExp> = BitOrExp => ActionFn(95);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action54::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 39)
+        let __nt = super::__action95::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 35)
     }
     pub(crate) fn __reduce81<
-        'input,
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Sum = LAssoc<PlusMinus, Product> => ActionFn(14);
-        let __sym0 = __pop_Variant1(__symbols);
+        // LAssoc<PlusMinus, Product> = LAssoc<PlusMinus, Product>, PlusMinus, Product => ActionFn(186);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action186::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 36)
+    }
+    pub(crate) fn __reduce82<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<PlusMinus, Product> = Product => ActionFn(85);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action85::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 36)
+    }
+    pub(crate) fn __reduce83<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<Shift, Sum> = LAssoc<Shift, Sum>, Shift, Sum => ActionFn(187);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action187::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 37)
+    }
+    pub(crate) fn __reduce84<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<Shift, Sum> = Sum => ActionFn(87);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action87::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 37)
+    }
+    pub(crate) fn __reduce85<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<Times, NotExp> = LAssoc<Times, NotExp>, Times, NotExp => ActionFn(188);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action188::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 38)
+    }
+    pub(crate) fn __reduce86<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<Times, NotExp> = NotExp => ActionFn(83);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action83::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 38)
+    }
+    pub(crate) fn __reduce87<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // LET = "let", Bindings, "in", Exp => ActionFn(189);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant4(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action189::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 39)
+    }
+    pub(crate) fn __reduce88<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Log = "&&" => ActionFn(36);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action14::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action36::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
         (1, 40)
     }
-    pub(crate) fn __reduce82<
-        'input,
+    pub(crate) fn __reduce89<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Times = "*" => ActionFn(18);
+        // Log = "||" => ActionFn(37);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action18::<>(input, __sym0);
+        let __nt = super::__action37::<>(__sym0);
         __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 40)
+    }
+    pub(crate) fn __reduce90<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // LogExp = RAssoc<Log, CmpExp> => ActionFn(16);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action16::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (1, 41)
     }
-    pub(crate) fn __reduce84<
-        'input,
+    pub(crate) fn __reduce91<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // __Prog = Prog => ActionFn(0);
-        let __sym0 = __pop_Variant18(__symbols);
+        // NotExp = "!", BaseExp => ActionFn(190);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action190::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 42)
+    }
+    pub(crate) fn __reduce92<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // NotExp = BaseExp => ActionFn(45);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action0::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        let __nt = super::__action45::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 42)
+    }
+    pub(crate) fn __reduce93<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // PlusMinus = "+" => ActionFn(24);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action24::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
         (1, 43)
     }
-}
-pub use self::__parse__Exp::ExpParser;
-
-#[cfg_attr(rustfmt, rustfmt_skip)]
-mod __parse__Prog {
-    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::all)]
-
-    use std::str::FromStr;
-    use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Prim};
-    use crate::span::Span1;
-    #[allow(unused_extern_crates)]
-    extern crate lalrpop_util as __lalrpop_util;
-    #[allow(unused_imports)]
-    use self::__lalrpop_util::state_machine as __state_machine;
-    extern crate core;
-    extern crate alloc;
-    use self::__lalrpop_util::lexer::Token;
-    #[allow(dead_code)]
-    pub(crate) enum __Symbol<'input>
-     {
-        Variant0(&'input str),
-        Variant1(Exp<Span1>),
-        Variant2(alloc::vec::Vec<Exp<Span1>>),
-        Variant3(String),
-        Variant4(alloc::vec::Vec<String>),
-        Variant5((SurfFunDecl<Span1>, &'input str)),
-        Variant6(alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>),
-        Variant7(usize),
-        Variant8(Vec<(String, Exp<Span1>)>),
-        Variant9(bool),
-        Variant10(Box<Exp<Span1>>),
-        Variant11(Prim),
-        Variant12(Vec<Exp<Span1>>),
-        Variant13(Vec<String>),
-        Variant14(core::option::Option<Exp<Span1>>),
-        Variant15(SurfFunDecl<Span1>),
-        Variant16(core::option::Option<String>),
-        Variant17(i64),
-        Variant18(SurfProg<Span1>),
-    }
-    const __ACTION: &[i8] = &[
-        // State 0
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 2
-        0, 0, 57, 0, -81, 0, 0, -81, 0, -81, 0, 0, 0, 0, 0, 0, 0, -81, 0, -81, 0, 0, -81, 0, 0, 0, 0, 0, 0, 58, 0, 0,
-        // State 3
-        0, 60, -36, 0, -36, 0, 0, -36, 0, -36, 61, 62, 0, 63, 64, 65, 0, -36, 0, -36, 0, 0, -36, 0, 0, 0, 0, 0, 0, -36, 0, 0,
-        // State 4
-        0, -82, -82, 0, -82, 0, 66, -82, 67, -82, -82, -82, 0, -82, -82, -82, 0, -82, 0, -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, -82, 0, 0,
-        // State 5
-        0, -78, -78, 0, -78, 68, -78, -78, -78, -78, -78, -78, 0, -78, -78, -78, 0, -78, 0, -78, 0, 0, -78, 0, 0, 0, 0, 0, 0, -78, 0, 0,
-        // State 6
-        0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 48, 0, 0, 49, 50, 0, 51, 52, 53, 0, 54, 55,
-        // State 7
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 8
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55,
-        // State 9
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 10
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55,
-        // State 11
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 48, 0, 0, 49, 50, 0, 51, 52, 53, 0, 54, 55,
-        // State 12
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 13
-        7, 0, 0, 8, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 14
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 48, 0, 0, 49, 50, 0, 51, 52, 53, 0, 54, 55,
-        // State 15
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 48, 0, 0, 49, 50, 0, 51, 52, 53, 0, 54, 55,
-        // State 16
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 48, 0, 0, 49, 50, 0, 51, 52, 53, 0, 54, 55,
-        // State 17
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 18
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 19
-        7, 0, 0, 8, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 20
-        0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55,
-        // State 21
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 22
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55,
-        // State 23
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 24
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 25
-        0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55,
-        // State 26
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 27
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 28
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 29
-        0, -69, -69, 0, -69, -69, -69, -69, -69, -69, -69, -69, 0, -69, -69, -69, 0, -69, 0, -69, 0, 0, -69, 0, 0, 0, 0, 0, 0, -69, 0, 0,
-        // State 30
-        0, 0, 0, 0, -48, 0, 0, -48, 0, -48, 0, 0, 0, 0, 0, 0, 0, -48, 0, -48, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 31
-        0, -19, -19, 0, -19, -19, -19, -19, -19, -19, -19, -19, 0, -19, -19, -19, 0, -19, 0, -19, 0, 0, -19, 0, 0, 0, 0, 0, 0, -19, 0, 0,
-        // State 32
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 33
-        0, 0, 0, 0, -47, 0, 0, -47, 0, -47, 0, 0, 0, 0, 0, 0, 0, -47, 0, -47, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 34
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 35
-        0, 0, 0, 0, -45, 0, 0, -45, 0, -45, 0, 0, 0, 0, 0, 0, 0, -45, 0, -45, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 36
-        0, -18, -18, 14, -18, -18, -18, -18, -18, -18, -18, -18, 0, -18, -18, -18, 0, -18, 0, -18, 0, 0, -18, 0, 0, 0, 0, 0, 0, -18, 0, 0,
-        // State 37
-        0, 0, 0, 0, -46, 0, 0, -46, 0, -46, 0, 0, 0, 0, 0, 0, 0, -46, 0, -46, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 38
-        0, 0, 0, 0, -24, 0, 0, -24, 0, -24, 0, 0, 0, 0, 0, 0, 0, -24, 0, -24, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 39
-        0, -63, -63, 0, -63, -63, -63, -63, -63, -63, -63, -63, 0, -63, -63, -63, 0, -63, 0, -63, 0, 0, -63, 0, 0, 0, 0, 0, 0, -63, 0, 0,
-        // State 40
-        0, -20, -20, 0, -20, -20, -20, -20, -20, -20, -20, -20, 0, -20, -20, -20, 0, -20, 0, -20, 0, 0, -20, 0, 0, 0, 0, 0, 0, -20, 0, 0,
-        // State 41
-        0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 42
-        0, -61, -61, 0, -61, 0, -61, -61, -61, -61, -61, -61, 0, -61, -61, -61, 0, -61, 0, -61, 0, 0, -61, 0, 0, 0, 0, 0, 0, -61, 0, 0,
-        // State 43
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 44
-        0, 0, 0, 0, -67, 0, 0, -67, 0, -67, 0, 0, 0, 0, 0, 0, 0, -67, 0, -67, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 45
-        0, -59, -59, 0, -59, 0, 0, -59, 0, -59, -59, -59, 0, -59, -59, -59, 0, -59, 0, -59, 0, 0, -59, 0, 0, 0, 0, 0, 0, -59, 0, 0,
-        // State 46
-        0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 47
-        0, -28, -28, 0, -28, -28, -28, -28, -28, -28, -28, -28, 0, -28, -28, -28, 0, -28, 0, -28, 0, 0, -28, 0, 0, 0, 0, 0, 0, -28, 0, 0,
-        // State 48
-        0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 49
-        0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 50
-        0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 51
-        0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 52
-        0, -27, -27, 0, -27, -27, -27, -27, -27, -27, -27, -27, 0, -27, -27, -27, 0, -27, 0, -27, 0, 0, -27, 0, 0, 0, 0, 0, 0, -27, 0, 0,
-        // State 53
-        0, -70, -70, 0, -70, -70, -70, -70, -70, -70, -70, -70, 0, -70, -70, -70, 0, -70, 0, -70, 0, 0, -70, 0, 0, 0, 0, 0, 0, -70, 0, 0,
-        // State 54
-        0, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, 0, -55, 0, -55, 0, 0, -55, 0, 0, 0, 0, 0, 0, -55, 0, 0,
-        // State 55
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 75, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 56
-        -65, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, -65, 0, 0, -65, -65, 0, -65, -65, -65, 0, -65, -65,
-        // State 57
-        -66, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, 0, 0, 0, -66, 0, 0, -66, -66, 0, -66, -66, -66, 0, -66, -66,
-        // State 58
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 59
-        -35, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, -35, 0, 0, -35, -35, 0, -35, -35, -35, 0, -35, -35,
-        // State 60
-        -30, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, -30, 0, 0, -30, -30, 0, -30, -30, -30, 0, -30, -30,
-        // State 61
-        -31, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, -31, 0, 0, -31, -31, 0, -31, -31, -31, 0, -31, -31,
-        // State 62
-        -34, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, -34, 0, 0, -34, -34, 0, -34, -34, -34, 0, -34, -34,
-        // State 63
-        -32, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, -32, 0, 0, -32, -32, 0, -32, -32, -32, 0, -32, -32,
-        // State 64
-        -33, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, -33, 0, 0, -33, -33, 0, -33, -33, -33, 0, -33, -33,
-        // State 65
-        -71, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, -71, 0, 0, -71, -71, 0, -71, -71, -71, 0, -71, -71,
-        // State 66
-        -72, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, -72, 0, 0, -72, -72, 0, -72, -72, -72, 0, -72, -72,
-        // State 67
-        -83, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, 0, -83, 0, 0, -83, -83, 0, -83, -83, -83, 0, -83, -83,
-        // State 68
-        0, -68, -68, 0, -68, -68, -68, -68, -68, -68, -68, -68, 0, -68, -68, -68, 0, -68, 0, -68, 0, 0, -68, 0, 0, 0, 0, 0, 0, -68, 0, 0,
-        // State 69
-        0, 0, 0, 0, 85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 70
-        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 71
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 72
-        0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 73
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 74
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 75
-        0, 0, 0, 0, -80, 0, 0, -80, 0, -80, 0, 0, 0, 0, 0, 0, 0, -80, 0, -80, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 76
-        0, 0, 0, 0, -51, 0, 0, -51, 0, -51, 0, 0, 0, 0, 0, 0, 0, -51, 0, -51, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 77
-        0, 0, 0, 0, -29, 0, 0, -29, 0, -29, 0, 0, 0, 0, 0, 0, 0, -29, 0, -29, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 78
-        0, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 79
-        0, 0, 0, 0, -37, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 80
-        0, -58, -58, 0, -58, 0, 0, -58, 0, -58, -58, -58, 0, -58, -58, -58, 0, -58, 0, -58, 0, 0, -58, 0, 0, 0, 0, 0, 0, -58, 0, 0,
-        // State 81
-        0, -60, -60, 0, -60, 0, -60, -60, -60, -60, -60, -60, 0, -60, -60, -60, 0, -60, 0, -60, 0, 0, -60, 0, 0, 0, 0, 0, 0, -60, 0, 0,
-        // State 82
-        0, -62, -62, 0, -62, -62, -62, -62, -62, -62, -62, -62, 0, -62, -62, -62, 0, -62, 0, -62, 0, 0, -62, 0, 0, 0, 0, 0, 0, -62, 0, 0,
-        // State 83
-        0, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 84
-        0, -22, -22, 0, -22, -22, -22, -22, -22, -22, -22, -22, 0, -22, -22, -22, 0, -22, 0, -22, 0, 0, -22, 0, 0, 0, 0, 0, 0, -22, 0, 0,
-        // State 85
-        0, 0, 0, 0, -52, 0, 0, -52, 0, -52, 0, 0, 0, 0, 0, 0, 0, -52, 0, -52, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 86
-        0, 0, 0, 0, -39, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 87
-        0, -23, -23, 0, -23, -23, -23, -23, -23, -23, -23, -23, 0, -23, -23, -23, 0, -23, 0, -23, 0, 0, -23, 0, 0, 0, 0, 0, 0, -23, 0, 0,
-        // State 88
-        -4, 0, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4, 0, -4, 0, -4, -4, 0, -4, -4, -4, -4, -4, -4, 0, -4, -4,
-        // State 89
-        0, -21, -21, 0, -21, -21, -21, -21, -21, -21, -21, -21, 0, -21, -21, -21, 0, -21, 0, -21, 0, 0, -21, 0, 0, 0, 0, 0, 0, -21, 0, 0,
-        // State 90
-        0, 0, 0, 0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 91
-        0, 0, 0, 0, -41, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 92
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 93
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 94
-        0, 0, 0, 0, -64, 0, 0, -64, 0, -64, 0, 0, 0, 0, 0, 0, 0, -64, 0, -64, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 95
-        0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 96
-        -5, 0, 0, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5, 0, -5, 0, -5, -5, 0, -5, -5, -5, -5, -5, -5, 0, -5, -5,
-        // State 97
-        0, 0, 0, 0, -43, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 98
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 99
-        0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9,
-        // State 100
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 101
-        0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10,
-        // State 102
-        0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 103
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 104
-        0, 0, 0, 0, -54, 0, 0, -54, 0, -54, 0, 0, 0, 0, 0, 0, 0, -54, 0, -54, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    ];
-    fn __action(state: i8, integer: usize) -> i8 {
-        __ACTION[(state as usize) * 32 + integer]
+    pub(crate) fn __reduce94<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // PlusMinus = "-" => ActionFn(25);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action25::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 43)
     }
-    const __EOF_ACTION: &[i8] = &[
-        // State 0
-        0,
-        // State 1
-        0,
-        // State 2
-        -81,
-        // State 3
-        -36,
-        // State 4
-        -82,
-        // State 5
-        -78,
-        // State 6
-        0,
-        // State 7
-        0,
-        // State 8
-        0,
-        // State 9
-        0,
-        // State 10
-        0,
-        // State 11
-        0,
-        // State 12
-        0,
-        // State 13
-        0,
-        // State 14
-        0,
-        // State 15
-        0,
-        // State 16
-        0,
-        // State 17
-        0,
-        // State 18
-        0,
-        // State 19
-        0,
-        // State 20
-        0,
-        // State 21
-        0,
-        // State 22
-        0,
-        // State 23
-        0,
-        // State 24
-        0,
-        // State 25
-        0,
-        // State 26
-        0,
-        // State 27
-        0,
-        // State 28
-        0,
-        // State 29
-        -69,
-        // State 30
-        -48,
-        // State 31
-        -19,
-        // State 32
-        -79,
-        // State 33
-        -47,
-        // State 34
-        0,
-        // State 35
-        -45,
-        // State 36
-        -18,
-        // State 37
-        -46,
-        // State 38
-        -24,
-        // State 39
-        -63,
-        // State 40
-        -20,
-        // State 41
-        0,
-        // State 42
-        -61,
-        // State 43
-        -85,
-        // State 44
-        -67,
-        // State 45
-        -59,
-        // State 46
-        0,
-        // State 47
-        -28,
-        // State 48
-        0,
-        // State 49
-        0,
-        // State 50
-        0,
-        // State 51
-        0,
-        // State 52
-        -27,
-        // State 53
-        -70,
-        // State 54
-        -55,
-        // State 55
-        0,
-        // State 56
-        0,
-        // State 57
-        0,
-        // State 58
-        0,
-        // State 59
-        0,
-        // State 60
-        0,
-        // State 61
-        0,
-        // State 62
-        0,
-        // State 63
-        0,
-        // State 64
-        0,
-        // State 65
-        0,
-        // State 66
-        0,
-        // State 67
-        0,
-        // State 68
-        -68,
-        // State 69
-        0,
-        // State 70
-        0,
-        // State 71
-        0,
-        // State 72
-        0,
-        // State 73
-        0,
-        // State 74
-        0,
-        // State 75
-        -80,
-        // State 76
-        -51,
-        // State 77
-        -29,
-        // State 78
-        0,
-        // State 79
-        0,
-        // State 80
-        -58,
-        // State 81
-        -60,
-        // State 82
-        -62,
-        // State 83
-        0,
-        // State 84
-        -22,
-        // State 85
-        -52,
-        // State 86
-        0,
-        // State 87
-        -23,
-        // State 88
-        0,
-        // State 89
-        -21,
-        // State 90
-        0,
-        // State 91
-        0,
-        // State 92
-        0,
-        // State 93
-        0,
-        // State 94
-        -64,
-        // State 95
-        0,
-        // State 96
-        0,
-        // State 97
-        0,
-        // State 98
-        0,
-        // State 99
-        0,
-        // State 100
-        0,
-        // State 101
-        0,
-        // State 102
-        0,
-        // State 103
-        0,
-        // State 104
-        -54,
-    ];
-    fn __goto(state: i8, nt: usize) -> i8 {
-        match nt {
-            2 => 19,
-            5 => 25,
-            8 => 1,
-            11 => match state {
-                6 => 68,
-                _ => 29,
-            },
-            12 => 30,
-            13 => 72,
-            14 => 31,
-            15 => match state {
-                18 => 85,
-                _ => 76,
-            },
-            16 => 14,
-            17 => 2,
-            18 => 78,
-            19 => 90,
-            20 => match state {
-                0 => 32,
-                7 => 69,
-                9 => 71,
-                13 => 79,
-                17 => 83,
-                19 => 86,
-                21 => 92,
-                23 => 94,
-                24 => 95,
-                26 => 102,
-                27 => 103,
-                28 => 104,
-                _ => 77,
-            },
-            22 => 33,
-            23 => match state {
-                1 => 55,
-                _ => 34,
-            },
-            24 => 35,
-            25 => match state {
-                8 => 70,
-                10 => 73,
-                20 => 91,
-                22 => 93,
-                25 => 97,
-                _ => 36,
-            },
-            27 => 3,
-            28 => 4,
-            29 => 5,
-            30 => 37,
-            31 => 11,
-            32 => 38,
-            33 => match state {
-                16 => 82,
-                _ => 39,
-            },
-            34 => 40,
-            35 => 15,
-            36 => 41,
-            37 => match state {
-                15 => 81,
-                _ => 42,
-            },
-            38 => 43,
-            39 => match state {
-                11 => 75,
-                _ => 44,
-            },
-            40 => match state {
-                14 => 80,
-                _ => 45,
-            },
-            41 => 16,
-            _ => 0,
-        }
+    pub(crate) fn __reduce95<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // PlusMinus = "++" => ActionFn(26);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action26::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 43)
     }
-    fn __expected_tokens(__state: i8) -> alloc::vec::Vec<alloc::string::String> {
-        const __TERMINAL: &[&str] = &[
-            r###""!""###,
-            r###""!=""###,
-            r###""&&""###,
-            r###""(""###,
-            r###"")""###,
-            r###""*""###,
-            r###""+""###,
-            r###"",""###,
-            r###""-""###,
-            r###"":""###,
-            r###""<""###,
-            r###""<=""###,
-            r###""=""###,
-            r###""==""###,
-            r###"">""###,
-            r###"">=""###,
-            r###""add1""###,
-            r###""and""###,
-            r###""def""###,
-            r###""else""###,
-            r###""false""###,
-            r###""if""###,
-            r###""in""###,
-            r###""isbool""###,
-            r###""isnum""###,
-            r###""let""###,
-            r###""print""###,
-            r###""sub1""###,
-            r###""true""###,
-            r###""||""###,
-            r###"r#"[+-]?[0-9]+"#"###,
-            r###"r#"[a-zA-Z][a-zA-Z0-9_]*"#"###,
-        ];
-        __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
-            let next_state = __action(__state, index);
-            if next_state == 0 {
-                None
-            } else {
-                Some(alloc::string::ToString::to_string(terminal))
-            }
-        }).collect()
+    pub(crate) fn __reduce96<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim0 = "readnum" => ActionFn(62);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action62::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 44)
     }
-    pub(crate) struct __StateMachine<'input>
-    where 
+    pub(crate) fn __reduce97<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
     {
-        input: &'input str,
-        __phantom: core::marker::PhantomData<(&'input ())>,
+        // Prim0 = "readbool" => ActionFn(63);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action63::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 44)
     }
-    impl<'input> __state_machine::ParserDefinition for __StateMachine<'input>
-    where 
+    pub(crate) fn __reduce98<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
     {
-        type Location = usize;
-        type Error = &'static str;
-        type Token = Token<'input>;
-        type TokenIndex = usize;
-        type Symbol = __Symbol<'input>;
-        type Success = SurfProg<Span1>;
-        type StateIndex = i8;
-        type Action = i8;
-        type ReduceIndex = i8;
-        type NonterminalIndex = usize;
-
-        #[inline]
-        fn start_location(&self) -> Self::Location {
-              Default::default()
-        }
-
-        #[inline]
-        fn start_state(&self) -> Self::StateIndex {
-              0
-        }
-
-        #[inline]
-        fn token_to_index(&self, token: &Self::Token) -> Option<usize> {
-            __token_to_integer(token, core::marker::PhantomData::<(&())>)
-        }
-
-        #[inline]
-        fn action(&self, state: i8, integer: usize) -> i8 {
-            __action(state, integer)
-        }
-
-        #[inline]
-        fn error_action(&self, state: i8) -> i8 {
-            __action(state, 32 - 1)
-        }
-
-        #[inline]
-        fn eof_action(&self, state: i8) -> i8 {
-            __EOF_ACTION[state as usize]
-        }
-
-        #[inline]
-        fn goto(&self, state: i8, nt: usize) -> i8 {
-            __goto(state, nt)
-        }
-
-        fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
-            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&())>)
-        }
-
-        fn expected_tokens(&self, state: i8) -> alloc::vec::Vec<alloc::string::String> {
-            __expected_tokens(state)
-        }
-
-        #[inline]
-        fn uses_error_recovery(&self) -> bool {
-            false
-        }
-
-        #[inline]
-        fn error_recovery_symbol(
-            &self,
-            recovery: __state_machine::ErrorRecovery<Self>,
-        ) -> Self::Symbol {
-            panic!("error recovery not enabled for this grammar")
-        }
-
-        fn reduce(
-            &mut self,
-            action: i8,
-            start_location: Option<&Self::Location>,
-            states: &mut alloc::vec::Vec<i8>,
-            symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
-        ) -> Option<__state_machine::ParseResult<Self>> {
-            __reduce(
-                self.input,
-                action,
-                start_location,
-                states,
-                symbols,
-                core::marker::PhantomData::<(&())>,
-            )
-        }
-
-        fn simulate_reduce(&self, action: i8) -> __state_machine::SimulatedReduce<Self> {
-            panic!("error recovery not enabled for this grammar")
-        }
+        // Prim1 = "add1" => ActionFn(64);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action64::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
     }
-    fn __token_to_integer<
-        'input,
+    pub(crate) fn __reduce99<
     >(
-        __token: &Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> Option<usize>
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
     {
-        match *__token {
-            Token(2, _) if true => Some(0),
-            Token(3, _) if true => Some(1),
-            Token(4, _) if true => Some(2),
-            Token(5, _) if true => Some(3),
-            Token(6, _) if true => Some(4),
-            Token(7, _) if true => Some(5),
-            Token(8, _) if true => Some(6),
-            Token(9, _) if true => Some(7),
-            Token(10, _) if true => Some(8),
-            Token(11, _) if true => Some(9),
-            Token(12, _) if true => Some(10),
-            Token(13, _) if true => Some(11),
-            Token(14, _) if true => Some(12),
-            Token(15, _) if true => Some(13),
-            Token(16, _) if true => Some(14),
-            Token(17, _) if true => Some(15),
-            Token(18, _) if true => Some(16),
-            Token(19, _) if true => Some(17),
-            Token(20, _) if true => Some(18),
-            Token(21, _) if true => Some(19),
-            Token(22, _) if true => Some(20),
-            Token(23, _) if true => Some(21),
-            Token(24, _) if true => Some(22),
-            Token(25, _) if true => Some(23),
-            Token(26, _) if true => Some(24),
-            Token(27, _) if true => Some(25),
-            Token(28, _) if true => Some(26),
-            Token(29, _) if true => Some(27),
-            Token(30, _) if true => Some(28),
-            Token(31, _) if true => Some(29),
-            Token(0, _) if true => Some(30),
-            Token(1, _) if true => Some(31),
-            _ => None,
-        }
+        // Prim1 = "sub1" => ActionFn(65);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action65::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
     }
-    fn __token_to_symbol<
-        'input,
+    pub(crate) fn __reduce100<
     >(
-        __token_index: usize,
-        __token: Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> __Symbol<'input>
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
     {
-        match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 => match __token {
-                Token(2, __tok0) | Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) | Token(30, __tok0) | Token(31, __tok0) | Token(0, __tok0) | Token(1, __tok0) if true => __Symbol::Variant0(__tok0),
-                _ => unreachable!(),
-            },
-            _ => unreachable!(),
-        }
+        // Prim1 = "print" => ActionFn(66);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action66::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
     }
-    pub struct ProgParser {
-        builder: __lalrpop_util::lexer::MatcherBuilder,
-        _priv: (),
+    pub(crate) fn __reduce101<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "isnum" => ActionFn(67);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action67::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
     }
-
-    impl ProgParser {
-        pub fn new() -> ProgParser {
-            let __builder = super::__intern_token::new_builder();
-            ProgParser {
-                builder: __builder,
-                _priv: (),
-            }
-        }
-
-        #[allow(dead_code)]
-        pub fn parse<
-            'input,
-        >(
-            &self,
-            input: &'input str,
-        ) -> Result<SurfProg<Span1>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
-        {
-            let mut __tokens = self.builder.matcher(input);
-            __state_machine::Parser::drive(
-                __StateMachine {
-                    input,
-                    __phantom: core::marker::PhantomData::<(&())>,
-                },
-                __tokens,
-            )
-        }
+    pub(crate) fn __reduce102<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "isbool" => ActionFn(68);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action68::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
     }
-    pub(crate) fn __reduce<
-        'input,
+    pub(crate) fn __reduce103<
     >(
-        input: &'input str,
-        __action: i8,
         __lookahead_start: Option<&usize>,
-        __states: &mut alloc::vec::Vec<i8>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> Option<Result<SurfProg<Span1>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
     {
-        let (__pop_states, __nonterminal) = match __action {
-            0 => {
-                __reduce0(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            1 => {
-                __reduce1(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            2 => {
-                __reduce2(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            3 => {
-                __reduce3(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            4 => {
-                __reduce4(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            5 => {
-                __reduce5(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            6 => {
-                __reduce6(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            7 => {
-                __reduce7(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            8 => {
-                __reduce8(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            9 => {
-                __reduce9(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            10 => {
-                __reduce10(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            11 => {
-                __reduce11(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            12 => {
-                __reduce12(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            13 => {
-                __reduce13(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            14 => {
-                __reduce14(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            15 => {
-                __reduce15(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            16 => {
-                __reduce16(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            17 => {
-                __reduce17(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            18 => {
-                __reduce18(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            19 => {
-                __reduce19(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            20 => {
-                __reduce20(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            21 => {
-                __reduce21(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            22 => {
-                __reduce22(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            23 => {
-                __reduce23(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            24 => {
-                __reduce24(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            25 => {
-                __reduce25(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            26 => {
-                __reduce26(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            27 => {
-                __reduce27(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            28 => {
-                __reduce28(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            29 => {
-                __reduce29(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            30 => {
-                __reduce30(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            31 => {
-                __reduce31(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            32 => {
-                __reduce32(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            33 => {
-                __reduce33(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            34 => {
-                __reduce34(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            35 => {
-                __reduce35(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            36 => {
-                __reduce36(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            37 => {
-                __reduce37(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            38 => {
-                __reduce38(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            39 => {
-                __reduce39(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            40 => {
-                __reduce40(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            41 => {
-                __reduce41(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            42 => {
-                __reduce42(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            43 => {
-                __reduce43(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            44 => {
-                __reduce44(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            45 => {
-                __reduce45(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            46 => {
-                __reduce46(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            47 => {
-                __reduce47(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            48 => {
-                __reduce48(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            49 => {
-                __reduce49(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            50 => {
-                __reduce50(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            51 => {
-                __reduce51(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            52 => {
-                __reduce52(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            53 => {
-                __reduce53(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            54 => {
-                __reduce54(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            55 => {
-                __reduce55(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            56 => {
-                __reduce56(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            57 => {
-                __reduce57(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            58 => {
-                __reduce58(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            59 => {
-                __reduce59(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            60 => {
-                __reduce60(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            61 => {
-                __reduce61(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            62 => {
-                __reduce62(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            63 => {
-                __reduce63(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            64 => {
-                __reduce64(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            65 => {
-                __reduce65(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            66 => {
-                __reduce66(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            67 => {
-                __reduce67(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            68 => {
-                __reduce68(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            69 => {
-                __reduce69(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            70 => {
-                __reduce70(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            71 => {
-                __reduce71(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            72 => {
-                __reduce72(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            73 => {
-                __reduce73(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            74 => {
-                __reduce74(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            75 => {
-                __reduce75(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            76 => {
-                __reduce76(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            77 => {
-                __reduce77(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            78 => {
-                __reduce78(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            79 => {
-                __reduce79(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            80 => {
-                __reduce80(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            81 => {
-                __reduce81(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            82 => {
-                __reduce82(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            83 => {
-                __reduce83(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            84 => {
-                // __Prog = Prog => ActionFn(0);
-                let __sym0 = __pop_Variant18(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action0::<>(input, __sym0);
-                return Some(Ok(__nt));
-            }
-            _ => panic!("invalid action code {}", __action)
-        };
-        let __states_len = __states.len();
-        __states.truncate(__states_len - __pop_states);
-        let __state = *__states.last().unwrap();
-        let __next_state = __goto(__state, __nonterminal);
-        __states.push(__next_state);
-        None
+        // Prim1 = "istuple" => ActionFn(69);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action69::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
     }
-    #[inline(never)]
-    fn __symbol_type_mismatch() -> ! {
-        panic!("symbol type mismatch")
+    pub(crate) fn __reduce104<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "isstring" => ActionFn(70);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action70::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
     }
-    fn __pop_Variant5<
-      'input,
+    pub(crate) fn __reduce105<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, (SurfFunDecl<Span1>, &'input str), usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "len" => ActionFn(71);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action71::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
     }
-    fn __pop_Variant10<
-      'input,
+    pub(crate) fn __reduce106<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Exp<Span1>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "tostring" => ActionFn(72);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action72::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
     }
-    fn __pop_Variant1<
-      'input,
+    pub(crate) fn __reduce107<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Exp<Span1>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "tonum" => ActionFn(73);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action73::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
     }
-    fn __pop_Variant11<
-      'input,
+    pub(crate) fn __reduce108<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Prim, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "abs" => ActionFn(74);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action74::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
     }
-    fn __pop_Variant3<
-      'input,
+    pub(crate) fn __reduce109<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, String, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim2 = "min" => ActionFn(75);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action75::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 46)
     }
-    fn __pop_Variant15<
-      'input,
+    pub(crate) fn __reduce110<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, SurfFunDecl<Span1>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim2 = "max" => ActionFn(76);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action76::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 46)
     }
-    fn __pop_Variant18<
-      'input,
+    pub(crate) fn __reduce111<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, SurfProg<Span1>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Product = LAssoc<Times, NotExp> => ActionFn(23);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action23::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 47)
     }
-    fn __pop_Variant8<
-      'input,
+    pub(crate) fn __reduce112<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<(String, Exp<Span1>)>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prog = Exp => ActionFn(2);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action2::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 48)
     }
-    fn __pop_Variant12<
-      'input,
+    pub(crate) fn __reduce113<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<Exp<Span1>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // RAssoc<Log, CmpExp> = CmpExp, Log, RAssoc<Log, CmpExp> => ActionFn(191);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action191::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 49)
     }
-    fn __pop_Variant13<
-      'input,
+    pub(crate) fn __reduce114<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<String>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant6<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant2<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<Exp<Span1>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant4<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<String>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant9<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, bool, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant14<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<Exp<Span1>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant16<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<String>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant17<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, i64, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant7<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, usize, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant0<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    pub(crate) fn __reduce0<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (<Exp> ",") = Exp, "," => ActionFn(73);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action73::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 0)
-    }
-    pub(crate) fn __reduce1<
-        'input,
-    >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")* =  => ActionFn(71);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action71::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (0, 1)
-    }
-    pub(crate) fn __reduce2<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (<Exp> ",")* = (<Exp> ",")+ => ActionFn(72);
-        let __sym0 = __pop_Variant2(__symbols);
+        // RAssoc<Log, CmpExp> = CmpExp => ActionFn(97);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action72::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 1)
+        let __nt = super::__action97::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 49)
     }
-    pub(crate) fn __reduce3<
-        'input,
+    pub(crate) fn __reduce115<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")+ = Exp, "," => ActionFn(78);
-        assert!(__symbols.len() >= 2);
+        // SETINDEX = BaseExp, "[", Exp, "]", ":=", Stmt => ActionFn(192);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant4(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action78::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (2, 2)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action192::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (6, 50)
     }
-    pub(crate) fn __reduce4<
-        'input,
+    pub(crate) fn __reduce116<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")+ = (<Exp> ",")+, Exp, "," => ActionFn(79);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Shift = "<<" => ActionFn(34);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action79::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (3, 2)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action34::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 51)
     }
-    pub(crate) fn __reduce5<
-        'input,
+    pub(crate) fn __reduce117<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Id> ",") = Id, "," => ActionFn(68);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Shift = ">>" => ActionFn(35);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action68::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (2, 3)
-    }
-    pub(crate) fn __reduce6<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (<Id> ",")* =  => ActionFn(66);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action66::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (0, 4)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action35::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 51)
     }
-    pub(crate) fn __reduce7<
-        'input,
+    pub(crate) fn __reduce118<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")* = (<Id> ",")+ => ActionFn(67);
+        // ShiftExp = LAssoc<Shift, Sum> => ActionFn(21);
         let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action67::<>(input, __sym0);
+        let __nt = super::__action21::<>(__sym0);
         __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (1, 4)
+        (1, 52)
     }
-    pub(crate) fn __reduce8<
-        'input,
+    pub(crate) fn __reduce119<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")+ = Id, "," => ActionFn(82);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Stmt = SETINDEX => ActionFn(10);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action82::<>(input, __sym0, __sym1);
+        let __end = __sym0.2.clone();
+        let __nt = super::__action10::<>(__sym0);
         __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (2, 5)
+        (1, 53)
     }
-    pub(crate) fn __reduce9<
-        'input,
+    pub(crate) fn __reduce120<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")+ = (<Id> ",")+, Id, "," => ActionFn(83);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
+        // Stmt = BinOps => ActionFn(11);
         let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action83::<>(input, __sym0, __sym1, __sym2);
+        let __end = __sym0.2.clone();
+        let __nt = super::__action11::<>(__sym0);
         __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (3, 5)
-    }
-    pub(crate) fn __reduce10<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (FunDecl "and") = FunDecl, "and" => ActionFn(60);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action60::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (2, 6)
-    }
-    pub(crate) fn __reduce11<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (FunDecl "and")* =  => ActionFn(58);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action58::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (0, 7)
+        (1, 53)
     }
-    pub(crate) fn __reduce12<
-        'input,
+    pub(crate) fn __reduce121<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")* = (FunDecl "and")+ => ActionFn(59);
-        let __sym0 = __pop_Variant6(__symbols);
+        // Sum = LAssoc<PlusMinus, Product> => ActionFn(22);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action59::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 7)
+        let __nt = super::__action22::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 54)
     }
-    pub(crate) fn __reduce13<
-        'input,
+    pub(crate) fn __reduce122<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")+ = FunDecl, "and" => ActionFn(86);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
+        // Times = "*" => ActionFn(27);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action86::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 8)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action27::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 55)
     }
-    pub(crate) fn __reduce14<
-        'input,
+    pub(crate) fn __reduce123<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")+ = (FunDecl "and")+, FunDecl, "and" => ActionFn(87);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant15(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
+        // Times = "/" => ActionFn(28);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action87::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 8)
-    }
-    pub(crate) fn __reduce15<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // @L =  => ActionFn(61);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action61::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (0, 9)
-    }
-    pub(crate) fn __reduce16<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // @R =  => ActionFn(56);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action56::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (0, 10)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action28::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 55)
     }
-    pub(crate) fn __reduce17<
-        'input,
+    pub(crate) fn __reduce124<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BaseExp = Id => ActionFn(105);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Times = "//" => ActionFn(29);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action105::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 11)
+        let __nt = super::__action29::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 55)
     }
-    pub(crate) fn __reduce18<
-        'input,
+    pub(crate) fn __reduce125<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BaseExp = Bool => ActionFn(106);
-        let __sym0 = __pop_Variant9(__symbols);
+        // Times = "%" => ActionFn(30);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action106::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 11)
+        let __nt = super::__action30::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 55)
     }
-    pub(crate) fn __reduce19<
-        'input,
+    pub(crate) fn __reduce127<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BaseExp = Num => ActionFn(107);
-        let __sym0 = __pop_Variant17(__symbols);
+        // __Prog = Prog => ActionFn(0);
+        let __sym0 = __pop_Variant19(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action107::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 11)
+        let __nt = super::__action0::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 57)
     }
-    pub(crate) fn __reduce20<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // BaseExp = Prim1, "(", Exp, ")" => ActionFn(108);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant11(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action108::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 11)
-    }
-    pub(crate) fn __reduce21<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // BaseExp = "(", Exp, ")" => ActionFn(33);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action33::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 11)
-    }
-    pub(crate) fn __reduce22<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // BaseExp = Id, "(", Comma<Exp>, ")" => ActionFn(109);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action109::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 11)
-    }
-    pub(crate) fn __reduce23<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // BinOps = LogExp => ActionFn(11);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action11::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 12)
-    }
-    pub(crate) fn __reduce24<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // Bindings = Id, "=", Exp => ActionFn(40);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action40::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (3, 13)
-    }
-    pub(crate) fn __reduce25<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // Bindings = Bindings, ",", Id, "=", Exp => ActionFn(41);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant1(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant8(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action41::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (5, 13)
-    }
-    pub(crate) fn __reduce26<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // Bool = "true" => ActionFn(44);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action44::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 14)
-    }
-    pub(crate) fn __reduce27<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // Bool = "false" => ActionFn(45);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action45::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 14)
-    }
-    pub(crate) fn __reduce28<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // Boxed<Exp> = Exp => ActionFn(57);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action57::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 15)
-    }
-    pub(crate) fn __reduce29<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // Cmp = "<" => ActionFn(21);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action21::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
-    }
-    pub(crate) fn __reduce30<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // Cmp = "<=" => ActionFn(22);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action22::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
-    }
-    pub(crate) fn __reduce31<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // Cmp = ">" => ActionFn(23);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action23::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+}
+pub use self::__parse__Exp::ExpParser;
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+mod __parse__Prog {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::all)]
+
+    use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Prim};
+    use crate::span::Span1;
+    use crate::lexer::{LexicalError, Tok};
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    #[allow(unused_imports)]
+    use self::__lalrpop_util::state_machine as __state_machine;
+    extern crate core;
+    extern crate alloc;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub(crate) enum __Symbol<>
+     {
+        Variant0(Tok),
+        Variant1(f64),
+        Variant2(String),
+        Variant3(i64),
+        Variant4(Exp<Span1>),
+        Variant5(alloc::vec::Vec<Exp<Span1>>),
+        Variant6(alloc::vec::Vec<String>),
+        Variant7((SurfFunDecl<Span1>, Tok)),
+        Variant8(alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>),
+        Variant9(usize),
+        Variant10(Vec<(String, Exp<Span1>)>),
+        Variant11(Prim),
+        Variant12(bool),
+        Variant13(Box<Exp<Span1>>),
+        Variant14(Vec<Exp<Span1>>),
+        Variant15(Vec<String>),
+        Variant16(core::option::Option<Exp<Span1>>),
+        Variant17(SurfFunDecl<Span1>),
+        Variant18(core::option::Option<String>),
+        Variant19(SurfProg<Span1>),
+    }
+    const __ACTION: &[i16] = &[
+        // State 0
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 1
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 2
+        0, 0, 0, 0, 97, 0, -115, 0, 0, 0, -115, 0, 0, 0, -115, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, 0, 0, 0, -115, 0, 0, -115, -115, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 98, 0, 0, 0, 0,
+        // State 3
+        0, -38, 0, 100, -38, 0, -38, 0, 0, 0, -38, 0, 0, 0, -38, 0, -38, -38, 0, -38, 0, -38, -38, -38, 0, 0, -38, -38, 0, 0, -38, 0, 0, -38, -38, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38, -38, 0, 0, 0, 0,
+        // State 4
+        0, -40, 0, 0, -40, 0, -40, 0, 0, 0, -40, 0, 0, 0, -40, 0, -40, -40, 0, -40, 0, -40, -40, -40, 0, 0, -40, 0, 0, 0, -40, 0, 0, -40, -40, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 101, -40, 0, 0, 0, 0,
+        // State 5
+        0, -42, 0, 0, -42, 0, -42, 0, 0, 0, -42, 0, 0, 0, -42, 0, -42, -42, 0, -42, 0, -42, -42, -42, 0, 0, -42, 102, 0, 0, -42, 0, 0, -42, -42, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, -42, 0, 0, 0, 0,
+        // State 6
+        0, 103, 0, 0, -52, 0, -52, 0, 0, 0, -52, 0, 0, 0, -52, 0, -52, 104, 0, 105, 0, 106, 107, 108, 0, 0, -52, 0, 0, 0, -52, 0, 0, -52, -52, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, 0, 0, 0, 0,
+        // State 7
+        0, -122, 0, -122, -122, 0, -122, 0, 109, 110, -122, 111, 0, 0, -122, 0, -122, -122, -122, -122, 0, -122, -122, -122, -122, 0, -122, -122, 0, 0, -122, 0, 0, -122, -122, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -122, -122, 0, 0, 0, 0,
+        // State 8
+        0, -119, 0, -119, -119, 0, -119, 0, 0, 0, -119, 0, 0, 0, -119, 0, -119, -119, 112, -119, 0, -119, -119, -119, 113, 0, -119, -119, 0, 0, -119, 0, 0, -119, -119, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119, -119, 0, 0, 0, 0,
+        // State 9
+        0, -112, 114, -112, -112, 0, -112, 115, -112, -112, -112, -112, 116, 117, -112, 0, -112, -112, -112, -112, 0, -112, -112, -112, -112, 0, -112, -112, 0, 0, -112, 0, 0, -112, -112, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, -112, 0, 0, 0, 0,
+        // State 10
+        0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 0, 0, 0, 75, 0, 0, 76, 77, 78, 79, 80, 81, 0, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 11
+        11, 0, 0, 0, 0, 12, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 12
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 13
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0,
+        // State 14
+        11, 0, 0, 0, 0, 12, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 15
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 16
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 0, 0, 0, 75, 0, 0, 76, 77, 78, 79, 80, 81, 0, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 17
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 18
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 0, 0, 0, 75, 0, 0, 76, 77, 78, 79, 80, 81, 0, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 19
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 0, 0, 0, 75, 0, 0, 76, 77, 78, 79, 80, 81, 0, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 20
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 0, 0, 0, 75, 0, 0, 76, 77, 78, 79, 80, 81, 0, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 21
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 0, 0, 0, 75, 0, 0, 76, 77, 78, 79, 80, 81, 0, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 22
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 0, 0, 0, 75, 0, 0, 76, 77, 78, 79, 80, 81, 0, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 23
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 0, 0, 0, 75, 0, 0, 76, 77, 78, 79, 80, 81, 0, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 24
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 0, 0, 0, 75, 0, 0, 76, 77, 78, 79, 80, 81, 0, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 25
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 26
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 27
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 28
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 29
+        0, 0, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 149, 0, 0,
+        // State 30
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 31
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 32
+        11, 0, 0, 0, 0, 12, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 33
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 34
+        11, 0, 0, 0, 0, 12, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 35
+        0, 0, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 149, 0, 0,
+        // State 36
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 37
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 38
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 39
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 40
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 41
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 0, 0, 0, 75, 0, 0, 76, 77, 78, 79, 80, 81, 0, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 42
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 43
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 44
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 45
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 46
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 73, 74, 0, 0, 75, 13, 0, 76, 77, 78, 79, 80, 81, 14, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 92, 93, 94, 95,
+        // State 47
+        0, -93, -93, -93, -93, 15, -93, -93, -93, -93, -93, -93, -93, -93, -93, 0, -93, -93, -93, -93, 0, -93, -93, -93, -93, 16, -93, -93, 0, 0, -93, 0, 0, -93, -93, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, -93, 0, 0, 0, 0,
+        // State 48
+        0, 0, 0, 0, 0, 0, -121, 0, 0, 0, -121, 0, 0, 0, -121, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, -121, 0, 0, 0, -121, 0, 0, -121, -121, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 49
+        0, -79, 0, 0, -79, 0, -79, 0, 0, 0, -79, 0, 0, 0, -79, 0, -79, -79, 0, -79, 0, -79, -79, -79, 0, 0, -79, -79, 0, 0, -79, 0, 0, -79, -79, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, -79, 0, 0, 0, 0,
+        // State 50
+        0, -81, 0, 0, -81, 0, -81, 0, 0, 0, -81, 0, 0, 0, -81, 0, -81, -81, 0, -81, 0, -81, -81, -81, 0, 0, -81, 0, 0, 0, -81, 0, 0, -81, -81, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, 0, 0,
+        // State 51
+        0, -77, 0, 0, -77, 0, -77, 0, 0, 0, -77, 0, 0, 0, -77, 0, -77, -77, 0, -77, 0, -77, -77, -77, 0, 0, -77, 0, 0, 0, -77, 0, 0, -77, -77, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, -77, 0, 0, 0, 0,
+        // State 52
+        0, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, 0, -19, -19, -19, -19, 0, -19, -19, -19, -19, -19, -19, -19, 0, 0, -19, 0, 0, -19, -19, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, -19, 0, 0, 0, 0,
+        // State 53
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 54
+        0, 0, 0, 0, 0, 0, -64, 0, 0, 0, -64, 0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64, 0, 0, 0, -64, 0, 0, -64, -64, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 55
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 56
+        0, 0, 0, 0, 0, 0, -62, 0, 0, 0, -62, 0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0, -62, 0, 0, -62, -62, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 57
+        0, 0, 0, 0, 0, 0, -63, 0, 0, 0, -63, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63, 0, 0, 0, -63, 0, 0, -63, -63, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 58
+        0, 0, 0, 0, 0, 0, -34, 0, 0, 0, -34, 0, 0, 0, -34, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, -34, 0, 0, -34, -34, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 59
+        0, -87, -87, -87, -87, 0, -87, -87, -87, -87, -87, -87, -87, -87, -87, 0, -87, -87, -87, -87, 0, -87, -87, -87, -87, 0, -87, -87, 0, 0, -87, 0, 0, -87, -87, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, -87, 0, 0, 0, 0,
+        // State 60
+        0, 0, 0, 0, 0, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 61
+        0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 62
+        0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 63
+        0, -83, 0, -83, -83, 0, -83, 0, -83, -83, -83, -83, 0, 0, -83, 0, -83, -83, -83, -83, 0, -83, -83, -83, -83, 0, -83, -83, 0, 0, -83, 0, 0, -83, -83, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, -83, 0, 0, 0, 0,
+        // State 64
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 65
+        0, 0, 0, 0, 0, 0, -91, 0, 0, 0, -91, 0, 0, 0, -91, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, -91, 0, 0, -91, -91, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 66
+        0, 0, 0, 0, 0, 0, -120, 0, 0, 0, -120, 0, 0, 0, -120, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120, 0, 0, 0, -120, 0, 0, -120, -120, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 67
+        0, -75, 0, -75, -75, 0, -75, 0, 0, 0, -75, 0, 0, 0, -75, 0, -75, -75, 0, -75, 0, -75, -75, -75, 0, 0, -75, -75, 0, 0, -75, 0, 0, -75, -75, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, -75, 0, 0, 0, 0,
+        // State 68
+        0, 0, 0, 0, 0, 0, -65, 0, 0, 0, -65, 0, 0, 0, -65, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, -65, 0, 0, -65, -65, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 69
+        0, -85, 0, -85, -85, 0, -85, 0, 0, 0, -85, 0, 0, 0, -85, 0, -85, -85, -85, -85, 0, -85, -85, -85, -85, 0, -85, -85, 0, 0, -85, 0, 0, -85, -85, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, -85, 0, 0, 0, 0,
+        // State 70
+        0, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 71
+        0, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 72
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 73
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 122, 0, 0,
+        // State 74
+        0, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, 0, -44, -44, -44, -44, 0, -44, -44, -44, -44, -44, -44, -44, 0, 0, -44, 0, 0, -44, -44, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, -44, 0, 0, 0, 0,
+        // State 75
+        0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 76
+        0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 77
+        0, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 78
+        0, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 79
+        0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 80
+        0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 81
+        0, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 82
+        0, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 83
+        0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 84
+        0, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 85
+        0, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 86
+        0, 0, 0, 0, 0, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 87
+        0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 88
+        0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 89
+        0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 90
+        0, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, 0, -43, -43, -43, -43, 0, -43, -43, -43, -43, -43, -43, -43, 0, 0, -43, 0, 0, -43, -43, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, -43, 0, 0, 0, 0,
+        // State 91
+        0, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, 0, -21, -21, -21, -21, 0, -21, -21, -21, -21, -21, -21, -21, 0, 0, -21, 0, 0, -21, -21, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, -21, 0, 0, 0, 0,
+        // State 92
+        0, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, 0, -18, -18, -18, -18, 0, -18, -18, -18, -18, -18, -18, -18, 0, 0, -18, 0, 0, -18, -18, 0, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, -18, 0, 0, 0, 0,
+        // State 93
+        0, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, 0, -20, -20, -20, -20, 0, -20, -20, -20, -20, -20, -20, -20, 0, 0, -20, 0, 0, -20, -20, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, -20, 0, 0, 0, 0,
+        // State 94
+        0, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, 0, -22, -22, -22, -22, 0, -22, -22, -22, -22, -22, -22, -22, 0, 0, -22, 0, 0, -22, -22, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, -22, 0, 0, 0, 0,
+        // State 95
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 96
+        -89, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, -89, 0, -89, 0, 0, 0, -89, 0, 0, -89, -89, -89, -89, -89, -89, 0, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, 0, 0, -89, -89, -89, -89,
+        // State 97
+        -90, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, -90, 0, -90, 0, 0, 0, -90, 0, 0, -90, -90, -90, -90, -90, -90, 0, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, 0, 0, -90, -90, -90, -90,
+        // State 98
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 99
+        -37, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, -37, 0, -37, 0, 0, 0, -37, 0, 0, -37, -37, -37, -37, -37, -37, 0, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, 0, 0, -37, -37, -37, -37,
+        // State 100
+        -39, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, -39, 0, -39, 0, 0, 0, -39, 0, 0, -39, -39, -39, -39, -39, -39, 0, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, 0, 0, -39, -39, -39, -39,
+        // State 101
+        -41, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, -41, 0, -41, 0, 0, 0, -41, 0, 0, -41, -41, -41, -41, -41, -41, 0, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, 0, 0, -41, -41, -41, -41,
+        // State 102
+        -51, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, -51, 0, -51, 0, 0, 0, -51, 0, 0, -51, -51, -51, -51, -51, -51, 0, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, 0, 0, -51, -51, -51, -51,
+        // State 103
+        -46, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, -46, 0, -46, 0, 0, 0, -46, 0, 0, -46, -46, -46, -46, -46, -46, 0, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, 0, 0, -46, -46, -46, -46,
+        // State 104
+        -47, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, -47, 0, -47, 0, 0, 0, -47, 0, 0, -47, -47, -47, -47, -47, -47, 0, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, 0, 0, -47, -47, -47, -47,
+        // State 105
+        -50, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, -50, 0, -50, 0, 0, 0, -50, 0, 0, -50, -50, -50, -50, -50, -50, 0, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, 0, 0, -50, -50, -50, -50,
+        // State 106
+        -48, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48, -48, 0, -48, 0, 0, 0, -48, 0, 0, -48, -48, -48, -48, -48, -48, 0, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, 0, 0, -48, -48, -48, -48,
+        // State 107
+        -49, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, -49, 0, -49, 0, 0, 0, -49, 0, 0, -49, -49, -49, -49, -49, -49, 0, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, 0, 0, -49, -49, -49, -49,
+        // State 108
+        -94, 0, 0, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, -94, 0, -94, 0, 0, 0, -94, 0, 0, -94, -94, -94, -94, -94, -94, 0, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, 0, 0, -94, -94, -94, -94,
+        // State 109
+        -96, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, -96, 0, -96, 0, 0, 0, -96, 0, 0, -96, -96, -96, -96, -96, -96, 0, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, 0, 0, -96, -96, -96, -96,
+        // State 110
+        -95, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, -95, 0, -95, 0, 0, 0, -95, 0, 0, -95, -95, -95, -95, -95, -95, 0, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, 0, 0, -95, -95, -95, -95,
+        // State 111
+        -117, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, -117, 0, -117, 0, 0, 0, -117, 0, 0, -117, -117, -117, -117, -117, -117, 0, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, 0, 0, -117, -117, -117, -117,
+        // State 112
+        -118, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, -118, 0, -118, 0, 0, 0, -118, 0, 0, -118, -118, -118, -118, -118, -118, 0, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, 0, 0, -118, -118, -118, -118,
+        // State 113
+        -126, 0, 0, 0, 0, -126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -126, -126, 0, -126, 0, 0, 0, -126, 0, 0, -126, -126, -126, -126, -126, -126, 0, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, 0, 0, -126, -126, -126, -126,
+        // State 114
+        -123, 0, 0, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -123, -123, 0, -123, 0, 0, 0, -123, 0, 0, -123, -123, -123, -123, -123, -123, 0, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, 0, 0, -123, -123, -123, -123,
+        // State 115
+        -124, 0, 0, 0, 0, -124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -124, -124, 0, -124, 0, 0, 0, -124, 0, 0, -124, -124, -124, -124, -124, -124, 0, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, 0, 0, -124, -124, -124, -124,
+        // State 116
+        -125, 0, 0, 0, 0, -125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -125, -125, 0, -125, 0, 0, 0, -125, 0, 0, -125, -125, -125, -125, -125, -125, 0, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, 0, 0, -125, -125, -125, -125,
+        // State 117
+        0, 0, 0, 0, 0, 0, 141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 118
+        0, -92, -92, -92, -92, 15, -92, -92, -92, -92, -92, -92, -92, -92, -92, 0, -92, -92, -92, -92, 0, -92, -92, -92, -92, 34, -92, -92, 0, 0, -92, 0, 0, -92, -92, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, -92, 0, 0, 0, 0,
+        // State 119
+        0, 0, 0, 0, 0, 0, 145, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 120
+        0, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, 0, -29, -29, -29, -29, 0, -29, -29, -29, -29, -29, -29, -29, 0, 0, -29, 0, 0, -29, -29, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, -29, 0, 0, 0, 0,
+        // State 121
+        0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 122
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 123
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 124
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 125
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 126
+        0, 0, 0, 0, 0, 0, 154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 127
+        0, 0, 0, 0, 0, 0, -53, 0, 0, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 128
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 129
+        0, -93, -93, -93, -93, 15, -93, -93, -93, -93, -93, -93, -93, -93, -93, 0, -93, -93, -93, -93, 0, -93, -93, -93, -93, 34, -93, -93, 0, 0, -93, 0, 0, -93, -93, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, -93, 0, 0, 0, 0,
+        // State 130
+        0, 0, 0, 0, 0, 0, -114, 0, 0, 0, -114, 0, 0, 0, -114, 0, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, 0, 0, 0, -114, 0, 0, -114, -114, 0, 0, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 131
+        0, 0, 0, 0, 0, 0, -68, 0, 0, 0, -68, 0, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0, 0, 0, -68, 0, 0, -68, -68, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 132
+        0, 0, 0, 0, 0, 0, -45, 0, 0, 0, -45, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, -45, 0, 0, -45, -45, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 133
+        0, -74, 0, -74, -74, 0, -74, 0, 0, 0, -74, 0, 0, 0, -74, 0, -74, -74, 0, -74, 0, -74, -74, -74, 0, 0, -74, -74, 0, 0, -74, 0, 0, -74, -74, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, -74, 0, 0, 0, 0,
+        // State 134
+        0, -76, 0, 0, -76, 0, -76, 0, 0, 0, -76, 0, 0, 0, -76, 0, -76, -76, 0, -76, 0, -76, -76, -76, 0, 0, -76, 0, 0, 0, -76, 0, 0, -76, -76, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, -76, 0, 0, 0, 0,
+        // State 135
+        0, -78, 0, 0, -78, 0, -78, 0, 0, 0, -78, 0, 0, 0, -78, 0, -78, -78, 0, -78, 0, -78, -78, -78, 0, 0, -78, -78, 0, 0, -78, 0, 0, -78, -78, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, -78, 0, 0, 0, 0,
+        // State 136
+        0, -80, 0, 0, -80, 0, -80, 0, 0, 0, -80, 0, 0, 0, -80, 0, -80, -80, 0, -80, 0, -80, -80, -80, 0, 0, -80, 0, 0, 0, -80, 0, 0, -80, -80, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, 0,
+        // State 137
+        0, -82, 0, -82, -82, 0, -82, 0, -82, -82, -82, -82, 0, 0, -82, 0, -82, -82, -82, -82, 0, -82, -82, -82, -82, 0, -82, -82, 0, 0, -82, 0, 0, -82, -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, -82, 0, 0, 0, 0,
+        // State 138
+        0, -84, 0, -84, -84, 0, -84, 0, 0, 0, -84, 0, 0, 0, -84, 0, -84, -84, -84, -84, 0, -84, -84, -84, -84, 0, -84, -84, 0, 0, -84, 0, 0, -84, -84, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84, -84, 0, 0, 0, 0,
+        // State 139
+        0, -86, -86, -86, -86, 0, -86, -86, -86, -86, -86, -86, -86, -86, -86, 0, -86, -86, -86, -86, 0, -86, -86, -86, -86, 0, -86, -86, 0, 0, -86, 0, 0, -86, -86, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, -86, 0, 0, 0, 0,
+        // State 140
+        0, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, 0, -26, -26, -26, -26, 0, -26, -26, -26, -26, -26, -26, -26, 0, 0, -26, 0, 0, -26, -26, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, -26, 0, 0, 0, 0,
+        // State 141
+        0, 0, 0, 0, 0, 0, 157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 142
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 143
+        0, 0, 0, 0, 0, 0, -61, 0, 0, 0, -61, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0, -61, 0, 0, -61, -61, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 144
+        0, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, 0, -27, -27, -27, -27, 0, -27, -27, -27, -27, -27, -27, -27, 0, 0, -27, 0, 0, -27, -27, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, -27, 0, 0, 0, 0,
+        // State 145
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 146
+        0, 0, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0,
+        // State 147
+        0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 148
+        0, 0, 0, 0, 0, 0, -57, 0, 0, 0, 165, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 149
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 166, 0, 0,
+        // State 150
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 151
+        0, 0, 0, 0, 0, 0, -69, 0, 0, 0, -69, 0, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, 0, 0, -69, 0, 0, -69, -69, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 152
+        0, 0, 0, 0, 0, 0, -55, 0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 153
+        0, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, 0, -32, -32, -32, -32, 0, -32, -32, -32, -32, -32, -32, -32, 0, 0, -32, 0, 0, -32, -32, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, -32, 0, 0, 0, 0,
+        // State 154
+        -4, 0, 0, 0, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4, -4, 0, -4, -4, 0, 0, -4, -4, 0, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, 0, 0, -4, -4, -4, -4,
+        // State 155
+        0, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, 42, -31, -31, -31, -31, 0, -31, -31, -31, -31, -31, -31, -31, 0, 0, -31, 0, 0, -31, -31, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, -31, 0, 0, 0, 0,
+        // State 156
+        0, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, 0, -23, -23, -23, -23, 0, -23, -23, -23, -23, -23, -23, -23, 0, 0, -23, 0, 0, -23, -23, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23, -23, 0, 0, 0, 0,
+        // State 157
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 158
+        0, 0, 0, 0, 0, 0, 172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 159
+        0, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, 0, -28, -28, -28, -28, 0, -28, -28, -28, -28, -28, -28, -28, 0, 0, -28, 0, 0, -28, -28, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, -28, 0, 0, 0, 0,
+        // State 160
+        0, 0, 0, 0, 0, 0, 173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 161
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 162
+        0, 0, 0, 0, 0, 0, -59, 0, 0, 0, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 163
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 164
+        0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, 0,
+        // State 165
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 166
+        0, 0, 0, 0, 0, 0, -88, 0, 0, 0, -88, 0, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, 0, 0, 0, -88, 0, 0, -88, -88, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 167
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 168
+        -5, 0, 0, 0, 0, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5, -5, 0, -5, -5, 0, 0, -5, -5, 0, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, 0, 0, -5, -5, -5, -5,
+        // State 169
+        0, 0, 0, 0, 0, 0, 178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 170
+        0, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, 0, -31, -31, -31, -31, 0, -31, -31, -31, -31, -31, -31, -31, 0, 0, -31, 0, 0, -31, -31, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, -31, 0, 0, 0, 0,
+        // State 171
+        0, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, 0, -30, -30, -30, -30, 0, -30, -30, -30, -30, -30, -30, -30, 0, 0, -30, 0, 0, -30, -30, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, -30, 0, 0, 0, 0,
+        // State 172
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 173
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 174
+        0, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, 0,
+        // State 175
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 176
+        0, 0, 0, 0, 0, 0, -116, 0, 0, 0, -116, 0, 0, 0, -116, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, 0, 0, 0, -116, 0, 0, -116, -116, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 177
+        0, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, 0, -24, -24, -24, -24, 0, -24, -24, -24, -24, -24, -24, -24, 0, 0, -24, 0, 0, -24, -24, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24, -24, 0, 0, 0, 0,
+        // State 178
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 179
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 180
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 181
+        0, 0, 0, 0, 0, 0, -71, 0, 0, 0, -71, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, -71, 0, 0, -71, -71, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 182
+        0, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, 0, -33, -33, -33, -33, 0, -33, -33, -33, -33, -33, -33, -33, 0, 0, -33, 0, 0, -33, -33, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, -33, 0, 0, 0, 0,
+        // State 183
+        0, 0, 0, 0, 0, 0, 185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 184
+        0, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, 0, -25, -25, -25, -25, 0, -25, -25, -25, -25, -25, -25, -25, 0, 0, -25, 0, 0, -25, -25, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, -25, 0, 0, 0, 0,
+    ];
+    fn __action(state: i16, integer: usize) -> i16 {
+        __ACTION[(state as usize) * 61 + integer]
     }
-    pub(crate) fn __reduce32<
-        'input,
-    >(
-        input: &'input str,
+    const __EOF_ACTION: &[i16] = &[
+        // State 0
+        0,
+        // State 1
+        0,
+        // State 2
+        -115,
+        // State 3
+        -38,
+        // State 4
+        -40,
+        // State 5
+        -42,
+        // State 6
+        -52,
+        // State 7
+        -122,
+        // State 8
+        -119,
+        // State 9
+        -112,
+        // State 10
+        0,
+        // State 11
+        0,
+        // State 12
+        0,
+        // State 13
+        0,
+        // State 14
+        0,
+        // State 15
+        0,
+        // State 16
+        0,
+        // State 17
+        0,
+        // State 18
+        0,
+        // State 19
+        0,
+        // State 20
+        0,
+        // State 21
+        0,
+        // State 22
+        0,
+        // State 23
+        0,
+        // State 24
+        0,
+        // State 25
+        0,
+        // State 26
+        0,
+        // State 27
+        0,
+        // State 28
+        0,
+        // State 29
+        0,
+        // State 30
+        0,
+        // State 31
+        0,
+        // State 32
+        0,
+        // State 33
+        0,
+        // State 34
+        0,
+        // State 35
+        0,
+        // State 36
+        0,
+        // State 37
+        0,
+        // State 38
+        0,
+        // State 39
+        0,
+        // State 40
+        0,
+        // State 41
+        0,
+        // State 42
+        0,
+        // State 43
+        0,
+        // State 44
+        0,
+        // State 45
+        0,
+        // State 46
+        0,
+        // State 47
+        -93,
+        // State 48
+        -121,
+        // State 49
+        -79,
+        // State 50
+        -81,
+        // State 51
+        -77,
+        // State 52
+        -19,
+        // State 53
+        -113,
+        // State 54
+        -64,
+        // State 55
+        0,
+        // State 56
+        -62,
+        // State 57
+        -63,
+        // State 58
+        -34,
+        // State 59
+        -87,
+        // State 60
+        0,
+        // State 61
+        0,
+        // State 62
+        0,
+        // State 63
+        -83,
+        // State 64
+        -128,
+        // State 65
+        -91,
+        // State 66
+        -120,
+        // State 67
+        -75,
+        // State 68
+        -65,
+        // State 69
+        -85,
+        // State 70
+        0,
+        // State 71
+        0,
+        // State 72
+        0,
+        // State 73
+        0,
+        // State 74
+        -44,
+        // State 75
+        0,
+        // State 76
+        0,
+        // State 77
+        0,
+        // State 78
+        0,
+        // State 79
+        0,
+        // State 80
+        0,
+        // State 81
+        0,
+        // State 82
+        0,
+        // State 83
+        0,
+        // State 84
+        0,
+        // State 85
+        0,
+        // State 86
+        0,
+        // State 87
+        0,
+        // State 88
+        0,
+        // State 89
+        0,
+        // State 90
+        -43,
+        // State 91
+        -21,
+        // State 92
+        -18,
+        // State 93
+        -20,
+        // State 94
+        -22,
+        // State 95
+        0,
+        // State 96
+        0,
+        // State 97
+        0,
+        // State 98
+        0,
+        // State 99
+        0,
+        // State 100
+        0,
+        // State 101
+        0,
+        // State 102
+        0,
+        // State 103
+        0,
+        // State 104
+        0,
+        // State 105
+        0,
+        // State 106
+        0,
+        // State 107
+        0,
+        // State 108
+        0,
+        // State 109
+        0,
+        // State 110
+        0,
+        // State 111
+        0,
+        // State 112
+        0,
+        // State 113
+        0,
+        // State 114
+        0,
+        // State 115
+        0,
+        // State 116
+        0,
+        // State 117
+        0,
+        // State 118
+        -92,
+        // State 119
+        0,
+        // State 120
+        -29,
+        // State 121
+        0,
+        // State 122
+        0,
+        // State 123
+        0,
+        // State 124
+        0,
+        // State 125
+        0,
+        // State 126
+        0,
+        // State 127
+        0,
+        // State 128
+        0,
+        // State 129
+        -93,
+        // State 130
+        -114,
+        // State 131
+        -68,
+        // State 132
+        -45,
+        // State 133
+        -74,
+        // State 134
+        -76,
+        // State 135
+        -78,
+        // State 136
+        -80,
+        // State 137
+        -82,
+        // State 138
+        -84,
+        // State 139
+        -86,
+        // State 140
+        -26,
+        // State 141
+        0,
+        // State 142
+        0,
+        // State 143
+        -61,
+        // State 144
+        -27,
+        // State 145
+        0,
+        // State 146
+        0,
+        // State 147
+        0,
+        // State 148
+        0,
+        // State 149
+        0,
+        // State 150
+        0,
+        // State 151
+        -69,
+        // State 152
+        0,
+        // State 153
+        -32,
+        // State 154
+        0,
+        // State 155
+        -31,
+        // State 156
+        -23,
+        // State 157
+        0,
+        // State 158
+        0,
+        // State 159
+        -28,
+        // State 160
+        0,
+        // State 161
+        0,
+        // State 162
+        0,
+        // State 163
+        0,
+        // State 164
+        0,
+        // State 165
+        0,
+        // State 166
+        -88,
+        // State 167
+        0,
+        // State 168
+        0,
+        // State 169
+        0,
+        // State 170
+        -31,
+        // State 171
+        -30,
+        // State 172
+        0,
+        // State 173
+        0,
+        // State 174
+        0,
+        // State 175
+        0,
+        // State 176
+        -116,
+        // State 177
+        -24,
+        // State 178
+        0,
+        // State 179
+        0,
+        // State 180
+        0,
+        // State 181
+        -71,
+        // State 182
+        -33,
+        // State 183
+        0,
+        // State 184
+        -25,
+    ];
+    fn __goto(state: i16, nt: usize) -> i16 {
+        match nt {
+            2 => 32,
+            5 => 146,
+            8 => 1,
+            11 => match state {
+                10 => 118,
+                16 | 18..=24 => 129,
+                _ => 47,
+            },
+            12 => 48,
+            13 => 123,
+            14 => 18,
+            15 => match state {
+                20 => 135,
+                _ => 49,
+            },
+            16 => 19,
+            17 => match state {
+                21 => 136,
+                _ => 50,
+            },
+            18 => 20,
+            19 => match state {
+                19 => 134,
+                _ => 51,
+            },
+            20 => 52,
+            21 => match state {
+                31 => 151,
+                42 => 178,
+                _ => 131,
+            },
+            22 => 21,
+            23 => 2,
+            24 => match state {
+                34 => 158,
+                _ => 126,
+            },
+            25 => match state {
+                35 => 160,
+                _ => 147,
+            },
+            26 => match state {
+                0 => 53,
+                11 => 119,
+                12 => 122,
+                14 | 34 => 127,
+                15 => 128,
+                25 => 141,
+                26 => 142,
+                27 => 143,
+                28 => 145,
+                30 => 150,
+                32 => 152,
+                33 => 157,
+                36 => 161,
+                37 => 166,
+                38 => 167,
+                39 => 169,
+                40 => 175,
+                43 => 179,
+                44 => 180,
+                45 => 181,
+                46 => 183,
+                _ => 132,
+            },
+            28 => 54,
+            29 => match state {
+                1 => 95,
+                _ => 55,
+            },
+            30 => 56,
+            32 => 3,
+            33 => 4,
+            34 => 5,
+            35 => 6,
+            36 => 7,
+            37 => 8,
+            38 => 9,
+            39 => 57,
+            40 => 16,
+            41 => 58,
+            42 => match state {
+                24 => 139,
+                _ => 59,
+            },
+            43 => 22,
+            44 => 60,
+            45 => 61,
+            46 => 62,
+            47 => match state {
+                22 => 137,
+                _ => 63,
+            },
+            48 => 64,
+            49 => match state {
+                16 => 130,
+                _ => 65,
+            },
+            50 => 66,
+            51 => 23,
+            52 => match state {
+                18 => 133,
+                _ => 67,
+            },
+            53 => match state {
+                41 => 176,
+                _ => 68,
+            },
+            54 => match state {
+                23 => 138,
+                _ => 69,
+            },
+            55 => 24,
+            _ => 0,
+        }
+    }
+    fn __expected_tokens(__state: i16) -> alloc::vec::Vec<alloc::string::String> {
+        const __TERMINAL: &[&str] = &[
+            r###""!""###,
+            r###""!=""###,
+            r###""%""###,
+            r###""&""###,
+            r###""&&""###,
+            r###""(""###,
+            r###"")""###,
+            r###""*""###,
+            r###""+""###,
+            r###""++""###,
+            r###"",""###,
+            r###""-""###,
+            r###""/""###,
+            r###""//""###,
+            r###"":""###,
+            r###"":=""###,
+            r###"";""###,
+            r###""<""###,
+            r###""<<""###,
+            r###""<=""###,
+            r###""=""###,
+            r###""==""###,
+            r###"">""###,
+            r###"">=""###,
+            r###"">>""###,
+            r###""[""###,
+            r###""]""###,
+            r###""^""###,
+            r###""abs""###,
+            r###""add1""###,
+            r###""and""###,
+            r###""block""###,
+            r###""def""###,
+            r###""else""###,
+            r###""end""###,
+            r###""false""###,
+            r###""if""###,
+            r###""in""###,
+            r###""isbool""###,
+            r###""isnum""###,
+            r###""isstring""###,
+            r###""istuple""###,
+            r###""lambda""###,
+            r###""len""###,
+            r###""let""###,
+            r###""max""###,
+            r###""min""###,
+            r###""print""###,
+            r###""readbool""###,
+            r###""readnum""###,
+            r###""sub1""###,
+            r###""substr""###,
+            r###""tonum""###,
+            r###""tostring""###,
+            r###""true""###,
+            r###""|""###,
+            r###""||""###,
+            r###"Float"###,
+            r###"Id"###,
+            r###"Num"###,
+            r###"Str"###,
+        ];
+        __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
+            let next_state = __action(__state, index);
+            if next_state == 0 {
+                None
+            } else {
+                Some(alloc::string::ToString::to_string(terminal))
+            }
+        }).collect()
+    }
+    pub(crate) struct __StateMachine<>
+    where 
+    {
+        __phantom: core::marker::PhantomData<()>,
+    }
+    impl<> __state_machine::ParserDefinition for __StateMachine<>
+    where 
+    {
+        type Location = usize;
+        type Error = LexicalError;
+        type Token = Tok;
+        type TokenIndex = usize;
+        type Symbol = __Symbol<>;
+        type Success = SurfProg<Span1>;
+        type StateIndex = i16;
+        type Action = i16;
+        type ReduceIndex = i16;
+        type NonterminalIndex = usize;
+
+        #[inline]
+        fn start_location(&self) -> Self::Location {
+              Default::default()
+        }
+
+        #[inline]
+        fn start_state(&self) -> Self::StateIndex {
+              0
+        }
+
+        #[inline]
+        fn token_to_index(&self, token: &Self::Token) -> Option<usize> {
+            __token_to_integer(token, core::marker::PhantomData::<()>)
+        }
+
+        #[inline]
+        fn action(&self, state: i16, integer: usize) -> i16 {
+            __action(state, integer)
+        }
+
+        #[inline]
+        fn error_action(&self, state: i16) -> i16 {
+            __action(state, 61 - 1)
+        }
+
+        #[inline]
+        fn eof_action(&self, state: i16) -> i16 {
+            __EOF_ACTION[state as usize]
+        }
+
+        #[inline]
+        fn goto(&self, state: i16, nt: usize) -> i16 {
+            __goto(state, nt)
+        }
+
+        fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
+            __token_to_symbol(token_index, token, core::marker::PhantomData::<()>)
+        }
+
+        fn expected_tokens(&self, state: i16) -> alloc::vec::Vec<alloc::string::String> {
+            __expected_tokens(state)
+        }
+
+        #[inline]
+        fn uses_error_recovery(&self) -> bool {
+            false
+        }
+
+        #[inline]
+        fn error_recovery_symbol(
+            &self,
+            recovery: __state_machine::ErrorRecovery<Self>,
+        ) -> Self::Symbol {
+            panic!("error recovery not enabled for this grammar")
+        }
+
+        fn reduce(
+            &mut self,
+            action: i16,
+            start_location: Option<&Self::Location>,
+            states: &mut alloc::vec::Vec<i16>,
+            symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
+        ) -> Option<__state_machine::ParseResult<Self>> {
+            __reduce(
+                action,
+                start_location,
+                states,
+                symbols,
+                core::marker::PhantomData::<()>,
+            )
+        }
+
+        fn simulate_reduce(&self, action: i16) -> __state_machine::SimulatedReduce<Self> {
+            panic!("error recovery not enabled for this grammar")
+        }
+    }
+    fn __token_to_integer<
+    >(
+        __token: &Tok,
+        _: core::marker::PhantomData<()>,
+    ) -> Option<usize>
+    {
+        match *__token {
+            Tok::Bang if true => Some(0),
+            Tok::Neq if true => Some(1),
+            Tok::Percent if true => Some(2),
+            Tok::Amp if true => Some(3),
+            Tok::AndAnd if true => Some(4),
+            Tok::LParen if true => Some(5),
+            Tok::RParen if true => Some(6),
+            Tok::Times if true => Some(7),
+            Tok::Plus if true => Some(8),
+            Tok::PlusPlus if true => Some(9),
+            Tok::Comma if true => Some(10),
+            Tok::Minus if true => Some(11),
+            Tok::Divide if true => Some(12),
+            Tok::SlashSlash if true => Some(13),
+            Tok::Colon if true => Some(14),
+            Tok::ColonEqual if true => Some(15),
+            Tok::Semicolon if true => Some(16),
+            Tok::Lt if true => Some(17),
+            Tok::LtLt if true => Some(18),
+            Tok::Le if true => Some(19),
+            Tok::Equal if true => Some(20),
+            Tok::EqEq if true => Some(21),
+            Tok::Gt if true => Some(22),
+            Tok::Ge if true => Some(23),
+            Tok::GtGt if true => Some(24),
+            Tok::LBracket if true => Some(25),
+            Tok::RBracket if true => Some(26),
+            Tok::Caret if true => Some(27),
+            Tok::Abs if true => Some(28),
+            Tok::Add1 if true => Some(29),
+            Tok::And if true => Some(30),
+            Tok::Block if true => Some(31),
+            Tok::Def if true => Some(32),
+            Tok::Else if true => Some(33),
+            Tok::End if true => Some(34),
+            Tok::False if true => Some(35),
+            Tok::If if true => Some(36),
+            Tok::In if true => Some(37),
+            Tok::IsBool if true => Some(38),
+            Tok::IsNum if true => Some(39),
+            Tok::IsStr if true => Some(40),
+            Tok::IsTuple if true => Some(41),
+            Tok::Lambda if true => Some(42),
+            Tok::Len if true => Some(43),
+            Tok::Let if true => Some(44),
+            Tok::Max if true => Some(45),
+            Tok::Min if true => Some(46),
+            Tok::Print if true => Some(47),
+            Tok::ReadBool if true => Some(48),
+            Tok::ReadNum if true => Some(49),
+            Tok::Sub1 if true => Some(50),
+            Tok::Substr if true => Some(51),
+            Tok::ToNum if true => Some(52),
+            Tok::ToStr if true => Some(53),
+            Tok::True if true => Some(54),
+            Tok::Pipe if true => Some(55),
+            Tok::OrOr if true => Some(56),
+            Tok::Float(_) if true => Some(57),
+            Tok::Id(_) if true => Some(58),
+            Tok::Num(_) if true => Some(59),
+            Tok::Str(_) if true => Some(60),
+            _ => None,
+        }
+    }
+    fn __token_to_symbol<
+    >(
+        __token_index: usize,
+        __token: Tok,
+        _: core::marker::PhantomData<()>,
+    ) -> __Symbol<>
+    {
+        match __token_index {
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 49 | 50 | 51 | 52 | 53 | 54 | 55 | 56 => __Symbol::Variant0(__token),
+            57 => match __token {
+                Tok::Float(__tok0) if true => __Symbol::Variant1(__tok0),
+                _ => unreachable!(),
+            },
+            58 | 60 => match __token {
+                Tok::Id(__tok0) | Tok::Str(__tok0) if true => __Symbol::Variant2(__tok0),
+                _ => unreachable!(),
+            },
+            59 => match __token {
+                Tok::Num(__tok0) if true => __Symbol::Variant3(__tok0),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+    pub struct ProgParser {
+        _priv: (),
+    }
+
+    impl ProgParser {
+        pub fn new() -> ProgParser {
+            ProgParser {
+                _priv: (),
+            }
+        }
+
+        #[allow(dead_code)]
+        pub fn parse<
+            __TOKEN: __ToTriple<>,
+            __TOKENS: IntoIterator<Item=__TOKEN>,
+        >(
+            &self,
+            __tokens0: __TOKENS,
+        ) -> Result<SurfProg<Span1>, __lalrpop_util::ParseError<usize, Tok, LexicalError>>
+        {
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+            __state_machine::Parser::drive(
+                __StateMachine {
+                    __phantom: core::marker::PhantomData::<()>,
+                },
+                __tokens,
+            )
+        }
+    }
+    pub(crate) fn __reduce<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut alloc::vec::Vec<i16>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> Option<Result<SurfProg<Span1>,__lalrpop_util::ParseError<usize, Tok, LexicalError>>>
+    {
+        let (__pop_states, __nonterminal) = match __action {
+            0 => {
+                __reduce0(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            1 => {
+                __reduce1(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            2 => {
+                __reduce2(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            3 => {
+                __reduce3(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            4 => {
+                __reduce4(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            5 => {
+                __reduce5(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            6 => {
+                __reduce6(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            7 => {
+                __reduce7(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            8 => {
+                __reduce8(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            9 => {
+                __reduce9(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            10 => {
+                __reduce10(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            11 => {
+                __reduce11(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            12 => {
+                __reduce12(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            13 => {
+                __reduce13(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            14 => {
+                __reduce14(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            15 => {
+                __reduce15(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            16 => {
+                __reduce16(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            17 => {
+                __reduce17(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            18 => {
+                __reduce18(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            19 => {
+                __reduce19(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            20 => {
+                __reduce20(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            21 => {
+                __reduce21(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            22 => {
+                __reduce22(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            23 => {
+                __reduce23(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            24 => {
+                __reduce24(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            25 => {
+                __reduce25(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            26 => {
+                __reduce26(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            27 => {
+                __reduce27(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            28 => {
+                __reduce28(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            29 => {
+                __reduce29(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            30 => {
+                __reduce30(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            31 => {
+                __reduce31(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            32 => {
+                __reduce32(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            33 => {
+                __reduce33(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            34 => {
+                __reduce34(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            35 => {
+                __reduce35(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            36 => {
+                __reduce36(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            37 => {
+                __reduce37(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            38 => {
+                __reduce38(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            39 => {
+                __reduce39(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            40 => {
+                __reduce40(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            41 => {
+                __reduce41(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            42 => {
+                __reduce42(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            43 => {
+                __reduce43(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            44 => {
+                __reduce44(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            45 => {
+                __reduce45(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            46 => {
+                __reduce46(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            47 => {
+                __reduce47(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            48 => {
+                __reduce48(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            49 => {
+                __reduce49(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            50 => {
+                __reduce50(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            51 => {
+                __reduce51(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            52 => {
+                __reduce52(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            53 => {
+                __reduce53(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            54 => {
+                __reduce54(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            55 => {
+                __reduce55(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            56 => {
+                __reduce56(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            57 => {
+                __reduce57(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            58 => {
+                __reduce58(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            59 => {
+                __reduce59(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            60 => {
+                __reduce60(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            61 => {
+                __reduce61(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            62 => {
+                __reduce62(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            63 => {
+                __reduce63(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            64 => {
+                __reduce64(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            65 => {
+                __reduce65(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            66 => {
+                __reduce66(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            67 => {
+                __reduce67(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            68 => {
+                __reduce68(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            69 => {
+                __reduce69(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            70 => {
+                __reduce70(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            71 => {
+                __reduce71(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            72 => {
+                __reduce72(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            73 => {
+                __reduce73(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            74 => {
+                __reduce74(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            75 => {
+                __reduce75(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            76 => {
+                __reduce76(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            77 => {
+                __reduce77(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            78 => {
+                __reduce78(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            79 => {
+                __reduce79(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            80 => {
+                __reduce80(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            81 => {
+                __reduce81(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            82 => {
+                __reduce82(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            83 => {
+                __reduce83(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            84 => {
+                __reduce84(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            85 => {
+                __reduce85(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            86 => {
+                __reduce86(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            87 => {
+                __reduce87(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            88 => {
+                __reduce88(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            89 => {
+                __reduce89(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            90 => {
+                __reduce90(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            91 => {
+                __reduce91(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            92 => {
+                __reduce92(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            93 => {
+                __reduce93(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            94 => {
+                __reduce94(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            95 => {
+                __reduce95(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            96 => {
+                __reduce96(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            97 => {
+                __reduce97(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            98 => {
+                __reduce98(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            99 => {
+                __reduce99(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            100 => {
+                __reduce100(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            101 => {
+                __reduce101(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            102 => {
+                __reduce102(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            103 => {
+                __reduce103(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            104 => {
+                __reduce104(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            105 => {
+                __reduce105(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            106 => {
+                __reduce106(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            107 => {
+                __reduce107(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            108 => {
+                __reduce108(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            109 => {
+                __reduce109(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            110 => {
+                __reduce110(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            111 => {
+                __reduce111(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            112 => {
+                __reduce112(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            113 => {
+                __reduce113(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            114 => {
+                __reduce114(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            115 => {
+                __reduce115(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            116 => {
+                __reduce116(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            117 => {
+                __reduce117(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            118 => {
+                __reduce118(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            119 => {
+                __reduce119(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            120 => {
+                __reduce120(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            121 => {
+                __reduce121(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            122 => {
+                __reduce122(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            123 => {
+                __reduce123(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            124 => {
+                __reduce124(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            125 => {
+                __reduce125(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            126 => {
+                __reduce126(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            127 => {
+                // __Prog = Prog => ActionFn(0);
+                let __sym0 = __pop_Variant19(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(__sym0);
+                return Some(Ok(__nt));
+            }
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __states_len = __states.len();
+        __states.truncate(__states_len - __pop_states);
+        let __state = *__states.last().unwrap();
+        let __next_state = __goto(__state, __nonterminal);
+        __states.push(__next_state);
+        None
+    }
+    #[inline(never)]
+    fn __symbol_type_mismatch() -> ! {
+        panic!("symbol type mismatch")
+    }
+    fn __pop_Variant7<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, (SurfFunDecl<Span1>, Tok), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant13<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Box<Exp<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant4<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Exp<Span1>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant11<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Prim, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant2<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, String, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant17<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, SurfFunDecl<Span1>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant19<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, SurfProg<Span1>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant0<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Tok, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant10<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Vec<(String, Exp<Span1>)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant14<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Vec<Exp<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant15<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Vec<String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant8<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant5<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, alloc::vec::Vec<Exp<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant6<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, alloc::vec::Vec<String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant12<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, bool, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant16<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, core::option::Option<Exp<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant18<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, core::option::Option<String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant1<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, f64, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant3<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, i64, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant9<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    pub(crate) fn __reduce0<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // (<Exp> ",") = Exp, "," => ActionFn(116);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action116::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 0)
+    }
+    pub(crate) fn __reduce1<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // (<Exp> ",")* =  => ActionFn(114);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action114::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (0, 1)
+    }
+    pub(crate) fn __reduce2<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // (<Exp> ",")* = (<Exp> ",")+ => ActionFn(115);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action115::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 1)
+    }
+    pub(crate) fn __reduce3<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // (<Exp> ",")+ = Exp, "," => ActionFn(121);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action121::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 2)
+    }
+    pub(crate) fn __reduce4<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // (<Exp> ",")+ = (<Exp> ",")+, Exp, "," => ActionFn(122);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action122::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 2)
+    }
+    pub(crate) fn __reduce5<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // (<Id> ",") = Id, "," => ActionFn(111);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action111::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (2, 3)
+    }
+    pub(crate) fn __reduce6<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // (<Id> ",")* =  => ActionFn(109);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action109::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (0, 4)
+    }
+    pub(crate) fn __reduce7<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // (<Id> ",")* = (<Id> ",")+ => ActionFn(110);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action110::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 4)
+    }
+    pub(crate) fn __reduce8<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // (<Id> ",")+ = Id, "," => ActionFn(125);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action125::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (2, 5)
+    }
+    pub(crate) fn __reduce9<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // (<Id> ",")+ = (<Id> ",")+, Id, "," => ActionFn(126);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action126::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (3, 5)
+    }
+    pub(crate) fn __reduce10<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // (FunDecl "and") = FunDecl, "and" => ActionFn(103);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant17(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action103::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 6)
+    }
+    pub(crate) fn __reduce11<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // (FunDecl "and")* =  => ActionFn(101);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action101::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (0, 7)
+    }
+    pub(crate) fn __reduce12<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // (FunDecl "and")* = (FunDecl "and")+ => ActionFn(102);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action102::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 7)
+    }
+    pub(crate) fn __reduce13<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // (FunDecl "and")+ = FunDecl, "and" => ActionFn(129);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant17(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action129::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (2, 8)
+    }
+    pub(crate) fn __reduce14<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // (FunDecl "and")+ = (FunDecl "and")+, FunDecl, "and" => ActionFn(130);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action130::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (3, 8)
+    }
+    pub(crate) fn __reduce15<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // @L =  => ActionFn(104);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action104::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (0, 9)
+    }
+    pub(crate) fn __reduce16<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // @R =  => ActionFn(99);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action99::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (0, 10)
+    }
+    pub(crate) fn __reduce17<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Id => ActionFn(163);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action163::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 11)
+    }
+    pub(crate) fn __reduce18<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Bool => ActionFn(164);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action164::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 11)
+    }
+    pub(crate) fn __reduce19<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Num => ActionFn(165);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action165::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 11)
+    }
+    pub(crate) fn __reduce20<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Float => ActionFn(166);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action166::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 11)
+    }
+    pub(crate) fn __reduce21<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Str => ActionFn(167);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action167::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 11)
+    }
+    pub(crate) fn __reduce22<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Prim1, "(", Exp, ")" => ActionFn(168);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action168::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 11)
+    }
+    pub(crate) fn __reduce23<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Prim2, "(", Exp, ",", Exp, ")" => ActionFn(169);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant4(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action169::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (6, 11)
+    }
+    pub(crate) fn __reduce24<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = "substr", "(", Exp, ",", Exp, ",", Exp, ")" => ActionFn(170);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant4(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant4(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym7.2.clone();
+        let __nt = super::__action170::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (8, 11)
+    }
+    pub(crate) fn __reduce25<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Prim0, "(", ")" => ActionFn(171);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action171::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 11)
+    }
+    pub(crate) fn __reduce26<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = "(", Exp, ")" => ActionFn(55);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action55::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 11)
+    }
+    pub(crate) fn __reduce27<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = "block", ":", Exp, "end" => ActionFn(56);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action56::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 11)
+    }
+    pub(crate) fn __reduce28<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = "(", ")" => ActionFn(172);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action172::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 11)
+    }
+    pub(crate) fn __reduce29<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = "(", Exp, ",", Comma<Exp>, ")" => ActionFn(173);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant14(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action173::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (5, 11)
+    }
+    pub(crate) fn __reduce30<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = BaseExp, "[", Exp, "]" => ActionFn(174);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action174::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 11)
+    }
+    pub(crate) fn __reduce31<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = BaseExp, "(", Comma<Exp>, ")" => ActionFn(175);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant14(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action175::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 11)
+    }
+    pub(crate) fn __reduce32<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = "lambda", "(", Comma<Id>, ")", ":", Boxed<Exp>, "end" => ActionFn(176);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant13(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action176::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (7, 11)
+    }
+    pub(crate) fn __reduce33<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BinOps = LogExp => ActionFn(15);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action15::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 12)
+    }
+    pub(crate) fn __reduce34<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Bindings = Id, "=", Exp => ActionFn(77);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action77::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 13)
+    }
+    pub(crate) fn __reduce35<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Bindings = Bindings, ",", Id, "=", Exp => ActionFn(78);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant4(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action78::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (5, 13)
+    }
+    pub(crate) fn __reduce36<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BitAnd = "&" => ActionFn(33);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action33::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 14)
+    }
+    pub(crate) fn __reduce37<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BitAndExp = LAssoc<BitAnd, ShiftExp> => ActionFn(20);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action20::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 15)
+    }
+    pub(crate) fn __reduce38<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BitOr = "|" => ActionFn(31);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action31::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 16)
+    }
+    pub(crate) fn __reduce39<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BitOrExp = LAssoc<BitOr, BitXorExp> => ActionFn(18);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action18::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 17)
+    }
+    pub(crate) fn __reduce40<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BitXor = "^" => ActionFn(32);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action32::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 18)
+    }
+    pub(crate) fn __reduce41<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // BitXorExp = LAssoc<BitXor, BitAndExp> => ActionFn(19);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action19::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 19)
+    }
+    pub(crate) fn __reduce42<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Bool = "true" => ActionFn(79);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action79::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 20)
+    }
+    pub(crate) fn __reduce43<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Bool = "false" => ActionFn(80);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action80::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 20)
+    }
+    pub(crate) fn __reduce44<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Boxed<Exp> = Exp => ActionFn(100);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action100::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 21)
+    }
+    pub(crate) fn __reduce45<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Cmp = "<" => ActionFn(38);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action38::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 22)
+    }
+    pub(crate) fn __reduce46<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Cmp = "<=" => ActionFn(39);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action39::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 22)
+    }
+    pub(crate) fn __reduce47<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Cmp = ">" => ActionFn(40);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action40::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 22)
+    }
+    pub(crate) fn __reduce48<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Cmp = ">=" => ActionFn(41);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action41::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 22)
+    }
+    pub(crate) fn __reduce49<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Cmp = "==" => ActionFn(42);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action42::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 22)
+    }
+    pub(crate) fn __reduce50<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Cmp = "!=" => ActionFn(43);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action43::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 22)
+    }
+    pub(crate) fn __reduce51<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // CmpExp = LAssoc<Cmp, BitOrExp> => ActionFn(17);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action17::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 23)
+    }
+    pub(crate) fn __reduce52<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Comma<Exp> = Exp => ActionFn(193);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action193::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 24)
+    }
+    pub(crate) fn __reduce53<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Comma<Exp> =  => ActionFn(194);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action194::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (0, 24)
+    }
+    pub(crate) fn __reduce54<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Comma<Exp> = (<Exp> ",")+, Exp => ActionFn(195);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action195::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (2, 24)
+    }
+    pub(crate) fn __reduce55<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Comma<Exp> = (<Exp> ",")+ => ActionFn(196);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action196::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 24)
+    }
+    pub(crate) fn __reduce56<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Comma<Id> = Id => ActionFn(197);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action197::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 25)
+    }
+    pub(crate) fn __reduce57<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Comma<Id> =  => ActionFn(198);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action198::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (0, 25)
+    }
+    pub(crate) fn __reduce58<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Comma<Id> = (<Id> ",")+, Id => ActionFn(199);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action199::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (2, 25)
+    }
+    pub(crate) fn __reduce59<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Comma<Id> = (<Id> ",")+ => ActionFn(200);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action200::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 25)
+    }
+    pub(crate) fn __reduce60<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Exp = Stmt, ";", Exp => ActionFn(177);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action177::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 26)
+    }
+    pub(crate) fn __reduce61<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Exp = IF => ActionFn(6);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action6::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 26)
+    }
+    pub(crate) fn __reduce62<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Exp = LET => ActionFn(7);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action7::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 26)
+    }
+    pub(crate) fn __reduce63<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Exp = FUNDEFS => ActionFn(8);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action8::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 26)
+    }
+    pub(crate) fn __reduce64<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Exp = Stmt => ActionFn(9);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action9::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 26)
+    }
+    pub(crate) fn __reduce65<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Exp? = Exp => ActionFn(112);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action112::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
+    }
+    pub(crate) fn __reduce66<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Exp? =  => ActionFn(113);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action113::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (0, 27)
+    }
+    pub(crate) fn __reduce67<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // FUNDEFS = FunDecl, "in", Boxed<Exp> => ActionFn(178);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant17(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action178::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 28)
+    }
+    pub(crate) fn __reduce68<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // FUNDEFS = (FunDecl "and")+, FunDecl, "in", Boxed<Exp> => ActionFn(179);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant13(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action179::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 28)
+    }
+    pub(crate) fn __reduce69<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // FunDecl = "def", Id, "(", Comma<Id>, ")", ":", Exp => ActionFn(180);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant4(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action180::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (7, 29)
+    }
+    pub(crate) fn __reduce70<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // IF = "if", Exp, ":", Exp, "else", ":", Exp => ActionFn(181);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant4(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant4(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action181::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (7, 30)
+    }
+    pub(crate) fn __reduce71<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Id? = Id => ActionFn(107);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action107::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 31)
+    }
+    pub(crate) fn __reduce72<
+    >(
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Cmp = ">=" => ActionFn(24);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Id? =  => ActionFn(108);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action108::<>(&__start, &__end);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (0, 31)
+    }
+    pub(crate) fn __reduce73<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<BitAnd, ShiftExp> = LAssoc<BitAnd, ShiftExp>, BitAnd, ShiftExp => ActionFn(182);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action24::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action182::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 32)
     }
-    pub(crate) fn __reduce33<
-        'input,
+    pub(crate) fn __reduce74<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Cmp = "==" => ActionFn(25);
-        let __sym0 = __pop_Variant0(__symbols);
+        // LAssoc<BitAnd, ShiftExp> = ShiftExp => ActionFn(89);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action25::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        let __nt = super::__action89::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 32)
     }
-    pub(crate) fn __reduce34<
-        'input,
+    pub(crate) fn __reduce75<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Cmp = "!=" => ActionFn(26);
-        let __sym0 = __pop_Variant0(__symbols);
+        // LAssoc<BitOr, BitXorExp> = LAssoc<BitOr, BitXorExp>, BitOr, BitXorExp => ActionFn(183);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action26::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action183::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 33)
     }
-    pub(crate) fn __reduce35<
-        'input,
+    pub(crate) fn __reduce76<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // CmpExp = LAssoc<Cmp, Sum> => ActionFn(13);
-        let __sym0 = __pop_Variant1(__symbols);
+        // LAssoc<BitOr, BitXorExp> = BitXorExp => ActionFn(93);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action13::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __nt = super::__action93::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 33)
     }
-    pub(crate) fn __reduce36<
-        'input,
+    pub(crate) fn __reduce77<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = Exp => ActionFn(120);
-        let __sym0 = __pop_Variant1(__symbols);
+        // LAssoc<BitXor, BitAndExp> = LAssoc<BitXor, BitAndExp>, BitXor, BitAndExp => ActionFn(184);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action120::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 18)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action184::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 34)
     }
-    pub(crate) fn __reduce37<
-        'input,
+    pub(crate) fn __reduce78<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> =  => ActionFn(121);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action121::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (0, 18)
+        // LAssoc<BitXor, BitAndExp> = BitAndExp => ActionFn(91);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action91::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 34)
     }
-    pub(crate) fn __reduce38<
-        'input,
+    pub(crate) fn __reduce79<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = (<Exp> ",")+, Exp => ActionFn(122);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        // LAssoc<Cmp, BitOrExp> = LAssoc<Cmp, BitOrExp>, Cmp, BitOrExp => ActionFn(185);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action122::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (2, 18)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action185::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 35)
     }
-    pub(crate) fn __reduce39<
-        'input,
+    pub(crate) fn __reduce80<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = (<Exp> ",")+ => ActionFn(123);
-        let __sym0 = __pop_Variant2(__symbols);
+        // LAssoc<Cmp, BitOrExp> = BitOrExp => ActionFn(95);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action123::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 18)
+        let __nt = super::__action95::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 35)
     }
-    pub(crate) fn __reduce40<
-        'input,
+    pub(crate) fn __reduce81<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = Id => ActionFn(124);
-        let __sym0 = __pop_Variant3(__symbols);
+        // LAssoc<PlusMinus, Product> = LAssoc<PlusMinus, Product>, PlusMinus, Product => ActionFn(186);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action124::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 19)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action186::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 36)
     }
-    pub(crate) fn __reduce41<
-        'input,
+    pub(crate) fn __reduce82<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Id> =  => ActionFn(125);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action125::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (0, 19)
+        // LAssoc<PlusMinus, Product> = Product => ActionFn(85);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action85::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 36)
     }
-    pub(crate) fn __reduce42<
-        'input,
+    pub(crate) fn __reduce83<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = (<Id> ",")+, Id => ActionFn(126);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant3(__symbols);
+        // LAssoc<Shift, Sum> = LAssoc<Shift, Sum>, Shift, Sum => ActionFn(187);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
         let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action126::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (2, 19)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action187::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 37)
     }
-    pub(crate) fn __reduce43<
-        'input,
+    pub(crate) fn __reduce84<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = (<Id> ",")+ => ActionFn(127);
+        // LAssoc<Shift, Sum> = Sum => ActionFn(87);
         let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action127::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 19)
+        let __nt = super::__action87::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 37)
     }
-    pub(crate) fn __reduce44<
-        'input,
+    pub(crate) fn __reduce85<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp = IF => ActionFn(5);
-        let __sym0 = __pop_Variant1(__symbols);
+        // LAssoc<Times, NotExp> = LAssoc<Times, NotExp>, Times, NotExp => ActionFn(188);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action5::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action188::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 38)
     }
-    pub(crate) fn __reduce45<
-        'input,
+    pub(crate) fn __reduce86<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp = LET => ActionFn(6);
-        let __sym0 = __pop_Variant1(__symbols);
+        // LAssoc<Times, NotExp> = NotExp => ActionFn(83);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action6::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        let __nt = super::__action83::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 38)
     }
-    pub(crate) fn __reduce46<
-        'input,
+    pub(crate) fn __reduce87<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp = FUNDEFS => ActionFn(7);
-        let __sym0 = __pop_Variant1(__symbols);
+        // LET = "let", Bindings, "in", Exp => ActionFn(189);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant4(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action189::<>(__sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 39)
+    }
+    pub(crate) fn __reduce88<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Log = "&&" => ActionFn(36);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action7::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        let __nt = super::__action36::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 40)
     }
-    pub(crate) fn __reduce47<
-        'input,
+    pub(crate) fn __reduce89<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp = BinOps => ActionFn(8);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Log = "||" => ActionFn(37);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action8::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        let __nt = super::__action37::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 40)
     }
-    pub(crate) fn __reduce48<
-        'input,
+    pub(crate) fn __reduce90<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp? = Exp => ActionFn(69);
-        let __sym0 = __pop_Variant1(__symbols);
+        // LogExp = RAssoc<Log, CmpExp> => ActionFn(16);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action69::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 21)
+        let __nt = super::__action16::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 41)
     }
-    pub(crate) fn __reduce49<
-        'input,
+    pub(crate) fn __reduce91<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Exp? =  => ActionFn(70);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action70::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (0, 21)
+        // NotExp = "!", BaseExp => ActionFn(190);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action190::<>(__sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 42)
     }
-    pub(crate) fn __reduce50<
-        'input,
+    pub(crate) fn __reduce92<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // FUNDEFS = FunDecl, "in", Boxed<Exp> => ActionFn(110);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
+        // NotExp = BaseExp => ActionFn(45);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action110::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 22)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action45::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 42)
     }
-    pub(crate) fn __reduce51<
-        'input,
+    pub(crate) fn __reduce93<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // FUNDEFS = (FunDecl "and")+, FunDecl, "in", Boxed<Exp> => ActionFn(111);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant15(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
+        // PlusMinus = "+" => ActionFn(24);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action111::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 22)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action24::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 43)
     }
-    pub(crate) fn __reduce52<
-        'input,
+    pub(crate) fn __reduce94<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // FunDecl = "def", Id, "(", Comma<Id>, ")", ":", Exp => ActionFn(112);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant1(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant13(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
+        // PlusMinus = "-" => ActionFn(25);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action112::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (7, 23)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action25::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 43)
     }
-    pub(crate) fn __reduce53<
-        'input,
+    pub(crate) fn __reduce95<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // IF = "if", Exp, ":", Exp, "else", ":", Exp => ActionFn(113);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant1(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant1(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
+        // PlusMinus = "++" => ActionFn(26);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action113::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (7, 24)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action26::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 43)
     }
-    pub(crate) fn __reduce54<
-        'input,
+    pub(crate) fn __reduce96<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Id = r#"[a-zA-Z][a-zA-Z0-9_]*"# => ActionFn(43);
+        // Prim0 = "readnum" => ActionFn(62);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action43::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 25)
+        let __nt = super::__action62::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 44)
     }
-    pub(crate) fn __reduce55<
-        'input,
+    pub(crate) fn __reduce97<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Id? = Id => ActionFn(64);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Prim0 = "readbool" => ActionFn(63);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action64::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 26)
+        let __nt = super::__action63::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 44)
     }
-    pub(crate) fn __reduce56<
-        'input,
+    pub(crate) fn __reduce98<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Id? =  => ActionFn(65);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action65::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (0, 26)
+        // Prim1 = "add1" => ActionFn(64);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action64::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
     }
-    pub(crate) fn __reduce57<
-        'input,
+    pub(crate) fn __reduce99<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAssoc<Cmp, Sum> = LAssoc<Cmp, Sum>, Cmp, Sum => ActionFn(114);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Prim1 = "sub1" => ActionFn(65);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action114::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 27)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action65::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
     }
-    pub(crate) fn __reduce58<
-        'input,
+    pub(crate) fn __reduce100<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAssoc<Cmp, Sum> = Sum => ActionFn(52);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Prim1 = "print" => ActionFn(66);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action52::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 27)
+        let __nt = super::__action66::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
     }
-    pub(crate) fn __reduce59<
-        'input,
+    pub(crate) fn __reduce101<
+    >(
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "isnum" => ActionFn(67);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action67::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
+    }
+    pub(crate) fn __reduce102<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAssoc<PlusMinus, Product> = LAssoc<PlusMinus, Product>, PlusMinus, Product => ActionFn(115);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Prim1 = "isbool" => ActionFn(68);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action115::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 28)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action68::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
     }
-    pub(crate) fn __reduce60<
-        'input,
+    pub(crate) fn __reduce103<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAssoc<PlusMinus, Product> = Product => ActionFn(50);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Prim1 = "istuple" => ActionFn(69);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action50::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 28)
+        let __nt = super::__action69::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
     }
-    pub(crate) fn __reduce61<
-        'input,
+    pub(crate) fn __reduce104<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAssoc<Times, NotExp> = LAssoc<Times, NotExp>, Times, NotExp => ActionFn(116);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Prim1 = "isstring" => ActionFn(70);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action116::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 29)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action70::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
     }
-    pub(crate) fn __reduce62<
-        'input,
+    pub(crate) fn __reduce105<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LAssoc<Times, NotExp> = NotExp => ActionFn(48);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Prim1 = "len" => ActionFn(71);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action48::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        let __nt = super::__action71::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
     }
-    pub(crate) fn __reduce63<
-        'input,
+    pub(crate) fn __reduce106<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LET = "let", Bindings, "in", Exp => ActionFn(117);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant1(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant8(__symbols);
+        // Prim1 = "tostring" => ActionFn(72);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action117::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 30)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action72::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 45)
     }
-    pub(crate) fn __reduce64<
-        'input,
+    pub(crate) fn __reduce107<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Log = "&&" => ActionFn(19);
+        // Prim1 = "tonum" => ActionFn(73);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action19::<>(input, __sym0);
+        let __nt = super::__action73::<>(__sym0);
         __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 31)
+        (1, 45)
     }
-    pub(crate) fn __reduce65<
-        'input,
+    pub(crate) fn __reduce108<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Log = "||" => ActionFn(20);
+        // Prim1 = "abs" => ActionFn(74);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action20::<>(input, __sym0);
+        let __nt = super::__action74::<>(__sym0);
         __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 31)
+        (1, 45)
     }
-    pub(crate) fn __reduce66<
-        'input,
+    pub(crate) fn __reduce109<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // LogExp = RAssoc<Log, CmpExp> => ActionFn(12);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Prim2 = "min" => ActionFn(75);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action12::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 32)
+        let __nt = super::__action75::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 46)
     }
-    pub(crate) fn __reduce67<
-        'input,
+    pub(crate) fn __reduce110<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // NotExp = "!", BaseExp => ActionFn(118);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
+        // Prim2 = "max" => ActionFn(76);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action118::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 33)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action76::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 46)
     }
-    pub(crate) fn __reduce68<
-        'input,
+    pub(crate) fn __reduce111<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // NotExp = BaseExp => ActionFn(28);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Product = LAssoc<Times, NotExp> => ActionFn(23);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action28::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 33)
+        let __nt = super::__action23::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 47)
     }
-    pub(crate) fn __reduce69<
-        'input,
+    pub(crate) fn __reduce112<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Num = r#"[+-]?[0-9]+"# => ActionFn(42);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Prog = Exp => ActionFn(2);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action42::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 34)
+        let __nt = super::__action2::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 48)
     }
-    pub(crate) fn __reduce70<
-        'input,
+    pub(crate) fn __reduce113<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // PlusMinus = "+" => ActionFn(16);
-        let __sym0 = __pop_Variant0(__symbols);
+        // RAssoc<Log, CmpExp> = CmpExp, Log, RAssoc<Log, CmpExp> => ActionFn(191);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action16::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 35)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action191::<>(__sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 49)
     }
-    pub(crate) fn __reduce71<
-        'input,
+    pub(crate) fn __reduce114<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // PlusMinus = "-" => ActionFn(17);
-        let __sym0 = __pop_Variant0(__symbols);
+        // RAssoc<Log, CmpExp> = CmpExp => ActionFn(97);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action17::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 35)
+        let __nt = super::__action97::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 49)
     }
-    pub(crate) fn __reduce72<
-        'input,
+    pub(crate) fn __reduce115<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prim1 = "add1" => ActionFn(35);
-        let __sym0 = __pop_Variant0(__symbols);
+        // SETINDEX = BaseExp, "[", Exp, "]", ":=", Stmt => ActionFn(192);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant4(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action35::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action192::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (6, 50)
     }
-    pub(crate) fn __reduce73<
-        'input,
+    pub(crate) fn __reduce116<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prim1 = "sub1" => ActionFn(36);
+        // Shift = "<<" => ActionFn(34);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action36::<>(input, __sym0);
+        let __nt = super::__action34::<>(__sym0);
         __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        (1, 51)
     }
-    pub(crate) fn __reduce74<
-        'input,
+    pub(crate) fn __reduce117<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prim1 = "print" => ActionFn(37);
+        // Shift = ">>" => ActionFn(35);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action37::<>(input, __sym0);
+        let __nt = super::__action35::<>(__sym0);
         __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        (1, 51)
     }
-    pub(crate) fn __reduce75<
-        'input,
+    pub(crate) fn __reduce118<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prim1 = "isnum" => ActionFn(38);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ShiftExp = LAssoc<Shift, Sum> => ActionFn(21);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action38::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        let __nt = super::__action21::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 52)
     }
-    pub(crate) fn __reduce76<
-        'input,
+    pub(crate) fn __reduce119<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prim1 = "isbool" => ActionFn(39);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Stmt = SETINDEX => ActionFn(10);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action39::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        let __nt = super::__action10::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 53)
     }
-    pub(crate) fn __reduce77<
-        'input,
+    pub(crate) fn __reduce120<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Product = LAssoc<Times, NotExp> => ActionFn(15);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Stmt = BinOps => ActionFn(11);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action15::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 37)
+        let __nt = super::__action11::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 53)
     }
-    pub(crate) fn __reduce78<
-        'input,
+    pub(crate) fn __reduce121<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Prog = Exp => ActionFn(2);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Sum = LAssoc<PlusMinus, Product> => ActionFn(22);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action2::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 38)
+        let __nt = super::__action22::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 54)
     }
-    pub(crate) fn __reduce79<
-        'input,
+    pub(crate) fn __reduce122<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // RAssoc<Log, CmpExp> = CmpExp, Log, RAssoc<Log, CmpExp> => ActionFn(119);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Times = "*" => ActionFn(27);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action119::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 39)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action27::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 55)
     }
-    pub(crate) fn __reduce80<
-        'input,
+    pub(crate) fn __reduce123<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // RAssoc<Log, CmpExp> = CmpExp => ActionFn(54);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Times = "/" => ActionFn(28);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action54::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 39)
+        let __nt = super::__action28::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 55)
     }
-    pub(crate) fn __reduce81<
-        'input,
+    pub(crate) fn __reduce124<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Sum = LAssoc<PlusMinus, Product> => ActionFn(14);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Times = "//" => ActionFn(29);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action14::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 40)
+        let __nt = super::__action29::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 55)
     }
-    pub(crate) fn __reduce82<
-        'input,
+    pub(crate) fn __reduce125<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Times = "*" => ActionFn(18);
+        // Times = "%" => ActionFn(30);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action18::<>(input, __sym0);
+        let __nt = super::__action30::<>(__sym0);
         __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 41)
+        (1, 55)
     }
-    pub(crate) fn __reduce83<
-        'input,
+    pub(crate) fn __reduce126<
     >(
-        input: &'input str,
         __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // __Exp = Exp => ActionFn(1);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action1::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 42)
+        // __Exp = Exp => ActionFn(1);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action1::<>(__sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 56)
+    }
+}
+pub use self::__parse__Prog::ProgParser;
+
+fn __action0<
+>(
+    (_, __0, _): (usize, SurfProg<Span1>, usize),
+) -> SurfProg<Span1>
+{
+    __0
+}
+
+fn __action1<
+>(
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+fn __action2<
+>(
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> SurfProg<Span1>
+{
+    __0
+}
+
+fn __action3<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, funs, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>, usize),
+    (_, last, _): (usize, SurfFunDecl<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    {
+      let mut decls: Vec<SurfFunDecl<Span1>> = funs.into_iter().map(|(decl, _)| decl).collect();
+      decls.push(last);
+      Exp::FunDefs { decls, body, ann: Span1 { start_ix: l, end_ix: r} }
+    }
+}
+
+fn __action4<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, name, _): (usize, String, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, parameters, _): (usize, Vec<String>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, body, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> SurfFunDecl<Span1>
+{
+    FunDecl { name, parameters, body, ann: Span1 { start_ix: l, end_ix: r}  }
+}
+
+fn __action5<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, first, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, second, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Seq { first: Box::new(first), second: Box::new(second), ann: Span1 { start_ix: l, end_ix: r }}
+}
+
+fn __action6<
+>(
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+fn __action7<
+>(
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+fn __action8<
+>(
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+fn __action9<
+>(
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+fn __action10<
+>(
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+fn __action11<
+>(
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+fn __action12<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, cond, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, thn, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, els, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::If { cond: Box::new(cond), thn: Box::new(thn), els: Box::new(els), ann: Span1 { start_ix: l, end_ix: r }}
+}
+
+fn __action13<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, bindings, _): (usize, Vec<(String, Exp<Span1>)>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, body, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Let { bindings, body: Box::new(body), ann: Span1 { start_ix: l, end_ix: r }}
+}
+
+fn __action14<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, t, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, i, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, v, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim(Prim::SetIndex, vec![Box::new(t), Box::new(i), Box::new(v)], Span1 { start_ix: l, end_ix: r })
+}
+
+fn __action15<
+>(
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+fn __action16<
+>(
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+fn __action17<
+>(
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+fn __action18<
+>(
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+fn __action19<
+>(
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+fn __action20<
+>(
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+fn __action21<
+>(
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+fn __action22<
+>(
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+fn __action23<
+>(
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+fn __action24<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Add
+}
+
+fn __action25<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Sub
+}
+
+fn __action26<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Concat
+}
+
+fn __action27<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Mul
+}
+
+fn __action28<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Div
+}
+
+fn __action29<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::IntDiv
+}
+
+fn __action30<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Rem
+}
+
+fn __action31<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::BitOr
+}
+
+fn __action32<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::BitXor
+}
+
+fn __action33<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::BitAnd
+}
+
+fn __action34<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Shl
+}
+
+fn __action35<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Shr
+}
+
+fn __action36<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::And
+}
+
+fn __action37<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Or
+}
+
+fn __action38<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Lt
+}
+
+fn __action39<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Le
+}
+
+fn __action40<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Gt
+}
+
+fn __action41<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Ge
+}
+
+fn __action42<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Eq
+}
+
+fn __action43<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Neq
+}
+
+fn __action44<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, e, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim(Prim::Not, vec![Box::new(e)], Span1 { start_ix: l, end_ix: r })
+}
+
+fn __action45<
+>(
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+fn __action46<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, x, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Var(x, Span1 { start_ix: l, end_ix: r })
+}
+
+fn __action47<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, b, _): (usize, bool, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Bool(b, Span1 { start_ix: l, end_ix: r })
+}
+
+fn __action48<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, n, _): (usize, i64, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Num(n, Span1 { start_ix: l, end_ix: r })
+}
+
+fn __action49<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, f, _): (usize, f64, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Float(f, Span1 { start_ix: l, end_ix: r })
+}
+
+fn __action50<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, s, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Str(s, Span1 { start_ix: l, end_ix: r })
+}
+
+fn __action51<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, p, _): (usize, Prim, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, e, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim(p, vec![Box::new(e)], Span1 { start_ix: l, end_ix: r })
+}
+
+fn __action52<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, p, _): (usize, Prim, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim(p, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
+}
+
+fn __action53<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, s, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, start, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, end, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim(Prim::Substr, vec![Box::new(s), Box::new(start), Box::new(end)], Span1 { start_ix: l, end_ix: r })
+}
+
+fn __action54<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, p, _): (usize, Prim, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim(p, vec![], Span1 { start_ix: l, end_ix: r })
+}
+
+fn __action55<
+>(
+    (_, _, _): (usize, Tok, usize),
+    (_, e, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+) -> Exp<Span1>
+{
+    e
+}
+
+fn __action56<
+>(
+    (_, _, _): (usize, Tok, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, e, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+) -> Exp<Span1>
+{
+    e
+}
+
+fn __action57<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim(Prim::MakeTuple, vec![], Span1 { start_ix: l, end_ix: r })
+}
+
+fn __action58<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, first, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, rest, _): (usize, Vec<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    {
+        let mut es = vec![Box::new(first)];
+        es.extend(rest.into_iter().map(Box::new));
+        Exp::Prim(Prim::MakeTuple, es, Span1 { start_ix: l, end_ix: r })
     }
 }
-pub use self::__parse__Prog::ProgParser;
-#[cfg_attr(rustfmt, rustfmt_skip)]
-mod __intern_token {
-    #![allow(unused_imports)]
-    use std::str::FromStr;
-    use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Prim};
-    use crate::span::Span1;
-    #[allow(unused_extern_crates)]
-    extern crate lalrpop_util as __lalrpop_util;
-    #[allow(unused_imports)]
-    use self::__lalrpop_util::state_machine as __state_machine;
-    extern crate core;
-    extern crate alloc;
-    pub fn new_builder() -> __lalrpop_util::lexer::MatcherBuilder {
-        let __strs: &[(&str, bool)] = &[
-            ("^([\\+\\-]?[0-9]+)", false),
-            ("^([A-Za-z][0-9A-Z_a-z]*)", false),
-            ("^(!)", false),
-            ("^(!=)", false),
-            ("^(\\&\\&)", false),
-            ("^(\\()", false),
-            ("^(\\))", false),
-            ("^(\\*)", false),
-            ("^(\\+)", false),
-            ("^(,)", false),
-            ("^(\\-)", false),
-            ("^(:)", false),
-            ("^(<)", false),
-            ("^(<=)", false),
-            ("^(=)", false),
-            ("^(==)", false),
-            ("^(>)", false),
-            ("^(>=)", false),
-            ("^(add1)", false),
-            ("^(and)", false),
-            ("^(def)", false),
-            ("^(else)", false),
-            ("^(false)", false),
-            ("^(if)", false),
-            ("^(in)", false),
-            ("^(isbool)", false),
-            ("^(isnum)", false),
-            ("^(let)", false),
-            ("^(print)", false),
-            ("^(sub1)", false),
-            ("^(true)", false),
-            ("^(\\|\\|)", false),
-            (r"^(\s*)", true),
-        ];
-        __lalrpop_util::lexer::MatcherBuilder::new(__strs.iter().copied()).unwrap()
+
+fn __action59<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, t, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, i, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim(Prim::GetIndex, vec![Box::new(t), Box::new(i)], Span1 { start_ix: l, end_ix: r })
+}
+
+fn __action60<
+>(
+    (_, l, _): (usize, usize, usize),
+    (_, f, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, es, _): (usize, Vec<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    match f {
+        Exp::Var(x, _) => Exp::Call(x, es, Span1 { start_ix: l, end_ix: r }),
+        f => Exp::ClosureCall { fun: Box::new(f), args: es, is_tail: false, ann: Span1 { start_ix: l, end_ix: r } },
     }
 }
-pub(crate) use self::__lalrpop_util::lexer::Token;
 
-#[allow(unused_variables)]
-fn __action0<
-    'input,
+fn __action61<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, SurfProg<Span1>, usize),
-) -> SurfProg<Span1>
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, parameters, _): (usize, Vec<String>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Lambda { parameters, body, ann: Span1 { start_ix: l, end_ix: r } }
+}
+
+fn __action62<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::ReadNum
+}
+
+fn __action63<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::ReadBool
+}
+
+fn __action64<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Add1
+}
+
+fn __action65<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Sub1
+}
+
+fn __action66<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Print
+}
+
+fn __action67<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::IsNum
+}
+
+fn __action68<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::IsBool
+}
+
+fn __action69<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::IsTuple
+}
+
+fn __action70<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::IsStr
+}
+
+fn __action71<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Len
+}
+
+fn __action72<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::ToStr
+}
+
+fn __action73<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::ToNum
+}
+
+fn __action74<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Abs
+}
+
+fn __action75<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Min
+}
+
+fn __action76<
+>(
+    (_, __0, _): (usize, Tok, usize),
+) -> Prim
+{
+    Prim::Max
+}
+
+fn __action77<
+>(
+    (_, x, _): (usize, String, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, e, _): (usize, Exp<Span1>, usize),
+) -> Vec<(String, Exp<Span1>)>
+{
+    vec![(x, e)]
+}
+
+fn __action78<
+>(
+    (_, mut bs, _): (usize, Vec<(String, Exp<Span1>)>, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, x, _): (usize, String, usize),
+    (_, _, _): (usize, Tok, usize),
+    (_, e, _): (usize, Exp<Span1>, usize),
+) -> Vec<(String, Exp<Span1>)>
 {
-    __0
+    {
+        bs.push((x, e));
+        bs
+    }
 }
 
-#[allow(unused_variables)]
-fn __action1<
-    'input,
+fn __action79<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    (_, __0, _): (usize, Tok, usize),
+) -> bool
 {
-    __0
+    true
 }
 
-#[allow(unused_variables)]
-fn __action2<
-    'input,
+fn __action80<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> SurfProg<Span1>
+    (_, __0, _): (usize, Tok, usize),
+) -> bool
 {
-    __0
+    false
 }
 
-#[allow(unused_variables)]
-fn __action3<
-    'input,
+fn __action81<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, funs, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    (_, last, _): (usize, SurfFunDecl<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Box<Exp<Span1>>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, mut v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+    (_, last, _): (usize, core::option::Option<Exp<Span1>>, usize),
+) -> Vec<Exp<Span1>>
 {
     {
-      let mut decls: Vec<SurfFunDecl<Span1>> = funs.into_iter().map(|(decl, _)| decl).collect();
-      decls.push(last);
-      Exp::FunDefs { decls, body, ann: Span1 { start_ix: l, end_ix: r} }
+        match last {
+            None => { },
+            Some(t) => { v.push(t); }
+        };
+        v
     }
 }
 
-#[allow(unused_variables)]
-fn __action4<
-    'input,
+fn __action82<
 >(
-    input: &'input str,
     (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, name, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, parameters, _): (usize, Vec<String>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Exp<Span1>, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, op, _): (usize, Prim, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
     (_, r, _): (usize, usize, usize),
-) -> SurfFunDecl<Span1>
+) -> Exp<Span1>
 {
-    FunDecl { name, parameters, body, ann: Span1 { start_ix: l, end_ix: r}  }
+    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
 }
 
-#[allow(unused_variables)]
-fn __action5<
-    'input,
+fn __action83<
 >(
-    input: &'input str,
     (_, __0, _): (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
     __0
 }
 
-#[allow(unused_variables)]
-fn __action6<
-    'input,
+fn __action84<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, op, _): (usize, Prim, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
 }
 
-#[allow(unused_variables)]
-fn __action7<
-    'input,
+fn __action85<
 >(
-    input: &'input str,
     (_, __0, _): (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
     __0
 }
 
-#[allow(unused_variables)]
-fn __action8<
-    'input,
+fn __action86<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, op, _): (usize, Prim, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
 }
 
-#[allow(unused_variables)]
-fn __action9<
-    'input,
+fn __action87<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, cond, _): (usize, Exp<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, thn, _): (usize, Exp<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, els, _): (usize, Exp<Span1>, usize),
-    (_, r, _): (usize, usize, usize),
+    (_, __0, _): (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
-    Exp::If { cond: Box::new(cond), thn: Box::new(thn), els: Box::new(els), ann: Span1 { start_ix: l, end_ix: r }}
+    __0
 }
 
-#[allow(unused_variables)]
-fn __action10<
-    'input,
+fn __action88<
 >(
-    input: &'input str,
     (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, bindings, _): (usize, Vec<(String, Exp<Span1>)>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Exp<Span1>, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, op, _): (usize, Prim, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
     (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    Exp::Let { bindings, body: Box::new(body), ann: Span1 { start_ix: l, end_ix: r }}
+    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
 }
 
-#[allow(unused_variables)]
-fn __action11<
-    'input,
+fn __action89<
 >(
-    input: &'input str,
     (_, __0, _): (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
     __0
 }
 
-#[allow(unused_variables)]
-fn __action12<
-    'input,
+fn __action90<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, op, _): (usize, Prim, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
 }
 
-#[allow(unused_variables)]
-fn __action13<
-    'input,
+fn __action91<
 >(
-    input: &'input str,
     (_, __0, _): (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
     __0
 }
 
-#[allow(unused_variables)]
-fn __action14<
-    'input,
+fn __action92<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, op, _): (usize, Prim, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
 }
 
-#[allow(unused_variables)]
-fn __action15<
-    'input,
+fn __action93<
 >(
-    input: &'input str,
     (_, __0, _): (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
     __0
 }
 
-#[allow(unused_variables)]
-fn __action16<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
-{
-    Prim::Add
-}
-
-#[allow(unused_variables)]
-fn __action17<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
-{
-    Prim::Sub
-}
-
-#[allow(unused_variables)]
-fn __action18<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
-{
-    Prim::Mul
-}
-
-#[allow(unused_variables)]
-fn __action19<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
-{
-    Prim::And
-}
-
-#[allow(unused_variables)]
-fn __action20<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
-{
-    Prim::Or
-}
-
-#[allow(unused_variables)]
-fn __action21<
-    'input,
+fn __action94<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, op, _): (usize, Prim, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Prim::Lt
+    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
 }
 
-#[allow(unused_variables)]
-fn __action22<
-    'input,
+fn __action95<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
 {
-    Prim::Le
+    __0
 }
 
-#[allow(unused_variables)]
-fn __action23<
-    'input,
+fn __action96<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, op, _): (usize, Prim, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Prim::Gt
+    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
 }
 
-#[allow(unused_variables)]
-fn __action24<
-    'input,
+fn __action97<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
 {
-    Prim::Ge
+    __0
 }
 
-#[allow(unused_variables)]
-fn __action25<
-    'input,
+fn __action98<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    (_, mut v, _): (usize, alloc::vec::Vec<String>, usize),
+    (_, last, _): (usize, core::option::Option<String>, usize),
+) -> Vec<String>
 {
-    Prim::Eq
+    {
+        match last {
+            None => { },
+            Some(t) => { v.push(t); }
+        };
+        v
+    }
 }
 
-#[allow(unused_variables)]
-fn __action26<
-    'input,
+fn __action99<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
 {
-    Prim::Neq
+    __lookbehind.clone()
 }
 
-#[allow(unused_variables)]
-fn __action27<
-    'input,
+fn __action100<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Exp<Span1>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    Exp::Prim(Prim::Not, vec![Box::new(e)], Span1 { start_ix: l, end_ix: r })
+    Box::new(t)
 }
 
-#[allow(unused_variables)]
-fn __action28<
-    'input,
+fn __action101<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>
 {
-    __0
+    alloc::vec![]
 }
 
-#[allow(unused_variables)]
-fn __action29<
-    'input,
+fn __action102<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, x, _): (usize, String, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, v, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>, usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>
 {
-    Exp::Var(x, Span1 { start_ix: l, end_ix: r })
+    v
 }
 
-#[allow(unused_variables)]
-fn __action30<
-    'input,
+fn __action103<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, b, _): (usize, bool, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, __0, _): (usize, SurfFunDecl<Span1>, usize),
+    (_, __1, _): (usize, Tok, usize),
+) -> (SurfFunDecl<Span1>, Tok)
 {
-    Exp::Bool(b, Span1 { start_ix: l, end_ix: r })
+    (__0, __1)
 }
 
-#[allow(unused_variables)]
-fn __action31<
-    'input,
+fn __action104<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, n, _): (usize, i64, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
 {
-    Exp::Num(n, Span1 { start_ix: l, end_ix: r })
+    __lookahead.clone()
 }
 
-#[allow(unused_variables)]
-fn __action32<
-    'input,
+fn __action105<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, p, _): (usize, Prim, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Exp<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, __0, _): (usize, (SurfFunDecl<Span1>, Tok), usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>
 {
-    Exp::Prim(p, vec![Box::new(e)], Span1 { start_ix: l, end_ix: r })
+    alloc::vec![__0]
 }
 
-#[allow(unused_variables)]
-fn __action33<
-    'input,
+fn __action106<
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Exp<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Exp<Span1>
+    (_, v, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>, usize),
+    (_, e, _): (usize, (SurfFunDecl<Span1>, Tok), usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>
 {
-    e
+    { let mut v = v; v.push(e); v }
 }
 
-#[allow(unused_variables)]
-fn __action34<
-    'input,
+fn __action107<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, x, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, es, _): (usize, Vec<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, __0, _): (usize, String, usize),
+) -> core::option::Option<String>
 {
-    Exp::Call(x, es, Span1 { start_ix: l, end_ix: r })
+    Some(__0)
 }
 
-#[allow(unused_variables)]
-fn __action35<
-    'input,
+fn __action108<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<String>
 {
-    Prim::Add1
+    None
 }
 
-#[allow(unused_variables)]
-fn __action36<
-    'input,
+fn __action109<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<String>
 {
-    Prim::Sub1
+    alloc::vec![]
 }
 
-#[allow(unused_variables)]
-fn __action37<
-    'input,
+fn __action110<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
+) -> alloc::vec::Vec<String>
 {
-    Prim::Print
+    v
 }
 
-#[allow(unused_variables)]
-fn __action38<
-    'input,
+fn __action111<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    (_, __0, _): (usize, String, usize),
+    (_, _, _): (usize, Tok, usize),
+) -> String
 {
-    Prim::IsNum
+    __0
 }
 
-#[allow(unused_variables)]
-fn __action39<
-    'input,
+fn __action112<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> core::option::Option<Exp<Span1>>
 {
-    Prim::IsBool
+    Some(__0)
 }
 
-#[allow(unused_variables)]
-fn __action40<
-    'input,
+fn __action113<
 >(
-    input: &'input str,
-    (_, x, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Exp<Span1>, usize),
-) -> Vec<(String, Exp<Span1>)>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Exp<Span1>>
 {
-    vec![(x, e)]
+    None
 }
 
-#[allow(unused_variables)]
-fn __action41<
-    'input,
+fn __action114<
 >(
-    input: &'input str,
-    (_, mut bs, _): (usize, Vec<(String, Exp<Span1>)>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, x, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Exp<Span1>, usize),
-) -> Vec<(String, Exp<Span1>)>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    {
-        bs.push((x, e));
-        bs
-    }
+    alloc::vec![]
 }
 
-#[allow(unused_variables)]
-fn __action42<
-    'input,
+fn __action115<
 >(
-    input: &'input str,
-    (_, s, _): (usize, &'input str, usize),
-) -> i64
+    (_, v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    i64::from_str(s).unwrap()
+    v
 }
 
-#[allow(unused_variables)]
-fn __action43<
-    'input,
+fn __action116<
 >(
-    input: &'input str,
-    (_, s, _): (usize, &'input str, usize),
-) -> String
+    (_, __0, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok, usize),
+) -> Exp<Span1>
 {
-    String::from(s)
+    __0
 }
 
-#[allow(unused_variables)]
-fn __action44<
-    'input,
+fn __action117<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> bool
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    true
+    alloc::vec![__0]
 }
 
-#[allow(unused_variables)]
-fn __action45<
-    'input,
+fn __action118<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> bool
+    (_, v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+    (_, e, _): (usize, Exp<Span1>, usize),
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    false
+    { let mut v = v; v.push(e); v }
 }
 
-#[allow(unused_variables)]
-fn __action46<
-    'input,
+fn __action119<
 >(
-    input: &'input str,
-    (_, mut v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-    (_, last, _): (usize, core::option::Option<Exp<Span1>>, usize),
-) -> Vec<Exp<Span1>>
+    (_, __0, _): (usize, String, usize),
+) -> alloc::vec::Vec<String>
 {
-    {
-        match last {
-            None => { },
-            Some(t) => { v.push(t); }
-        };
-        v
-    }
+    alloc::vec![__0]
 }
 
-#[allow(unused_variables)]
-fn __action47<
-    'input,
+fn __action120<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, e1, _): (usize, Exp<Span1>, usize),
-    (_, op, _): (usize, Prim, usize),
-    (_, e2, _): (usize, Exp<Span1>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
+    (_, e, _): (usize, String, usize),
+) -> alloc::vec::Vec<String>
 {
-    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
+    { let mut v = v; v.push(e); v }
 }
 
-#[allow(unused_variables)]
-fn __action48<
-    'input,
+fn __action121<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Tok, usize),
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action116(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action117(
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action49<
-    'input,
+fn __action122<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, e1, _): (usize, Exp<Span1>, usize),
-    (_, op, _): (usize, Prim, usize),
-    (_, e2, _): (usize, Exp<Span1>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+    __1: (usize, Exp<Span1>, usize),
+    __2: (usize, Tok, usize),
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action116(
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action118(
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action50<
-    'input,
+fn __action123<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    __0: (usize, core::option::Option<Exp<Span1>>, usize),
+) -> Vec<Exp<Span1>>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action114(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action81(
+        __temp0,
+        __0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action51<
-    'input,
+fn __action124<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, e1, _): (usize, Exp<Span1>, usize),
-    (_, op, _): (usize, Prim, usize),
-    (_, e2, _): (usize, Exp<Span1>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+    __1: (usize, core::option::Option<Exp<Span1>>, usize),
+) -> Vec<Exp<Span1>>
 {
-    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action115(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action81(
+        __temp0,
+        __1,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action52<
-    'input,
+fn __action125<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    __0: (usize, String, usize),
+    __1: (usize, Tok, usize),
+) -> alloc::vec::Vec<String>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action111(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action119(
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action53<
-    'input,
+fn __action126<
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, e1, _): (usize, Exp<Span1>, usize),
-    (_, op, _): (usize, Prim, usize),
-    (_, e2, _): (usize, Exp<Span1>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    __0: (usize, alloc::vec::Vec<String>, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Tok, usize),
+) -> alloc::vec::Vec<String>
 {
-    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action111(
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action120(
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action54<
-    'input,
+fn __action127<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    __0: (usize, core::option::Option<String>, usize),
+) -> Vec<String>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action109(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action98(
+        __temp0,
+        __0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action55<
-    'input,
+fn __action128<
 >(
-    input: &'input str,
-    (_, mut v, _): (usize, alloc::vec::Vec<String>, usize),
-    (_, last, _): (usize, core::option::Option<String>, usize),
+    __0: (usize, alloc::vec::Vec<String>, usize),
+    __1: (usize, core::option::Option<String>, usize),
 ) -> Vec<String>
 {
-    {
-        match last {
-            None => { },
-            Some(t) => { v.push(t); }
-        };
-        v
-    }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action110(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action98(
+        __temp0,
+        __1,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action56<
-    'input,
+fn __action129<
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> usize
+    __0: (usize, SurfFunDecl<Span1>, usize),
+    __1: (usize, Tok, usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>
 {
-    __lookbehind.clone()
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action103(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action105(
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action57<
-    'input,
+fn __action130<
 >(
-    input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>, usize),
+    __1: (usize, SurfFunDecl<Span1>, usize),
+    __2: (usize, Tok, usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>
 {
-    Box::new(t)
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action103(
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action106(
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action58<
-    'input,
+fn __action131<
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    __0: (usize, usize, usize),
+    __1: (usize, SurfFunDecl<Span1>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![]
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action101(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action3(
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action59<
-    'input,
+fn __action132<
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    __0: (usize, usize, usize),
+    __1: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>, usize),
+    __2: (usize, SurfFunDecl<Span1>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Box<Exp<Span1>>, usize),
+    __5: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    v
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action102(
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action3(
+        __0,
+        __temp0,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action60<
-    'input,
+fn __action133<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, SurfFunDecl<Span1>, usize),
-    (_, __1, _): (usize, &'input str, usize),
-) -> (SurfFunDecl<Span1>, &'input str)
+    __0: (usize, String, usize),
+    __1: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    (__0, __1)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action46(
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action61<
-    'input,
+fn __action134<
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> usize
+    __0: (usize, bool, usize),
+    __1: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    __lookahead.clone()
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action47(
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action62<
-    'input,
+fn __action135<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, (SurfFunDecl<Span1>, &'input str), usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    __0: (usize, i64, usize),
+    __1: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action48(
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action63<
-    'input,
+fn __action136<
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    (_, e, _): (usize, (SurfFunDecl<Span1>, &'input str), usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    __0: (usize, f64, usize),
+    __1: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action49(
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action64<
-    'input,
+fn __action137<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-) -> core::option::Option<String>
+    __0: (usize, String, usize),
+    __1: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Some(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action50(
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action65<
-    'input,
+fn __action138<
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<String>
+    __0: (usize, Prim, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    None
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action51(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action66<
-    'input,
+fn __action139<
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<String>
+    __0: (usize, Prim, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Exp<Span1>, usize),
+    __5: (usize, Tok, usize),
+    __6: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action52(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action67<
-    'input,
+fn __action140<
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
-) -> alloc::vec::Vec<String>
+    __0: (usize, Tok, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Exp<Span1>, usize),
+    __5: (usize, Tok, usize),
+    __6: (usize, Exp<Span1>, usize),
+    __7: (usize, Tok, usize),
+    __8: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action53(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action68<
-    'input,
+fn __action141<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> String
+    __0: (usize, Prim, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action54(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action69<
-    'input,
+fn __action142<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> core::option::Option<Exp<Span1>>
+    __0: (usize, Tok, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Some(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action57(
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action70<
-    'input,
+fn __action143<
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<Exp<Span1>>
+    __0: (usize, Tok, usize),
+    __1: (usize, Exp<Span1>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Vec<Exp<Span1>>, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    None
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action58(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action71<
-    'input,
+fn __action144<
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<Exp<Span1>>
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action59(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action72<
-    'input,
+fn __action145<
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Vec<Exp<Span1>>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action60(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action73<
-    'input,
+fn __action146<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
+    __0: (usize, Tok, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Vec<String>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, Box<Exp<Span1>>, usize),
+    __6: (usize, Tok, usize),
+    __7: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action61(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action74<
-    'input,
+fn __action147<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action5(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action75<
-    'input,
+fn __action148<
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-    (_, e, _): (usize, Exp<Span1>, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    __0: (usize, SurfFunDecl<Span1>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action131(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action76<
-    'input,
+fn __action149<
 >(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-) -> alloc::vec::Vec<String>
+    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>, usize),
+    __1: (usize, SurfFunDecl<Span1>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action132(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action77<
-    'input,
+fn __action150<
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
-    (_, e, _): (usize, String, usize),
-) -> alloc::vec::Vec<String>
+    __0: (usize, Tok, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Vec<String>, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, Tok, usize),
+    __6: (usize, Exp<Span1>, usize),
+    __7: (usize, usize, usize),
+) -> SurfFunDecl<Span1>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action4(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
 }
 
-#[allow(unused_variables)]
-fn __action78<
-    'input,
+fn __action151<
 >(
-    input: &'input str,
-    __0: (usize, Exp<Span1>, usize),
-    __1: (usize, &'input str, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    __0: (usize, Tok, usize),
+    __1: (usize, Exp<Span1>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Exp<Span1>, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, Tok, usize),
+    __6: (usize, Exp<Span1>, usize),
+    __7: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action73(
-        input,
-        __0,
-        __1,
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action74(
-        input,
+    __action12(
         __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
     )
 }
 
-#[allow(unused_variables)]
-fn __action79<
-    'input,
+fn __action152<
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-    __1: (usize, Exp<Span1>, usize),
-    __2: (usize, &'input str, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action73(
-        input,
-        __1,
-        __2,
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action75(
-        input,
-        __0,
+    __action88(
         __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
     )
 }
 
-#[allow(unused_variables)]
-fn __action80<
-    'input,
+fn __action153<
 >(
-    input: &'input str,
-    __0: (usize, core::option::Option<Exp<Span1>>, usize),
-) -> Vec<Exp<Span1>>
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.0.clone();
-    let __temp0 = __action71(
-        input,
+    let __temp0 = __action104(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action46(
-        input,
+    __action92(
         __temp0,
         __0,
+        __1,
+        __2,
+        __3,
     )
 }
 
-#[allow(unused_variables)]
-fn __action81<
-    'input,
+fn __action154<
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-    __1: (usize, core::option::Option<Exp<Span1>>, usize),
-) -> Vec<Exp<Span1>>
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action72(
-        input,
-        __0,
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action46(
-        input,
+    __action90(
         __temp0,
+        __0,
         __1,
+        __2,
+        __3,
     )
 }
 
-#[allow(unused_variables)]
-fn __action82<
-    'input,
+fn __action155<
 >(
-    input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, &'input str, usize),
-) -> alloc::vec::Vec<String>
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action68(
-        input,
-        __0,
-        __1,
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action76(
-        input,
+    __action94(
         __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action83<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<String>, usize),
-    __1: (usize, String, usize),
-    __2: (usize, &'input str, usize),
-) -> alloc::vec::Vec<String>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action68(
-        input,
+        __0,
         __1,
         __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action77(
-        input,
-        __0,
-        __temp0,
+        __3,
     )
 }
 
-#[allow(unused_variables)]
-fn __action84<
-    'input,
+fn __action156<
 >(
-    input: &'input str,
-    __0: (usize, core::option::Option<String>, usize),
-) -> Vec<String>
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.0.clone();
-    let __temp0 = __action66(
-        input,
+    let __temp0 = __action104(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action55(
-        input,
+    __action84(
         __temp0,
         __0,
+        __1,
+        __2,
+        __3,
     )
 }
 
-#[allow(unused_variables)]
-fn __action85<
-    'input,
+fn __action157<
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<String>, usize),
-    __1: (usize, core::option::Option<String>, usize),
-) -> Vec<String>
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action67(
-        input,
-        __0,
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action55(
-        input,
+    __action86(
         __temp0,
+        __0,
         __1,
+        __2,
+        __3,
     )
 }
 
-#[allow(unused_variables)]
-fn __action86<
-    'input,
+fn __action158<
 >(
-    input: &'input str,
-    __0: (usize, SurfFunDecl<Span1>, usize),
-    __1: (usize, &'input str, usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action60(
-        input,
-        __0,
-        __1,
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action62(
-        input,
+    __action82(
         __temp0,
-    )
-}
-
-#[allow(unused_variables)]
-fn __action87<
-    'input,
->(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    __1: (usize, SurfFunDecl<Span1>, usize),
-    __2: (usize, &'input str, usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
-{
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action60(
-        input,
+        __0,
         __1,
         __2,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action63(
-        input,
-        __0,
-        __temp0,
+        __3,
     )
 }
 
-#[allow(unused_variables)]
-fn __action88<
-    'input,
+fn __action159<
 >(
-    input: &'input str,
-    __0: (usize, usize, usize),
-    __1: (usize, SurfFunDecl<Span1>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
+    __0: (usize, Tok, usize),
+    __1: (usize, Vec<(String, Exp<Span1>)>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Exp<Span1>, usize),
     __4: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action58(
-        input,
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action3(
-        input,
-        __0,
+    __action13(
         __temp0,
+        __0,
         __1,
         __2,
         __3,
@@ -6833,297 +9777,244 @@ fn __action88<
     )
 }
 
-#[allow(unused_variables)]
-fn __action89<
-    'input,
+fn __action160<
 >(
-    input: &'input str,
-    __0: (usize, usize, usize),
-    __1: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    __2: (usize, SurfFunDecl<Span1>, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, Box<Exp<Span1>>, usize),
-    __5: (usize, usize, usize),
+    __0: (usize, Tok, usize),
+    __1: (usize, Exp<Span1>, usize),
+    __2: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action59(
-        input,
-        __1,
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action104(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action3(
-        input,
-        __0,
+    __action44(
         __temp0,
+        __0,
+        __1,
         __2,
-        __3,
-        __4,
-        __5,
     )
 }
 
-#[allow(unused_variables)]
-fn __action90<
-    'input,
+fn __action161<
 >(
-    input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, usize, usize),
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, usize, usize),
 ) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __temp0 = __action104(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action29(
-        input,
+    __action96(
         __temp0,
         __0,
         __1,
+        __2,
+        __3,
     )
 }
 
-#[allow(unused_variables)]
-fn __action91<
-    'input,
+fn __action162<
 >(
-    input: &'input str,
-    __0: (usize, bool, usize),
-    __1: (usize, usize, usize),
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, Exp<Span1>, usize),
+    __6: (usize, usize, usize),
 ) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __temp0 = __action104(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action30(
-        input,
+    __action14(
         __temp0,
         __0,
         __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
     )
 }
 
-#[allow(unused_variables)]
-fn __action92<
-    'input,
+fn __action163<
 >(
-    input: &'input str,
-    __0: (usize, i64, usize),
-    __1: (usize, usize, usize),
+    __0: (usize, String, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action31(
-        input,
-        __temp0,
+    __action133(
         __0,
-        __1,
+        __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action93<
-    'input,
+fn __action164<
 >(
-    input: &'input str,
-    __0: (usize, Prim, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Exp<Span1>, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, usize, usize),
+    __0: (usize, bool, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action32(
-        input,
-        __temp0,
+    __action134(
         __0,
-        __1,
-        __2,
-        __3,
-        __4,
+        __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action94<
-    'input,
+fn __action165<
 >(
-    input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Vec<Exp<Span1>>, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, usize, usize),
+    __0: (usize, i64, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action34(
-        input,
-        __temp0,
+    __action135(
         __0,
-        __1,
-        __2,
-        __3,
-        __4,
+        __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action95<
-    'input,
+fn __action166<
 >(
-    input: &'input str,
-    __0: (usize, SurfFunDecl<Span1>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, usize, usize),
+    __0: (usize, f64, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action88(
-        input,
-        __temp0,
+    __action136(
         __0,
-        __1,
-        __2,
-        __3,
+        __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action96<
-    'input,
+fn __action167<
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    __1: (usize, SurfFunDecl<Span1>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
-    __4: (usize, usize, usize),
+    __0: (usize, String, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action89(
-        input,
+    __action137(
+        __0,
         __temp0,
+    )
+}
+
+fn __action168<
+>(
+    __0: (usize, Prim, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok, usize),
+) -> Exp<Span1>
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action99(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action138(
         __0,
         __1,
         __2,
         __3,
-        __4,
+        __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action97<
-    'input,
+fn __action169<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, String, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Vec<String>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, Exp<Span1>, usize),
-    __7: (usize, usize, usize),
-) -> SurfFunDecl<Span1>
+    __0: (usize, Prim, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Exp<Span1>, usize),
+    __5: (usize, Tok, usize),
+) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action4(
-        input,
-        __temp0,
+    __action139(
         __0,
         __1,
         __2,
         __3,
         __4,
         __5,
-        __6,
-        __7,
+        __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action98<
-    'input,
+fn __action170<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Exp<Span1>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Exp<Span1>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, &'input str, usize),
+    __0: (usize, Tok, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Exp<Span1>, usize),
+    __5: (usize, Tok, usize),
     __6: (usize, Exp<Span1>, usize),
-    __7: (usize, usize, usize),
+    __7: (usize, Tok, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __7.2.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action9(
-        input,
-        __temp0,
+    __action140(
         __0,
         __1,
         __2,
@@ -7132,330 +10023,299 @@ fn __action98<
         __5,
         __6,
         __7,
+        __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action99<
-    'input,
+fn __action171<
 >(
-    input: &'input str,
-    __0: (usize, Exp<Span1>, usize),
-    __1: (usize, Prim, usize),
-    __2: (usize, Exp<Span1>, usize),
-    __3: (usize, usize, usize),
+    __0: (usize, Prim, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Tok, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action51(
-        input,
-        __temp0,
+    __action141(
         __0,
         __1,
         __2,
-        __3,
+        __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action100<
-    'input,
+fn __action172<
 >(
-    input: &'input str,
-    __0: (usize, Exp<Span1>, usize),
-    __1: (usize, Prim, usize),
-    __2: (usize, Exp<Span1>, usize),
-    __3: (usize, usize, usize),
+    __0: (usize, Tok, usize),
+    __1: (usize, Tok, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action49(
-        input,
-        __temp0,
+    __action142(
         __0,
         __1,
-        __2,
-        __3,
+        __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action101<
-    'input,
+fn __action173<
 >(
-    input: &'input str,
-    __0: (usize, Exp<Span1>, usize),
-    __1: (usize, Prim, usize),
-    __2: (usize, Exp<Span1>, usize),
-    __3: (usize, usize, usize),
+    __0: (usize, Tok, usize),
+    __1: (usize, Exp<Span1>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Vec<Exp<Span1>>, usize),
+    __4: (usize, Tok, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action47(
-        input,
-        __temp0,
+    __action143(
         __0,
         __1,
         __2,
         __3,
+        __4,
+        __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action102<
-    'input,
+fn __action174<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Vec<(String, Exp<Span1>)>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Exp<Span1>, usize),
-    __4: (usize, usize, usize),
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action10(
-        input,
-        __temp0,
+    __action144(
         __0,
         __1,
         __2,
         __3,
-        __4,
+        __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action103<
-    'input,
+fn __action175<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Exp<Span1>, usize),
-    __2: (usize, usize, usize),
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Vec<Exp<Span1>>, usize),
+    __3: (usize, Tok, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action27(
-        input,
-        __temp0,
+    __action145(
         __0,
         __1,
         __2,
+        __3,
+        __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action104<
-    'input,
+fn __action176<
 >(
-    input: &'input str,
-    __0: (usize, Exp<Span1>, usize),
-    __1: (usize, Prim, usize),
-    __2: (usize, Exp<Span1>, usize),
-    __3: (usize, usize, usize),
+    __0: (usize, Tok, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Vec<String>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, Box<Exp<Span1>>, usize),
+    __6: (usize, Tok, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action53(
-        input,
-        __temp0,
+    __action146(
         __0,
         __1,
         __2,
         __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action105<
-    'input,
+fn __action177<
 >(
-    input: &'input str,
-    __0: (usize, String, usize),
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action90(
-        input,
+    __action147(
         __0,
+        __1,
+        __2,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action106<
-    'input,
+fn __action178<
 >(
-    input: &'input str,
-    __0: (usize, bool, usize),
+    __0: (usize, SurfFunDecl<Span1>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action91(
-        input,
+    __action148(
         __0,
+        __1,
+        __2,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action107<
-    'input,
+fn __action179<
 >(
-    input: &'input str,
-    __0: (usize, i64, usize),
+    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok)>, usize),
+    __1: (usize, SurfFunDecl<Span1>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action92(
-        input,
+    __action149(
         __0,
+        __1,
+        __2,
+        __3,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action108<
-    'input,
+fn __action180<
 >(
-    input: &'input str,
-    __0: (usize, Prim, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Exp<Span1>, usize),
-    __3: (usize, &'input str, usize),
-) -> Exp<Span1>
+    __0: (usize, Tok, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Vec<String>, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, Tok, usize),
+    __6: (usize, Exp<Span1>, usize),
+) -> SurfFunDecl<Span1>
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action93(
-        input,
+    __action150(
         __0,
         __1,
         __2,
         __3,
+        __4,
+        __5,
+        __6,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action109<
-    'input,
+fn __action181<
 >(
-    input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Vec<Exp<Span1>>, usize),
-    __3: (usize, &'input str, usize),
+    __0: (usize, Tok, usize),
+    __1: (usize, Exp<Span1>, usize),
+    __2: (usize, Tok, usize),
+    __3: (usize, Exp<Span1>, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, Tok, usize),
+    __6: (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action94(
-        input,
+    __action151(
         __0,
         __1,
         __2,
         __3,
+        __4,
+        __5,
+        __6,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action110<
-    'input,
+fn __action182<
 >(
-    input: &'input str,
-    __0: (usize, SurfFunDecl<Span1>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
     let __start0 = __2.2.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action95(
-        input,
+    __action152(
         __0,
         __1,
         __2,
@@ -7463,110 +10323,74 @@ fn __action110<
     )
 }
 
-#[allow(unused_variables)]
-fn __action111<
-    'input,
+fn __action183<
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    __1: (usize, SurfFunDecl<Span1>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action96(
-        input,
+    __action153(
         __0,
         __1,
         __2,
-        __3,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action112<
-    'input,
+fn __action184<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, String, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Vec<String>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, Exp<Span1>, usize),
-) -> SurfFunDecl<Span1>
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
 {
-    let __start0 = __6.2.clone();
-    let __end0 = __6.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action97(
-        input,
+    __action154(
         __0,
         __1,
         __2,
-        __3,
-        __4,
-        __5,
-        __6,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action113<
-    'input,
+fn __action185<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Exp<Span1>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Exp<Span1>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, Exp<Span1>, usize),
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __6.2.clone();
-    let __end0 = __6.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action98(
-        input,
+    __action155(
         __0,
         __1,
         __2,
-        __3,
-        __4,
-        __5,
-        __6,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action114<
-    'input,
+fn __action186<
 >(
-    input: &'input str,
     __0: (usize, Exp<Span1>, usize),
     __1: (usize, Prim, usize),
     __2: (usize, Exp<Span1>, usize),
@@ -7574,14 +10398,12 @@ fn __action114<
 {
     let __start0 = __2.2.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action99(
-        input,
+    __action156(
         __0,
         __1,
         __2,
@@ -7589,11 +10411,8 @@ fn __action114<
     )
 }
 
-#[allow(unused_variables)]
-fn __action115<
-    'input,
+fn __action187<
 >(
-    input: &'input str,
     __0: (usize, Exp<Span1>, usize),
     __1: (usize, Prim, usize),
     __2: (usize, Exp<Span1>, usize),
@@ -7601,14 +10420,12 @@ fn __action115<
 {
     let __start0 = __2.2.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action100(
-        input,
+    __action157(
         __0,
         __1,
         __2,
@@ -7616,11 +10433,8 @@ fn __action115<
     )
 }
 
-#[allow(unused_variables)]
-fn __action116<
-    'input,
+fn __action188<
 >(
-    input: &'input str,
     __0: (usize, Exp<Span1>, usize),
     __1: (usize, Prim, usize),
     __2: (usize, Exp<Span1>, usize),
@@ -7628,14 +10442,12 @@ fn __action116<
 {
     let __start0 = __2.2.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action101(
-        input,
+    __action158(
         __0,
         __1,
         __2,
@@ -7643,27 +10455,22 @@ fn __action116<
     )
 }
 
-#[allow(unused_variables)]
-fn __action117<
-    'input,
+fn __action189<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
+    __0: (usize, Tok, usize),
     __1: (usize, Vec<(String, Exp<Span1>)>, usize),
-    __2: (usize, &'input str, usize),
+    __2: (usize, Tok, usize),
     __3: (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
     let __start0 = __3.2.clone();
     let __end0 = __3.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action102(
-        input,
+    __action159(
         __0,
         __1,
         __2,
@@ -7672,36 +10479,28 @@ fn __action117<
     )
 }
 
-#[allow(unused_variables)]
-fn __action118<
-    'input,
+fn __action190<
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
+    __0: (usize, Tok, usize),
     __1: (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
     let __start0 = __1.2.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action103(
-        input,
+    __action160(
         __0,
         __1,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action119<
-    'input,
+fn __action191<
 >(
-    input: &'input str,
     __0: (usize, Exp<Span1>, usize),
     __1: (usize, Prim, usize),
     __2: (usize, Exp<Span1>, usize),
@@ -7709,14 +10508,12 @@ fn __action119<
 {
     let __start0 = __2.2.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __temp0 = __action99(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action104(
-        input,
+    __action161(
         __0,
         __1,
         __2,
@@ -7724,200 +10521,188 @@ fn __action119<
     )
 }
 
-#[allow(unused_variables)]
-fn __action120<
-    'input,
+fn __action192<
+>(
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Tok, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok, usize),
+    __4: (usize, Tok, usize),
+    __5: (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action99(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action162(
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+fn __action193<
 >(
-    input: &'input str,
     __0: (usize, Exp<Span1>, usize),
 ) -> Vec<Exp<Span1>>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action69(
-        input,
+    let __temp0 = __action112(
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action80(
-        input,
+    __action123(
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action121<
-    'input,
+fn __action194<
 >(
-    input: &'input str,
     __lookbehind: &usize,
     __lookahead: &usize,
 ) -> Vec<Exp<Span1>>
 {
     let __start0 = __lookbehind.clone();
     let __end0 = __lookahead.clone();
-    let __temp0 = __action70(
-        input,
+    let __temp0 = __action113(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action80(
-        input,
+    __action123(
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action122<
-    'input,
+fn __action195<
 >(
-    input: &'input str,
     __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
     __1: (usize, Exp<Span1>, usize),
 ) -> Vec<Exp<Span1>>
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action69(
-        input,
+    let __temp0 = __action112(
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action81(
-        input,
+    __action124(
         __0,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action123<
-    'input,
+fn __action196<
 >(
-    input: &'input str,
     __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
 ) -> Vec<Exp<Span1>>
 {
     let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action70(
-        input,
+    let __temp0 = __action113(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action81(
-        input,
+    __action124(
         __0,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action124<
-    'input,
+fn __action197<
 >(
-    input: &'input str,
     __0: (usize, String, usize),
 ) -> Vec<String>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action64(
-        input,
+    let __temp0 = __action107(
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action84(
-        input,
+    __action127(
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action125<
-    'input,
+fn __action198<
 >(
-    input: &'input str,
     __lookbehind: &usize,
     __lookahead: &usize,
 ) -> Vec<String>
 {
     let __start0 = __lookbehind.clone();
     let __end0 = __lookahead.clone();
-    let __temp0 = __action65(
-        input,
+    let __temp0 = __action108(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action84(
-        input,
+    __action127(
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action126<
-    'input,
+fn __action199<
 >(
-    input: &'input str,
     __0: (usize, alloc::vec::Vec<String>, usize),
     __1: (usize, String, usize),
 ) -> Vec<String>
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action64(
-        input,
+    let __temp0 = __action107(
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action85(
-        input,
+    __action128(
         __0,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action127<
-    'input,
+fn __action200<
 >(
-    input: &'input str,
     __0: (usize, alloc::vec::Vec<String>, usize),
 ) -> Vec<String>
 {
     let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action65(
-        input,
+    let __temp0 = __action108(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action85(
-        input,
+    __action128(
         __0,
         __temp0,
     )
 }
 
-pub trait __ToTriple<'input, >
+pub trait __ToTriple<>
 {
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>;
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize), __lalrpop_util::ParseError<usize, Tok, LexicalError>>;
 }
 
-impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize)
+impl<> __ToTriple<> for (usize, Tok, usize)
 {
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize), __lalrpop_util::ParseError<usize, Tok, LexicalError>> {
         Ok(value)
     }
 }
-impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize), &'static str>
+impl<> __ToTriple<> for Result<(usize, Tok, usize), LexicalError>
 {
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+    fn to_triple(value: Self) -> Result<(usize,Tok,usize), __lalrpop_util::ParseError<usize, Tok, LexicalError>> {
         match value {
             Ok(v) => Ok(v),
             Err(error) => Err(__lalrpop_util::ParseError::User { error }),
@@ -0,0 +1,632 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+
+use crate::lexer;
+use crate::span::Span1;
+use crate::syntax::{exp_ann, Exp, FunDecl, ImmExp, Prim, SeqExp, SeqProg};
+
+// ********************************************************************
+//
+//                  printing programs as Snake source
+//
+// ********************************************************************
+
+// Surface expressions are printed in the concrete syntax of
+// parser.lalrpop, with only the parentheses its precedence and
+// associativity require, so that what is printed parses back to the same
+// tree. The forms introduced by the compiler have no concrete syntax and
+// are printed as
+//     jump f(x)              InternalTailCall
+//     call f(x)              ExternalCall, `tail call f(x)` in tail position
+//     tail g(x)              ClosureCall in tail position
+//     closure(f, 2, env)     MakeClosure
+// and a string literal of the sequential form is printed as the literal.
+
+// How the printed source is laid out
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Style {
+    // spaces per level of nesting
+    pub indent: usize,
+    // the width lines are broken at when they can be
+    pub width: usize,
+}
+
+impl Default for Style {
+    fn default() -> Style {
+        Style { indent: 2, width: 80 }
+    }
+}
+
+// A document in the style of Wadler's "A prettier printer": groups are
+// laid out on one line if they fit, and with their lines broken if not
+enum Doc {
+    Text(String),
+    // a space, or a newline if the group it is in is broken
+    Line,
+    // a newline that breaks every group around it
+    HardLine,
+    // text put at the end of the line, right before the next newline
+    LineSuffix(String),
+    // indented one level more after newlines
+    Nest(Box<Doc>),
+    Group(Box<Doc>),
+    Concat(Vec<Doc>),
+}
+
+fn text(s: &str) -> Doc {
+    Doc::Text(s.to_string())
+}
+
+fn nest(d: Doc) -> Doc {
+    Doc::Nest(Box::new(d))
+}
+
+fn group(d: Doc) -> Doc {
+    Doc::Group(Box::new(d))
+}
+
+fn concat(ds: Vec<Doc>) -> Doc {
+    Doc::Concat(ds)
+}
+
+// (docs) separated by ", "
+fn comma_sep(docs: Vec<Doc>) -> Doc {
+    let mut out = Vec::new();
+    for (i, d) in docs.into_iter().enumerate() {
+        if i > 0 {
+            out.push(text(", "));
+        }
+        out.push(d);
+    }
+    concat(out)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Flat,
+    Break,
+}
+
+// The width of (d) laid out on one line, None if it can't be
+fn flat_width(d: &Doc) -> Option<usize> {
+    match d {
+        Doc::Text(s) => Some(s.len()),
+        Doc::Line => Some(1),
+        Doc::HardLine => None,
+        Doc::LineSuffix(_) => Some(0),
+        Doc::Nest(d) | Doc::Group(d) => flat_width(d),
+        Doc::Concat(ds) => ds.iter().map(flat_width).sum(),
+    }
+}
+
+fn layout(d: &Doc, style: &Style) -> String {
+    let mut out = String::new();
+    let mut column = 0;
+    // the comments for the end of the line
+    let mut suffix: Vec<&str> = Vec::new();
+    // the documents left to lay out, the next one last
+    let mut stack: Vec<(usize, Mode, &Doc)> = vec![(0, Mode::Break, d)];
+    while let Some((level, mode, d)) = stack.pop() {
+        match d {
+            Doc::Text(s) => {
+                out.push_str(s);
+                column += s.len();
+            }
+            Doc::Line if mode == Mode::Flat => {
+                out.push(' ');
+                column += 1;
+            }
+            Doc::Line | Doc::HardLine => {
+                flush_suffix(&mut out, &mut suffix);
+                out.push('\n');
+                column = level * style.indent;
+                out.push_str(&" ".repeat(column));
+            }
+            Doc::LineSuffix(s) => suffix.push(s),
+            Doc::Nest(d) => stack.push((level + 1, mode, d)),
+            Doc::Group(d) => {
+                let fits = matches!(flat_width(d), Some(w) if column + w <= style.width);
+                stack.push((level, if fits { Mode::Flat } else { Mode::Break }, d));
+            }
+            Doc::Concat(ds) => stack.extend(ds.iter().rev().map(|d| (level, mode, d))),
+        }
+    }
+    flush_suffix(&mut out, &mut suffix);
+    out
+}
+
+// Ends the line with (suffix), a # comment last since it runs to the end
+// of the line
+fn flush_suffix(out: &mut String, suffix: &mut Vec<&str>) {
+    suffix.sort_by_key(|comment| comment.starts_with('#'));
+    for comment in suffix.drain(..) {
+        out.push(' ');
+        out.push_str(comment);
+    }
+}
+
+// ********************************************************************
+//                          precedence levels
+// ********************************************************************
+
+// The levels of the grammar, from the loosest: a subexpression is put in
+// parentheses when it is looser than where it occurs
+static EXP: u8 = 0; // e1; e2, if, let, def
+static STMT: u8 = 1; // t[i] := v, and the first expression of e1; e2
+static LOG: u8 = 2; // && and ||, right associative
+static CMP: u8 = 3;
+static BIT_OR: u8 = 4;
+static BIT_XOR: u8 = 5;
+static BIT_AND: u8 = 6;
+static SHIFT: u8 = 7;
+static SUM: u8 = 8;
+static PRODUCT: u8 = 9;
+static NOT: u8 = 10;
+static BASE: u8 = 11;
+
+fn prim1_name(p: Prim) -> Option<&'static str> {
+    match p {
+        Prim::Add1 => Some("add1"),
+        Prim::Sub1 => Some("sub1"),
+        Prim::Print => Some("print"),
+        Prim::IsBool => Some("isbool"),
+        Prim::IsNum => Some("isnum"),
+        Prim::IsTuple => Some("istuple"),
+        Prim::IsStr => Some("isstring"),
+        Prim::Len => Some("len"),
+        Prim::ToStr => Some("tostring"),
+        Prim::ToNum => Some("tonum"),
+        Prim::Abs => Some("abs"),
+        _ => None,
+    }
+}
+
+// The operator of a binary primitive and its level
+fn prim2_op(p: Prim) -> Option<(&'static str, u8)> {
+    match p {
+        Prim::And => Some(("&&", LOG)),
+        Prim::Or => Some(("||", LOG)),
+        Prim::Lt => Some(("<", CMP)),
+        Prim::Gt => Some((">", CMP)),
+        Prim::Le => Some(("<=", CMP)),
+        Prim::Ge => Some((">=", CMP)),
+        Prim::Eq => Some(("==", CMP)),
+        Prim::Neq => Some(("!=", CMP)),
+        Prim::BitOr => Some(("|", BIT_OR)),
+        Prim::BitXor => Some(("^", BIT_XOR)),
+        Prim::BitAnd => Some(("&", BIT_AND)),
+        Prim::Shl => Some(("<<", SHIFT)),
+        Prim::Shr => Some((">>", SHIFT)),
+        Prim::Add => Some(("+", SUM)),
+        Prim::Sub => Some(("-", SUM)),
+        Prim::Concat => Some(("++", SUM)),
+        Prim::Mul => Some(("*", PRODUCT)),
+        Prim::Div => Some(("/", PRODUCT)),
+        Prim::IntDiv => Some(("//", PRODUCT)),
+        Prim::Rem => Some(("%", PRODUCT)),
+        _ => None,
+    }
+}
+
+// The levels of the operands of a binary operator at (level)
+fn operand_levels(level: u8) -> (u8, u8) {
+    if level == LOG {
+        (level + 1, level)
+    } else {
+        (level, level + 1)
+    }
+}
+
+fn float(x: f64) -> String {
+    // the lexer reads neither exponents nor integral floats without a dot
+    let s = x.to_string();
+    if s.contains('.') { s } else { format!("{}.0", s) }
+}
+
+fn imm(i: &ImmExp) -> Doc {
+    match i {
+        ImmExp::Num(n) => Doc::Text(n.to_string()),
+        ImmExp::Float(x) => Doc::Text(float(*x)),
+        ImmExp::Bool(b) => Doc::Text(b.to_string()),
+        ImmExp::Var(x) => text(x),
+    }
+}
+
+fn call(prefix: &str, f: Doc, args: Vec<Doc>) -> Doc {
+    concat(vec![text(prefix), f, text("("), comma_sep(args), text(")")])
+}
+
+fn parens(d: Doc) -> Doc {
+    concat(vec![text("("), d, text(")")])
+}
+
+// A primitive applied to (args), the documents of the operands at the
+// level each one needs
+fn prim<A>(p: Prim, args: &[A], arg: &dyn Fn(&A, u8) -> Doc) -> (Doc, u8) {
+    if let Some(name) = prim1_name(p) {
+        return (call("", text(name), vec![arg(&args[0], EXP)]), BASE);
+    }
+    if let Some((op, level)) = prim2_op(p) {
+        let (left, right) = operand_levels(level);
+        let d = concat(vec![arg(&args[0], left), Doc::Text(format!(" {} ", op)), arg(&args[1], right)]);
+        return (d, level);
+    }
+    match p {
+        Prim::Not => (concat(vec![text("!"), arg(&args[0], BASE)]), NOT),
+        Prim::MakeTuple => {
+            let elems = comma_sep(args.iter().map(|a| arg(a, EXP)).collect());
+            let trailing = if args.len() == 1 { "," } else { "" };
+            (concat(vec![text("("), elems, text(trailing), text(")")]), BASE)
+        }
+        Prim::GetIndex => (
+            concat(vec![arg(&args[0], BASE), text("["), arg(&args[1], EXP), text("]")]),
+            BASE,
+        ),
+        Prim::Substr | Prim::Min | Prim::Max => {
+            let name = match p {
+                Prim::Substr => "substr",
+                Prim::Min => "min",
+                _ => "max",
+            };
+            (call("", text(name), args.iter().map(|a| arg(a, EXP)).collect()), BASE)
+        }
+        Prim::ReadNum => (call("", text("readnum"), vec![]), BASE),
+        Prim::ReadBool => (call("", text("readbool"), vec![]), BASE),
+        Prim::SetIndex => (
+            concat(vec![
+                arg(&args[0], BASE),
+                text("["),
+                arg(&args[1], EXP),
+                text("] := "),
+                arg(&args[2], STMT),
+            ]),
+            STMT,
+        ),
+        _ => unreachable!("every primitive has a concrete syntax"),
+    }
+}
+
+// def f(x, y): body
+fn decl_doc(name: &str, parameters: &[String], body: Doc) -> Doc {
+    group(concat(vec![
+        Doc::Text(format!("def {}({}):", name, parameters.join(", "))),
+        nest(concat(vec![Doc::Line, body])),
+    ]))
+}
+
+// The declarations joined by `and`, followed by `in` and the body
+fn fun_defs_doc(decls: Vec<Doc>, body: Doc, block: bool) -> Doc {
+    let sep = || if block { Doc::HardLine } else { Doc::Line };
+    let mut out = Vec::new();
+    for (i, d) in decls.into_iter().enumerate() {
+        if i > 0 {
+            out.extend(vec![sep(), text("and"), sep()]);
+        }
+        out.push(d);
+    }
+    out.extend(vec![sep(), text("in"), sep(), body]);
+    group(concat(out))
+}
+
+fn if_doc(cond: Doc, thn: Doc, els: Doc) -> Doc {
+    group(concat(vec![
+        text("if "),
+        cond,
+        text(":"),
+        nest(concat(vec![Doc::Line, thn])),
+        Doc::Line,
+        text("else:"),
+        nest(concat(vec![Doc::Line, els])),
+    ]))
+}
+
+// let x = e1, y = e2 in body
+fn let_doc(bindings: Vec<Doc>, body: Doc, block: bool) -> Doc {
+    let mut header = vec![text("let ")];
+    let mut rest = Vec::new();
+    for (i, b) in bindings.into_iter().enumerate() {
+        if i > 0 {
+            rest.extend(vec![text(","), Doc::Line]);
+        }
+        rest.push(b);
+    }
+    header.push(nest(concat(rest)));
+    header.push(text(" in"));
+    let header = group(concat(header));
+    group(concat(vec![header, if block { Doc::HardLine } else { Doc::Line }, body]))
+}
+
+// e1; e2; e3, one expression per line in a block
+fn seq_exps_doc(exps: Vec<Doc>, block: bool) -> Doc {
+    let mut out = Vec::new();
+    for (i, d) in exps.into_iter().enumerate() {
+        if i > 0 {
+            out.extend(vec![text(";"), if block { Doc::HardLine } else { Doc::Line }]);
+        }
+        out.push(d);
+    }
+    group(concat(out))
+}
+
+fn lambda_doc(parameters: &[String], body: Doc) -> Doc {
+    group(concat(vec![
+        Doc::Text(format!("lambda ({}):", parameters.join(", "))),
+        nest(concat(vec![Doc::Line, body])),
+        Doc::Line,
+        text("end"),
+    ]))
+}
+
+// ********************************************************************
+//                          surface expressions
+// ********************************************************************
+
+// The comments to print around the expression or declaration with the
+// annotation: the ones before it, each on a line of its own, and the ones
+// at the end of its last line
+type Notes<'a, Ann> = &'a dyn Fn(&Ann) -> (Vec<String>, Vec<String>);
+
+fn no_notes<Ann>(_: &Ann) -> (Vec<String>, Vec<String>) {
+    (vec![], vec![])
+}
+
+fn with_notes<Ann>(d: Doc, ann: &Ann, notes: Notes<Ann>) -> Doc {
+    let (before, after) = notes(ann);
+    if before.is_empty() && after.is_empty() {
+        return d;
+    }
+    let mut out = Vec::new();
+    for comment in before {
+        out.extend(vec![Doc::Text(comment), Doc::HardLine]);
+    }
+    out.push(d);
+    out.extend(after.into_iter().map(Doc::LineSuffix));
+    concat(out)
+}
+
+// The document of (e) where an expression of (level) is expected. In a
+// block, at the top of the program or of a body, lets and defs put their
+// body on the next line.
+fn exp_doc<Ann>(e: &Exp<Ann>, level: u8, block: bool, notes: Notes<Ann>) -> Doc {
+    let (d, own) = exp_level(e, block && level == EXP, notes);
+    with_notes(if own < level { parens(d) } else { d }, exp_ann(e), notes)
+}
+
+fn fun_decl_doc<Ann>(decl: &FunDecl<Exp<Ann>, Ann>, notes: Notes<Ann>) -> Doc {
+    let d = decl_doc(&decl.name, &decl.parameters, exp_doc(&decl.body, EXP, true, notes));
+    with_notes(d, &decl.ann, notes)
+}
+
+fn exp_level<Ann>(e: &Exp<Ann>, block: bool, notes: Notes<Ann>) -> (Doc, u8) {
+    let arg = |e: &&Exp<Ann>, level| exp_doc(e, level, false, notes);
+    let args = |es: &[Exp<Ann>]| es.iter().map(|e| exp_doc(e, EXP, false, notes)).collect();
+    match e {
+        Exp::Num(n, _) => (Doc::Text(n.to_string()), BASE),
+        Exp::Float(x, _) => (Doc::Text(float(*x)), BASE),
+        Exp::Bool(b, _) => (Doc::Text(b.to_string()), BASE),
+        Exp::Str(s, _) => (Doc::Text(lexer::quote(s)), BASE),
+        Exp::Var(x, _) => (text(x), BASE),
+        Exp::Prim(p, es, _) => prim(*p, &es.iter().map(|e| &**e).collect::<Vec<_>>(), &arg),
+        Exp::Let { bindings, body, .. } => {
+            let bindings = bindings
+                .iter()
+                .map(|(x, e)| concat(vec![Doc::Text(format!("{} = ", x)), exp_doc(e, EXP, false, notes)]))
+                .collect();
+            (let_doc(bindings, exp_doc(body, EXP, true, notes), block), EXP)
+        }
+        Exp::If { cond, thn, els, .. } => (
+            if_doc(exp_doc(cond, EXP, false, notes), exp_doc(thn, EXP, true, notes), exp_doc(els, EXP, true, notes)),
+            EXP,
+        ),
+        Exp::FunDefs { decls, body, .. } => {
+            let decls = decls.iter().map(|decl| fun_decl_doc(decl, notes));
+            (fun_defs_doc(decls.collect(), exp_doc(body, EXP, true, notes), block), EXP)
+        }
+        Exp::Lambda { parameters, body, .. } => (lambda_doc(parameters, exp_doc(body, EXP, true, notes)), BASE),
+        Exp::Seq { first, second, .. } => {
+            let mut exps = vec![exp_doc(first, STMT, false, notes)];
+            let mut last = &**second;
+            while let Exp::Seq { first, second, .. } = last {
+                exps.push(exp_doc(first, STMT, false, notes));
+                last = second;
+            }
+            exps.push(exp_doc(last, EXP, block, notes));
+            (seq_exps_doc(exps, block), EXP)
+        }
+        Exp::Call(f, es, _) => (call("", text(f), args(es)), BASE),
+        Exp::ClosureCall { fun, args: es, is_tail, .. } => {
+            // a lambda called directly reads better in parentheses
+            let fun = match fun.as_ref() {
+                Exp::Lambda { .. } => parens(exp_doc(fun, EXP, false, notes)),
+                _ => exp_doc(fun, BASE, false, notes),
+            };
+            (call(if *is_tail { "tail " } else { "" }, fun, args(es)), BASE)
+        }
+        Exp::InternalTailCall(f, es, _) => (call("jump ", text(f), args(es)), BASE),
+        Exp::ExternalCall { fun_name, args: es, is_tail, .. } => {
+            (call(if *is_tail { "tail call " } else { "call " }, text(fun_name), args(es)), BASE)
+        }
+        Exp::MakeClosure { fun_name, arity, env, .. } => {
+            let d = vec![text(fun_name), Doc::Text(arity.to_string()), exp_doc(env, EXP, false, notes)];
+            (call("", text("closure"), d), BASE)
+        }
+    }
+}
+
+// ********************************************************************
+//                        sequential expressions
+// ********************************************************************
+
+fn seq_doc<Ann>(e: &SeqExp<Ann>, level: u8, block: bool) -> Doc {
+    let (d, own) = seq_level(e, block && level == EXP);
+    if own < level { parens(d) } else { d }
+}
+
+fn seq_level<Ann>(e: &SeqExp<Ann>, block: bool) -> (Doc, u8) {
+    let arg = |i: &ImmExp, _| imm(i);
+    let args = |is: &[ImmExp]| is.iter().map(imm).collect();
+    match e {
+        SeqExp::Imm(i, _) => (imm(i), BASE),
+        SeqExp::Str(s, _) => (Doc::Text(lexer::quote(s)), BASE),
+        SeqExp::Prim(p, is, _) => prim(*p, is, &arg),
+        SeqExp::Let { var, bound_exp, body, .. } => {
+            let binding = concat(vec![Doc::Text(format!("{} = ", var)), seq_doc(bound_exp, EXP, false)]);
+            (let_doc(vec![binding], seq_doc(body, EXP, true), block), EXP)
+        }
+        SeqExp::If { cond, thn, els, .. } => {
+            (if_doc(imm(cond), seq_doc(thn, EXP, true), seq_doc(els, EXP, true)), EXP)
+        }
+        SeqExp::FunDefs { decls, body, .. } => {
+            let decls = decls.iter().map(|decl| decl_doc(&decl.name, &decl.parameters, seq_doc(&decl.body, EXP, true)));
+            (fun_defs_doc(decls.collect(), seq_doc(body, EXP, true), block), EXP)
+        }
+        SeqExp::InternalTailCall(f, is, _) => (call("jump ", text(f), args(is)), BASE),
+        SeqExp::ExternalCall { fun_name, args: is, is_tail, .. } => {
+            (call(if *is_tail { "tail call " } else { "call " }, text(fun_name), args(is)), BASE)
+        }
+        SeqExp::ClosureCall { fun, args: is, is_tail, .. } => {
+            (call(if *is_tail { "tail " } else { "" }, imm(fun), args(is)), BASE)
+        }
+        SeqExp::MakeClosure { fun_name, arity, env, .. } => {
+            let d = vec![text(fun_name), Doc::Text(arity.to_string()), imm(env)];
+            (call("", text("closure"), d), BASE)
+        }
+    }
+}
+
+// ********************************************************************
+//                            entry points
+// ********************************************************************
+
+// The source of (e), without a final newline
+pub fn exp_to_string<Ann>(e: &Exp<Ann>, style: &Style) -> String {
+    layout(&exp_doc(e, EXP, true, &no_notes), style)
+}
+
+// A comment of the source, with the offsets of its first byte and past its
+// last one, and whether code comes before it on its line
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Comment {
+    pub text: String,
+    pub start: usize,
+    pub end: usize,
+    pub trailing: bool,
+}
+
+// The source of (e) parsed with (comments), without a final newline. A
+// comment after code stays at the end of the line of the expression that
+// ends last before it, and one on its own line goes on its own line before
+// the outermost expression starting first after it, or at the end.
+pub fn exp_to_string_with_comments(e: &Exp<Span1>, comments: Vec<Comment>, style: &Style) -> String {
+    let mut spans = Vec::new();
+    collect_spans(e, &mut spans);
+    let mut before: HashMap<usize, Vec<String>> = HashMap::new();
+    let mut after: HashMap<usize, Vec<String>> = HashMap::new();
+    let mut last = Vec::new();
+    for comment in comments {
+        let ends_before = spans.iter().map(|span| span.end_ix).filter(|end| *end <= comment.start).max();
+        let starts_after = spans.iter().map(|span| span.start_ix).filter(|start| *start >= comment.end).min();
+        match (comment.trailing, ends_before, starts_after) {
+            (true, Some(end), _) => after.entry(end).or_default().push(comment.text),
+            (_, _, Some(start)) => before.entry(start).or_default().push(comment.text),
+            _ => last.push(comment.text),
+        }
+    }
+    let before = RefCell::new(before);
+    let after = RefCell::new(after);
+    let notes = |span: &Span1| {
+        let b = before.borrow_mut().remove(&span.start_ix).unwrap_or_default();
+        let a = after.borrow_mut().remove(&span.end_ix).unwrap_or_default();
+        (b, a)
+    };
+    let mut out = vec![exp_doc(e, EXP, true, &notes)];
+    for comment in last {
+        out.extend(vec![Doc::HardLine, Doc::Text(comment)]);
+    }
+    layout(&concat(out), style)
+}
+
+// The spans of the expressions and declarations of (e)
+fn collect_spans(e: &Exp<Span1>, spans: &mut Vec<Span1>) {
+    spans.push(*exp_ann(e));
+    match e {
+        Exp::Num(..) | Exp::Float(..) | Exp::Bool(..) | Exp::Str(..) | Exp::Var(..) => {}
+        Exp::Prim(_, es, _) => es.iter().for_each(|e| collect_spans(e, spans)),
+        Exp::Let { bindings, body, .. } => {
+            bindings.iter().for_each(|(_, e)| collect_spans(e, spans));
+            collect_spans(body, spans);
+        }
+        Exp::If { cond, thn, els, .. } => {
+            collect_spans(cond, spans);
+            collect_spans(thn, spans);
+            collect_spans(els, spans);
+        }
+        Exp::FunDefs { decls, body, .. } => {
+            for decl in decls {
+                spans.push(decl.ann);
+                collect_spans(&decl.body, spans);
+            }
+            collect_spans(body, spans);
+        }
+        Exp::Lambda { body, .. } => collect_spans(body, spans),
+        Exp::Seq { first, second, .. } => {
+            collect_spans(first, spans);
+            collect_spans(second, spans);
+        }
+        Exp::Call(_, es, _) | Exp::InternalTailCall(_, es, _) | Exp::ExternalCall { args: es, .. } => {
+            es.iter().for_each(|e| collect_spans(e, spans))
+        }
+        Exp::ClosureCall { fun, args, .. } => {
+            collect_spans(fun, spans);
+            args.iter().for_each(|e| collect_spans(e, spans));
+        }
+        Exp::MakeClosure { env, .. } => collect_spans(env, spans),
+    }
+}
+
+pub fn seq_exp_to_string<Ann>(e: &SeqExp<Ann>, style: &Style) -> String {
+    layout(&seq_doc(e, EXP, true), style)
+}
+
+// The top-level functions of (p), as mutually recursive definitions around
+// its main expression
+pub fn seq_prog_to_string<Ann>(p: &SeqProg<Ann>, style: &Style) -> String {
+    if p.funs.is_empty() {
+        return seq_exp_to_string(&p.main, style);
+    }
+    let decls = p.funs.iter().map(|decl| decl_doc(&decl.name, &decl.parameters, seq_doc(&decl.body, EXP, true)));
+    layout(&fun_defs_doc(decls.collect(), seq_doc(&p.main, EXP, true), true), style)
+}
+
+impl<Ann> Display for Exp<Ann> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", exp_to_string(self, &Style::default()))
+    }
+}
+
+impl<Ann> Display for FunDecl<Exp<Ann>, Ann> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let d = fun_decl_doc(self, &no_notes);
+        write!(f, "{}", layout(&d, &Style::default()))
+    }
+}
+
+impl<Ann> Display for FunDecl<SeqExp<Ann>, Ann> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let d = decl_doc(&self.name, &self.parameters, seq_doc(&self.body, EXP, true));
+        write!(f, "{}", layout(&d, &Style::default()))
+    }
+}
+
+impl<Ann> Display for SeqExp<Ann> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", seq_exp_to_string(self, &Style::default()))
+    }
+}
+
+impl<Ann> Display for SeqProg<Ann> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", seq_prog_to_string(self, &Style::default()))
+    }
+}
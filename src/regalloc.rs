@@ -0,0 +1,120 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::asm::Reg;
+use crate::syntax::{ImmExp, SeqExp};
+
+// ********************************************************************
+//
+//                 liveness & register allocation
+//
+// ********************************************************************
+
+// The let-bound variables of a function body that may share a register,
+// i.e. are never live at the same time
+#[derive(Debug, Default)]
+pub struct InterferenceGraph {
+    edges: HashMap<String, HashSet<String>>,
+    // the variables in the order they are defined
+    order: Vec<String>,
+    // the parameters of local functions: internal tail calls write them to
+    // their stack slots, so they never live in a register
+    on_stack: HashSet<String>,
+}
+
+impl InterferenceGraph {
+    fn add_node(&mut self, x: &str) {
+        self.edges.entry(x.to_string()).or_default();
+        self.order.push(x.to_string());
+    }
+
+    fn add_edge(&mut self, x: &str, y: &str) {
+        self.edges.entry(x.to_string()).or_default().insert(y.to_string());
+        self.edges.entry(y.to_string()).or_default().insert(x.to_string());
+    }
+}
+
+fn imm_uses(imms: &[ImmExp], live: &mut HashSet<String>) {
+    for imm in imms.iter() {
+        if let ImmExp::Var(x) = imm {
+            live.insert(x.clone());
+        }
+    }
+}
+
+// Returns the variables live before e given the ones live after it, and
+// records in (graph) that a let-bound variable interferes with everything
+// live while it is defined.
+pub fn liveness<Ann>(e: &SeqExp<Ann>, live_after: &HashSet<String>, graph: &mut InterferenceGraph) -> HashSet<String> {
+    match e {
+        SeqExp::Imm(imm, _) => {
+            let mut live = live_after.clone();
+            imm_uses(std::slice::from_ref(imm), &mut live);
+            live
+        },
+        SeqExp::Prim(_, args, _) | SeqExp::InternalTailCall(_, args, _) | SeqExp::ExternalCall { args, .. } => {
+            let mut live = live_after.clone();
+            imm_uses(args, &mut live);
+            live
+        },
+        SeqExp::ClosureCall { fun, args, .. } => {
+            let mut live = live_after.clone();
+            imm_uses(std::slice::from_ref(fun), &mut live);
+            imm_uses(args, &mut live);
+            live
+        },
+        SeqExp::MakeClosure { env, .. } => {
+            let mut live = live_after.clone();
+            imm_uses(std::slice::from_ref(env), &mut live);
+            live
+        },
+        SeqExp::Str(_, _) => live_after.clone(),
+        SeqExp::Let { var, bound_exp, body, .. } => {
+            graph.add_node(var);
+            let mut live = liveness(body, live_after, graph);
+            live.remove(var);
+            for y in live.iter() {
+                graph.add_edge(var, y);
+            }
+            liveness(bound_exp, &live, graph)
+        },
+        SeqExp::If { cond, thn, els, .. } => {
+            let mut live = liveness(thn, live_after, graph);
+            live.extend(liveness(els, live_after, graph));
+            imm_uses(std::slice::from_ref(cond), &mut live);
+            live
+        },
+        SeqExp::FunDefs { decls, body, .. } => {
+            // local functions return from the enclosing function
+            let mut live = liveness(body, live_after, graph);
+            for decl in decls.iter() {
+                let mut decl_live = liveness(&decl.body, &HashSet::new(), graph);
+                for x in decl.parameters.iter() {
+                    decl_live.remove(x);
+                    graph.on_stack.insert(x.clone());
+                }
+                live.extend(decl_live);
+            }
+            live
+        },
+    }
+}
+
+// Colors the interference graph of a function body with (registers),
+// greedily in definition order. The variables left out are spilled to
+// their stack slots.
+pub fn allocate_registers<Ann>(e: &SeqExp<Ann>, registers: &[Reg]) -> HashMap<String, Reg> {
+    let mut graph = InterferenceGraph::default();
+    liveness(e, &HashSet::new(), &mut graph);
+
+    let mut allocation: HashMap<String, Reg> = HashMap::new();
+    for x in graph.order.iter() {
+        if graph.on_stack.contains(x) || allocation.contains_key(x) {
+            continue;
+        }
+        let taken: Vec<Reg> = graph.edges[x].iter().filter_map(|y| allocation.get(y).copied()).collect();
+        if let Some(reg) = registers.iter().find(|reg| !taken.contains(reg)) {
+            allocation.insert(x.clone(), *reg);
+        }
+    }
+    allocation
+}
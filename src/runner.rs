@@ -1,17 +1,30 @@
 use std::fs::File;
 use std::io::prelude::*;
 use std::io::{BufReader, Read, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
+use std::sync::{Mutex, OnceLock};
 
 use std::fmt::{Display, Formatter};
 
+use lalrpop_util::ParseError;
+
 use crate::compile;
-use crate::compile::{compile_to_string, CompileErr};
+use crate::compile::{compile_prog_to_instrs, compile_to_object, compile_to_string, compile_to_string_typed, CompileErr, Stage};
+use crate::desugar::desugar;
+use crate::diff;
+use crate::diff::{Comparison, Outcome};
+use crate::fuzz;
+use crate::fuzz::Failure;
+use crate::jit;
 use crate::interp;
 use crate::interp::InterpErr;
+use crate::lexer::{Lexer, LexicalError, Tok};
 use crate::parser::ProgParser;
-use crate::syntax::SurfProg;
+use crate::pretty;
+use crate::pretty::Style;
+use crate::syntax::{Exp, SurfFunDecl, SurfProg};
+use crate::warnings::{check_warnings, CompileWarning, Lints};
 
 mod span {
     use crate::span::{Span1, Span2};
@@ -59,11 +72,21 @@ mod span {
         panic!("internal error: offset_to_line_col. Send this to the professor");
     }
 
+    // The one-character span at (offset), which may be the end of the
+    // input, e.g. for parse errors at the end of the file
+    pub fn offset_to_span2(info: &FileInfo, offset: usize) -> Span2 {
+        if info.len == 0 {
+            return Span2 { start_line: 1, start_col: 0, end_line: 1, end_col: 1 };
+        }
+        let start_ix = offset.min(info.len - 1);
+        span1_to_span2(info, Span1 { start_ix, end_ix: start_ix + 1 })
+    }
+
     use crate::compile::CompileErr;
     impl<Span> CompileErr<Span> {
         pub fn map_span<F, SpanPrime>(self, f: F) -> CompileErr<SpanPrime>
         where
-            F: FnOnce(&Span) -> SpanPrime,
+            F: Fn(&Span) -> SpanPrime,
         {
             match self {
                 CompileErr::UnboundVariable { unbound, location } => CompileErr::UnboundVariable {
@@ -73,9 +96,11 @@ mod span {
                 CompileErr::DuplicateBinding {
                     duplicated_name,
                     location,
+                    previous,
                 } => CompileErr::DuplicateBinding {
                     duplicated_name,
                     location: f(&location),
+                    previous: f(&previous),
                 },
                 CompileErr::Overflow { num, location } => CompileErr::Overflow {
                     num,
@@ -100,36 +125,47 @@ mod span {
                 CompileErr::DuplicateFunName {
                     duplicated_name,
                     location,
+                    previous,
                 } => CompileErr::DuplicateFunName {
                     duplicated_name: duplicated_name.clone(),
                     location: f(&location),
+                    previous: f(&previous),
                 },
 
-                CompileErr::FunctionUsedAsValue {
+                CompileErr::FunctionCalledWrongArity {
                     function_name,
+                    correct_arity,
+                    arity_used,
                     location,
-                } => CompileErr::FunctionUsedAsValue {
+                } => CompileErr::FunctionCalledWrongArity {
                     function_name: function_name.clone(),
+                    correct_arity,
+                    arity_used,
                     location: f(&location),
                 },
 
-                CompileErr::ValueUsedAsFunction {
-                    variable_name,
-                    location,
-                } => CompileErr::ValueUsedAsFunction {
-                    variable_name: variable_name.clone(),
+                CompileErr::ReservedVariable { name, location } => CompileErr::ReservedVariable {
+                    name,
                     location: f(&location),
                 },
 
-                CompileErr::FunctionCalledWrongArity {
-                    function_name,
-                    correct_arity,
-                    arity_used,
+                CompileErr::TypeMismatch {
+                    expected,
+                    found,
                     location,
-                } => CompileErr::FunctionCalledWrongArity {
-                    function_name: function_name.clone(),
-                    correct_arity: correct_arity.clone(),
-                    arity_used: arity_used.clone(),
+                } => CompileErr::TypeMismatch {
+                    expected,
+                    found,
+                    location: f(&location),
+                },
+
+                CompileErr::InfiniteType { var, ty, location } => CompileErr::InfiniteType {
+                    var,
+                    ty,
+                    location: f(&location),
+                },
+
+                CompileErr::UnknownTupleSize { location } => CompileErr::UnknownTupleSize {
                     location: f(&location),
                 },
             }
@@ -147,99 +183,106 @@ mod span {
     }
 }
 use crate::span::{Span1, Span2};
-use span::{file_info, span1_to_span2, FileInfo};
+use span::{file_info, offset_to_span2, span1_to_span2, FileInfo};
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum RunnerErr<Span> {
     FileOpen(String),
-    Lex(String),
-    Parse(String),
+    Lex {
+        msg: String,
+        location: Span,
+    },
+    Parse {
+        msg: String,
+        location: Span,
+        expected: Vec<String>, // the tokens the parser would have accepted
+    },
     CodeGen(CompileErr<Span>),
+    Check(Vec<CompileErr<Span>>), // every error found by check_prog_all
     Link(String),
     Interp(InterpErr),
     Run(String),
 }
 
-impl<Span> Display for CompileErr<Span>
-where
-    Span: Display,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl<Span> CompileErr<Span> {
+    // The description of the error, without its location
+    pub fn message(&self) -> String {
         match self {
-            CompileErr::UnboundVariable { unbound, location } => {
-                write!(f, "Unbound variable {} at {}", unbound, location)
+            CompileErr::UnboundVariable { unbound, .. } => format!("Unbound variable {}", unbound),
+            CompileErr::UndefinedFunction { undefined, .. } => {
+                format!("Undefined function {} called", undefined)
             }
-            CompileErr::UndefinedFunction {
-                undefined,
-                location,
-            } => {
-                write!(f, "Undefined function {} called at {}", undefined, location)
+            CompileErr::DuplicateBinding { duplicated_name, .. } => {
+                format!("Variable {} defined twice in let-expression", duplicated_name)
+            }
+            CompileErr::Overflow { num, .. } => {
+                format!("Number literal {} doesn't fit into 62-bit integer", num)
+            }
+            CompileErr::DuplicateArgName { duplicated_name, .. } => {
+                format!("multiple arguments named \"{}\"", duplicated_name)
+            }
+            CompileErr::DuplicateFunName { duplicated_name, .. } => {
+                format!("multiple defined functions named \"{}\"", duplicated_name)
             }
-            CompileErr::DuplicateBinding {
-                duplicated_name,
-                location,
-            } => write!(
-                f,
-                "Variable {} defined twice in let-expression at {}",
-                duplicated_name, location
-            ),
-
-            CompileErr::Overflow { num, location } => write!(
-                f,
-                "Number literal {} doesn't fit into 63-bit integer at {}",
-                num, location
-            ),
-
-            CompileErr::DuplicateArgName {
-                duplicated_name,
-                location,
-            } => write!(
-                f,
-                "multiple arguments named \"{}\" at {}",
-                duplicated_name, location,
-            ),
-
-            CompileErr::DuplicateFunName {
-                duplicated_name,
-                location,
-            } => write!(
-                f,
-                "multiple defined functions named \"{}\" at {}",
-                duplicated_name, location
-            ),
-
-            CompileErr::FunctionUsedAsValue {
-                function_name,
-                location,
-            } => write!(
-                f,
-                "function {} used in non-function call position at {}",
-                function_name, location
-            ),
-
-            CompileErr::ValueUsedAsFunction {
-                variable_name,
-                location,
-            } => write!(
-                f,
-                "value variable {} used in function call position at {}",
-                variable_name, location
-            ),
-
             CompileErr::FunctionCalledWrongArity {
                 function_name,
                 correct_arity,
                 arity_used,
-                location,
-            } => write!(
-                f,
-                "function {} of arity {} called with {} arguments at {}",
-                function_name, correct_arity, arity_used, location
+                ..
+            } => format!(
+                "function {} of arity {} called with {} arguments",
+                function_name, correct_arity, arity_used
             ),
+            CompileErr::ReservedVariable { name, .. } => {
+                format!("{} is reserved for the input of the program and can't be rebound", name)
+            }
+            CompileErr::TypeMismatch { expected, found, .. } => {
+                format!("type mismatch: expected {} but found {}", expected, found)
+            }
+            CompileErr::InfiniteType { var, ty, .. } => {
+                format!("cannot construct the infinite type {} = {}", var, ty)
+            }
+            CompileErr::UnknownTupleSize { .. } => {
+                String::from("cannot infer the size of the tuple indexed")
+            }
+        }
+    }
+
+    pub fn location(&self) -> &Span {
+        match self {
+            CompileErr::UnboundVariable { location, .. }
+            | CompileErr::UndefinedFunction { location, .. }
+            | CompileErr::DuplicateBinding { location, .. }
+            | CompileErr::Overflow { location, .. }
+            | CompileErr::DuplicateArgName { location, .. }
+            | CompileErr::DuplicateFunName { location, .. }
+            | CompileErr::FunctionCalledWrongArity { location, .. }
+            | CompileErr::ReservedVariable { location, .. }
+            | CompileErr::TypeMismatch { location, .. }
+            | CompileErr::InfiniteType { location, .. }
+            | CompileErr::UnknownTupleSize { location } => location,
+        }
+    }
+
+    // Other locations relevant to the error
+    pub fn notes(&self) -> Vec<(&str, &Span)> {
+        match self {
+            CompileErr::DuplicateBinding { previous, .. } => vec![("previous binding here", previous)],
+            CompileErr::DuplicateFunName { previous, .. } => vec![("previously defined here", previous)],
+            _ => vec![],
         }
     }
 }
 
+impl<Span> Display for CompileErr<Span>
+where
+    Span: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} at {}", self.message(), self.location())
+    }
+}
+
 impl<Span> Display for RunnerErr<Span>
 where
     Span: Display,
@@ -247,9 +290,21 @@ where
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
             RunnerErr::FileOpen(s) => write!(f, "Error reading file: {}", s),
-            RunnerErr::Lex(s) => write!(f, "Error lexing input: {}", s),
-            RunnerErr::Parse(s) => write!(f, "Error parsing input: {}", s),
+            RunnerErr::Lex { msg, location } => write!(f, "Error lexing input: {} at {}", msg, location),
+            RunnerErr::Parse { msg, location, expected } => {
+                write!(f, "Error parsing input: {} at {}", msg, location)?;
+                if !expected.is_empty() {
+                    write!(f, ", expected one of {}", expected.join(", "))?;
+                }
+                Ok(())
+            }
             RunnerErr::CodeGen(ce) => write!(f, "Error generating assembly: {}", ce),
+            RunnerErr::Check(ces) => {
+                for ce in ces.iter() {
+                    writeln!(f, "Error generating assembly: {}", ce)?;
+                }
+                write!(f, "{}", error_count(ces.len()))
+            }
             RunnerErr::Link(s) => write!(f, "Error linking generated assembly with runtime: {}", s),
             RunnerErr::Interp(s) => write!(f, "Error in interpreter: {}", s),
             RunnerErr::Run(s) => write!(f, "Error running your compiled output: {}", s),
@@ -257,70 +312,740 @@ where
     }
 }
 
-fn fail<Span>(e: RunnerErr<Span>)
-where
-    Span: Display,
-{
-    eprintln!("{}", e);
+impl RunnerErr<Span2> {
+    // Formats the error in the style of rustc, quoting the lines of (src)
+    // it points to. (file) is the name the locations refer to.
+    pub fn render(&self, file: &str, src: &str) -> String {
+        let (msg, location, notes, expected) = match self {
+            RunnerErr::Check(ces) => {
+                let mut out = String::new();
+                for ce in ces.iter() {
+                    out.push_str(&RunnerErr::CodeGen(ce.clone()).render(file, src));
+                    out.push('\n');
+                }
+                return format!("{}error: {}\n", out, error_count(ces.len()));
+            }
+            RunnerErr::Lex { msg, location } => {
+                (msg.clone(), location, vec![], &[][..])
+            }
+            RunnerErr::Parse { msg, location, expected } => (msg.clone(), location, vec![], &expected[..]),
+            RunnerErr::CodeGen(ce) => (ce.message(), ce.location(), ce.notes(), &[][..]),
+            // runtime errors have no location in the source
+            e => return format!("{}\n", e),
+        };
+        let expected = if expected.is_empty() {
+            vec![]
+        } else {
+            vec![format!("expected one of {}", expected.join(", "))]
+        };
+        diagnostic("error", &msg, file, src, location, &notes, &expected)
+    }
+}
+
+impl CompileWarning<Span2> {
+    pub fn render(&self, file: &str, src: &str) -> String {
+        let help = format!("help: silence with `-A {}`", self.lint().name());
+        diagnostic("warning", &self.message(), file, src, self.location(), &self.notes(), &[help])
+    }
+}
+
+// A message at a (location) of (file), notes pointing at other locations
+// and trailing remarks, each quoting (src)
+fn diagnostic(
+    level: &str,
+    msg: &str,
+    file: &str,
+    src: &str,
+    location: &Span2,
+    notes: &[(&str, &Span2)],
+    remarks: &[String],
+) -> String {
+    let mut out = format!("{}: {}\n", level, msg);
+    out.push_str(&snippet(file, src, location));
+    for (note, location) in notes {
+        out.push_str(&format!("note: {}\n", note));
+        out.push_str(&snippet(file, src, location));
+    }
+    for remark in remarks {
+        out.push_str(&format!("  = {}\n", remark));
+    }
+    out
+}
+
+fn error_count(n: usize) -> String {
+    format!("aborting due to {} previous error{}", n, if n == 1 { "" } else { "s" })
+}
+
+// The location and first line of (span), underlined with ^
+fn snippet(file: &str, src: &str, span: &Span2) -> String {
+    let line = src.lines().nth(span.start_line - 1).unwrap_or("");
+    let end_col = if span.end_line == span.start_line { span.end_col } else { line.len() };
+    // keep the tabs so that the underline lines up with the source
+    let pad: String = line
+        .chars()
+        .take(span.start_col)
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+    let carets = "^".repeat(end_col.saturating_sub(span.start_col).max(1));
+    let gutter = " ".repeat(span.start_line.to_string().len());
+    format!(
+        "{g}--> {file}:{l}:{c}\n{g} |\n{l} | {line}\n{g} | {pad}{carets}\n",
+        g = gutter,
+        file = file,
+        l = span.start_line,
+        c = span.start_col + 1,
+        line = line,
+        pad = pad,
+        carets = carets
+    )
+}
+
+fn parse_err(e: ParseError<usize, Tok, LexicalError>, info: &FileInfo) -> RunnerErr<Span2> {
+    let span = |l: usize, r: usize| span1_to_span2(info, Span1 { start_ix: l, end_ix: r.max(l + 1) });
+    match e {
+        ParseError::User { error } => RunnerErr::Lex {
+            msg: error.to_string(),
+            location: offset_to_span2(info, error.location()),
+        },
+        ParseError::InvalidToken { location } => RunnerErr::Parse {
+            msg: String::from("invalid token"),
+            location: offset_to_span2(info, location),
+            expected: vec![],
+        },
+        ParseError::UnrecognizedEOF { location, expected } => RunnerErr::Parse {
+            msg: String::from("unexpected end of input"),
+            location: offset_to_span2(info, location),
+            expected,
+        },
+        ParseError::UnrecognizedToken { token: (l, tok, r), expected } => RunnerErr::Parse {
+            msg: format!("unexpected token `{}`", tok),
+            location: span(l, r),
+            expected,
+        },
+        ParseError::ExtraToken { token: (l, tok, r) } => RunnerErr::Parse {
+            msg: format!("extra token `{}`", tok),
+            location: span(l, r),
+            expected: vec![],
+        },
+    }
+}
+
+// Reports an error about the program in file (p)
+fn fail(p: &Path, e: RunnerErr<Span2>) {
+    let src = std::fs::read_to_string(p).unwrap_or_default();
+    eprint!("{}", e.render(&p.display().to_string(), &src));
     std::process::exit(1);
 }
 
-fn handle_errs<Span>(r: Result<String, RunnerErr<Span>>)
-where
-    Span: Display,
-{
+fn handle_errs(p: &Path, r: Result<String, RunnerErr<Span2>>) {
     match r {
         Ok(s) => println!("{}", s),
+        Err(e) => fail(p, e),
+    }
+}
+
+// Prints the enabled warnings about the program in file (p). A program
+// that does not compile gets no warnings, its errors come first.
+pub fn report_warnings(p: &Path, lints: &Lints) {
+    let src = match std::fs::read_to_string(p) {
+        Ok(src) => src,
+        Err(_) => return,
+    };
+    let prog = match ProgParser::new().parse(Lexer::new(&src)) {
+        Ok(prog) => prog,
+        Err(_) => return,
+    };
+    if !compile::check_prog_all(&desugar(&prog)).is_empty() {
+        return;
+    }
+    let info = file_info(&src);
+    let mut warnings: Vec<CompileWarning<Span2>> = check_warnings(&prog)
+        .into_iter()
+        .filter(|w| lints.is_enabled(w.lint()))
+        .map(|w| w.map_span(|s| span1_to_span2(&info, *s)))
+        .collect();
+    if warnings.is_empty() {
+        return;
+    }
+    warnings.sort_by_key(|w| (w.location().start_line, w.location().start_col));
+    let file = p.display().to_string();
+    for w in warnings.iter() {
+        eprintln!("{}", w.render(&file, &src));
+    }
+    let n = warnings.len();
+    eprintln!("warning: {} warning{} emitted\n", n, if n == 1 { "" } else { "s" });
+}
+
+pub fn emit_assembly(p: &Path) {
+    handle_errs(p, compile_file(p, false))
+}
+
+pub fn emit_typed_assembly(p: &Path) {
+    handle_errs(p, compile_file(p, true))
+}
+
+// Prints the program after (stage) of the compiler
+pub fn emit(p: &Path, stage: Stage, typed: bool) {
+    handle_errs(p, emit_file(p, stage, typed))
+}
+
+pub fn run(p: &Path, opts: &BuildOptions, input: &ProgramInput) {
+    let res = with_build_dir(opts, |dir| {
+        let exe = build_executable(p, opts, dir)?;
+        run_executable(&exe, input, &mut std::io::stdout())
+    });
+    if let Err(e) = res {
+        fail(p, e)
+    }
+}
+
+// Compiles and links the program into an executable without running it
+pub fn build(p: &Path, opts: &BuildOptions) {
+    if let Err(e) = with_build_dir(opts, |dir| build_executable(p, opts, dir).map(|_| ())) {
+        fail(p, e)
+    }
+}
+
+pub fn jit(p: &Path, input: &ProgramInput) {
+    if let Err(e) = jit_file_with(p, input, &mut std::io::stdout()) {
+        fail(p, e)
+    }
+}
+
+// Reports whether the interpreter and the compiled code agree on the
+// program, failing if they don't
+pub fn diff(p: &Path, opts: &BuildOptions) {
+    match with_build_dir(opts, |dir| diff_file(p, opts.backend, dir)) {
+        Ok(comparison) if comparison.agrees() => println!("{}", comparison),
+        Ok(comparison) => {
+            println!("{}", comparison);
+            std::process::exit(1)
+        }
+        Err(e) => fail(p, e),
+    }
+}
+
+// Compares the interpreter with the compiled code on (runs) random
+// programs, the first one from (seed). The first program they disagree
+// on is shrunk, printed and saved in the temporary directory, and the
+// fuzzer fails.
+pub fn fuzz(runs: u64, seed: u64, backend: Backend) {
+    println!("fuzzing {} programs from seed {}", runs, seed);
+    // panics of the compiler are reported as failures
+    std::panic::set_hook(Box::new(|_| {}));
+    let res = with_build_dir(&BuildOptions::default(), |dir| {
+        for i in 0..runs {
+            let prog = fuzz::gen_prog(seed + i, FUZZ_SIZES[i as usize % FUZZ_SIZES.len()]);
+            let failure = match fuzz_prog(&prog, backend, dir) {
+                None => continue,
+                Some(failure) => failure,
+            };
+            println!("program {} (seed {}) fails, shrinking it", i, seed + i);
+            let shrunk = match failure {
+                Failure::Rejected(_) => prog,
+                _ => fuzz::shrink(prog, |candidate| {
+                    matches!(fuzz_prog(candidate, backend, dir), Some(f) if f.same_kind(&failure))
+                }),
+            };
+            let failure = fuzz_prog(&shrunk, backend, dir).unwrap_or(failure);
+            let saved = std::env::temp_dir().join(format!("fuzz-{}.snake", seed + i));
+            let src = format!("{}\n", shrunk);
+            std::fs::write(&saved, &src).map_err(|e| RunnerErr::FileOpen(e.to_string()))?;
+            println!("{}\n\nthe program, saved as {}:\n{}", failure, saved.display(), src);
+            return Ok(false);
+        }
+        Ok(true)
+    });
+    let _ = std::panic::take_hook();
+    match res {
+        Ok(true) => println!("the interpreter and the compiled code agree on all {} programs", runs),
+        Ok(false) => std::process::exit(1),
         Err(e) => {
             eprintln!("{}", e);
-            std::process::exit(1);
+            std::process::exit(1)
         }
     }
 }
 
-pub fn emit_assembly(p: &Path) {
-    handle_errs(compile_file(p))
+// Formats the program in file (p) in place. With (check), only reports
+// whether it is formatted already, failing if it isn't.
+pub fn fmt(p: &Path, check: bool, style: &Style) {
+    let (src, formatted) = match read_file(p).and_then(|src| Ok((format_source(&src, style)?, src))) {
+        Ok((formatted, src)) => (src, formatted),
+        Err(e) => return fail(p, e),
+    };
+    if src == formatted {
+        return;
+    }
+    if check {
+        eprintln!("{} is not formatted", p.display());
+        std::process::exit(1);
+    }
+    if let Err(e) = std::fs::write(p, formatted) {
+        fail(p, RunnerErr::FileOpen(e.to_string()))
+    }
 }
 
-pub fn run(p: &Path) {
-    if let Err(e) = compile_and_run_file(p, Path::new("runtime"), &mut std::io::stdout()) {
-        fail(e)
+// The formatted version of (src). Comments aren't part of the syntax tree:
+// the ones before the program are kept as they are, and the others are
+// put next to the expressions they were next to, see
+// pretty::exp_to_string_with_comments.
+pub fn format_source(src: &str, style: &Style) -> Result<String, RunnerErr<Span2>> {
+    let info = file_info(src);
+    let e = ProgParser::new().parse(Lexer::new(src)).map_err(|e| parse_err(e, &info))?;
+    let (header, comments) = comments(src);
+    let header = header.trim_end();
+    let header = if header.is_empty() { String::new() } else { format!("{}\n", header) };
+    Ok(format!("{}{}\n", header, pretty::exp_to_string_with_comments(&e, comments, style)))
+}
+
+// The text before the first token of (src) and the comments after it,
+// found between the tokens. Lexical errors are left to the parser.
+fn comments(src: &str) -> (&str, Vec<pretty::Comment>) {
+    let mut tokens = Vec::new();
+    for tok in Lexer::new(src) {
+        match tok {
+            Ok((start, _, end)) => tokens.push((start, end)),
+            Err(_) => return (&src[..tokens.first().map_or(0, |t| t.0)], Vec::new()),
+        }
+    }
+    let first = match tokens.first() {
+        Some((start, _)) => *start,
+        None => return (src, Vec::new()),
+    };
+    let gaps = tokens.windows(2).map(|w| (w[0].1, w[1].0));
+    let last = tokens.last().map_or(0, |t| t.1);
+    let mut comments = Vec::new();
+    for (from, to) in gaps.chain(Some((last, src.len()))) {
+        let mut i = from;
+        while i < to {
+            let rest = &src[i..to];
+            let len = if rest.starts_with('#') {
+                rest.find('\n').unwrap_or(rest.len())
+            } else if rest.starts_with("/*") {
+                block_comment_len(rest)
+            } else {
+                i += rest.chars().next().map_or(1, char::len_utf8);
+                continue;
+            };
+            comments.push(pretty::Comment {
+                text: rest[..len].to_string(),
+                start: i,
+                end: i + len,
+                trailing: !src[from..i].contains('\n'),
+            });
+            i += len;
+        }
     }
+    (&src[..first], comments)
 }
 
-pub fn interp<W>(p: &Path, w: &mut W)
+// The length of the nested block comment (src) starts with
+fn block_comment_len(src: &str) -> usize {
+    let mut depth = 0;
+    let mut i = 0;
+    while i < src.len() {
+        if src[i..].starts_with("/*") {
+            depth += 1;
+            i += 2;
+        } else if src[i..].starts_with("*/") {
+            depth -= 1;
+            i += 2;
+            if depth == 0 {
+                return i;
+            }
+        } else {
+            i += src[i..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    src.len()
+}
+
+pub fn interp<W>(p: &Path, input: &ProgramInput, w: &mut W)
 where
     W: std::io::Write,
 {
-    if let Err(e) = interpret_file(p, w) {
-        fail(e)
+    if let Err(e) = interpret_file(p, input, w) {
+        fail(p, e)
     }
 }
 
-pub fn interpret_file<W>(p: &Path, w: &mut W) -> Result<(), RunnerErr<Span2>>
+pub fn interpret_file<W>(p: &Path, input: &ProgramInput, w: &mut W) -> Result<(), RunnerErr<Span2>>
 where
     W: std::io::Write,
 {
     let (info, prog) = parse_file(p)?;
-    let () = compile::check_prog(&prog)
-        .map_err(|e| RunnerErr::CodeGen(e.map_span(|s| span1_to_span2(&info, *s))))?;
+    check_all(&prog, &info)?;
 
-    interp::prog(&prog, w).map_err(|e| RunnerErr::Interp(e))?;
+    let mut stdin = input.stdin_reader();
+    interp::prog(&prog, input.arg.as_deref(), &mut stdin, w).map_err(RunnerErr::Interp)?;
     Ok(())
 }
 
+pub fn repl() {
+    let stdin = std::io::stdin();
+    if let Err(e) = repl_session(stdin.lock(), &mut std::io::stdout()) {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+// An entry of the repl: an expression to evaluate, or top-level
+// declarations or let bindings (without "in") kept for the next entries
+enum ReplInput {
+    Exp(SurfProg<Span1>),
+    Defs(Vec<SurfFunDecl<Span1>>),
+    Let(Vec<(String, Exp<Span1>)>),
+    Incomplete,
+}
+
+// What the checker needs to know about the earlier entries of a session
+enum ReplScope {
+    Defs(Vec<SurfFunDecl<Span1>>),
+    Var(String),
+}
+
+// An entry that ends early is (Incomplete), unless the user ended it
+// with an empty line
+fn parse_repl_input(src: &str, ended: bool) -> Result<ReplInput, RunnerErr<Span2>> {
+    let err = match ProgParser::new().parse(Lexer::new(src)) {
+        Ok(e) => return Ok(ReplInput::Exp(desugar(&e))),
+        Err(err) => err,
+    };
+    let trimmed = src.trim_start();
+    if trimmed.starts_with("def") || trimmed.starts_with("let") {
+        let decl_src = format!("{} in 0", src);
+        match ProgParser::new().parse(Lexer::new(&decl_src)).map(|e| desugar(&e)) {
+            Ok(Exp::FunDefs { decls, .. }) => return Ok(ReplInput::Defs(decls)),
+            Ok(Exp::Let { bindings, .. }) => return Ok(ReplInput::Let(bindings)),
+            _ => {}
+        }
+    }
+    match err {
+        ParseError::UnrecognizedEOF { .. } if !ended => Ok(ReplInput::Incomplete),
+        e => Err(parse_err(e, &file_info(src))),
+    }
+}
+
+// Checks e in the scope of the earlier entries
+fn check_repl_input(e: SurfProg<Span1>, scopes: &[ReplScope], src: &str) -> Result<(), RunnerErr<Span2>> {
+    let ann = Span1 { start_ix: 0, end_ix: src.len() };
+    let prog = scopes.iter().rev().fold(e, |body, scope| match scope {
+        ReplScope::Defs(decls) => Exp::FunDefs {
+            decls: decls.clone(),
+            body: Box::new(body),
+            ann,
+        },
+        ReplScope::Var(x) => Exp::Let {
+            bindings: vec![(x.clone(), Exp::Num(0, ann))],
+            body: Box::new(body),
+            ann,
+        },
+    });
+    check_all(&prog, &file_info(src))
+}
+
+fn eval_repl_input<W>(
+    input: ReplInput,
+    src: &str,
+    session: &mut interp::Session<Span1>,
+    scopes: &mut Vec<ReplScope>,
+    out: &mut W,
+) -> Result<(), RunnerErr<Span2>>
+where
+    W: std::io::Write,
+{
+    let ann = Span1 { start_ix: 0, end_ix: src.len() };
+    match input {
+        ReplInput::Exp(e) => {
+            check_repl_input(e.clone(), scopes, src)?;
+            session.eval(e, out).map_err(RunnerErr::Interp)
+        }
+        ReplInput::Defs(decls) => {
+            let body = Box::new(Exp::Num(0, ann));
+            check_repl_input(Exp::FunDefs { decls: decls.clone(), body, ann }, scopes, src)?;
+            session.declare(decls.clone());
+            scopes.push(ReplScope::Defs(decls));
+            Ok(())
+        }
+        ReplInput::Let(bindings) => {
+            let body = Box::new(Exp::Num(0, ann));
+            check_repl_input(Exp::Let { bindings: bindings.clone(), body, ann }, scopes, src)?;
+            // the bindings evaluated before an error are kept
+            for (x, e) in bindings {
+                session.bind(x.clone(), e, out).map_err(RunnerErr::Interp)?;
+                scopes.push(ReplScope::Var(x));
+            }
+            Ok(())
+        }
+        ReplInput::Incomplete => unreachable!(),
+    }
+}
+
+// Reads entries from (input) until it ends, an entry spans several lines
+// as long as it is incomplete or until an empty line. Errors are reported
+// to (out) and the session goes on.
+pub fn repl_session<R, W>(input: R, out: &mut W) -> std::io::Result<()>
+where
+    R: std::io::BufRead,
+    W: std::io::Write,
+{
+    let mut session = interp::Session::new();
+    let mut scopes: Vec<ReplScope> = Vec::new();
+    let mut lines = input.lines();
+    let mut src = String::new();
+    loop {
+        write!(out, "{}", if src.is_empty() { "> " } else { ". " })?;
+        out.flush()?;
+        let line = match lines.next() {
+            None => return writeln!(out),
+            Some(line) => line?,
+        };
+        let ended = line.trim().is_empty();
+        if ended && src.is_empty() {
+            continue;
+        }
+        src.push_str(&line);
+        src.push('\n');
+        let result = match parse_repl_input(&src, ended) {
+            Ok(ReplInput::Incomplete) => continue,
+            Ok(input) => eval_repl_input(input, &src, &mut session, &mut scopes, out),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            write!(out, "{}", e.render("<repl>", &src))?;
+        }
+        src.clear();
+    }
+}
+
+// How the compiled code becomes the object file linked with the runtime
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Backend {
+    // emit assembly and run nasm on it
+    #[default]
+    Nasm,
+    // encode the instructions into an ELF64 object ourselves, linux only
+    Elf,
+}
+
+// What a program reads: the command-line argument its input is parsed
+// from, false without one, and its stdin, the one of the runner if None
+#[derive(Clone, Debug, Default)]
+pub struct ProgramInput {
+    pub arg: Option<String>,
+    pub stdin: Option<Vec<u8>>,
+}
+
+impl ProgramInput {
+    fn stdin_reader(&self) -> Box<dyn BufRead + Send> {
+        match &self.stdin {
+            Some(bytes) => Box::new(std::io::Cursor::new(bytes.clone())),
+            None => Box::new(BufReader::new(std::io::stdin())),
+        }
+    }
+}
+
 pub fn compile_and_run_file<W>(p: &Path, dir: &Path, out: &mut W) -> Result<(), RunnerErr<Span2>>
 where
     W: std::io::Write,
 {
-    let asm = compile_file(p)?;
-    link_and_run(&asm, dir, out)
+    compile_and_run_with(p, false, Backend::Nasm, &ProgramInput::default(), dir, out)
+}
+
+// Type checks the program before compiling it without runtime tag checks
+pub fn compile_and_run_typed_file<W>(p: &Path, dir: &Path, out: &mut W) -> Result<(), RunnerErr<Span2>>
+where
+    W: std::io::Write,
+{
+    compile_and_run_with(p, true, Backend::Nasm, &ProgramInput::default(), dir, out)
+}
+
+// Like compile_and_run_file, without going through nasm
+pub fn compile_and_run_elf_file<W>(p: &Path, dir: &Path, out: &mut W) -> Result<(), RunnerErr<Span2>>
+where
+    W: std::io::Write,
+{
+    compile_and_run_with(p, false, Backend::Elf, &ProgramInput::default(), dir, out)
+}
+
+pub fn compile_and_run_with<W>(
+    p: &Path,
+    typed: bool,
+    backend: Backend,
+    input: &ProgramInput,
+    dir: &Path,
+    out: &mut W,
+) -> Result<(), RunnerErr<Span2>>
+where
+    W: std::io::Write,
+{
+    let opts = BuildOptions {
+        backend,
+        typed,
+        ..BuildOptions::default()
+    };
+    let exe = build_executable(p, &opts, dir)?;
+    run_executable(&exe, input, out)
+}
+
+// How the command line builds programs
+#[derive(Clone, Debug, Default)]
+pub struct BuildOptions {
+    pub backend: Backend,
+    pub typed: bool,
+    // keep the build directory instead of deleting it
+    pub keep_artifacts: bool,
+    // where to put the executable, stub.exe in the build directory if None
+    pub output: Option<PathBuf>,
+}
+
+// Runs (f) on a new temporary build directory, so that builds running at
+// the same time don't overwrite each other's files
+fn with_build_dir<T, F>(opts: &BuildOptions, f: F) -> Result<T, RunnerErr<Span2>>
+where
+    F: FnOnce(&Path) -> Result<T, RunnerErr<Span2>>,
+{
+    let dir = tempfile::Builder::new()
+        .prefix("snake-build")
+        .tempdir()
+        .map_err(|e| RunnerErr::Link(e.to_string()))?;
+    let res = f(dir.path());
+    if opts.keep_artifacts {
+        eprintln!("note: build artifacts kept in {}", dir.keep().display());
+    }
+    res
+}
+
+// Compiles the program into an object in (dir) and links it with the
+// runtime, returns the path of the executable
+fn build_executable(p: &Path, opts: &BuildOptions, dir: &Path) -> Result<PathBuf, RunnerErr<Span2>> {
+    match opts.backend {
+        Backend::Nasm => assemble(&compile_file(p, opts.typed)?, dir)?,
+        Backend::Elf => write_object(&compile_object_file(p, opts.typed)?, dir)?,
+    }
+    let exe = opts.output.clone().unwrap_or_else(|| dir.join("stub.exe"));
+    link(dir, &exe)?;
+    Ok(exe)
+}
+
+// Reports all the well-formedness errors of the program at once
+fn check_all(prog: &SurfProg<Span1>, info: &FileInfo) -> Result<(), RunnerErr<Span2>> {
+    let errs = compile::check_prog_all(prog);
+    if errs.is_empty() {
+        Ok(())
+    } else {
+        Err(RunnerErr::Check(errs.into_iter().map(|e| e.map_span(|s| span1_to_span2(info, *s))).collect()))
+    }
+}
+
+fn compile_file(p: &Path, typed: bool) -> Result<String, RunnerErr<Span2>> {
+    let (info, prog) = parse_file(p)?;
+    check_all(&prog, &info)?;
+    let compile = if typed { compile_to_string_typed } else { compile_to_string };
+    compile(&prog).map_err(|e| RunnerErr::CodeGen(e.map_span(|s| span1_to_span2(&info, *s))))
+}
+
+pub fn emit_file(p: &Path, stage: Stage, typed: bool) -> Result<String, RunnerErr<Span2>> {
+    let (info, prog) = parse_file(p)?;
+    check_all(&prog, &info)?;
+    compile::emit_stage(&prog, stage, typed).map_err(|e| RunnerErr::CodeGen(e.map_span(|s| span1_to_span2(&info, *s))))
+}
+
+static INTERP_STACK_SIZE: usize = 1 << 30;
+
+// Runs the program compiled with (backend) in (dir) and with the reference
+// interpreter.
+// Errors are the ones that keep it from running at all.
+pub fn diff_file(p: &Path, backend: Backend, dir: &Path) -> Result<Comparison, RunnerErr<Span2>> {
+    let (info, prog) = parse_file(p)?;
+    check_all(&prog, &info)?;
+
+    let opts = BuildOptions {
+        backend,
+        ..BuildOptions::default()
+    };
+    let exe = build_executable(p, &opts, dir)?;
+    let out = Command::new(Path::new(".").join(exe))
+        .output()
+        .map_err(|e| RunnerErr::Run(e.to_string()))?;
+    let compiled = Outcome::of_compiled(&out.stdout, &out.stderr, out.status.success());
+    if diff::is_inconclusive(&compiled.ending) {
+        return Ok(Comparison { compiled, interp: None });
+    }
+
+    // the interpreter recurses much deeper than the compiled code
+    let interp = std::thread::scope(|scope| {
+        std::thread::Builder::new()
+            .stack_size(INTERP_STACK_SIZE)
+            .spawn_scoped(scope, || {
+                let mut stdout = Vec::new();
+                let res = interp::prog(&prog, None, &mut std::io::empty(), &mut stdout);
+                Outcome::of_interp(&stdout, res)
+            })
+            .map_err(|e| RunnerErr::Run(e.to_string()))?
+            .join()
+            .map_err(|_| RunnerErr::Run(String::from("the interpreter crashed")))
+    })?;
+    Ok(Comparison {
+        compiled,
+        interp: Some(interp),
+    })
+}
+
+// The sizes of the programs the fuzzer generates, in turn
+static FUZZ_SIZES: [usize; 4] = [5, 15, 30, 60];
+
+// Runs the generated program (prog) through diff_file in (dir), returns
+// why it fails if it does
+pub fn fuzz_prog(prog: &SurfProg<()>, backend: Backend, dir: &Path) -> Option<Failure> {
+    let path = dir.join("fuzz.snake");
+    if let Err(e) = std::fs::write(&path, format!("{}\n", prog)) {
+        return Some(Failure::Crashed(e.to_string()));
+    }
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| diff_file(&path, backend, dir)));
+    match res {
+        Ok(Ok(comparison)) if comparison.agrees() => None,
+        Ok(Ok(comparison)) => Some(Failure::Diverged(comparison)),
+        Ok(Err(e @ RunnerErr::Lex { .. })) | Ok(Err(e @ RunnerErr::Parse { .. })) | Ok(Err(e @ RunnerErr::Check(_))) => {
+            Some(Failure::Rejected(e.to_string()))
+        }
+        Ok(Err(e)) => Some(Failure::Crashed(e.to_string())),
+        Err(panic) => {
+            let msg = match (panic.downcast_ref::<&str>(), panic.downcast_ref::<String>()) {
+                (Some(msg), _) => msg.to_string(),
+                (_, Some(msg)) => msg.clone(),
+                _ => String::from("panicked"),
+            };
+            Some(Failure::Crashed(msg))
+        }
+    }
+}
+
+// Runs the compiled code in this process, without linking it with the
+// runtime
+pub fn jit_file<W>(p: &Path, out: &mut W) -> Result<(), RunnerErr<Span2>>
+where
+    W: std::io::Write,
+{
+    jit_file_with(p, &ProgramInput::default(), out)
+}
+
+pub fn jit_file_with<W>(p: &Path, input: &ProgramInput, out: &mut W) -> Result<(), RunnerErr<Span2>>
+where
+    W: std::io::Write,
+{
+    let (info, prog) = parse_file(p)?;
+    check_all(&prog, &info)?;
+    let instrs =
+        compile_prog_to_instrs(&prog, false).map_err(|e| RunnerErr::CodeGen(e.map_span(|s| span1_to_span2(&info, *s))))?;
+    jit::run(instrs, input.arg.as_deref(), input.stdin_reader(), out).map_err(RunnerErr::Run)
 }
 
-fn compile_file(p: &Path) -> Result<String, RunnerErr<Span2>> {
+fn compile_object_file(p: &Path, typed: bool) -> Result<Vec<u8>, RunnerErr<Span2>> {
     let (info, prog) = parse_file(p)?;
-    compile_to_string(&prog)
-        .map_err(|e| RunnerErr::CodeGen(e.map_span(|s| span1_to_span2(&info, *s))))
+    check_all(&prog, &info)?;
+    compile_to_object(&prog, typed).map_err(|e| RunnerErr::CodeGen(e.map_span(|s| span1_to_span2(&info, *s))))
 }
 
 fn read_file<Span>(p: &Path) -> Result<String, RunnerErr<Span>> {
@@ -333,30 +1058,25 @@ fn read_file<Span>(p: &Path) -> Result<String, RunnerErr<Span>> {
 
 fn parse_file(p: &Path) -> Result<(FileInfo, SurfProg<Span1>), RunnerErr<Span2>> {
     let s = read_file(p)?;
-    let e = ProgParser::new()
-        .parse(&s)
-        .map_err(|e| RunnerErr::Parse(e.to_string()))?;
-    Ok((file_info(&s), e))
+    let info = file_info(&s);
+    let e = ProgParser::new().parse(Lexer::new(&s)).map_err(|e| parse_err(e, &info))?;
+    Ok((info, desugar(&e)))
 }
 
-fn link_and_run<W>(assembly: &str, dir: &Path, out: &mut W) -> Result<(), RunnerErr<Span2>>
-where
-    W: std::io::Write,
-{
-    let (nasm_format, lib_name) = if cfg!(target_os = "linux") {
-        ("elf64", "libcompiled_code.a")
+// Assembles (assembly) into the object compiled_code.o in (dir)
+fn assemble(assembly: &str, dir: &Path) -> Result<(), RunnerErr<Span2>> {
+    let nasm_format = if cfg!(target_os = "linux") {
+        "elf64"
     } else if cfg!(target_os = "macos") {
-        ("macho64", "libcompiled_code.a")
+        "macho64"
     } else if cfg!(target_os = "windows") {
-        ("win64", "compiled_code.lib")
+        "win64"
     } else {
         panic!("Runner script only works on linux, macos and windows")
     };
 
     let asm_fname = dir.join("compiled_code.s");
     let obj_fname = dir.join("compiled_code.o");
-    let lib_fname = dir.join(lib_name);
-    let exe_fname = dir.join("stub.exe");
 
     // first put the assembly in a new file compiled_code.s
     let mut asm_file = File::create(&asm_fname).map_err(|e| RunnerErr::Link(e.to_string()))?;
@@ -383,63 +1103,190 @@ where
             std::str::from_utf8(&nasm_out.stderr).expect("nasm produced invalid UTF-8")
         )));
     }
+    Ok(())
+}
 
-    // ar r libcompiled_code.a compiled_code.o
-    let ar_out = Command::new("ar")
-        .arg("rus")
-        .arg(lib_fname)
-        .arg(&obj_fname)
-        .output()
-        .map_err(|e| RunnerErr::Link(format!("ar err: {}", e)))?;
-    if !ar_out.status.success() {
-        return Err(RunnerErr::Link(format!(
-            "Failure in ar call:\n{}\n{}",
-            ar_out.status,
-            std::str::from_utf8(&ar_out.stderr).expect("ar produced invalid UTF-8")
-        )));
+// Writes the object (object) as compiled_code.o in (dir), doing the work
+// of nasm
+fn write_object(object: &[u8], dir: &Path) -> Result<(), RunnerErr<Span2>> {
+    if !cfg!(target_os = "linux") {
+        return Err(RunnerErr::Link(String::from("ELF objects can only be linked on linux, use nasm")));
     }
+    std::fs::write(dir.join("compiled_code.o"), object).map_err(|e| RunnerErr::Link(e.to_string()))
+}
 
-    // rustc stub.rs -L tmp
-    let rustc_out = if cfg!(target_os = "macos") {
-        Command::new("rustc")
-            .arg("runtime/stub.rs")
-            .arg("--target")
-            .arg("x86_64-apple-darwin")
-            .arg("-L")
-            .arg(dir)
-            .arg("-o")
-            .arg(&exe_fname)
-            .output()
-            .map_err(|e| RunnerErr::Link(format!("rustc err: {}", e)))?
-    } else {
-        Command::new("rustc")
-            .arg("runtime/stub.rs")
-            .arg("-L")
-            .arg(dir)
-            .arg("-o")
-            .arg(&exe_fname)
-            .output()
-            .map_err(|e| RunnerErr::Link(format!("rustc err: {}", e)))?
-    };
+// The runtime is part of the compiler, so that it can build programs from
+// any directory. stub.rs include!s the other files, which it shares with
+// the jit.
+static RUNTIME_SRC: [(&str, &str); 3] = [
+    ("stub.rs", include_str!("../runtime/stub.rs")),
+    ("common.rs", include_str!("../runtime/common.rs")),
+    ("error_codes.rs", include_str!("../runtime/error_codes.rs")),
+];
+
+// The directory holding the runtime built as the static library libstub.a
+// and the native libraries it needs, once built
+static RUNTIME_LIB: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+// Builds the runtime into the cache directory of the user unless that was
+// done before, see build_runtime
+fn runtime_lib() -> Result<PathBuf, RunnerErr<Span2>> {
+    let mut cached = RUNTIME_LIB.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(lib_dir) = cached.as_ref() {
+        return Ok(lib_dir.clone());
+    }
+    let lib_dir = build_runtime(&runtime_cache_dir())?;
+    *cached = Some(lib_dir.clone());
+    Ok(lib_dir)
+}
+
+// $SNAKE_CACHE_DIR if it is set, so that the tests can keep their runtime
+// apart, else $XDG_CACHE_HOME/snake, falling back to ~/.cache/snake and
+// then to the target directory of the compiler. Unlike the shared
+// temporary directory, only the user can put a runtime there.
+fn runtime_cache_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os("SNAKE_CACHE_DIR").filter(|dir| !dir.is_empty()) {
+        return PathBuf::from(dir);
+    }
+    match std::env::var_os("XDG_CACHE_HOME").filter(|dir| !dir.is_empty()) {
+        Some(dir) => PathBuf::from(dir).join("snake"),
+        None => match std::env::var_os("HOME").filter(|dir| !dir.is_empty()) {
+            Some(home) => PathBuf::from(home).join(".cache").join("snake"),
+            None => Path::new(env!("CARGO_MANIFEST_DIR")).join("target"),
+        },
+    }
+}
+
+// 64-bit FNV-1a, which unlike DefaultHasher gives the same hash with every
+// release of Rust
+fn stable_hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+        (hash ^ *b as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+// The hash of the source of the runtime and the version of rustc, asked
+// for once per process
+fn runtime_key() -> Result<u64, RunnerErr<Span2>> {
+    static KEY: OnceLock<u64> = OnceLock::new();
+    if let Some(key) = KEY.get() {
+        return Ok(*key);
+    }
+    let version = Command::new("rustc")
+        .arg("-vV")
+        .output()
+        .map_err(|e| RunnerErr::Link(format!("rustc err: {}", e)))?;
+    let mut bytes: Vec<u8> = RUNTIME_SRC.iter().flat_map(|(_, src)| src.bytes()).collect();
+    bytes.extend(version.stdout);
+    Ok(*KEY.get_or_init(|| stable_hash(&bytes)))
+}
+
+// Builds runtime/stub.rs into a static library in a subdirectory of
+// (cache_dir), unless that was done before, and returns the subdirectory.
+// It is keyed by the source of the runtime and the version of rustc, so
+// that it is shared by all the runs and rebuilt when either changes.
+pub fn build_runtime(cache_dir: &Path) -> Result<PathBuf, RunnerErr<Span2>> {
+    let lib_dir = cache_dir.join(format!("snake-runtime-{:016x}", runtime_key()?));
+    if lib_dir.join("native-static-libs").exists() {
+        return Ok(lib_dir);
+    }
+    create_private_dir(cache_dir)?;
+    // build in a fresh directory and move it in place at once, in case
+    // another process is building it too
+    let build_dir = tempfile::Builder::new()
+        .prefix("snake-runtime-build")
+        .tempdir_in(cache_dir)
+        .map_err(|e| RunnerErr::Link(e.to_string()))?;
+    for (fname, src) in RUNTIME_SRC.iter() {
+        std::fs::write(build_dir.path().join(fname), src).map_err(|e| RunnerErr::Link(e.to_string()))?;
+    }
+    let src_fname = build_dir.path().join("stub.rs");
+    let mut rustc = Command::new("rustc");
+    rustc
+        .arg(&src_fname)
+        .arg("--crate-type=staticlib")
+        .arg("--print=native-static-libs")
+        .arg("-o")
+        .arg(build_dir.path().join("libstub.a"));
+    if cfg!(target_os = "macos") {
+        rustc.arg("--target").arg("x86_64-apple-darwin");
+    }
+    let rustc_out = rustc.output().map_err(|e| RunnerErr::Link(format!("rustc err: {}", e)))?;
+    let stderr = std::str::from_utf8(&rustc_out.stderr).expect("rustc produced invalid UTF-8");
     if !rustc_out.status.success() {
+        return Err(RunnerErr::Link(format!("Failure in rustc call: {}\n{}", rustc_out.status, stderr)));
+    }
+    let native_libs = stderr
+        .lines()
+        .find_map(|line| line.split("native-static-libs:").nth(1))
+        .unwrap_or("")
+        .trim();
+    std::fs::write(build_dir.path().join("native-static-libs"), native_libs)
+        .map_err(|e| RunnerErr::Link(e.to_string()))?;
+    if std::fs::rename(build_dir.path(), &lib_dir).is_err() && !lib_dir.join("native-static-libs").exists() {
+        return Err(RunnerErr::Link(format!("failed to cache the runtime in {}", lib_dir.display())));
+    }
+    Ok(lib_dir)
+}
+
+// Creates (dir) if needed, readable and writable by the user only
+fn create_private_dir(dir: &Path) -> Result<(), RunnerErr<Span2>> {
+    let mut builder = std::fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+    builder.create(dir).map_err(|e| RunnerErr::Link(e.to_string()))
+}
+
+// Links compiled_code.o in (dir) with the runtime into the executable
+// (exe_fname)
+fn link(dir: &Path, exe_fname: &Path) -> Result<(), RunnerErr<Span2>> {
+    let lib_dir = runtime_lib()?;
+    let native_libs = std::fs::read_to_string(lib_dir.join("native-static-libs"))
+        .map_err(|e| RunnerErr::Link(e.to_string()))?;
+
+    // cc compiled_code.o libstub.a NATIVE_LIBS -o stub.exe
+    let mut cc = Command::new("cc");
+    cc.arg(dir.join("compiled_code.o"))
+        .arg(lib_dir.join("libstub.a"))
+        .args(native_libs.split_whitespace())
+        .arg("-o")
+        .arg(exe_fname);
+    if cfg!(target_os = "macos") {
+        cc.arg("-arch").arg("x86_64");
+    }
+    let cc_out = cc.output().map_err(|e| RunnerErr::Link(format!("cc err: {}", e)))?;
+    if !cc_out.status.success() {
         return Err(RunnerErr::Link(format!(
-            "Failure in rustc call: {}\n{}",
-            rustc_out.status,
-            std::str::from_utf8(&rustc_out.stderr).expect("rustc produced invalid UTF-8")
+            "Failure in cc call: {}\n{}",
+            cc_out.status,
+            std::str::from_utf8(&cc_out.stderr).expect("cc produced invalid UTF-8")
         )));
     }
+    Ok(())
+}
 
-    let mut child = Command::new(&exe_fname)
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .map_err(|e| RunnerErr::Run(format!("{}", e)))?;
-    let compiled_out = BufReader::new(
-        child
-            .stdout
-            .take()
-            .expect("Failed to capture compiled code's stdout"),
-    );
+fn run_executable<W>(exe_fname: &Path, input: &ProgramInput, out: &mut W) -> Result<(), RunnerErr<Span2>>
+where
+    W: std::io::Write,
+{
+    // a bare file name would be looked up in PATH
+    let exe_fname = Path::new(".").join(exe_fname);
+    let mut cmd = Command::new(&exe_fname);
+    cmd.args(input.arg.iter()).stdout(Stdio::piped()).stderr(Stdio::piped());
+    if input.stdin.is_some() {
+        cmd.stdin(Stdio::piped());
+    }
+    let mut child = cmd.spawn().map_err(|e| RunnerErr::Run(format!("{}", e)))?;
+    // written on its own thread, the program may print before it reads
+    let writer = match (child.stdin.take(), input.stdin.clone()) {
+        (Some(mut stdin), Some(bytes)) => Some(std::thread::spawn(move || stdin.write_all(&bytes))),
+        _ => None,
+    };
+    let mut compiled_out = child
+        .stdout
+        .take()
+        .expect("Failed to capture compiled code's stdout");
     let compiled_err = BufReader::new(
         child
             .stderr
@@ -447,14 +1294,16 @@ where
             .expect("Failed to capture compiled code's stderr"),
     );
 
-    for line in compiled_out.lines() {
-        let line = line.map_err(|e| RunnerErr::Run(format!("{}", e)))?;
-        writeln!(out, "{}", line).map_err(|e| RunnerErr::Run(format!("I/O error: {}", e)))?;
-    }
+    // as raw bytes, strings needn't be valid UTF-8
+    std::io::copy(&mut compiled_out, out).map_err(|e| RunnerErr::Run(format!("I/O error: {}", e)))?;
 
     let status = child
         .wait()
         .map_err(|e| RunnerErr::Run(format!("Error waiting for child process {}", e)))?;
+    // the program may exit without reading all of its stdin
+    if let Some(writer) = writer {
+        let _ = writer.join();
+    }
     if !status.success() {
         let mut stderr = String::new();
         for line in compiled_err.lines() {
@@ -1,7 +1,7 @@
 pub type SurfProg<Ann> = Exp<Ann>;
 pub type SurfFunDecl<Ann> = FunDecl<Exp<Ann>, Ann>;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct FunDecl<E, Ann> {
     pub name: String,
     pub parameters: Vec<String>,
@@ -10,10 +10,12 @@ pub struct FunDecl<E, Ann> {
 }
 
 /* Expressions */
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Exp<Ann> {
     Num(i64, Ann),
+    Float(f64, Ann),
     Bool(bool, Ann),
+    Str(String, Ann),
     Var(String, Ann),
     Prim(Prim, Vec<Box<Exp<Ann>>>, Ann),
     Let {
@@ -32,10 +34,30 @@ pub enum Exp<Ann> {
         body: Box<Exp<Ann>>,
         ann: Ann,
     },
+    // lambda (x, y): body end
+    Lambda {
+        parameters: Vec<String>,
+        body: Box<Exp<Ann>>,
+        ann: Ann,
+    },
+    // first; second, which desugar turns into a let before check_prog
+    Seq {
+        first: Box<Exp<Ann>>,
+        second: Box<Exp<Ann>>,
+        ann: Ann,
+    },
 
     // Source program calls will be parsed as a call.
     // In your lambda_lift function you should
     Call(String, Vec<Exp<Ann>>, Ann),
+    // A call to any expression that evaluates to a closure
+    // marked to indicate whether it is a tail call or not
+    ClosureCall {
+        fun: Box<Exp<Ann>>,
+        args: Vec<Exp<Ann>>,
+        is_tail: bool,
+        ann: Ann,
+    },
 
     // An internal tail call to a locally defined function.
     InternalTailCall(String, Vec<Exp<Ann>>, Ann),
@@ -48,6 +70,96 @@ pub enum Exp<Ann> {
         is_tail: bool,
         ann: Ann,
     },
+    // Allocates a closure for a lifted function used as a value,
+    // env is the tuple of the variables it captures
+    MakeClosure {
+        fun_name: String,
+        arity: usize,
+        env: Box<Exp<Ann>>,
+        ann: Ann,
+    },
+}
+
+// The annotation of the outermost node of e
+pub fn exp_ann<Span>(e: &Exp<Span>) -> &Span {
+    match e {
+        Exp::Num(_, ann) | Exp::Float(_, ann) | Exp::Bool(_, ann) | Exp::Str(_, ann) | Exp::Var(_, ann)
+        | Exp::Prim(_, _, ann) | Exp::Call(_, _, ann) | Exp::InternalTailCall(_, _, ann) => ann,
+        Exp::Let { ann, .. } | Exp::If { ann, .. } | Exp::FunDefs { ann, .. } | Exp::Lambda { ann, .. } | Exp::Seq { ann, .. }
+        | Exp::ClosureCall { ann, .. } | Exp::ExternalCall { ann, .. } | Exp::MakeClosure { ann, .. } => ann,
+    }
+}
+
+// e with (f) applied to every annotation, e.g. to compare expressions
+// regardless of their spans with map_ann(e, &|_| ())
+pub fn map_ann<A, B, F>(e: &Exp<A>, f: &F) -> Exp<B>
+where
+    F: Fn(&A) -> B,
+{
+    let boxed = |e: &Exp<A>| Box::new(map_ann(e, f));
+    let all = |es: &[Exp<A>]| es.iter().map(|e| map_ann(e, f)).collect();
+    match e {
+        Exp::Num(n, ann) => Exp::Num(*n, f(ann)),
+        Exp::Float(x, ann) => Exp::Float(*x, f(ann)),
+        Exp::Bool(b, ann) => Exp::Bool(*b, f(ann)),
+        Exp::Str(s, ann) => Exp::Str(s.clone(), f(ann)),
+        Exp::Var(x, ann) => Exp::Var(x.clone(), f(ann)),
+        Exp::Prim(p, es, ann) => Exp::Prim(*p, es.iter().map(|e| boxed(e)).collect(), f(ann)),
+        Exp::Let { bindings, body, ann } => Exp::Let {
+            bindings: bindings.iter().map(|(x, e)| (x.clone(), map_ann(e, f))).collect(),
+            body: boxed(body),
+            ann: f(ann),
+        },
+        Exp::If { cond, thn, els, ann } => Exp::If {
+            cond: boxed(cond),
+            thn: boxed(thn),
+            els: boxed(els),
+            ann: f(ann),
+        },
+        Exp::FunDefs { decls, body, ann } => Exp::FunDefs {
+            decls: decls
+                .iter()
+                .map(|decl| FunDecl {
+                    name: decl.name.clone(),
+                    parameters: decl.parameters.clone(),
+                    body: map_ann(&decl.body, f),
+                    ann: f(&decl.ann),
+                })
+                .collect(),
+            body: boxed(body),
+            ann: f(ann),
+        },
+        Exp::Lambda { parameters, body, ann } => Exp::Lambda {
+            parameters: parameters.clone(),
+            body: boxed(body),
+            ann: f(ann),
+        },
+        Exp::Seq { first, second, ann } => Exp::Seq {
+            first: boxed(first),
+            second: boxed(second),
+            ann: f(ann),
+        },
+        Exp::Call(fun, es, ann) => Exp::Call(fun.clone(), all(es), f(ann)),
+        Exp::ClosureCall { fun, args, is_tail, ann } => Exp::ClosureCall {
+            fun: boxed(fun),
+            args: all(args),
+            is_tail: *is_tail,
+            ann: f(ann),
+        },
+        Exp::InternalTailCall(fun, es, ann) => Exp::InternalTailCall(fun.clone(), all(es), f(ann)),
+        Exp::ExternalCall { fun_name, args, is_tail, ann } => Exp::ExternalCall {
+            fun_name: fun_name.clone(),
+            args: all(args),
+            is_tail: *is_tail,
+            ann: f(ann),
+        },
+        Exp::MakeClosure { fun_name, arity, env, ann } => Exp::MakeClosure {
+            fun_name: fun_name.clone(),
+            arity: *arity,
+            env: boxed(env),
+            ann: f(ann),
+        },
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -59,11 +171,27 @@ pub enum Prim {
     Print,
     IsBool,
     IsNum,
+    IsTuple,
+    IsStr,
+    Len,      // the number of bytes of a string
+    ToStr,    // a number as it is printed
+    ToNum,    // the integer written in a string
+    Abs,
 
     // binary
     Add,
     Sub,
     Mul,
+    Div,      // always a float
+    IntDiv,   // a // b, rounded towards zero
+    Rem,      // a % b, with the sign of a
+    BitAnd,
+    BitOr,
+    BitXor,
+    Shl,      // a << b, for b in [0, 63]
+    Shr,      // a >> b, arithmetic, for b in [0, 63]
+    Min,
+    Max,
     And,
     Or,
     Lt,
@@ -72,10 +200,23 @@ pub enum Prim {
     Ge,
     Eq,
     Neq,
+    GetIndex, // tuple[index]
+    Concat,   // string ++ string
+
+    // ternary
+    SetIndex, // tuple[index] := value
+    Substr,   // substr(string, start, end), the bytes [start, end)
+
+    // n-ary
+    MakeTuple,
+
+    // nullary, reading a line of the program's input
+    ReadNum,
+    ReadBool,
 }
 
 /* Sequential Expressions */
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct SeqProg<Ann> {
     pub funs: Vec<FunDecl<SeqExp<Ann>, Ann>>,
     pub main: SeqExp<Ann>,
@@ -84,14 +225,15 @@ pub struct SeqProg<Ann> {
 
 pub type SeqFunDecl<Ann> = FunDecl<SeqExp<Ann>, Ann>;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum ImmExp {
     Num(i64),
+    Float(f64),
     Bool(bool),
     Var(String),
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum SeqExp<Ann> {
     Imm(ImmExp, Ann),
     Prim(Prim, Vec<ImmExp>, Ann),
@@ -126,7 +268,20 @@ pub enum SeqExp<Ann> {
         is_tail: bool,
         ann: Ann,
     },
+    // Calls the closure fun with the Snake Calling Convention v0,
+    // passing its captured environment before the arguments
+    ClosureCall {
+        fun: ImmExp,
+        args: Vec<ImmExp>,
+        is_tail: bool,
+        ann: Ann,
+    },
+    MakeClosure {
+        fun_name: String,
+        arity: usize,
+        env: ImmExp,
+        ann: Ann,
+    },
+    // Allocates a string literal
+    Str(String, Ann),
 }
-
-
-
@@ -0,0 +1,658 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::compile::{CompileErr, INPUT};
+use crate::syntax::{exp_ann, Exp, Prim, SurfProg};
+
+// ********************************************************************
+//
+//                    Hindley-Milner type inference
+//
+// ********************************************************************
+
+/* Types */
+#[derive(Clone, Debug, PartialEq)]
+pub enum Type {
+    Int,
+    Float,
+    Bool,
+    Str,
+    Var(usize),
+    Tuple(Vec<Type>),
+    Fun(Vec<Type>, Box<Type>),
+}
+
+// A type quantified over some of its type variables,
+// e.g. forall 'a. ('a) -> 'a for the identity function, with the
+// arithmetic constraints on them that each instance has to meet
+#[derive(Clone, Debug)]
+struct Scheme<Span> {
+    vars: Vec<usize>,
+    ty: Type,
+    arith: Vec<ArithConstraint<Span>>,
+}
+
+fn mono<Span>(ty: Type) -> Scheme<Span> {
+    Scheme { vars: Vec::new(), ty, arith: Vec::new() }
+}
+
+type Env<Span> = HashMap<String, Scheme<Span>>;
+
+// tuple[index] has type elem. Indexing needs the size of the tuple, so the
+// constraint waits until unification has found it. A dynamic index (None)
+// requires all the elements to have type elem.
+struct IndexConstraint<Span> {
+    tuple: Type,
+    index: Option<usize>,
+    elem: Type,
+    location: Span,
+}
+
+// left op right has type result for +, - and *: int when both operands
+// are ints and float as soon as one of them is a float. The constraint
+// waits until unification has found enough of the three types.
+#[derive(Clone, Debug)]
+struct ArithConstraint<Span> {
+    left: Type,
+    right: Type,
+    result: Type,
+    location: Span,
+}
+
+enum UnifyErr {
+    Mismatch,
+    NotNumeric(Type),
+    Infinite(usize, Type),
+}
+
+struct Infer<Span> {
+    // the solution of each type variable found so far
+    subst: Vec<Option<Type>>,
+    // type variables that may only stand for int or float
+    numeric: HashSet<usize>,
+    pending: Vec<IndexConstraint<Span>>,
+    pending_arith: Vec<ArithConstraint<Span>>,
+}
+
+// Type variables are printed as 'a, 'b, ... in order of appearance,
+// (names) is shared so that the two types of an error agree
+fn type_to_string(t: &Type, names: &mut HashMap<usize, String>) -> String {
+    match t {
+        Type::Int => String::from("int"),
+        Type::Float => String::from("float"),
+        Type::Bool => String::from("bool"),
+        Type::Str => String::from("string"),
+        Type::Var(v) => {
+            let next = names.len();
+            names.entry(*v).or_insert_with(|| {
+                let letter = (b'a' + (next % 26) as u8) as char;
+                if next < 26 { format!("'{}", letter) } else { format!("'{}{}", letter, next / 26) }
+            }).clone()
+        },
+        Type::Tuple(elems) => {
+            let elems: Vec<String> = elems.iter().map(|t| type_to_string(t, names)).collect();
+            if elems.len() == 1 {
+                format!("({},)", elems[0])
+            } else {
+                format!("({})", elems.join(", "))
+            }
+        },
+        Type::Fun(params, ret) => {
+            let params: Vec<String> = params.iter().map(|t| type_to_string(t, names)).collect();
+            format!("({}) -> {}", params.join(", "), type_to_string(ret, names))
+        },
+    }
+}
+
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", type_to_string(self, &mut HashMap::new()))
+    }
+}
+
+impl<Span: Clone> Infer<Span> {
+    fn fresh(&mut self) -> Type {
+        self.subst.push(None);
+        Type::Var(self.subst.len() - 1)
+    }
+
+    // follows the solved variables at the root of t
+    fn shallow(&self, t: &Type) -> Type {
+        let mut t = t.clone();
+        while let Type::Var(v) = t {
+            match &self.subst[v] {
+                Some(solved) => t = solved.clone(),
+                None => break,
+            }
+        }
+        t
+    }
+
+    // substitutes all the solved variables in t
+    fn resolve(&self, t: &Type) -> Type {
+        match self.shallow(t) {
+            Type::Tuple(elems) => Type::Tuple(elems.iter().map(|t| self.resolve(t)).collect()),
+            Type::Fun(params, ret) => {
+                Type::Fun(params.iter().map(|t| self.resolve(t)).collect(), Box::new(self.resolve(&ret)))
+            },
+            t => t,
+        }
+    }
+
+    fn free_vars(&self, t: &Type, vars: &mut HashSet<usize>) {
+        match self.shallow(t) {
+            Type::Var(v) => { vars.insert(v); },
+            Type::Tuple(elems) => elems.iter().for_each(|t| self.free_vars(t, vars)),
+            Type::Fun(params, ret) => {
+                params.iter().for_each(|t| self.free_vars(t, vars));
+                self.free_vars(&ret, vars);
+            },
+            Type::Int | Type::Float | Type::Bool | Type::Str => {},
+        }
+    }
+
+    fn bind(&mut self, v: usize, t: Type) -> Result<(), UnifyErr> {
+        let mut vars = HashSet::new();
+        self.free_vars(&t, &mut vars);
+        if vars.contains(&v) {
+            return Err(UnifyErr::Infinite(v, t));
+        }
+        if self.numeric.contains(&v) {
+            match &t {
+                Type::Var(w) => { self.numeric.insert(*w); },
+                Type::Int | Type::Float => {},
+                _ => return Err(UnifyErr::NotNumeric(t)),
+            }
+        }
+        self.subst[v] = Some(t);
+        Ok(())
+    }
+
+    fn unify_help(&mut self, t1: &Type, t2: &Type) -> Result<(), UnifyErr> {
+        match (self.shallow(t1), self.shallow(t2)) {
+            (Type::Var(v), Type::Var(w)) if v == w => Ok(()),
+            (Type::Var(v), t) | (t, Type::Var(v)) => self.bind(v, t),
+            (Type::Int, Type::Int) | (Type::Float, Type::Float) | (Type::Bool, Type::Bool) | (Type::Str, Type::Str) => Ok(()),
+            (Type::Tuple(elems1), Type::Tuple(elems2)) if elems1.len() == elems2.len() => {
+                for (e1, e2) in elems1.iter().zip(elems2.iter()) {
+                    self.unify_help(e1, e2)?;
+                }
+                Ok(())
+            },
+            (Type::Fun(params1, ret1), Type::Fun(params2, ret2)) if params1.len() == params2.len() => {
+                for (p1, p2) in params1.iter().zip(params2.iter()) {
+                    self.unify_help(p1, p2)?;
+                }
+                self.unify_help(&ret1, &ret2)
+            },
+            _ => Err(UnifyErr::Mismatch),
+        }
+    }
+
+    fn unify(&mut self, expected: &Type, found: &Type, location: &Span) -> Result<(), CompileErr<Span>> {
+        self.unify_help(expected, found).map_err(|e| {
+            let mut names = HashMap::new();
+            match e {
+                UnifyErr::Mismatch => CompileErr::TypeMismatch {
+                    expected: type_to_string(&self.resolve(expected), &mut names),
+                    found: type_to_string(&self.resolve(found), &mut names),
+                    location: location.clone(),
+                },
+                UnifyErr::NotNumeric(t) => CompileErr::TypeMismatch {
+                    expected: String::from("a number"),
+                    found: type_to_string(&self.resolve(&t), &mut names),
+                    location: location.clone(),
+                },
+                UnifyErr::Infinite(v, t) => CompileErr::InfiniteType {
+                    var: type_to_string(&Type::Var(v), &mut names),
+                    ty: type_to_string(&self.resolve(&t), &mut names),
+                    location: location.clone(),
+                },
+            }
+        })
+    }
+
+    fn require_numeric(&mut self, t: &Type, location: &Span) -> Result<(), CompileErr<Span>> {
+        match self.shallow(t) {
+            Type::Var(v) => { self.numeric.insert(v); Ok(()) },
+            Type::Int | Type::Float => Ok(()),
+            t => Err(CompileErr::TypeMismatch {
+                expected: String::from("a number"),
+                found: type_to_string(&self.resolve(&t), &mut HashMap::new()),
+                location: location.clone(),
+            }),
+        }
+    }
+
+    fn instantiate(&mut self, scheme: &Scheme<Span>) -> Result<Type, CompileErr<Span>> {
+        fn subst_vars(t: &Type, mapping: &HashMap<usize, Type>) -> Type {
+            match t {
+                Type::Var(v) => mapping.get(v).cloned().unwrap_or(Type::Var(*v)),
+                Type::Tuple(elems) => Type::Tuple(elems.iter().map(|t| subst_vars(t, mapping)).collect()),
+                Type::Fun(params, ret) => {
+                    Type::Fun(params.iter().map(|t| subst_vars(t, mapping)).collect(), Box::new(subst_vars(ret, mapping)))
+                },
+                t => t.clone(),
+            }
+        }
+
+        let mut mapping = HashMap::new();
+        for v in scheme.vars.iter() {
+            let fresh = self.fresh();
+            if let Type::Var(w) = fresh {
+                if self.numeric.contains(v) {
+                    self.numeric.insert(w);
+                }
+            }
+            mapping.insert(*v, fresh);
+        }
+        for constraint in scheme.arith.iter() {
+            self.pending_arith.push(ArithConstraint {
+                left: subst_vars(&constraint.left, &mapping),
+                right: subst_vars(&constraint.right, &mapping),
+                result: subst_vars(&constraint.result, &mapping),
+                location: constraint.location.clone(),
+            });
+        }
+        self.solve_pending()?;
+        Ok(subst_vars(&scheme.ty, &mapping))
+    }
+
+    // Quantifies the variables of t that are not free in env. Variables
+    // waiting on an index constraint stay monomorphic, their tuple size
+    // may still be found by a later use. Arithmetic constraints on the
+    // quantified variables move into the scheme, the ones on a variable
+    // that stays monomorphic keep all of their variables monomorphic.
+    fn generalize(&mut self, env: &Env<Span>, t: &Type) -> Result<Scheme<Span>, CompileErr<Span>> {
+        self.solve_pending()?;
+        let ty = self.resolve(t);
+        let mut vars = HashSet::new();
+        self.free_vars(&ty, &mut vars);
+
+        let mut fixed = HashSet::new();
+        for scheme in env.values() {
+            let mut scheme_vars = HashSet::new();
+            self.free_vars(&scheme.ty, &mut scheme_vars);
+            fixed.extend(scheme_vars.into_iter().filter(|v| !scheme.vars.contains(v)));
+        }
+        for constraint in self.pending.iter() {
+            self.free_vars(&constraint.tuple, &mut fixed);
+            self.free_vars(&constraint.elem, &mut fixed);
+        }
+
+        let arith_vars: Vec<HashSet<usize>> = self.pending_arith.iter().map(|c| self.arith_vars(c)).collect();
+        loop {
+            let before = fixed.len();
+            for cvars in arith_vars.iter() {
+                if !cvars.is_disjoint(&fixed) {
+                    fixed.extend(cvars.iter().copied());
+                }
+            }
+            if fixed.len() == before {
+                break;
+            }
+        }
+        let mut arith = Vec::new();
+        for (constraint, cvars) in std::mem::take(&mut self.pending_arith).into_iter().zip(arith_vars) {
+            if cvars.is_disjoint(&fixed) && !cvars.is_disjoint(&vars) {
+                vars.extend(cvars);
+                arith.push(constraint);
+            } else {
+                self.pending_arith.push(constraint);
+            }
+        }
+
+        let mut vars: Vec<usize> = vars.into_iter().filter(|v| !fixed.contains(v)).collect();
+        vars.sort_unstable();
+        Ok(Scheme { vars, ty, arith })
+    }
+
+    fn arith_vars(&self, constraint: &ArithConstraint<Span>) -> HashSet<usize> {
+        let mut vars = HashSet::new();
+        self.free_vars(&constraint.left, &mut vars);
+        self.free_vars(&constraint.right, &mut vars);
+        self.free_vars(&constraint.result, &mut vars);
+        vars
+    }
+
+    // Applies the arithmetic constraints whose types are known well enough
+    // to decide the others. Returns whether it applied any.
+    fn solve_arith(&mut self) -> Result<bool, CompileErr<Span>> {
+        let mut progress = false;
+        for constraint in std::mem::take(&mut self.pending_arith) {
+            let left = self.shallow(&constraint.left);
+            let right = self.shallow(&constraint.right);
+            let result = self.shallow(&constraint.result);
+            let location = &constraint.location;
+            match (&left, &right, &result) {
+                (Type::Float, _, _) | (_, Type::Float, _) => self.unify(&Type::Float, &result, location)?,
+                (Type::Int, Type::Int, _) => self.unify(&Type::Int, &result, location)?,
+                (_, _, Type::Int) => {
+                    self.unify(&Type::Int, &left, location)?;
+                    self.unify(&Type::Int, &right, location)?;
+                },
+                (Type::Int, _, Type::Float) => self.unify(&Type::Float, &right, location)?,
+                (_, Type::Int, Type::Float) => self.unify(&Type::Float, &left, location)?,
+                _ => {
+                    self.pending_arith.push(constraint);
+                    continue;
+                },
+            }
+            progress = true;
+        }
+        Ok(progress)
+    }
+
+    // Decides the arithmetic constraints nothing else decides by giving
+    // both operands the type of the result, which any int or float meets
+    fn default_arith(&mut self) -> Result<(), CompileErr<Span>> {
+        for constraint in std::mem::take(&mut self.pending_arith) {
+            self.unify(&constraint.result, &constraint.left, &constraint.location)?;
+            self.unify(&constraint.result, &constraint.right, &constraint.location)?;
+        }
+        Ok(())
+    }
+
+    // Applies the index and arithmetic constraints that are known well
+    // enough by now
+    fn solve_pending(&mut self) -> Result<(), CompileErr<Span>> {
+        loop {
+            let pos = self.pending.iter().position(|c| !matches!(self.shallow(&c.tuple), Type::Var(_)));
+            let constraint = match pos {
+                Some(pos) => self.pending.remove(pos),
+                None if self.solve_arith()? => continue,
+                None => return Ok(()),
+            };
+            let elems = match self.shallow(&constraint.tuple) {
+                Type::Tuple(elems) => elems,
+                t => {
+                    return Err(CompileErr::TypeMismatch {
+                        expected: String::from("a tuple"),
+                        found: type_to_string(&self.resolve(&t), &mut HashMap::new()),
+                        location: constraint.location,
+                    })
+                }
+            };
+            match constraint.index {
+                Some(index) => match elems.get(index) {
+                    Some(elem) => self.unify(elem, &constraint.elem, &constraint.location)?,
+                    None => {
+                        return Err(CompileErr::TypeMismatch {
+                            expected: format!("a tuple of at least {} elements", index + 1),
+                            found: type_to_string(&self.resolve(&constraint.tuple), &mut HashMap::new()),
+                            location: constraint.location,
+                        })
+                    }
+                },
+                None => {
+                    for elem in elems.iter() {
+                        self.unify(elem, &constraint.elem, &constraint.location)?;
+                    }
+                },
+            }
+        }
+    }
+
+    fn infer_index(&mut self, tuple: &Exp<Span>, index: &Exp<Span>, env: &Env<Span>, location: &Span) -> Result<(Type, Type), CompileErr<Span>> {
+        let tuple_t = self.infer(tuple, env)?;
+        let index_t = self.infer(index, env)?;
+        self.unify(&Type::Int, &index_t, exp_ann(index))?;
+        let elem = self.fresh();
+        let index = match index {
+            Exp::Num(n, _) if *n >= 0 => Some(*n as usize),
+            _ => None,
+        };
+        self.pending.push(IndexConstraint { tuple: tuple_t.clone(), index, elem: elem.clone(), location: location.clone() });
+        self.solve_pending()?;
+        Ok((tuple_t, elem))
+    }
+
+    // the type of calling a function of type fun_t with args
+    fn infer_call(&mut self, fun_t: Type, args: &[Exp<Span>], env: &Env<Span>, location: &Span) -> Result<Type, CompileErr<Span>> {
+        let mut arg_ts = Vec::new();
+        for arg in args.iter() {
+            arg_ts.push(self.infer(arg, env)?);
+        }
+        match self.shallow(&fun_t) {
+            // blame the mismatching argument when the arity is right
+            Type::Fun(params, ret) if params.len() == args.len() => {
+                for ((param, arg_t), arg) in params.iter().zip(arg_ts.iter()).zip(args.iter()) {
+                    self.unify(param, arg_t, exp_ann(arg))?;
+                }
+                Ok(*ret)
+            },
+            _ => {
+                let ret = self.fresh();
+                self.unify(&fun_t, &Type::Fun(arg_ts, Box::new(ret.clone())), location)?;
+                Ok(ret)
+            },
+        }
+    }
+
+    fn infer(&mut self, e: &Exp<Span>, env: &Env<Span>) -> Result<Type, CompileErr<Span>> {
+        match e {
+            Exp::Num(..) => Ok(Type::Int),
+            Exp::Float(..) => Ok(Type::Float),
+            Exp::Bool(..) => Ok(Type::Bool),
+            Exp::Str(..) => Ok(Type::Str),
+
+            Exp::Var(x, ann) => match env.get(x) {
+                Some(scheme) => self.instantiate(scheme),
+                None => Err(CompileErr::UnboundVariable { unbound: x.clone(), location: ann.clone() }),
+            },
+
+            Exp::Prim(Prim::GetIndex, args, ann) => {
+                let (_, elem) = self.infer_index(&args[0], &args[1], env, ann)?;
+                Ok(elem)
+            },
+
+            Exp::Prim(Prim::SetIndex, args, ann) => {
+                let (tuple_t, elem) = self.infer_index(&args[0], &args[1], env, ann)?;
+                let value_t = self.infer(&args[2], env)?;
+                self.unify(&elem, &value_t, exp_ann(&args[2]))?;
+                self.solve_pending()?;
+                Ok(tuple_t)
+            },
+
+            Exp::Prim(prim, args, ann) => {
+                let mut ts = Vec::new();
+                for arg in args.iter() {
+                    ts.push(self.infer(arg, env)?);
+                }
+                match prim {
+                    Prim::Add1 | Prim::Sub1 => {
+                        self.require_numeric(&ts[0], exp_ann(&args[0]))?;
+                        Ok(ts[0].clone())
+                    },
+                    Prim::Add | Prim::Sub | Prim::Mul => {
+                        self.require_numeric(&ts[0], exp_ann(&args[0]))?;
+                        self.require_numeric(&ts[1], exp_ann(&args[1]))?;
+                        let result = self.fresh();
+                        self.require_numeric(&result, ann)?;
+                        self.pending_arith.push(ArithConstraint {
+                            left: ts[0].clone(),
+                            right: ts[1].clone(),
+                            result: result.clone(),
+                            location: ann.clone(),
+                        });
+                        self.solve_pending()?;
+                        Ok(result)
+                    },
+                    Prim::Abs | Prim::IntDiv | Prim::Rem | Prim::BitAnd | Prim::BitOr | Prim::BitXor |
+                    Prim::Shl | Prim::Shr | Prim::Min | Prim::Max => {
+                        for (t, arg) in ts.iter().zip(args.iter()) {
+                            self.unify(&Type::Int, t, exp_ann(arg))?;
+                        }
+                        Ok(Type::Int)
+                    },
+                    Prim::Div => {
+                        self.require_numeric(&ts[0], exp_ann(&args[0]))?;
+                        self.require_numeric(&ts[1], exp_ann(&args[1]))?;
+                        Ok(Type::Float)
+                    },
+                    Prim::Not | Prim::And | Prim::Or => {
+                        for (t, arg) in ts.iter().zip(args.iter()) {
+                            self.unify(&Type::Bool, t, exp_ann(arg))?;
+                        }
+                        Ok(Type::Bool)
+                    },
+                    Prim::Lt | Prim::Gt | Prim::Le | Prim::Ge => {
+                        for (t, arg) in ts.iter().zip(args.iter()) {
+                            self.unify(&Type::Int, t, exp_ann(arg))?;
+                        }
+                        Ok(Type::Bool)
+                    },
+                    Prim::Eq | Prim::Neq => {
+                        self.unify(&ts[0], &ts[1], exp_ann(&args[1]))?;
+                        Ok(Type::Bool)
+                    },
+                    Prim::IsBool | Prim::IsNum | Prim::IsTuple | Prim::IsStr => Ok(Type::Bool),
+                    Prim::Len | Prim::ToNum => {
+                        self.unify(&Type::Str, &ts[0], exp_ann(&args[0]))?;
+                        Ok(Type::Int)
+                    },
+                    Prim::ToStr => {
+                        self.require_numeric(&ts[0], exp_ann(&args[0]))?;
+                        Ok(Type::Str)
+                    },
+                    Prim::Concat => {
+                        for (t, arg) in ts.iter().zip(args.iter()) {
+                            self.unify(&Type::Str, t, exp_ann(arg))?;
+                        }
+                        Ok(Type::Str)
+                    },
+                    Prim::Substr => {
+                        self.unify(&Type::Str, &ts[0], exp_ann(&args[0]))?;
+                        for (t, arg) in ts.iter().zip(args.iter()).skip(1) {
+                            self.unify(&Type::Int, t, exp_ann(arg))?;
+                        }
+                        Ok(Type::Str)
+                    },
+                    Prim::ReadNum => Ok(Type::Int),
+                    Prim::ReadBool => Ok(Type::Bool),
+                    Prim::Print => Ok(ts[0].clone()),
+                    Prim::MakeTuple => Ok(Type::Tuple(ts)),
+                    Prim::GetIndex | Prim::SetIndex => unreachable!(),
+                }
+            },
+
+            Exp::Let { bindings, body, ann: _ } => {
+                let mut env = env.clone();
+                for (x, bound) in bindings.iter() {
+                    let t = self.infer(bound, &env)?;
+                    // only syntactic functions are generalized, tuples are mutable
+                    let scheme = match bound {
+                        Exp::Lambda { .. } => self.generalize(&env, &t)?,
+                        _ => mono(t),
+                    };
+                    env.insert(x.clone(), scheme);
+                }
+                self.infer(body, &env)
+            },
+
+            Exp::If { cond, thn, els, ann: _ } => {
+                let cond_t = self.infer(cond, env)?;
+                self.unify(&Type::Bool, &cond_t, exp_ann(cond))?;
+                let thn_t = self.infer(thn, env)?;
+                let els_t = self.infer(els, env)?;
+                self.unify(&thn_t, &els_t, exp_ann(els))?;
+                Ok(thn_t)
+            },
+
+            Exp::FunDefs { decls, body, ann: _ } => {
+                // the functions are mutually recursive and monomorphic
+                // within their own definitions
+                let outer_env = env;
+                let mut env = env.clone();
+                let mut fun_ts = Vec::new();
+                for decl in decls.iter() {
+                    let params = decl.parameters.iter().map(|_| self.fresh()).collect();
+                    let fun_t = Type::Fun(params, Box::new(self.fresh()));
+                    env.insert(decl.name.clone(), mono(fun_t.clone()));
+                    fun_ts.push(fun_t);
+                }
+                for (decl, fun_t) in decls.iter().zip(fun_ts.iter()) {
+                    let (params, ret) = match fun_t {
+                        Type::Fun(params, ret) => (params, ret),
+                        _ => unreachable!(),
+                    };
+                    let mut body_env = env.clone();
+                    for (x, t) in decl.parameters.iter().zip(params.iter()) {
+                        body_env.insert(x.clone(), mono(t.clone()));
+                    }
+                    let body_t = self.infer(&decl.body, &body_env)?;
+                    self.unify(ret, &body_t, exp_ann(&decl.body))?;
+                }
+                for (decl, fun_t) in decls.iter().zip(fun_ts.iter()) {
+                    let scheme = self.generalize(outer_env, fun_t)?;
+                    env.insert(decl.name.clone(), scheme);
+                }
+                self.infer(body, &env)
+            },
+
+            Exp::Lambda { parameters, body, ann: _ } => {
+                let mut env = env.clone();
+                let mut params = Vec::new();
+                for x in parameters.iter() {
+                    let t = self.fresh();
+                    env.insert(x.clone(), mono(t.clone()));
+                    params.push(t);
+                }
+                let body_t = self.infer(body, &env)?;
+                Ok(Type::Fun(params, Box::new(body_t)))
+            },
+
+            Exp::Call(fun_name, args, ann) => {
+                let fun_t = match env.get(fun_name) {
+                    Some(scheme) => self.instantiate(scheme)?,
+                    None => return Err(CompileErr::UndefinedFunction { undefined: fun_name.clone(), location: ann.clone() }),
+                };
+                self.infer_call(fun_t, args, env, ann)
+            },
+
+            Exp::ClosureCall { fun, args, is_tail: _, ann } => {
+                let fun_t = self.infer(fun, env)?;
+                self.infer_call(fun_t, args, env, ann)
+            },
+
+            Exp::InternalTailCall(..) | Exp::ExternalCall { .. } | Exp::MakeClosure { .. } => {
+                panic!("InternalTailCall, ExternalCall and MakeClosure shouldn't occur at type checking stage")
+            },
+
+            Exp::Seq { .. } => {
+                panic!("Seq shouldn't occur at type checking stage")
+            },
+        }
+    }
+}
+
+// Infers the types of a program that passed check_prog and of its input. A
+// program that type checks never fails a runtime tag check once its input
+// has the inferred type, so its code can skip them.
+pub fn type_check<Span>(p: &SurfProg<Span>) -> Result<(Type, Type), CompileErr<Span>>
+where
+    Span: Clone,
+{
+    let mut infer = Infer { subst: Vec::new(), numeric: HashSet::new(), pending: Vec::new(), pending_arith: Vec::new() };
+    // the input is whatever the program uses it as, as long as the
+    // command line can give it: an int or a bool
+    let input_t = infer.fresh();
+    let t = infer.infer(p, &HashMap::from([(INPUT.to_string(), mono(input_t.clone()))]))?;
+    infer.solve_pending()?;
+    infer.default_arith()?;
+    infer.solve_pending()?;
+    if let Some(constraint) = infer.pending.first() {
+        return Err(CompileErr::UnknownTupleSize { location: constraint.location.clone() });
+    }
+    let input_t = infer.resolve(&input_t);
+    match input_t {
+        Type::Int | Type::Bool | Type::Var(_) => {},
+        ref t => {
+            return Err(CompileErr::TypeMismatch {
+                expected: String::from("int or bool"),
+                found: type_to_string(t, &mut HashMap::new()),
+                location: exp_ann(p).clone(),
+            })
+        }
+    }
+    Ok((infer.resolve(&t), input_t))
+}
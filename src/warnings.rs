@@ -0,0 +1,329 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::syntax::{exp_ann, Exp, SurfProg};
+
+// ********************************************************************
+//
+//                             warnings
+//
+// ********************************************************************
+
+// Non-fatal diagnostics: the program compiles, but probably not into what
+// was meant. Like for CompileErr, the Span of a let binding is the Span of
+// its bound expression and the Span of a parameter is its function's.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CompileWarning<Span> {
+    UnusedVariable {
+        name: String,
+        location: Span,
+    },
+    UnusedParameter {
+        name: String,
+        location: Span,
+    },
+    UnusedFunction {
+        name: String,
+        location: Span,
+    },
+    Shadowing {
+        name: String,
+        location: Span,
+        previous: Span, // the binding that is shadowed
+    },
+    // the branch that is never taken by an if on a constant condition
+    DeadBranch {
+        cond: bool,
+        location: Span,
+    },
+}
+
+// The groups of warnings that can be turned on and off from the command line
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Lint {
+    UnusedVariables,
+    UnusedParameters,
+    UnusedFunctions,
+    Shadowing,
+    DeadCode,
+}
+
+impl Lint {
+    pub const ALL: [Lint; 5] = [
+        Lint::UnusedVariables,
+        Lint::UnusedParameters,
+        Lint::UnusedFunctions,
+        Lint::Shadowing,
+        Lint::DeadCode,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lint::UnusedVariables => "unused-variables",
+            Lint::UnusedParameters => "unused-parameters",
+            Lint::UnusedFunctions => "unused-functions",
+            Lint::Shadowing => "shadowing",
+            Lint::DeadCode => "dead-code",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Lint> {
+        Lint::ALL.iter().copied().find(|lint| lint.name() == name)
+    }
+}
+
+// The lints that are reported, all of them unless allowed
+#[derive(Clone, Debug, Default)]
+pub struct Lints {
+    allowed: HashSet<Lint>,
+}
+
+impl Lints {
+    pub fn allow(&mut self, lint: Lint) {
+        self.allowed.insert(lint);
+    }
+
+    pub fn warn(&mut self, lint: Lint) {
+        self.allowed.remove(&lint);
+    }
+
+    pub fn is_enabled(&self, lint: Lint) -> bool {
+        !self.allowed.contains(&lint)
+    }
+}
+
+impl<Span> CompileWarning<Span> {
+    pub fn lint(&self) -> Lint {
+        match self {
+            CompileWarning::UnusedVariable { .. } => Lint::UnusedVariables,
+            CompileWarning::UnusedParameter { .. } => Lint::UnusedParameters,
+            CompileWarning::UnusedFunction { .. } => Lint::UnusedFunctions,
+            CompileWarning::Shadowing { .. } => Lint::Shadowing,
+            CompileWarning::DeadBranch { .. } => Lint::DeadCode,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            CompileWarning::UnusedVariable { name, .. } => format!("unused variable `{}`", name),
+            CompileWarning::UnusedParameter { name, .. } => format!("unused parameter `{}`", name),
+            CompileWarning::UnusedFunction { name, .. } => format!("function `{}` is never used", name),
+            CompileWarning::Shadowing { name, .. } => format!("`{}` shadows an earlier binding", name),
+            CompileWarning::DeadBranch { cond, .. } => {
+                format!("unreachable branch, the condition is always {}", cond)
+            }
+        }
+    }
+
+    pub fn location(&self) -> &Span {
+        match self {
+            CompileWarning::UnusedVariable { location, .. }
+            | CompileWarning::UnusedParameter { location, .. }
+            | CompileWarning::UnusedFunction { location, .. }
+            | CompileWarning::Shadowing { location, .. }
+            | CompileWarning::DeadBranch { location, .. } => location,
+        }
+    }
+
+    // Other locations relevant to the warning
+    pub fn notes(&self) -> Vec<(&str, &Span)> {
+        match self {
+            CompileWarning::Shadowing { previous, .. } => vec![("previous binding here", previous)],
+            _ => vec![],
+        }
+    }
+
+    pub fn map_span<F, SpanPrime>(self, f: F) -> CompileWarning<SpanPrime>
+    where
+        F: Fn(&Span) -> SpanPrime,
+    {
+        match self {
+            CompileWarning::UnusedVariable { name, location } => CompileWarning::UnusedVariable {
+                name,
+                location: f(&location),
+            },
+            CompileWarning::UnusedParameter { name, location } => CompileWarning::UnusedParameter {
+                name,
+                location: f(&location),
+            },
+            CompileWarning::UnusedFunction { name, location } => CompileWarning::UnusedFunction {
+                name,
+                location: f(&location),
+            },
+            CompileWarning::Shadowing { name, location, previous } => CompileWarning::Shadowing {
+                name,
+                location: f(&location),
+                previous: f(&previous),
+            },
+            CompileWarning::DeadBranch { cond, location } => CompileWarning::DeadBranch {
+                cond,
+                location: f(&location),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BindingKind {
+    Variable,
+    Parameter,
+    Function,
+}
+
+struct Binding<Span> {
+    name: String,
+    kind: BindingKind,
+    location: Span,
+    used: bool,
+}
+
+// (bindings) has every binding seen so far, scopes map names to them.
+// Functions and variables share a namespace, as in check_prog.
+struct Analysis<Span> {
+    bindings: Vec<Binding<Span>>,
+    warnings: Vec<CompileWarning<Span>>,
+}
+
+type Scope = HashMap<String, usize>;
+
+impl<Span: Clone> Analysis<Span> {
+    fn bind(&mut self, scope: &mut Scope, name: &str, kind: BindingKind, location: &Span) -> usize {
+        if let Some(&previous) = scope.get(name) {
+            self.warnings.push(CompileWarning::Shadowing {
+                name: name.to_string(),
+                location: location.clone(),
+                previous: self.bindings[previous].location.clone(),
+            });
+        }
+        self.bindings.push(Binding {
+            name: name.to_string(),
+            kind,
+            location: location.clone(),
+            used: false,
+        });
+        scope.insert(name.to_string(), self.bindings.len() - 1);
+        self.bindings.len() - 1
+    }
+
+    fn use_name(&mut self, scope: &Scope, name: &str) {
+        if let Some(&ix) = scope.get(name) {
+            self.bindings[ix].used = true;
+        }
+    }
+
+    fn check_used(&mut self, ix: usize) {
+        let b = &self.bindings[ix];
+        if b.used {
+            return;
+        }
+        let name = b.name.clone();
+        let location = b.location.clone();
+        self.warnings.push(match b.kind {
+            BindingKind::Variable => CompileWarning::UnusedVariable { name, location },
+            BindingKind::Parameter => CompileWarning::UnusedParameter { name, location },
+            BindingKind::Function => CompileWarning::UnusedFunction { name, location },
+        });
+    }
+
+    fn params(&mut self, scope: &mut Scope, parameters: &[String], location: &Span) -> Vec<usize> {
+        parameters
+            .iter()
+            .map(|x| self.bind(scope, x, BindingKind::Parameter, location))
+            .collect()
+    }
+
+    fn exp(&mut self, e: &Exp<Span>, scope: &Scope) {
+        match e {
+            Exp::Num(..) | Exp::Float(..) | Exp::Bool(..) | Exp::Str(..) => {}
+            Exp::Var(x, _) => self.use_name(scope, x),
+            Exp::Prim(_, args, _) => {
+                for arg in args.iter() {
+                    self.exp(arg, scope);
+                }
+            }
+            Exp::Let { bindings, body, .. } => {
+                let mut inner = scope.clone();
+                let mut ixs = Vec::new();
+                for (x, bound) in bindings.iter() {
+                    self.exp(bound, &inner);
+                    ixs.push(self.bind(&mut inner, x, BindingKind::Variable, exp_ann(bound)));
+                }
+                self.exp(body, &inner);
+                for ix in ixs {
+                    self.check_used(ix);
+                }
+            }
+            Exp::If { cond, thn, els, .. } => {
+                if let Exp::Bool(b, _) = cond.as_ref() {
+                    let dead = if *b { els } else { thn };
+                    self.warnings.push(CompileWarning::DeadBranch {
+                        cond: *b,
+                        location: exp_ann(dead).clone(),
+                    });
+                }
+                self.exp(cond, scope);
+                self.exp(thn, scope);
+                self.exp(els, scope);
+            }
+            Exp::FunDefs { decls, body, .. } => {
+                let mut inner = scope.clone();
+                let funs: Vec<usize> = decls
+                    .iter()
+                    .map(|decl| self.bind(&mut inner, &decl.name, BindingKind::Function, &decl.ann))
+                    .collect();
+                for (decl, &fun) in decls.iter().zip(funs.iter()) {
+                    // a function that only calls itself is still unused
+                    let used = self.bindings[fun].used;
+                    let mut decl_scope = inner.clone();
+                    let params = self.params(&mut decl_scope, &decl.parameters, &decl.ann);
+                    self.exp(&decl.body, &decl_scope);
+                    self.bindings[fun].used = used;
+                    for ix in params {
+                        self.check_used(ix);
+                    }
+                }
+                self.exp(body, &inner);
+                for ix in funs {
+                    self.check_used(ix);
+                }
+            }
+            Exp::Lambda { parameters, body, ann } => {
+                let mut inner = scope.clone();
+                let params = self.params(&mut inner, parameters, ann);
+                self.exp(body, &inner);
+                for ix in params {
+                    self.check_used(ix);
+                }
+            }
+            Exp::Seq { first, second, .. } => {
+                self.exp(first, scope);
+                self.exp(second, scope);
+            }
+            Exp::Call(f, args, _) => {
+                self.use_name(scope, f);
+                for arg in args.iter() {
+                    self.exp(arg, scope);
+                }
+            }
+            Exp::ClosureCall { fun, args, .. } => {
+                self.exp(fun, scope);
+                for arg in args.iter() {
+                    self.exp(arg, scope);
+                }
+            }
+            Exp::InternalTailCall(..) | Exp::ExternalCall { .. } | Exp::MakeClosure { .. } => {
+                panic!("internal compiler forms shouldn't occur before lambda lifting")
+            }
+        }
+    }
+}
+
+// The warnings of a program that passed check_prog, in the order they are
+// found
+pub fn check_warnings<Span: Clone>(p: &SurfProg<Span>) -> Vec<CompileWarning<Span>> {
+    let mut analysis = Analysis {
+        bindings: Vec::new(),
+        warnings: Vec::new(),
+    };
+    analysis.exp(p, &HashMap::new());
+    analysis.warnings
+}
@@ -1,4 +1,17 @@
+use snake::pretty::Style;
 use snake::runner;
+use snake::runner::{Backend, RunnerErr};
+use snake::span::Span2;
+use snake::syntax::{map_ann, SurfProg};
+use std::path::Path;
+
+type Compiler = fn(&Path, &Path, &mut Vec<u8>) -> Result<(), RunnerErr<Span2>>;
+
+const BACKENDS: [Compiler; 3] = [
+    runner::compile_and_run_file,
+    runner::compile_and_run_elf_file,
+    |p, _, out| runner::jit_file(p, out),
+];
 
 macro_rules! mk_test {
     ($test_name:ident, $file_name:expr, $expected_output:expr) => {
@@ -18,41 +31,727 @@ macro_rules! mk_fail_test {
     };
 }
 
+// type check the program and run it without runtime tag checks
+macro_rules! mk_typed_test {
+    ($test_name:ident, $file_name:expr, $expected_output:expr) => {
+        #[test]
+        fn $test_name() -> std::io::Result<()> {
+            test_example_file_with($file_name, $expected_output, runner::compile_and_run_typed_file)
+        }
+    };
+}
+
+macro_rules! mk_typed_fail_test {
+    ($test_name:ident, $file_name:expr, $expected_output:expr) => {
+        #[test]
+        fn $test_name() -> std::io::Result<()> {
+            test_example_fail_with($file_name, $expected_output, runner::compile_and_run_typed_file)
+        }
+    };
+}
+
+// compile and run the program with each backend: nasm, the ELF backend
+// and the jit, which runs it in memory without linking it
+macro_rules! mk_backend_test {
+    ($test_name:ident, $file_name:expr, $expected_output:expr) => {
+        #[test]
+        fn $test_name() -> std::io::Result<()> {
+            for compile_and_run in BACKENDS.iter() {
+                test_example_file_with($file_name, $expected_output, *compile_and_run)?;
+            }
+            Ok(())
+        }
+    };
+}
+
+macro_rules! mk_backend_fail_test {
+    ($test_name:ident, $file_name:expr, $expected_output:expr) => {
+        #[test]
+        fn $test_name() -> std::io::Result<()> {
+            for compile_and_run in BACKENDS.iter() {
+                test_example_fail_with($file_name, $expected_output, *compile_and_run)?;
+            }
+            Ok(())
+        }
+    };
+}
+
 /*
  * YOUR TESTS GO HERE
  */
 
 
- mk_fail_test!(g1, "g1.garter", "arithmetic expected a number but got a boolean"); /* Garter tests */
- mk_fail_test!(g2, "g2.garter", "comparison expected an integer but got a boolean"); /* Garter tests */
- mk_fail_test!(g3, "g3.garter", "comparison expected an integer but got a floating point"); /* Garter tests */
- mk_fail_test!(g4, "g4.garter", "expected a boolean but got a numbern"); /* Garter tests */
- mk_test!(g5, "g5.garter", "4.3415926"); /* Garter tests */
- mk_test!(g6, "g6.garter", "7.0\n-1.0\n12.0\n0.75"); /* Garter tests */
- mk_fail_test!(g7, "g7.garter", "if logic expected a boolean but got a number"); /* Garter tests */
- mk_fail_test!(g8, "g8.garter", "overflow"); /* Garter tests */
- mk_fail_test!(g9, "g9.garter", "divided by zero"); /* Garter tests */
+ mk_fail_test!(g1, "g1.snake", "arithmetic expected a number but got a boolean"); /* Garter tests */
+ mk_fail_test!(g2, "g2.snake", "comparison expected an integer but got a boolean"); /* Garter tests */
+ mk_fail_test!(g3, "g3.snake", "comparison expected an integer but got a floating point"); /* Garter tests */
+ mk_fail_test!(g4, "g4.snake", "expected a boolean but got a number"); /* Garter tests */
+ mk_test!(g5, "g5.snake", "4.3415926"); /* Garter tests */
+ mk_backend_test!(g6, "g6.snake", "7.0\n-1.0\n12.0\n0.75\n0.75"); /* Garter tests */
+ mk_fail_test!(g7, "g7.snake", "if expected a boolean but got a number"); /* Garter tests */
+ mk_backend_fail_test!(g8, "g8.snake", "overflow"); /* Garter tests */
+ mk_fail_test!(g9, "g9.snake", "divided by zero"); /* Garter tests */
+
+ mk_test!(float_add1, "float_add1.snake", "7.0");
+ mk_test!(div_ints, "div_ints.snake", "1.5");
+ mk_test!(float_neg, "float_neg.snake", "5.0");
+ mk_fail_test!(int_overflow, "int_overflow.snake", "overflow");
+ mk_fail_test!(div_zero_int, "div_zero_int.snake", "divided by zero");
+
+ mk_test!(comments, "comments.snake", "3");
+ mk_fail_test!(unterminated_comment, "unterminated_comment.snake", "unterminated block comment");
+
+ mk_backend_test!(tuples, "tuples.snake", "(7,)\n(1, (2.5, true), ())");
+ mk_test!(tuple_index, "tuple_index.snake", "80");
+ mk_test!(tuple_set, "tuple_set.snake", "(((4, 5), 2, false), true, false, true, false)");
+ mk_backend_test!(tuple_cycle, "tuple_cycle.snake", "(1, <loop>)");
+ mk_backend_fail_test!(tuple_bounds, "tuple_bounds.snake", "index out of bounds");
+ mk_fail_test!(tuple_not_tuple, "tuple_not_tuple.snake", "indexing expected a tuple but got an integer");
+ mk_fail_test!(tuple_bad_index, "tuple_bad_index.snake", "index expected an integer but got a boolean");
+ mk_fail_test!(tuple_arith, "tuple_arith.snake", "arithmetic expected a number but got a tuple");
+
+ mk_test!(lambda, "lambda.snake", "15");
+ mk_test!(closure_compose, "closure_compose.snake", "(8, 7)");
+ mk_test!(closure_map, "closure_map.snake", "((4, 5), (false, true))");
+ mk_backend_test!(closure_rec, "closure_rec.snake", "(200000, 1024)");
+ mk_test!(closure_shadow, "closure_shadow.snake", "<closure>\n(2, 10, <closure>)");
+ mk_backend_test!(closure_eq, "closure_eq.snake", "(true, true, true, false)");
+ mk_typed_test!(typed_closure_eq, "closure_eq.snake", "(true, true, true, false)");
+ mk_fail_test!(call_non_function, "call_non_function.snake", "call expected a function but got an integer");
+ mk_backend_fail_test!(closure_arity, "closure_arity.snake", "wrong number of arguments");
+
+ mk_test!(local_fun_captures, "local_fun_captures.snake", "1234");
+ mk_test!(local_fun_unused, "local_fun_unused.snake", "6");
+ mk_backend_test!(gc_garbage, "gc_garbage.snake", "(500000500000, 42)");
+ mk_backend_test!(gc_deep, "gc_deep.snake", "50005000");
+ mk_backend_fail_test!(gc_oom, "gc_oom.snake", "out of memory");
+
+ mk_backend_test!(regalloc_pressure, "regalloc_pressure.snake", "(160005000000, (1, 2), 21, 15)");
+ mk_typed_test!(typed_regalloc_pressure, "regalloc_pressure.snake", "(160005000000, (1, 2), 21, 15)");
+
+ mk_test!(fold_consts, "fold_consts.snake", "14\n(15, 6, true, false)");
+ mk_fail_test!(fold_keeps_errors, "fold_keeps_errors.snake", "overflow");
+ mk_fail_test!(fold_keeps_type_errors, "fold_keeps_type_errors.snake", "arithmetic expected a number but got a boolean");
+
+ mk_fail_test!(many_errors, "many_errors.snake", "aborting due to 4 previous errors");
+ mk_fail_test!(many_errors_unbound_z, "many_errors.snake", "Unbound variable z");
+ mk_fail_test!(many_errors_arity, "many_errors.snake", "function g of arity 1 called with 2 arguments");
+
+ mk_typed_test!(typed_poly, "typed_poly.snake", "(8, true, 3.5, false)");
+ mk_typed_test!(typed_closure_rec, "closure_rec.snake", "(200000, 1024)");
+ mk_typed_test!(typed_gc_garbage, "gc_garbage.snake", "(500000500000, 42)");
+ mk_typed_fail_test!(typed_mismatch, "typed_mismatch.snake", "type mismatch: expected a number but found bool");
+ mk_typed_fail_test!(typed_infinite, "typed_infinite.snake", "infinite type");
+ mk_typed_fail_test!(typed_tuple_size, "typed_tuple_size.snake", "cannot infer the size of the tuple");
+ mk_typed_fail_test!(typed_not_tuple, "tuple_not_tuple.snake", "expected a tuple but found int");
+ mk_typed_fail_test!(typed_bounds, "tuple_bounds.snake", "expected a tuple of at least 3 elements");
+ mk_typed_test!(typed_floats, "g6.snake", "7.0\n-1.0\n12.0\n0.75\n0.75");
+ mk_typed_test!(typed_promotion, "typed_promotion.snake", "(3.5, 3.5, 1, 1.5, 0.75)");
+ mk_backend_test!(promotion, "typed_promotion.snake", "(3.5, 3.5, 1, 1.5, 0.75)");
+
+ mk_backend_test!(strings, "strings.snake", "hello, world\nsay \"hi\"\tthen\\leave\nworld\n(12, \"say \\\"hi\\\"\\tthen\\\\leave\", -34, \"-34!\", \"2.5\", true, false, true, true)");
+ mk_backend_test!(string_gc, "string_gc.snake", "(\"<11><10><9><8><7><6><5><4><3><2><1>\", 35, 12)");
+ mk_fail_test!(string_substr_bounds, "string_substr_bounds.snake", "index out of bounds 6");
+ mk_fail_test!(string_not_string, "string_not_string.snake", "string operation expected a string but got a tuple");
+ mk_backend_fail_test!(string_tonum_bad, "string_tonum_bad.snake", "tonum expected the digits of an integer but got \"12ab\"");
+ mk_typed_test!(typed_string_gc, "string_gc.snake", "(\"<11><10><9><8><7><6><5><4><3><2><1>\", 35, 12)");
+ mk_typed_fail_test!(typed_string_not_string, "string_not_string.snake", "type mismatch");
+
+ mk_fail_test!(input_reserved, "input_reserved.snake", "input is reserved for the input of the program");
+ mk_typed_fail_test!(typed_input_string, "input_string.snake", "type mismatch: expected int or bool but found string");
+ mk_typed_test!(typed_input_bool, "input_bool.snake", "2");
+ mk_typed_fail_test!(typed_input_default, "input_int.snake", "input expected an integer but got a boolean false");
+
+ mk_test!(seq, "seq.snake", "3\n5\n6\n6\ntrue\n11\n22");
+ mk_backend_test!(seq_loop, "seq_loop.snake", "1000000");
+ mk_typed_test!(typed_seq, "seq.snake", "3\n5\n6\n6\ntrue\n11\n22");
+ mk_backend_test!(int_ops, "int_ops.snake", "(12, 3, false, 0)\n(-3, -2, -3, -1048581, 3)");
+ mk_typed_test!(typed_int_ops, "int_ops.snake", "(12, 3, false, 0)\n(-3, -2, -3, -1048581, 3)");
+ mk_backend_fail_test!(int_div_zero, "int_div_zero.snake", "divided by zero");
+ mk_fail_test!(int_div_overflow, "int_div_overflow.snake", "overflow");
+ mk_fail_test!(int_ops_float, "int_ops_float.snake", "integer arithmetic expected an integer");
+ mk_typed_fail_test!(typed_int_ops_float, "int_ops_float.snake", "type mismatch");
+ mk_backend_test!(short_circuit, "short_circuit.snake", "false\ntrue\nfalse\ntrue\nfalse\n3");
+ mk_typed_test!(typed_short_circuit, "short_circuit.snake", "false\ntrue\nfalse\ntrue\nfalse\n3");
+ mk_backend_fail_test!(short_circuit_left_err, "short_circuit_left_err.snake", "logic expected a boolean");
+ mk_fail_test!(short_circuit_right_err, "short_circuit_right_err.snake", "logic expected a boolean");
+
+// Runs the example with the command-line argument (arg) and (stdin) compiled,
+// type checked, in the jit and in the interpreter, the output or the error of each
+fn run_with_input(f: &str, arg: &str, stdin: &str) -> Vec<Result<String, String>> {
+    use_test_cache();
+    let path = format!("examples/{}", f);
+    let path = Path::new(&path);
+    let input = runner::ProgramInput { arg: Some(arg.to_string()), stdin: Some(stdin.as_bytes().to_vec()) };
+    let tmp_dir = tempfile::TempDir::new().unwrap();
+    let mut results = Vec::new();
+    for typed in [false, true] {
+        let mut out = Vec::new();
+        let res = runner::compile_and_run_with(path, typed, Backend::Elf, &input, tmp_dir.path(), &mut out);
+        results.push(res.map(|()| String::from_utf8(out).unwrap()).map_err(|e| e.to_string()));
+    }
+    let mut out = Vec::new();
+    let res = runner::jit_file_with(path, &input, &mut out);
+    results.push(res.map(|()| String::from_utf8(out).unwrap()).map_err(|e| e.to_string()));
+    let mut out = Vec::new();
+    let res = runner::interpret_file(path, &input, &mut out);
+    results.push(res.map(|()| String::from_utf8(out).unwrap()).map_err(|e| e.to_string()));
+    results
+}
+
+#[test]
+fn programs_read_their_input() {
+    for res in run_with_input("input.snake", "3", "1\n 2 \n-3\ntrue\n") {
+        assert_eq!(res, Ok(String::from("0\n")));
+    }
+    for res in run_with_input("input.snake", "2", "40\n2\nfalse") {
+        assert_eq!(res, Ok(String::from("42\n")));
+    }
+}
+
+#[test]
+fn bad_input_is_a_runtime_error() {
+    let cases = [
+        ("x", "", "input expected an integer or a boolean but got \"x\""),
+        ("2", "1\ntwo\n", "readnum expected an integer but got \"two\""),
+        ("1", "1\nyes\n", "readbool expected a boolean but got \"yes\""),
+        ("2", "1\n", "readnum expected an integer but got the end of the input"),
+    ];
+    for (arg, stdin, msg) in cases {
+        for res in run_with_input("input.snake", arg, stdin) {
+            let err = res.unwrap_err();
+            assert!(err.contains(msg), "expected {} but got {}", msg, err);
+        }
+    }
+}
+
+// typed code skips the tag checks on the input, so it checks the input
+// against the type inferred for it before it starts
+#[test]
+fn typed_input_is_checked() {
+    use_test_cache();
+    let cases = [
+        ("input_int.snake", "2", Ok("1\n")),
+        ("input_int.snake", "true", Err("input expected an integer but got a boolean true")),
+        ("input_bool.snake", "true", Ok("1\n")),
+        ("input_bool.snake", "3", Err("input expected a boolean but got an integer 3")),
+    ];
+    let tmp_dir = tempfile::TempDir::new().unwrap();
+    for (f, arg, expected) in cases {
+        let path = format!("examples/{}", f);
+        let input = runner::ProgramInput { arg: Some(arg.to_string()), stdin: None };
+        for backend in [Backend::Nasm, Backend::Elf] {
+            let mut out = Vec::new();
+            let res = runner::compile_and_run_with(Path::new(&path), true, backend, &input, tmp_dir.path(), &mut out);
+            match (res, expected) {
+                (Ok(()), Ok(expected)) => assert_eq!(String::from_utf8(out).unwrap(), expected),
+                (Err(e), Err(msg)) => assert!(e.to_string().contains(msg), "expected {} but got {}", msg, e),
+                (res, expected) => panic!("{} {} on {:?}: expected {:?} but got {:?}", f, arg, backend, expected, res.err().map(|e| e.to_string())),
+            }
+        }
+    }
+}
+
+// é is two bytes, and every backend prints the half of it substr leaves
+// as it is
+#[test]
+fn strings_are_printed_as_bytes() {
+    use_test_cache();
+    let path = Path::new("examples/string_utf8.snake");
+    let input = runner::ProgramInput::default();
+    let expected = b"6\nh\xc3\nh\xc3\xa9llo\n\xc3\n".to_vec();
+    let tmp_dir = tempfile::TempDir::new().unwrap();
+    for backend in [Backend::Nasm, Backend::Elf] {
+        let mut out = Vec::new();
+        runner::compile_and_run_with(path, false, backend, &input, tmp_dir.path(), &mut out).unwrap();
+        assert_eq!(out, expected, "{:?}", backend);
+    }
+    let mut out = Vec::new();
+    runner::jit_file(path, &mut out).unwrap();
+    assert_eq!(out, expected, "jit");
+    let mut out = Vec::new();
+    runner::interpret_file(path, &input, &mut out).unwrap();
+    assert_eq!(out, expected, "interpreter");
+}
+
+#[test]
+fn jit_keeps_output_before_errors() {
+    let mut out = Vec::new();
+    let err = runner::jit_file(Path::new("examples/print_then_fail.snake"), &mut out).unwrap_err();
+    assert_eq!(std::str::from_utf8(&out).unwrap(), "(1, 2)\n");
+    assert!(format!("{}", err).contains("arithmetic expected a number but got a boolean true"));
+
+    // the process survives the error and can run more code
+    let mut out = Vec::new();
+    runner::jit_file(Path::new("examples/tuples.snake"), &mut out).unwrap();
+    assert_eq!(std::str::from_utf8(&out).unwrap(), "(7,)\n(1, (2.5, true), ())\n");
+}
+
+#[test]
+fn elf_objects_are_relocatable() {
+    use snake::compile::compile_to_object;
+    use snake::lexer::Lexer;
+    use snake::parser::ProgParser;
+
+    let prog = ProgParser::new().parse(Lexer::new("print((1, 2.5))")).unwrap();
+    let obj = compile_to_object(&prog, false).unwrap();
+    assert_eq!(&obj[..4], b"\x7fELF");
+    assert_eq!(u16::from_le_bytes([obj[16], obj[17]]), 1);
+    let contains = |s: &[u8]| obj.windows(s.len()).any(|w| w == s);
+    assert!(contains(b"start_here\0"));
+    assert!(contains(b"print_snake_val\0"));
+    assert!(contains(b".rela.text\0"));
+}
+
+#[test]
+fn typed_skips_tag_checks() {
+    use snake::compile::{compile_to_string, compile_to_string_typed};
+    use snake::lexer::Lexer;
+    use snake::parser::ProgParser;
+
+    let src = std::fs::read_to_string("examples/typed_poly.snake").unwrap();
+    let prog = ProgParser::new().parse(Lexer::new(&src)).unwrap();
+    assert!(compile_to_string(&prog).unwrap().contains("Check Whether"));
+    assert!(!compile_to_string_typed(&prog).unwrap().contains("Check Whether"));
+}
+
+#[test]
+fn constants_are_folded() {
+    use snake::compile::compile_to_string;
+    use snake::lexer::Lexer;
+    use snake::parser::ProgParser;
+
+    let prog = ProgParser::new().parse(Lexer::new("let x = 3 in x * 4 + add1(2)")).unwrap();
+    let asm = compile_to_string(&prog).unwrap();
+    assert!(asm.contains("mov rax, 60"));
+    assert!(!asm.contains("Check Whether"));
+}
+
+#[test]
+fn repl_keeps_definitions() {
+    let input = "def f(x): x * 2\nlet t = (1, f(3))\nt[1] + y\nf(true)\nlet u = print(t[1]) + 1\n(t, u)\n";
+    let mut out = Vec::new();
+    runner::repl_session(input.as_bytes(), &mut out).unwrap();
+    let out = std::str::from_utf8(&out).unwrap();
+    assert!(out.contains("error: Unbound variable y"), "{}", out);
+    assert!(out.contains("arithmetic expected a number"), "{}", out);
+    assert!(out.ends_with("> 6\n> ((1, 6), 7)\n> \n"), "{}", out);
+}
+
+#[test]
+fn repl_continues_incomplete_entries() {
+    let input = "def g(n):\n  if n == 0: 0\n  else: n + g(n - 1)\ng(100)\nlet x = (\n\nx\n";
+    let mut out = Vec::new();
+    runner::repl_session(input.as_bytes(), &mut out).unwrap();
+    let out = std::str::from_utf8(&out).unwrap();
+    assert!(out.contains("5050"), "{}", out);
+    assert!(out.contains("unexpected end of input"), "{}", out);
+    assert!(out.contains("Unbound variable x"), "{}", out);
+}
+
+// the diagnostics printed by the snake binary
+fn diagnostics(f: &str) -> String {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_snake"))
+        .arg(format!("examples/{}", f))
+        .output()
+        .unwrap();
+    assert!(!out.status.success());
+    String::from_utf8(out.stderr).unwrap()
+}
+
+#[test]
+fn diagnostics_quote_the_source() {
+    let err = diagnostics("dup_binding.snake");
+    assert_eq!(
+        err,
+        "\
+error: Variable x defined twice in let-expression
+ --> examples/dup_binding.snake:3:9
+  |
+3 |     x = 3 in
+  |         ^
+note: previous binding here
+ --> examples/dup_binding.snake:1:9
+  |
+1 | let x = 1,
+  |         ^
+
+error: aborting due to 1 previous error
+"
+    );
+}
+
+#[test]
+fn diagnostics_list_expected_tokens() {
+    let err = diagnostics("parse_eof.snake");
+    assert!(err.starts_with("error: unexpected end of input\n --> examples/parse_eof.snake:1:13\n"), "{}", err);
+    assert!(err.contains("  = expected one of \"!\", \"(\""), "{}", err);
+}
+
+#[test]
+fn warnings_are_found() {
+    use snake::lexer::Lexer;
+    use snake::parser::ProgParser;
+    use snake::warnings::{check_warnings, CompileWarning};
+
+    let src = std::fs::read_to_string("examples/warnings.snake").unwrap();
+    let prog = ProgParser::new().parse(Lexer::new(&src)).unwrap();
+    let found: Vec<String> = check_warnings(&prog)
+        .iter()
+        .map(|w| match w {
+            CompileWarning::UnusedVariable { name, .. }
+            | CompileWarning::UnusedParameter { name, .. }
+            | CompileWarning::UnusedFunction { name, .. }
+            | CompileWarning::Shadowing { name, .. } => format!("{}: {}", w.lint().name(), name),
+            CompileWarning::DeadBranch { cond, .. } => format!("{}: {}", w.lint().name(), cond),
+        })
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            "unused-parameters: unused_param",
+            "unused-parameters: w",
+            "shadowing: a",
+            "dead-code: true",
+            "unused-variables: c",
+            "unused-functions: never",
+        ]
+    );
+}
+
+#[test]
+fn warnings_can_be_allowed() {
+    let run = |flags: &[&str]| {
+        let out = std::process::Command::new(env!("CARGO_BIN_EXE_snake"))
+            .args(flags)
+            .args(["--interp", "examples/warnings.snake"])
+            .output()
+            .unwrap();
+        assert_eq!(String::from_utf8(out.stdout).unwrap(), "3\n");
+        String::from_utf8(out.stderr).unwrap()
+    };
+    let all = run(&[]);
+    assert!(all.contains("warning: unused variable `c`\n --> examples/warnings.snake:4:23"), "{}", all);
+    assert!(all.contains("warning: 6 warnings emitted"), "{}", all);
+    let some = run(&["-A", "all", "-W", "shadowing"]);
+    assert!(some.starts_with("warning: `a` shadows an earlier binding"), "{}", some);
+    assert!(some.contains("warning: 1 warning emitted"), "{}", some);
+    assert_eq!(run(&["-A", "all"]), "");
+}
+
+// every example that passes the checks prints the same and fails with the
+// same error when interpreted
+#[test]
+fn examples_agree_with_the_interpreter() {
+    use_test_cache();
+    let tmp_dir = tempfile::TempDir::new().unwrap();
+    let mut divergences = Vec::new();
+    for path in example_paths().iter() {
+        match runner::diff_file(path, Backend::Elf, tmp_dir.path()) {
+            Ok(comparison) if comparison.agrees() => {}
+            Ok(comparison) => divergences.push(format!("{}: {}", path.display(), comparison)),
+            Err(RunnerErr::Lex { .. }) | Err(RunnerErr::Parse { .. }) | Err(RunnerErr::Check(_)) => {}
+            Err(e) => divergences.push(format!("{}: {}", path.display(), e)),
+        }
+    }
+    assert!(divergences.is_empty(), "{}", divergences.join("\n"));
+}
+
+#[test]
+fn diff_compares_error_codes() {
+    use snake::diff::{Comparison, Ending, Outcome};
+
+    use_test_cache();
+    let tmp_dir = tempfile::TempDir::new().unwrap();
+    let comparison = runner::diff_file(Path::new("examples/print_then_fail.snake"), Backend::Elf, tmp_dir.path()).unwrap();
+    let expected = Outcome {
+        stdout: String::from("(1, 2)"),
+        ending: Ending::Failed(0),
+    };
+    assert_eq!(comparison.compiled, expected);
+    assert_eq!(comparison.interp, Some(expected));
+
+    let comparison = runner::diff_file(Path::new("examples/gc_oom.snake"), Backend::Elf, tmp_dir.path()).unwrap();
+    assert!(comparison.is_inconclusive());
 
+    let diverging = Comparison {
+        compiled: Outcome::of_compiled(b"1\n2\n", b"overflow\n", false),
+        interp: Some(Outcome {
+            stdout: String::from("1\n3"),
+            ending: Ending::Finished,
+        }),
+    };
+    assert!(!diverging.agrees());
+    let report = diverging.to_string();
+    assert!(report.contains("output differs at line 2"), "{}", report);
+    assert!(report.contains("compiled code failed with error 4"), "{}", report);
+}
 
+fn parse_source(src: &str) -> SurfProg<()> {
+    let prog = snake::parser::ProgParser::new()
+        .parse(snake::lexer::Lexer::new(src))
+        .unwrap_or_else(|e| panic!("{:?} in\n{}", e, src));
+    map_ann(&prog, &|_| ())
+}
+
+// the .snake files in examples/, in order
+fn example_paths() -> Vec<std::path::PathBuf> {
+    let mut examples: Vec<_> = std::fs::read_dir("examples")
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "snake"))
+        .collect();
+    examples.sort();
+    examples
+}
+
+fn example_programs() -> Vec<(std::path::PathBuf, SurfProg<()>)> {
+    example_paths()
+        .into_iter()
+        .filter_map(|path| {
+            let src = std::fs::read_to_string(&path).unwrap();
+            let prog = snake::parser::ProgParser::new().parse(snake::lexer::Lexer::new(&src)).ok()?;
+            Some((path, map_ann(&prog, &|_| ())))
+        })
+        .collect()
+}
+
+// printing and parsing back gives the same program, however it is laid out
+#[test]
+fn examples_print_and_parse_back() {
+    let styles = [Style::default(), Style { indent: 4, width: 100 }, Style { indent: 1, width: 10 }];
+    for (path, prog) in example_programs() {
+        for style in styles.iter() {
+            let src = snake::pretty::exp_to_string(&prog, style);
+            assert_eq!(parse_source(&src), prog, "{} printed as\n{}", path.display(), src);
+        }
+    }
+}
+
+// generated programs are well-formed and print back to the same program
+#[test]
+fn generated_programs_print_and_parse_back() {
+    for seed in 0..300 {
+        let prog = snake::fuzz::gen_prog(seed, 40);
+        let src = prog.to_string();
+        let parsed = parse_source(&src);
+        assert!(snake::compile::check_prog_all(&snake::desugar::desugar(&parsed)).is_empty(), "seed {}:\n{}", seed, src);
+        assert_eq!(parsed, prog, "seed {}:\n{}", seed, src);
+    }
+}
+
+#[test]
+fn printing_uses_minimal_parentheses() {
+    let cases = [
+        "(1 + 2) * 3 - (4 - 5) - 6",
+        "a && (b || c) && d || e",
+        "(a && b) && c",
+        "!(x < y) == (f(x) <= -1)",
+        "(if x: 1 else: 2) + add1(let y = 3 in y)",
+        "t[0][1] := (lambda (x): x end)(5)",
+        "((1,), (), (2, 3))[0]",
+        "f(print(1); 2, 3) + (print(4); 5)",
+        "g((let x = 1 in x); t[0] := (if b: 1 else: 2); t[0] := 3; t)",
+        "n & 1 == 0 && (a | b) ^ c << 2 + 1",
+        "(a & b) << 2 // (x % 3) - abs(min(x, y))",
+    ];
+    for case in cases.iter() {
+        assert_eq!(parse_source(case).to_string(), *case);
+    }
+}
+
+// ; binds looser than everything but the bodies of let, if and def
+#[test]
+fn sequences_extend_as_far_as_possible() {
+    let same = [
+        ("let x = 1 in print(x); x", "let x = 1 in (print(x); x)"),
+        ("if b: print(1); 2 else: print(3); 4", "if b: (print(1); 2) else: (print(3); 4)"),
+        ("t[0] := 1 + 2; t", "(t[0] := (1 + 2)); t"),
+        ("print(1) || b; 2", "(print(1) || b); 2"),
+        ("block: print(1); 2 end * 3", "(print(1); 2) * 3"),
+        ("a; b; c", "a; (b; c)"),
+    ];
+    for (src, bracketed) in same.iter() {
+        assert_eq!(parse_source(src), parse_source(bracketed), "{}", src);
+    }
+}
+
+#[test]
+fn format_keeps_comments() {
+    let style = Style::default();
+    let src = "# leading\n/* block */\nlet x = (1)   in x  +  2";
+    assert_eq!(runner::format_source(src, &style).unwrap(), "# leading\n/* block */\nlet x = 1 in\nx + 2\n");
+    let src = std::fs::read_to_string("examples/g6.snake").unwrap();
+    assert_eq!(
+        runner::format_source(&src, &style).unwrap(),
+        "\
+/* Garter tests */
+let a = 3 in
+let b = 4.0 in
+let PRINT_1 = print(a + b) in # 7.0
+let PRINT_2 = print(a - b) in # -1.0
+let PRINT_3 = print(a * b) in # 12.0
+print(a / b) # 0.75
+"
+    );
+    let src = "def f(x):\n  # doubles\n  x * 2 /* twice */ # done\nin\nf(1)";
+    assert_eq!(runner::format_source(src, &style).unwrap(), "def f(x):\n  # doubles\n  x * 2 /* twice */ # done\nin\nf(1)\n");
+    // every example keeps its program and its comments, and is formatted
+    // the same again
+    for (path, prog) in example_programs() {
+        let src = std::fs::read_to_string(&path).unwrap();
+        let formatted = runner::format_source(&src, &style).unwrap();
+        assert_eq!(parse_source(&formatted), prog, "{}", path.display());
+        let count = |s: &str| s.matches('#').count() + s.matches("/*").count();
+        assert_eq!(count(&formatted), count(&src), "{}", path.display());
+        assert_eq!(runner::format_source(&formatted, &style).unwrap(), formatted, "{}", path.display());
+    }
+}
+
+#[test]
+fn generated_programs_agree_with_the_interpreter() {
+    use_test_cache();
+    let tmp_dir = tempfile::TempDir::new().unwrap();
+    for seed in 0..10 {
+        let prog = snake::fuzz::gen_prog(seed, 30);
+        if let Some(failure) = runner::fuzz_prog(&prog, Backend::Elf, tmp_dir.path()) {
+            panic!("seed {}: {}\n{}", seed, failure, prog);
+        }
+    }
+}
+
+#[test]
+fn shrinking_keeps_programs_well_formed() {
+    use snake::syntax::{Exp, Prim};
+
+    fn prints(e: &Exp<()>) -> bool {
+        e.to_string().contains("print(")
+    }
+    let prog = (0..)
+        .map(|seed| snake::fuzz::gen_prog(seed, 30))
+        .find(|prog| prints(prog) && prog.to_string().contains("def "))
+        .unwrap();
+    let shrunk = snake::fuzz::shrink(prog, |candidate| {
+        snake::compile::check_prog_all(&snake::desugar::desugar(candidate)).is_empty() && prints(candidate)
+    });
+    assert_eq!(shrunk, Exp::Prim(Prim::Print, vec![Box::new(Exp::Num(0, ()))], ()));
+}
+
+#[test]
+fn emit_prints_every_stage() {
+    use snake::compile::Stage;
+
+    let path = Path::new("examples/closure_map.snake");
+    let emit = |stage| runner::emit_file(path, stage, false).unwrap();
+    let src = std::fs::read_to_string(path).unwrap();
+    assert_eq!(parse_source(&emit(Stage::Ast)), parse_source(&src));
+    assert!(emit(Stage::Uniq).contains("def make_adder#"), "{}", emit(Stage::Uniq));
+    // the lambda and the local function are lifted to the top
+    let lifted = emit(Stage::Lifted);
+    assert!(lifted.contains("\nand\ndef adder#") && lifted.contains("closure(lambda#"), "{}", lifted);
+    assert!(emit(Stage::Seq).contains("let #"), "{}", emit(Stage::Seq));
+    assert!(emit(Stage::Asm).starts_with("section .text"));
+    for stage in Stage::ALL.iter() {
+        assert_eq!(Stage::from_name(stage.name()), Some(*stage));
+    }
+    assert!(matches!(
+        runner::emit_file(Path::new("examples/typed_mismatch.snake"), Stage::Seq, true),
+        Err(RunnerErr::CodeGen(_))
+    ));
+}
+
+#[test]
+fn output_flag_builds_an_executable() {
+    use_test_cache();
+    let tmp_dir = tempfile::TempDir::new().unwrap();
+    let exe = tmp_dir.path().join("tuples");
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_snake"))
+        .arg("-o")
+        .arg(&exe)
+        .arg("examples/tuples.snake")
+        .output()
+        .unwrap();
+    assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+    assert!(out.stdout.is_empty());
+
+    let out = std::process::Command::new(&exe).output().unwrap();
+    assert_eq!(String::from_utf8(out.stdout).unwrap(), "(7,)\n(1, (2.5, true), ())\n");
+
+    // the modes that produce no executable refuse -o
+    for mode in ["--jit", "--interp", "--diff"] {
+        let out = std::process::Command::new(env!("CARGO_BIN_EXE_snake"))
+            .arg("-o")
+            .arg(&exe)
+            .args([mode, "examples/tuples.snake"])
+            .output()
+            .unwrap();
+        assert!(!out.status.success(), "{}", mode);
+        let stderr = String::from_utf8(out.stderr).unwrap();
+        assert!(stderr.starts_with(&format!("-o only combines with building or running a program, {}", mode)), "{}", stderr);
+    }
+}
+
+#[test]
+fn build_artifacts_can_be_kept() {
+    use_test_cache();
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_snake"))
+        .args(["--keep-artifacts", "--elf", "--run", "examples/g6.snake"])
+        .output()
+        .unwrap();
+    assert!(out.status.success());
+    let stderr = String::from_utf8(out.stderr).unwrap();
+    let dir = stderr
+        .lines()
+        .find_map(|line| line.strip_prefix("note: build artifacts kept in "))
+        .unwrap_or_else(|| panic!("no build directory in {}", stderr));
+    let dir = Path::new(dir);
+    assert!(dir.join("compiled_code.o").exists());
+    assert!(dir.join("stub.exe").exists());
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn runtime_is_built_once() {
+    let cache = tempfile::TempDir::new().unwrap();
+    let lib_dir = runner::build_runtime(cache.path()).unwrap();
+    assert!(lib_dir.starts_with(cache.path()));
+    let lib = lib_dir.join("libstub.a");
+    let built = std::fs::metadata(&lib).unwrap().modified().unwrap();
+
+    assert_eq!(runner::build_runtime(cache.path()).unwrap(), lib_dir);
+    assert_eq!(std::fs::metadata(&lib).unwrap().modified().unwrap(), built);
+    assert_eq!(std::fs::read_dir(cache.path()).unwrap().count(), 1);
+}
 
 
 
 // IMPLEMENTATION
+
+// Builds the runtime in the temporary directory cargo gives the tests, not
+// in the cache directory of the user, also for the snake binary they run
+fn use_test_cache() {
+    static SET: std::sync::Once = std::sync::Once::new();
+    SET.call_once(|| std::env::set_var("SNAKE_CACHE_DIR", Path::new(env!("CARGO_TARGET_TMPDIR")).join("runtime-cache")));
+}
+
 fn test_example_file(f: &str, expected_str: &str) -> std::io::Result<()> {
-    use std::path::Path;
+    test_example_file_with(f, expected_str, runner::compile_and_run_file)
+}
+
+fn test_example_file_with(f: &str, expected_str: &str, compile_and_run: Compiler) -> std::io::Result<()> {
+    use_test_cache();
     let p_name = format!("examples/{}", f);
     let path = Path::new(&p_name);
 
     // Test the compiler
     let tmp_dir = tempfile::TempDir::new()?;
     let mut w = Vec::new();
-    match runner::compile_and_run_file(&path, tmp_dir.path(), &mut w) {
+    match compile_and_run(path, tmp_dir.path(), &mut w) {
         Ok(()) => {
             let stdout = std::str::from_utf8(&w).unwrap();
             assert_eq!(stdout.trim(), expected_str)
         }
         Err(e) => {
-            assert!(false, "Expected {}, got an error: {}", expected_str, e)
+            panic!("Expected {}, got an error: {}", expected_str, e)
         }
     }
 
@@ -60,21 +759,24 @@ fn test_example_file(f: &str, expected_str: &str) -> std::io::Result<()> {
 }
 
 fn test_example_fail(f: &str, includes: &str) -> std::io::Result<()> {
-    use std::path::Path;
+    test_example_fail_with(f, includes, runner::compile_and_run_file)
+}
+
+fn test_example_fail_with(f: &str, includes: &str, compile_and_run: Compiler) -> std::io::Result<()> {
+    use_test_cache();
     let p_name = format!("examples/{}", f);
-    let path = Path::new(&p_name);
 
     // Test the compiler
     let tmp_dir = tempfile::TempDir::new()?;
     let mut w_run = Vec::new();
-    match runner::compile_and_run_file(
-        &Path::new(&format!("examples/{}", f)),
+    match compile_and_run(
+        Path::new(&p_name),
         tmp_dir.path(),
         &mut w_run,
     ) {
         Ok(()) => {
             let stdout = std::str::from_utf8(&w_run).unwrap();
-            assert!(false, "Expected a failure but got: {}", stdout.trim())
+            panic!("Expected a failure but got: {}", stdout.trim())
         }
         Err(e) => {
             let msg = format!("{}", e);